@@ -1,6 +1,7 @@
 use _corruption_engine::{
     DeleteRandomWordsOp, DeterministicRng, TextOperation, Operation, MotorWeighting,
-    ReduplicateWordsOp, SwapAdjacentWordsOp, TextBuffer, TypoOp,
+    OcrArtifactsOp, ReduplicateWordsOp, ReduplicationSeparator, SwapAdjacentWordsOp, TextBuffer,
+    TypoOp, WordWeighting,
 };
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use pprof::criterion::{Output, PProfProfiler};
@@ -51,10 +52,16 @@ fn create_typical_pipeline() -> Vec<Operation> {
         Operation::Reduplicate(ReduplicateWordsOp {
             rate: 0.05,
             unweighted: false,
+            repeats: 1,
+            stutter: false,
+            separator: ReduplicationSeparator::Space,
         }),
         Operation::Delete(DeleteRandomWordsOp {
             rate: 0.03,
             unweighted: false,
+            min_words: 0,
+            weighting: WordWeighting::Length,
+            preserve_spacing: false,
         }),
         Operation::SwapAdjacent(SwapAdjacentWordsOp { rate: 0.04 }),
     ]
@@ -174,6 +181,9 @@ fn bench_heavy_replace(c: &mut Criterion) {
                     let op = ReduplicateWordsOp {
                         rate: 0.2, // 20% of words
                         unweighted: false,
+                        repeats: 1,
+                        stutter: false,
+                        separator: ReduplicationSeparator::Space,
                     };
 
                     let _ = op.apply(&mut buffer, &mut rng);
@@ -207,6 +217,9 @@ fn bench_heavy_delete(c: &mut Criterion) {
                     let op = DeleteRandomWordsOp {
                         rate: 0.2, // 20% of words
                         unweighted: false,
+                        min_words: 0,
+                        weighting: WordWeighting::Length,
+                        preserve_spacing: false,
                     };
 
                     let _ = op.apply(&mut buffer, &mut rng);
@@ -274,6 +287,11 @@ fn bench_typo(c: &mut Criterion) {
                         layout: layout.clone(),
                         shift_slip: None,
                         motor_weighting: MotorWeighting::Uniform,
+                        grapheme_aware: false,
+                        burstiness: 0.0,
+                        homerow_bias: 0.0,
+                        keyboard: Default::default(),
+                        syllable_swaps: false,
                     };
 
                     let _ = op.apply(&mut buffer, &mut rng);
@@ -286,6 +304,29 @@ fn bench_typo(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark building many identical OCR ops with extra confusions; the merged
+/// table is cached, so only the first build should compile an automaton
+fn bench_ocr_extra_confusions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ocr_extra_confusions");
+
+    let extras = vec![
+        ("5".to_string(), vec!["S".to_string()]),
+        ("qu".to_string(), vec!["gu".to_string(), "qv".to_string()]),
+    ];
+
+    group.throughput(Throughput::Elements(10_000));
+    group.bench_function("build_10k_ops", |b| {
+        b.iter(|| {
+            for _ in 0..10_000 {
+                let op = OcrArtifactsOp::new(0.1).with_extra_confusions(black_box(&extras).clone());
+                black_box(op);
+            }
+        });
+    });
+
+    group.finish();
+}
+
 /// Benchmark scaling: measure how performance scales with input size
 fn bench_scaling(c: &mut Criterion) {
     let mut group = c.benchmark_group("scaling");
@@ -332,6 +373,7 @@ criterion_group! {
         bench_heavy_delete,
         bench_mixed_ops_large,
         bench_typo,
+        bench_ocr_extra_confusions,
         bench_scaling
 }
 
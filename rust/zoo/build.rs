@@ -106,6 +106,8 @@ fn emit_rerun_if_changed(path: &Path) -> io::Result<()> {
     }
 
     if path.is_dir() {
+        // Watch the directory itself so newly added files trigger a rebuild.
+        println!("cargo:rerun-if-changed={}", path.display());
         for entry in fs::read_dir(path)? {
             let entry = entry?;
             emit_rerun_if_changed(&entry.path())?;
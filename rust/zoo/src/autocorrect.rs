@@ -0,0 +1,157 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use serde::{Deserialize, Serialize};
+
+use crate::homophones::apply_casing;
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::resources::split_affixes_ref;
+use crate::text_buffer::TextBuffer;
+
+const RAW_AUTOCORRECT_WORDS: &str =
+    include_str!(concat!(env!("OUT_DIR"), "/autocorrect_words.txt"));
+
+/// Shortest core considered for correction; shorter words have too many
+/// neighbours for a "correction" to look plausible.
+const MIN_CORE_CHARS: usize = 3;
+
+/// Bundled dictionary mapping each word to its frequency rank (0 = most common).
+static AUTOCORRECT_RANKS: LazyLock<HashMap<&'static str, usize>> = LazyLock::new(|| {
+    RAW_AUTOCORRECT_WORDS
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .enumerate()
+        .map(|(rank, word)| (word, rank))
+        .collect()
+});
+
+/// Returns the most frequent dictionary word at Levenshtein distance 1 from
+/// `word` (lowercase ASCII), excluding `word` itself.
+fn nearest_neighbour(word: &str) -> Option<&'static str> {
+    let ranks = &*AUTOCORRECT_RANKS;
+    let chars: Vec<char> = word.chars().collect();
+    let mut best: Option<(&'static str, usize)> = None;
+    let mut consider = |candidate: String| {
+        if let Some((&entry, &rank)) = ranks.get_key_value(candidate.as_str()) {
+            if entry != word && best.is_none_or(|(_, best_rank)| rank < best_rank) {
+                best = Some((entry, rank));
+            }
+        }
+    };
+
+    for idx in 0..=chars.len() {
+        let (head, tail) = chars.split_at(idx);
+        if let Some((_, rest)) = tail.split_first() {
+            // Deletion.
+            consider(head.iter().chain(rest).collect());
+            // Substitution.
+            for letter in 'a'..='z' {
+                consider(head.iter().chain(&[letter]).chain(rest).collect());
+            }
+        }
+        // Insertion.
+        for letter in 'a'..='z' {
+            consider(head.iter().chain(&[letter]).chain(tail).collect());
+        }
+    }
+
+    best.map(|(entry, _)| entry)
+}
+
+/// Over-eager autocorrect ("ducking"): replaces words with the most common
+/// dictionary word one edit away.
+///
+/// Only alphabetic cores of at least three letters with a neighbour in the
+/// bundled frequency list are eligible; each is replaced with probability
+/// `rate`. Affixes and casing are preserved.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct AutocorrectOp {
+    pub rate: f64,
+}
+
+impl TextOperation for AutocorrectOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx) else {
+                continue;
+            };
+            if !segment.is_mutable() {
+                continue;
+            }
+
+            let (prefix, core, suffix) = split_affixes_ref(segment.text());
+            if core.chars().count() < MIN_CORE_CHARS
+                || !core.chars().all(|ch| ch.is_ascii_alphabetic())
+            {
+                continue;
+            }
+
+            let Some(correction) = nearest_neighbour(&core.to_ascii_lowercase()) else {
+                continue;
+            };
+
+            if rng.random()? >= rate * segment.rate_scale() {
+                continue;
+            }
+
+            let replacement = apply_casing(core, correction);
+            replacements.push((idx, format!("{prefix}{replacement}{suffix}")));
+        }
+
+        let edits = replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_words_bulk(replacements)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, seed=None, return_count=false))]
+pub(crate) fn autocorrect(
+    text: &str,
+    rate: f64,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = AutocorrectOp { rate };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{nearest_neighbour, AutocorrectOp};
+    use crate::operations::apply_seeded;
+
+    fn correct(text: &str, rate: f64, seed: u64) -> String {
+        apply_seeded(&AutocorrectOp { rate }, text, seed)
+    }
+
+    #[test]
+    fn neighbours_are_one_edit_away_and_most_frequent() {
+        assert_eq!(nearest_neighbour("fucking"), Some("ducking"));
+        assert_eq!(nearest_neighbour("thw"), Some("the"));
+        assert_eq!(nearest_neighbour("hous"), Some("house"));
+        assert_eq!(nearest_neighbour("xyzzy"), None);
+    }
+
+    #[test]
+    fn corrections_keep_affixes_and_casing() {
+        assert_eq!(correct("(Fucking) SHOP, xyzzy!", 1.0, 1), "(Ducking) SHOW, xyzzy!");
+        let text = "Teh dog ate hous food";
+        assert_eq!(correct(text, 0.5, 9), correct(text, 0.5, 9));
+        assert_eq!(correct(text, 0.0, 9), text);
+    }
+}
@@ -0,0 +1,102 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::resources::split_affixes_ref;
+use crate::text_buffer::TextBuffer;
+
+/// Directional overrides that open a wrapped span: RIGHT-TO-LEFT OVERRIDE and
+/// LEFT-TO-RIGHT OVERRIDE.
+pub(crate) const BIDI_OVERRIDES: &[char] = &['\u{202E}', '\u{202D}'];
+
+/// POP DIRECTIONAL FORMATTING, which closes every override span.
+pub(crate) const POP_DIRECTIONAL_FORMATTING: char = '\u{202C}';
+
+/// Wraps word cores in directional override spans ("Trojan Source" style).
+///
+/// Each selected word becomes `prefix + override + core + PDF + suffix`, so
+/// the stored characters are unchanged while their rendered order flips.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BidiOp {
+    pub rate: f64,
+}
+
+impl TextOperation for BidiOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx) else {
+                continue;
+            };
+            if !segment.is_mutable() {
+                continue;
+            }
+
+            let (prefix, core, suffix) = split_affixes_ref(segment.text());
+            if core.is_empty() {
+                continue;
+            }
+
+            if rng.random()? >= rate * segment.rate_scale() {
+                continue;
+            }
+
+            let control = BIDI_OVERRIDES[rng.rand_index(BIDI_OVERRIDES.len())?];
+            let replacement = format!("{prefix}{control}{core}{POP_DIRECTIONAL_FORMATTING}{suffix}");
+            replacements.push((idx, replacement));
+        }
+
+        let edits = replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_words_bulk(replacements)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, seed=None, return_count=false))]
+pub(crate) fn inject_bidi(
+    text: &str,
+    rate: f64,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = BidiOp { rate };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BidiOp, BIDI_OVERRIDES, POP_DIRECTIONAL_FORMATTING};
+    use crate::operations::apply_seeded;
+    use crate::zero_width::strip_zero_widths;
+
+    fn inject(text: &str, seed: u64) -> String {
+        apply_seeded(&BidiOp { rate: 1.0 }, text, seed)
+    }
+
+    #[test]
+    fn overrides_wrap_cores_and_strip_cleanly() {
+        let text = "\"Héllo,\" said the café owner.";
+        let result = inject(text, 42);
+        assert_eq!(result, inject(text, 42));
+        assert!(result.starts_with('"'));
+        assert!(result.ends_with(&format!("{POP_DIRECTIONAL_FORMATTING}.")));
+        let opened = result.chars().filter(|ch| BIDI_OVERRIDES.contains(ch)).count();
+        let closed = result.matches(POP_DIRECTIONAL_FORMATTING).count();
+        assert_eq!(opened, 5);
+        assert_eq!(closed, 5);
+        assert_eq!(strip_zero_widths(&result), text);
+    }
+}
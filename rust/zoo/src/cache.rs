@@ -10,7 +10,7 @@
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, PoisonError, RwLock};
 
 /// A thread-safe cache that uses content-based keys.
 ///
@@ -20,6 +20,9 @@ use std::sync::{Arc, RwLock};
 ///
 /// Values are stored as `Arc<V>` internally, so retrieval returns a cheap
 /// reference-counted pointer rather than cloning the entire value.
+///
+/// Entries are immutable once inserted, so a lock poisoned by a panicking
+/// thread is recovered rather than turning every later lookup into a miss.
 pub struct ContentCache<V> {
     data: RwLock<HashMap<u64, Arc<V>>>,
 }
@@ -35,7 +38,11 @@ impl<V> ContentCache<V> {
     ///
     /// Returns an `Arc<V>` for cheap access without deep cloning.
     pub fn get(&self, hash: u64) -> Option<Arc<V>> {
-        self.data.read().ok()?.get(&hash).cloned()
+        self.data
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&hash)
+            .cloned()
     }
 
     /// Get a cached value or insert it if not present.
@@ -52,14 +59,33 @@ impl<V> ContentCache<V> {
 
         // Slow path: compute and insert
         let value = Arc::new(f());
-        if let Ok(mut guard) = self.data.write() {
-            // Double-check in case another thread inserted while we were computing
-            if let Some(existing) = guard.get(&hash) {
-                return Arc::clone(existing);
-            }
-            guard.insert(hash, Arc::clone(&value));
+        let mut guard = self.data.write().unwrap_or_else(PoisonError::into_inner);
+        // Double-check in case another thread inserted while we were computing
+        Arc::clone(guard.entry(hash).or_insert(value))
+    }
+
+    /// Like [`ContentCache::get_or_insert_with`] for an already-built value,
+    /// but only reuses a cached entry that `matches` it.
+    ///
+    /// A 64-bit content hash can still collide; on a mismatch the fresh value
+    /// is returned uncached, so unrelated content never leaks between callers.
+    pub fn get_or_insert_matching<F>(&self, hash: u64, value: V, matches: F) -> Arc<V>
+    where
+        F: Fn(&V, &V) -> bool,
+    {
+        if let Some(cached) = self.get(hash) {
+            return if matches(&cached, &value) {
+                cached
+            } else {
+                Arc::new(value)
+            };
+        }
+        let mut value = Some(value);
+        let cached = self.get_or_insert_with(hash, || value.take().expect("value is present"));
+        match value {
+            Some(fresh) if !matches(&cached, &fresh) => Arc::new(fresh),
+            _ => cached,
         }
-        value
     }
 }
 
@@ -116,6 +142,19 @@ pub fn hash_shift_map(shift_map: &HashMap<String, String>) -> u64 {
     hasher.finish()
 }
 
+/// Whether two layout vectors hold the same entries, ignoring order (as
+/// [`hash_layout_vec`] does).
+pub fn layout_vecs_match(left: &[(String, Vec<String>)], right: &[(String, Vec<String>)]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut left: Vec<_> = left.iter().collect();
+    let mut right: Vec<_> = right.iter().collect();
+    left.sort_by_key(|(k, _)| k);
+    right.sort_by_key(|(k, _)| k);
+    left == right
+}
+
 /// Compute a content-based hash for a layout vector.
 pub fn hash_layout_vec(layout: &[(String, Vec<String>)]) -> u64 {
     let mut hasher = DefaultHasher::new();
@@ -179,4 +218,44 @@ mod tests {
         // Different content should produce different hash
         assert_ne!(hash_layout_map(&map1), hash_layout_map(&map2));
     }
+
+    #[test]
+    fn test_colliding_layouts_do_not_share_entries() {
+        let cache: ContentCache<Vec<(String, Vec<String>)>> = ContentCache::new();
+        let qwerty = vec![("a".to_string(), vec!["s".to_string()])];
+        let dvorak = vec![("a".to_string(), vec!["o".to_string()])];
+
+        // Force both layouts onto the same key, as a hash collision would.
+        let first = cache.get_or_insert_matching(7, qwerty.clone(), |a, b| layout_vecs_match(a, b));
+        let second = cache.get_or_insert_matching(7, dvorak.clone(), |a, b| layout_vecs_match(a, b));
+        assert_eq!(*first, qwerty);
+        assert_eq!(*second, dvorak);
+
+        let reordered = vec![
+            ("b".to_string(), vec!["v".to_string()]),
+            ("a".to_string(), vec!["s".to_string()]),
+        ];
+        let sorted = vec![reordered[1].clone(), reordered[0].clone()];
+        assert!(layout_vecs_match(&reordered, &sorted));
+        assert!(!layout_vecs_match(&qwerty, &dvorak));
+    }
+
+    #[test]
+    fn test_poisoned_cache_keeps_serving_entries() {
+        let cache: Arc<ContentCache<String>> = Arc::new(ContentCache::new());
+        cache.get_or_insert_with(1, || "kept".to_string());
+
+        let poisoner = Arc::clone(&cache);
+        let result = std::thread::spawn(move || {
+            let _guard = poisoner.data.write().unwrap();
+            panic!("poison the lock");
+        })
+        .join();
+        assert!(result.is_err());
+        assert!(cache.data.is_poisoned());
+
+        assert_eq!(cache.get(1).as_deref().map(String::as_str), Some("kept"));
+        let inserted = cache.get_or_insert_with(2, || "new".to_string());
+        assert!(Arc::ptr_eq(&inserted, &cache.get(2).expect("entry was cached")));
+    }
 }
@@ -0,0 +1,209 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::text_buffer::TextBuffer;
+
+const VALID_MODE_MESSAGE: &str = "random, alternating, random_words";
+
+/// How [`CaseOp`] rewrites letter casing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CaseMode {
+    /// Flip the case of each letter with probability `rate`.
+    Random,
+    /// sArCaSm cAsE: selected words follow a lower/upper parity that runs
+    /// across every letter of the text, starting lowercase.
+    Alternating,
+    /// Uppercase whole words with probability `rate`.
+    RandomWords,
+}
+
+impl CaseMode {
+    pub fn parse(mode: &str) -> Result<Self, String> {
+        let normalized = mode.to_ascii_lowercase().replace('-', "_");
+        match normalized.as_str() {
+            "random" => Ok(Self::Random),
+            "alternating" | "sarcasm" => Ok(Self::Alternating),
+            "random_words" | "words" => Ok(Self::RandomWords),
+            _ => Err(format!(
+                "Unsupported case mode '{mode}'. Expected one of: {VALID_MODE_MESSAGE}"
+            )),
+        }
+    }
+}
+
+#[inline]
+fn is_cased(ch: char) -> bool {
+    ch.is_uppercase() || ch.is_lowercase()
+}
+
+fn push_flipped(output: &mut String, ch: char) {
+    if ch.is_uppercase() {
+        output.extend(ch.to_lowercase());
+    } else if ch.is_lowercase() {
+        output.extend(ch.to_uppercase());
+    } else {
+        output.push(ch);
+    }
+}
+
+/// Randomises letter casing inside mutable word segments.
+///
+/// Digits, punctuation and other uncased characters pass through untouched.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CaseOp {
+    pub rate: f64,
+    pub mode: CaseMode,
+}
+
+impl TextOperation for CaseOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        // Parity for alternating mode; advanced for every cased letter so a
+        // word's pattern doesn't depend on which earlier words were selected.
+        let mut upper_next = false;
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx) else {
+                continue;
+            };
+            if !segment.is_mutable() {
+                continue;
+            }
+            let word = segment.text();
+            if !word.chars().any(is_cased) {
+                continue;
+            }
+            let rate = rate * segment.rate_scale();
+
+            let mut output = String::with_capacity(word.len());
+            match self.mode {
+                CaseMode::Random => {
+                    for ch in word.chars() {
+                        if is_cased(ch) && rng.random()? < rate {
+                            push_flipped(&mut output, ch);
+                        } else {
+                            output.push(ch);
+                        }
+                    }
+                }
+                CaseMode::Alternating => {
+                    let selected = rng.random()? < rate;
+                    for ch in word.chars() {
+                        if !is_cased(ch) {
+                            output.push(ch);
+                            continue;
+                        }
+                        if !selected {
+                            output.push(ch);
+                        } else if upper_next {
+                            output.extend(ch.to_uppercase());
+                        } else {
+                            output.extend(ch.to_lowercase());
+                        }
+                        upper_next = !upper_next;
+                    }
+                }
+                CaseMode::RandomWords => {
+                    if rng.random()? >= rate {
+                        continue;
+                    }
+                    output.extend(word.chars().flat_map(char::to_uppercase));
+                }
+            }
+
+            if output != word {
+                replacements.push((idx, output));
+            }
+        }
+
+        let edits = replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_words_bulk(replacements)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, mode, seed=None, return_count=false))]
+pub(crate) fn corrupt_case(
+    text: &str,
+    rate: f64,
+    mode: &str,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let mode = CaseMode::parse(mode).map_err(PyValueError::new_err)?;
+    let op = CaseOp { rate, mode };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CaseMode, CaseOp};
+    use crate::operations::{apply_seeded, TextOperation};
+    use crate::rng::DeterministicRng;
+    use crate::text_buffer::TextBuffer;
+
+    fn corrupt(text: &str, rate: f64, mode: CaseMode, seed: u64) -> String {
+        apply_seeded(&CaseOp { rate, mode }, text, seed)
+    }
+
+    #[test]
+    fn alternating_mode_has_stable_parity() {
+        assert_eq!(
+            corrupt("Sarcasm case, 100%!", 1.0, CaseMode::Alternating, 1),
+            "sArCaSm CaSe, 100%!"
+        );
+        assert_eq!(
+            corrupt("Sarcasm case", 1.0, CaseMode::Alternating, 1),
+            corrupt("Sarcasm case", 1.0, CaseMode::Alternating, 99)
+        );
+    }
+
+    #[test]
+    fn random_mode_flips_only_cased_letters() {
+        let text = "Straße 42, élan!";
+        let result = corrupt(text, 1.0, CaseMode::Random, 3);
+        assert_eq!(result, "sTRASSE 42, ÉLAN!");
+        let partial = corrupt(text, 0.5, CaseMode::Random, 3);
+        assert_eq!(partial, corrupt(text, 0.5, CaseMode::Random, 3));
+        assert!(partial.contains(" 42, "));
+    }
+
+    #[test]
+    fn random_words_uppercases_whole_words_and_skips_protected_spans() {
+        assert_eq!(
+            corrupt("quiet words here", 1.0, CaseMode::RandomWords, 5),
+            "QUIET WORDS HERE"
+        );
+        let exclude = vec![regex::Regex::new("keep").unwrap()];
+        let mut buffer = TextBuffer::from_owned("keep this".to_string(), &[], &exclude);
+        let mut rng = DeterministicRng::new(5);
+        CaseOp {
+            rate: 1.0,
+            mode: CaseMode::RandomWords,
+        }
+        .apply(&mut buffer, &mut rng)
+        .expect("case corruption succeeds");
+        assert_eq!(buffer.to_string(), "keep THIS");
+    }
+
+    #[test]
+    fn parse_accepts_aliases() {
+        assert_eq!(CaseMode::parse("Sarcasm"), Ok(CaseMode::Alternating));
+        assert_eq!(CaseMode::parse("random-words"), Ok(CaseMode::RandomWords));
+        assert!(CaseMode::parse("shouty").is_err());
+    }
+}
@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use smallvec::smallvec;
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::resources::split_affixes_ref;
+use crate::text_buffer::{Reduplication, TextBuffer};
+use crate::word_boundaries::mergeable_pairs;
+
+const VALID_MODE_MESSAGE: &str = "contract, expand";
+
+/// Contractions paired with their expansions, in canonical casing. Each side
+/// is unique so the table reads in either direction; ambiguous forms such as
+/// "it's" resolve to the `is` reading.
+const CONTRACTION_TABLE: &[(&str, &str)] = &[
+    ("I'm", "I am"),
+    ("I've", "I have"),
+    ("I'll", "I will"),
+    ("I'd", "I would"),
+    ("you're", "you are"),
+    ("you've", "you have"),
+    ("you'll", "you will"),
+    ("you'd", "you would"),
+    ("he's", "he is"),
+    ("he'll", "he will"),
+    ("he'd", "he would"),
+    ("she's", "she is"),
+    ("she'll", "she will"),
+    ("she'd", "she would"),
+    ("it's", "it is"),
+    ("it'll", "it will"),
+    ("we're", "we are"),
+    ("we've", "we have"),
+    ("we'll", "we will"),
+    ("we'd", "we would"),
+    ("they're", "they are"),
+    ("they've", "they have"),
+    ("they'll", "they will"),
+    ("they'd", "they would"),
+    ("that's", "that is"),
+    ("there's", "there is"),
+    ("what's", "what is"),
+    ("who's", "who is"),
+    ("let's", "let us"),
+    ("isn't", "is not"),
+    ("aren't", "are not"),
+    ("wasn't", "was not"),
+    ("weren't", "were not"),
+    ("haven't", "have not"),
+    ("hasn't", "has not"),
+    ("hadn't", "had not"),
+    ("don't", "do not"),
+    ("doesn't", "does not"),
+    ("didn't", "did not"),
+    ("won't", "will not"),
+    ("wouldn't", "would not"),
+    ("can't", "cannot"),
+    ("couldn't", "could not"),
+    ("shouldn't", "should not"),
+    ("mustn't", "must not"),
+    ("needn't", "need not"),
+];
+
+/// Lowercased contraction → canonical expansion.
+static EXPANSIONS: LazyLock<HashMap<String, &'static str>> = LazyLock::new(|| {
+    CONTRACTION_TABLE
+        .iter()
+        .map(|&(contraction, expansion)| (contraction.to_lowercase(), expansion))
+        .collect()
+});
+
+/// Lowercased expansion → canonical contraction.
+static CONTRACTIONS: LazyLock<HashMap<String, &'static str>> = LazyLock::new(|| {
+    CONTRACTION_TABLE
+        .iter()
+        .map(|&(contraction, expansion)| (expansion.to_lowercase(), contraction))
+        .collect()
+});
+
+/// Lowercases `core` and folds typographic apostrophes to ASCII for lookup.
+fn lookup_key(core: &str) -> String {
+    core.replace('\u{2019}', "'").to_lowercase()
+}
+
+/// Carries the casing of `source` onto the canonical table form: shouting
+/// stays uppercase and a leading capital survives, while a lowercase source
+/// keeps the canonical form so "i'm" still expands to "I am".
+fn match_casing(source: &str, canonical: &str) -> String {
+    let cased: Vec<char> = source
+        .chars()
+        .filter(|ch| ch.is_uppercase() || ch.is_lowercase())
+        .collect();
+    if cased.len() > 1 && cased.iter().all(|ch| ch.is_uppercase()) {
+        return canonical.to_uppercase();
+    }
+    if source.chars().next().is_some_and(char::is_uppercase) {
+        let mut chars = canonical.chars();
+        if let Some(first) = chars.next() {
+            return first.to_uppercase().chain(chars).collect();
+        }
+    }
+    canonical.to_string()
+}
+
+/// Direction [`ContractionOp`] rewrites in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContractionMode {
+    /// "do not" → "don't", "cannot" → "can't".
+    Contract,
+    /// "don't" → "do not", "can't" → "cannot".
+    Expand,
+}
+
+impl ContractionMode {
+    pub fn parse(mode: &str) -> Result<Self, String> {
+        match mode.to_ascii_lowercase().as_str() {
+            "contract" => Ok(Self::Contract),
+            "expand" => Ok(Self::Expand),
+            _ => Err(format!(
+                "Unsupported contraction mode '{mode}'. Expected one of: {VALID_MODE_MESSAGE}"
+            )),
+        }
+    }
+}
+
+/// Contracts or expands English contractions from a bidirectional table.
+///
+/// Each matching word (or, when contracting, adjacent word pair) is rewritten
+/// with probability `rate`. Two-word expansions only contract across plain
+/// whitespace with no punctuation between the words, mirroring
+/// [`crate::word_boundaries::MergeWordsOp`]. Affixes stay on the outer words
+/// and casing follows the source, so "I am" ↔ "I'm" and "DO NOT" ↔ "DON'T".
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ContractionOp {
+    pub rate: f64,
+    pub mode: ContractionMode,
+}
+
+impl ContractionOp {
+    fn contract(
+        &self,
+        buffer: &mut TextBuffer,
+        rng: &mut dyn OperationRng,
+        rate: f64,
+    ) -> Result<usize, OperationError> {
+        let pairs = mergeable_pairs(buffer);
+        let mut pairs = pairs.into_iter().peekable();
+        let mut merges: Vec<(usize, String)> = Vec::new();
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        let mut next_free = 0;
+        for idx in 0..buffer.word_count() {
+            while pairs.next_if(|&pair| pair < idx).is_some() {}
+            if idx < next_free {
+                continue;
+            }
+            let Some(first) = buffer.word_segment(idx).filter(|segment| segment.is_mutable()) else {
+                continue;
+            };
+            let (prefix, first_core, inner_suffix) = split_affixes_ref(first.text());
+            let rate = rate * first.rate_scale();
+
+            let pair_match = pairs
+                .next_if_eq(&idx)
+                .and_then(|_| buffer.word_segment(idx + 1))
+                .filter(|second| second.is_mutable() && inner_suffix.is_empty())
+                .and_then(|second| {
+                    let (inner_prefix, second_core, suffix) = split_affixes_ref(second.text());
+                    if !inner_prefix.is_empty() || first_core.is_empty() || second_core.is_empty() {
+                        return None;
+                    }
+                    let source = format!("{first_core} {second_core}");
+                    CONTRACTIONS
+                        .get(&lookup_key(&source))
+                        .map(|&canonical| (source, canonical, suffix))
+                });
+
+            if let Some((source, canonical, suffix)) = pair_match {
+                if rng.random()? < rate {
+                    let contracted = match_casing(&source, canonical);
+                    merges.push((idx, format!("{prefix}{contracted}{suffix}")));
+                    next_free = idx + 2;
+                }
+                continue;
+            }
+
+            if let Some(&canonical) = CONTRACTIONS.get(&lookup_key(first_core)) {
+                if rng.random()? < rate {
+                    let contracted = match_casing(first_core, canonical);
+                    replacements.push((idx, format!("{prefix}{contracted}{inner_suffix}")));
+                }
+            }
+        }
+
+        let edits = merges.len() + replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_words_bulk(replacements)?;
+        }
+        if !merges.is_empty() {
+            buffer.merge_words_bulk(merges)?;
+        }
+        Ok(edits)
+    }
+
+    fn expand(
+        &self,
+        buffer: &mut TextBuffer,
+        rng: &mut dyn OperationRng,
+        rate: f64,
+    ) -> Result<usize, OperationError> {
+        let mut splits: Vec<Reduplication> = Vec::new();
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx).filter(|word| word.is_mutable()) else {
+                continue;
+            };
+            let (prefix, core, suffix) = split_affixes_ref(segment.text());
+            let Some(&canonical) = EXPANSIONS.get(&lookup_key(core)) else {
+                continue;
+            };
+            if rng.random()? >= rate * segment.rate_scale() {
+                continue;
+            }
+
+            let expanded = match_casing(core, canonical);
+            match expanded.split_once(' ') {
+                Some((left, right)) => splits.push((
+                    idx,
+                    format!("{prefix}{left}"),
+                    smallvec![format!("{right}{suffix}")],
+                    Some(" ".to_string()),
+                )),
+                None => replacements.push((idx, format!("{prefix}{expanded}{suffix}"))),
+            }
+        }
+
+        let edits = splits.len() + replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_words_bulk(replacements)?;
+        }
+        if !splits.is_empty() {
+            buffer.reduplicate_words_bulk(splits)?;
+        }
+        Ok(edits)
+    }
+}
+
+impl TextOperation for ContractionOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let edits = match self.mode {
+            ContractionMode::Contract => self.contract(buffer, rng, rate)?,
+            ContractionMode::Expand => self.expand(buffer, rng, rate)?,
+        };
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, mode, seed=None, return_count=false))]
+pub(crate) fn contractions(
+    text: &str,
+    rate: f64,
+    mode: &str,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let mode = ContractionMode::parse(mode).map_err(PyValueError::new_err)?;
+    let op = ContractionOp { rate, mode };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContractionMode, ContractionOp, CONTRACTION_TABLE};
+    use crate::operations::apply_seeded;
+
+    fn rewrite(text: &str, mode: ContractionMode) -> String {
+        apply_seeded(&ContractionOp { rate: 1.0, mode }, text, 2099)
+    }
+
+    #[test]
+    fn contractions_round_trip_with_casing() {
+        assert_eq!(rewrite("I am happy", ContractionMode::Contract), "I'm happy");
+        assert_eq!(rewrite("I'm happy", ContractionMode::Expand), "I am happy");
+        assert_eq!(
+            rewrite("It is fine, DO NOT panic.", ContractionMode::Contract),
+            "It's fine, DON'T panic."
+        );
+        assert_eq!(
+            rewrite("It's fine, DON'T panic.", ContractionMode::Expand),
+            "It is fine, DO NOT panic."
+        );
+        assert_eq!(rewrite("\"You cannot!\"", ContractionMode::Contract), "\"You can't!\"");
+        assert_eq!(
+            rewrite("i\u{2019}m sure we\u{2019}re", ContractionMode::Expand),
+            "I am sure we are"
+        );
+    }
+
+    #[test]
+    fn contraction_needs_a_clean_gap_between_words() {
+        assert_eq!(rewrite("it, is", ContractionMode::Contract), "it, is");
+        assert_eq!(rewrite("do\nnot", ContractionMode::Contract), "do\nnot");
+        assert_eq!(rewrite("we are not", ContractionMode::Contract), "we're not");
+    }
+
+    #[test]
+    fn table_is_unique_in_both_directions() {
+        let mut contractions: Vec<String> =
+            CONTRACTION_TABLE.iter().map(|(short, _)| short.to_lowercase()).collect();
+        let mut expansions: Vec<String> =
+            CONTRACTION_TABLE.iter().map(|(_, long)| long.to_lowercase()).collect();
+        contractions.sort_unstable();
+        contractions.dedup();
+        expansions.sort_unstable();
+        expansions.dedup();
+        assert_eq!(contractions.len(), CONTRACTION_TABLE.len());
+        assert_eq!(expansions.len(), CONTRACTION_TABLE.len());
+    }
+}
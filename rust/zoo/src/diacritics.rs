@@ -0,0 +1,238 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::text_buffer::TextBuffer;
+
+const VALID_MODE_MESSAGE: &str = "strip, add";
+
+/// Combining accents attached by [`DiacriticMode::Add`]: grave, acute,
+/// circumflex, tilde and diaeresis.
+const ACCENT_PALETTE: &[char] = &['\u{0300}', '\u{0301}', '\u{0302}', '\u{0303}', '\u{0308}'];
+
+/// Precomposed Latin letters grouped by the base letter their canonical
+/// decomposition starts with (Latin-1 Supplement and Latin Extended-A).
+///
+/// Letters without a canonical decomposition ("ø", "ß", "æ", "ł") are
+/// deliberately absent: stripping them would change the letter, not its accent.
+const DECOMPOSITIONS: &[(&str, char)] = &[
+    ("ÀÁÂÃÄÅĀĂĄ", 'A'),
+    ("àáâãäåāăą", 'a'),
+    ("ÇĆĈĊČ", 'C'),
+    ("çćĉċč", 'c'),
+    ("Ď", 'D'),
+    ("ď", 'd'),
+    ("ÈÉÊËĒĔĖĘĚ", 'E'),
+    ("èéêëēĕėęě", 'e'),
+    ("ĜĞĠĢ", 'G'),
+    ("ĝğġģ", 'g'),
+    ("Ĥ", 'H'),
+    ("ĥ", 'h'),
+    ("ÌÍÎÏĨĪĬĮİ", 'I'),
+    ("ìíîïĩīĭį", 'i'),
+    ("Ĵ", 'J'),
+    ("ĵ", 'j'),
+    ("Ķ", 'K'),
+    ("ķ", 'k'),
+    ("ĹĻĽ", 'L'),
+    ("ĺļľ", 'l'),
+    ("ÑŃŅŇ", 'N'),
+    ("ñńņň", 'n'),
+    ("ÒÓÔÕÖŌŎŐ", 'O'),
+    ("òóôõöōŏő", 'o'),
+    ("ŔŖŘ", 'R'),
+    ("ŕŗř", 'r'),
+    ("ŚŜŞŠ", 'S'),
+    ("śŝşš", 's'),
+    ("ŢŤ", 'T'),
+    ("ţť", 't'),
+    ("ÙÚÛÜŨŪŬŮŰŲ", 'U'),
+    ("ùúûüũūŭůűų", 'u'),
+    ("Ŵ", 'W'),
+    ("ŵ", 'w'),
+    ("ÝŶŸ", 'Y'),
+    ("ýÿŷ", 'y'),
+    ("ŹŻŽ", 'Z'),
+    ("źżž", 'z'),
+];
+
+#[inline]
+const fn is_combining_mark(ch: char) -> bool {
+    matches!(ch, '\u{0300}'..='\u{036F}')
+}
+
+/// Returns the unaccented base letter for a precomposed character.
+fn base_letter(ch: char) -> Option<char> {
+    if ch.is_ascii() {
+        return None;
+    }
+    DECOMPOSITIONS
+        .iter()
+        .find(|(composed, _)| composed.contains(ch))
+        .map(|&(_, base)| base)
+}
+
+#[inline]
+const fn is_accentable_vowel(ch: char) -> bool {
+    matches!(ch.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// Whether [`DiacriticOp`] removes or adds accents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiacriticMode {
+    /// Decompose accented letters and drop every combining mark.
+    Strip,
+    /// Attach a random combining accent to vowels with probability `rate`.
+    Add,
+}
+
+impl DiacriticMode {
+    pub fn parse(mode: &str) -> Result<Self, String> {
+        match mode.to_ascii_lowercase().as_str() {
+            "strip" | "remove" => Ok(Self::Strip),
+            "add" => Ok(Self::Add),
+            _ => Err(format!(
+                "Unsupported diacritic mode '{mode}'. Expected one of: {VALID_MODE_MESSAGE}"
+            )),
+        }
+    }
+}
+
+/// Adds or strips diacritics inside mutable word segments.
+///
+/// `Strip` is deterministic and ignores `rate`: precomposed letters from the
+/// curated decomposition table lose their accent ("café" → "cafe") and
+/// combining marks (U+0300–U+036F) are dropped. `Add` attaches a combining
+/// accent from a small palette to unaccented ASCII vowels, each with
+/// probability `rate`; consonants, digits and punctuation are never touched.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DiacriticOp {
+    pub rate: f64,
+    pub mode: DiacriticMode,
+}
+
+impl DiacriticOp {
+    fn strip(word: &str) -> String {
+        word.chars()
+            .filter(|&ch| !is_combining_mark(ch))
+            .map(|ch| base_letter(ch).unwrap_or(ch))
+            .collect()
+    }
+
+    fn add(word: &str, rate: f64, rng: &mut dyn OperationRng) -> Result<String, OperationError> {
+        let mut output = String::with_capacity(word.len() * 2);
+        let mut chars = word.chars().peekable();
+        while let Some(ch) = chars.next() {
+            output.push(ch);
+            let already_marked = chars.peek().copied().is_some_and(is_combining_mark);
+            if is_accentable_vowel(ch) && !already_marked && rng.random()? < rate {
+                output.push(ACCENT_PALETTE[rng.rand_index(ACCENT_PALETTE.len())?]);
+            }
+        }
+        Ok(output)
+    }
+}
+
+impl TextOperation for DiacriticOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = if self.rate.is_nan() { 0.0 } else { self.rate.clamp(0.0, 1.0) };
+        if self.mode == DiacriticMode::Add && rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx) else {
+                continue;
+            };
+            if !segment.is_mutable() {
+                continue;
+            }
+            let word = segment.text();
+
+            let output = match self.mode {
+                DiacriticMode::Strip => {
+                    if word.is_ascii() {
+                        continue;
+                    }
+                    Self::strip(word)
+                }
+                DiacriticMode::Add => Self::add(word, rate * segment.rate_scale(), rng)?,
+            };
+
+            if output != word {
+                replacements.push((idx, output));
+            }
+        }
+
+        let edits = replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_words_bulk(replacements)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, mode, seed=None, return_count=false))]
+pub(crate) fn diacritics(
+    text: &str,
+    rate: f64,
+    mode: &str,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let mode = DiacriticMode::parse(mode).map_err(PyValueError::new_err)?;
+    let op = DiacriticOp { rate, mode };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_combining_mark, DiacriticMode, DiacriticOp};
+    use crate::operations::apply_seeded;
+
+    fn run(text: &str, rate: f64, mode: DiacriticMode) -> String {
+        apply_seeded(&DiacriticOp { rate, mode }, text, 2068)
+    }
+
+    #[test]
+    fn strip_removes_precomposed_and_combining_accents() {
+        assert_eq!(run("café", 0.0, DiacriticMode::Strip), "cafe");
+        assert_eq!(
+            run("Crème Brûlée, naïve Ångström", 1.0, DiacriticMode::Strip),
+            "Creme Brulee, naive Angstrom"
+        );
+        assert_eq!(run("cafe\u{0301} smørrebrød", 1.0, DiacriticMode::Strip), "cafe smørrebrød");
+    }
+
+    #[test]
+    fn add_only_accents_letters() {
+        let text = "Quiet 42 owls, 7 eels & 3 emus!";
+        let result = run(text, 1.0, DiacriticMode::Add);
+        assert_ne!(result, text);
+        let base: String = result.chars().filter(|&ch| !is_combining_mark(ch)).collect();
+        assert_eq!(base, text);
+        let mut previous = ' ';
+        for ch in result.chars() {
+            if is_combining_mark(ch) {
+                assert!(previous.is_alphabetic(), "mark attached to {previous:?}");
+            }
+            previous = ch;
+        }
+        assert_eq!(run(&result, 0.0, DiacriticMode::Strip), text);
+        assert_eq!(run(text, 0.0, DiacriticMode::Add), text);
+    }
+
+    #[test]
+    fn parse_rejects_unknown_modes() {
+        assert_eq!(DiacriticMode::parse("Strip"), Ok(DiacriticMode::Strip));
+        assert!(DiacriticMode::parse("toggle").unwrap_err().contains("strip, add"));
+    }
+}
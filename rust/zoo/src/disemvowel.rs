@@ -0,0 +1,127 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::resources::split_affixes_ref;
+use crate::text_buffer::TextBuffer;
+use crate::word_stretching::is_vowel;
+
+/// Returns `core` without its non-leading vowels, or `None` when nothing would
+/// be dropped or every letter is a vowel ("you", "aeiou" stay whole).
+fn strip_vowels(core: &str) -> Option<String> {
+    let is_vowel_char = |ch: char| is_vowel(ch.to_ascii_lowercase());
+    if !core.chars().any(|ch| ch.is_alphabetic() && !is_vowel_char(ch)) {
+        return None;
+    }
+
+    let stripped: String = core
+        .chars()
+        .enumerate()
+        .filter(|&(idx, ch)| idx == 0 || !is_vowel_char(ch))
+        .map(|(_, ch)| ch)
+        .collect();
+    (stripped.len() < core.len()).then_some(stripped)
+}
+
+/// Drops vowels from words: "text" → "txt", "about" → "abt".
+///
+/// Each word with an interior vowel loses all of them with probability
+/// `rate`. A leading vowel is kept so words stay readable, affixes and
+/// casing are untouched, and words made only of vowels are left alone. The
+/// inverse of Hokey's vowel stretching, sharing its vowel test.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DisemvowelOp {
+    pub rate: f64,
+}
+
+impl TextOperation for DisemvowelOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx) else {
+                continue;
+            };
+            if !segment.is_mutable() {
+                continue;
+            }
+            let (prefix, core, suffix) = split_affixes_ref(segment.text());
+            let Some(stripped) = strip_vowels(core) else {
+                continue;
+            };
+            if rng.random()? >= rate * segment.rate_scale() {
+                continue;
+            }
+
+            let mut output = String::with_capacity(prefix.len() + stripped.len() + suffix.len());
+            output.push_str(prefix);
+            output.push_str(&stripped);
+            output.push_str(suffix);
+            replacements.push((idx, output));
+        }
+
+        let edits = replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_words_bulk(replacements)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, seed=None, return_count=false))]
+pub(crate) fn disemvowel(
+    text: &str,
+    rate: f64,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = DisemvowelOp { rate };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{strip_vowels, DisemvowelOp};
+    use crate::operations::apply_seeded;
+
+    fn disemvowel(text: &str, rate: f64, seed: u64) -> String {
+        apply_seeded(&DisemvowelOp { rate }, text, seed)
+    }
+
+    #[test]
+    fn strips_interior_vowels_and_keeps_affixes() {
+        assert_eq!(
+            disemvowel("Send the (TEXT) about it, Alice!", 1.0, 7),
+            "Snd th (TXT) abt it, Alc!"
+        );
+        assert_eq!(disemvowel("a you aeiou 42", 1.0, 7), "a you aeiou 42");
+    }
+
+    #[test]
+    fn rate_selects_words_deterministically() {
+        let text = "several ordinary words remain readable after vowels vanish";
+        let partial = disemvowel(text, 0.5, 11);
+        assert_eq!(partial, disemvowel(text, 0.5, 11));
+        assert_ne!(partial, text);
+        assert_ne!(partial, disemvowel(text, 1.0, 11));
+        assert_eq!(disemvowel(text, 0.0, 11), text);
+    }
+
+    #[test]
+    fn never_empties_a_word() {
+        assert_eq!(strip_vowels("eat").as_deref(), Some("et"));
+        assert_eq!(strip_vowels("you"), None);
+        assert_eq!(strip_vowels("rhythm").as_deref(), Some("rhthm"));
+        assert_eq!(strip_vowels("txt"), None);
+    }
+}
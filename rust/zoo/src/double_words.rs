@@ -0,0 +1,103 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use smallvec::smallvec;
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::resources::{is_stopword, split_affixes_ref};
+use crate::text_buffer::{Reduplication, TextBuffer};
+
+/// Dictation-style doubled function words: "went to the store" → "went to to
+/// the store".
+///
+/// Unlike [`crate::operations::ReduplicateWordsOp`], only words in the bundled
+/// stopword list are eligible, and each is doubled with probability `rate`.
+/// The copy is inserted verbatim after the word with a single space, and the
+/// word's affixes wrap the pair, so "(the" becomes "(the the" and "to,"
+/// becomes "to to,".
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DoubleWordOp {
+    pub rate: f64,
+}
+
+impl TextOperation for DoubleWordOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut doubles: Vec<Reduplication> = Vec::new();
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx).filter(|word| word.is_mutable()) else {
+                continue;
+            };
+            let (prefix, core, suffix) = split_affixes_ref(segment.text());
+            if core.is_empty() || !is_stopword(core) {
+                continue;
+            }
+            if rng.random()? >= rate * segment.rate_scale() {
+                continue;
+            }
+
+            doubles.push((
+                idx,
+                format!("{prefix}{core}"),
+                smallvec![format!("{core}{suffix}")],
+                Some(" ".to_string()),
+            ));
+        }
+
+        let edits = doubles.len();
+        if !doubles.is_empty() {
+            buffer.reduplicate_words_bulk(doubles)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, seed=None, return_count=false))]
+pub(crate) fn double_words(
+    text: &str,
+    rate: f64,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = DoubleWordOp { rate };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DoubleWordOp;
+    use crate::operations::apply_seeded;
+
+    fn double(text: &str, rate: f64, seed: u64) -> String {
+        apply_seeded(&DoubleWordOp { rate }, text, seed)
+    }
+
+    #[test]
+    fn doubles_only_function_words_and_keeps_casing() {
+        assert_eq!(
+            double("The dog went to (the) store, OF course", 1.0, 9),
+            "The The dog went to to (the the) store, OF OF course"
+        );
+        assert_eq!(double("quick brown foxes", 1.0, 9), "quick brown foxes");
+    }
+
+    #[test]
+    fn partial_rates_are_deterministic() {
+        let text = "I went to the store and then to the park with a friend";
+        let doubled = double(text, 0.5, 4);
+        assert_eq!(doubled, double(text, 0.5, 4));
+        let words = |text: &str| text.split_whitespace().count();
+        assert!(words(&doubled) > words(text));
+        assert!(words(&doubled) < words(&double(text, 1.0, 4)));
+        assert_eq!(double(text, 0.0, 4), text);
+    }
+}
@@ -0,0 +1,167 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::text_buffer::TextBuffer;
+
+/// Default spoken-style fillers and discourse markers.
+pub(crate) const DEFAULT_FILLERS: &[&str] = &[
+    "um", "uh", "er", "like", "you know", "I mean", "well", "so", "basically", "actually",
+];
+
+/// Sprinkles filler words ("um", "like", "you know") between words to
+/// simulate transcribed speech.
+///
+/// Each word boundary independently receives a filler with probability
+/// `rate`. The boundary after the final word is skipped unless `trailing` is
+/// set, and `commas` appends a comma to each inserted filler ("so um, yes").
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FillerOp {
+    pub rate: f64,
+    fillers: Vec<String>,
+    #[serde(default)]
+    pub commas: bool,
+    #[serde(default)]
+    pub trailing: bool,
+}
+
+impl FillerOp {
+    /// Builds the op, falling back to [`DEFAULT_FILLERS`]. Every filler must
+    /// contain at least one non-whitespace character.
+    pub fn new(rate: f64, fillers: Option<Vec<String>>) -> Result<Self, String> {
+        let fillers = fillers.unwrap_or_else(|| {
+            DEFAULT_FILLERS
+                .iter()
+                .map(|filler| (*filler).to_string())
+                .collect()
+        });
+        if fillers.is_empty() {
+            return Err("filler list must not be empty".to_string());
+        }
+        if let Some(filler) = fillers.iter().find(|filler| filler.trim().is_empty()) {
+            return Err(format!("fillers must contain a word, got {filler:?}"));
+        }
+        Ok(Self {
+            rate,
+            fillers,
+            commas: false,
+            trailing: false,
+        })
+    }
+
+    #[must_use]
+    pub const fn with_commas(mut self, commas: bool) -> Self {
+        self.commas = commas;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_trailing(mut self, trailing: bool) -> Self {
+        self.trailing = trailing;
+        self
+    }
+
+    #[must_use]
+    pub fn fillers(&self) -> &[String] {
+        &self.fillers
+    }
+}
+
+impl TextOperation for FillerOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let boundaries = if self.trailing {
+            buffer.word_count()
+        } else {
+            buffer.word_count() - 1
+        };
+        let mut insertions: Vec<(usize, &str)> = Vec::new();
+        for idx in 0..boundaries {
+            if rng.random()? >= rate * buffer.word_rate_scale(idx) {
+                continue;
+            }
+            let filler = &self.fillers[rng.rand_index(self.fillers.len())?];
+            insertions.push((idx, filler.as_str()));
+        }
+
+        // Insert back to front so earlier word indices stay valid, and each
+        // filler's words last-first so they read in order after the anchor.
+        for &(idx, filler) in insertions.iter().rev() {
+            let mut words: Vec<String> = filler.split_whitespace().map(str::to_string).collect();
+            if self.commas {
+                if let Some(last) = words.last_mut() {
+                    last.push(',');
+                }
+            }
+            for word in words.iter().rev() {
+                buffer.insert_word_after(idx, word, Some(" "))?;
+            }
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(insertions.len()))
+    }
+}
+
+#[pyfunction(signature = (text, rate, fillers=None, seed=None, commas=false, trailing=false, return_count=false))]
+pub(crate) fn inject_fillers(
+    text: &str,
+    rate: f64,
+    fillers: Option<Vec<String>>,
+    seed: Option<u64>,
+    commas: bool,
+    trailing: bool,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = FillerOp::new(rate, fillers)
+        .map_err(PyValueError::new_err)?
+        .with_commas(commas)
+        .with_trailing(trailing);
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FillerOp;
+    use crate::operations::apply_seeded;
+
+    #[test]
+    fn fillers_land_between_words_but_not_at_the_end() {
+        let op = FillerOp::new(1.0, Some(vec!["you know".to_string()])).unwrap();
+        assert_eq!(apply_seeded(&op, "I think so", 7), "I you know think you know so");
+        let op = op.with_commas(true).with_trailing(true);
+        assert_eq!(apply_seeded(&op, "yes", 7), "yes you know,");
+    }
+
+    #[test]
+    fn injection_is_deterministic_and_keeps_original_words() {
+        let op = FillerOp::new(0.5, None).unwrap();
+        let text = "the quick brown fox jumps over the lazy dog";
+        let result = apply_seeded(&op, text, 2098);
+        assert_eq!(result, apply_seeded(&op, text, 2098));
+        assert_ne!(result, text);
+        let mut originals = text.split_whitespace();
+        let mut expected = originals.next();
+        for word in result.split_whitespace() {
+            if Some(word) == expected {
+                expected = originals.next();
+            }
+        }
+        assert_eq!(expected, None);
+        assert!(!result.ends_with("um") && result.ends_with("dog"));
+    }
+
+    #[test]
+    fn filler_list_rejects_blank_entries() {
+        assert!(FillerOp::new(0.5, Some(Vec::new())).is_err());
+        assert!(FillerOp::new(0.5, Some(vec!["  ".to_string()])).is_err());
+    }
+}
@@ -1,26 +1,61 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 
 use std::sync::LazyLock;
 use pyo3::exceptions::PyValueError;
-use pyo3::PyErr;
+use pyo3::prelude::*;
 use regex::{Captures, Regex};
 use sha2::{Digest, Sha256};
+use serde::{Deserialize, Serialize};
 
-use crate::operations::{TextOperation, OperationError, OperationRng, QuotePairsOp};
+use crate::operations::{TextOperation, OpOutcome, OperationError, OperationRng, QuotePairsOp};
 use crate::rng::DeterministicRng;
 use crate::text_buffer::TextBuffer;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 enum PedantStone {
     Andi,       // Coordinate pronoun hypercorrection
     Infinitoad, // Split infinitive correction
     Aetheria,   // Archaic ligatures/diaeresis
     Apostrofae, // Curly quotes
     Commama,    // Oxford comma insertion
+    Spelling(SpellingDirection),
+}
+
+/// Which way the spelling stones convert between American and British forms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpellingDirection {
+    /// "color" → "colour"
+    ToBritish,
+    /// "colour" → "color"
+    ToAmerican,
+}
+
+impl SpellingDirection {
+    /// Parses a direction label: `"uk"`/`"british"` or `"us"`/`"american"`.
+    pub fn try_from_str(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "uk" | "gb" | "british" | "to_uk" => Some(Self::ToBritish),
+            "us" | "american" | "to_us" => Some(Self::ToAmerican),
+            _ => None,
+        }
+    }
 }
 
 impl PedantStone {
+    /// Every stone in display order.
+    const ALL: [Self; 7] = [
+        Self::Andi,
+        Self::Infinitoad,
+        Self::Aetheria,
+        Self::Apostrofae,
+        Self::Commama,
+        Self::Spelling(SpellingDirection::ToBritish),
+        Self::Spelling(SpellingDirection::ToAmerican),
+    ];
+
+    /// Resolves a stone by display name. The spelling stones also answer to
+    /// their direction labels (`"uk"`, `"us"`, ...).
     fn try_from_name(name: &str) -> Option<Self> {
         match name {
             "Hypercorrectite" => Some(Self::Andi),
@@ -28,7 +63,9 @@ impl PedantStone {
             "Coeurite" => Some(Self::Aetheria),
             "Curlite" => Some(Self::Apostrofae),
             "Oxfordium" => Some(Self::Commama),
-            _ => None,
+            "Colourite" => Some(Self::Spelling(SpellingDirection::ToBritish)),
+            "Colorite" => Some(Self::Spelling(SpellingDirection::ToAmerican)),
+            other => SpellingDirection::try_from_str(other).map(Self::Spelling),
         }
     }
 
@@ -39,6 +76,8 @@ impl PedantStone {
             Self::Aetheria => "Coeurite",
             Self::Apostrofae => "Curlite",
             Self::Commama => "Oxfordium",
+            Self::Spelling(SpellingDirection::ToBritish) => "Colourite",
+            Self::Spelling(SpellingDirection::ToAmerican) => "Colorite",
         }
     }
 
@@ -49,26 +88,44 @@ impl PedantStone {
             Self::Aetheria => "Aetheria",
             Self::Apostrofae => "Apostrofae",
             Self::Commama => "Commama",
+            Self::Spelling(SpellingDirection::ToBritish) => "Britannicus",
+            Self::Spelling(SpellingDirection::ToAmerican) => "Americanus",
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GrammarRuleOp {
     root_seed: i128,
     stone: PedantStone,
 }
 
 impl GrammarRuleOp {
+    /// Builds the op for `stone_name`, either a stone's display name (see
+    /// [`list_pedant_stones`]) or a spelling direction (`"uk"` converts to
+    /// British spelling, `"us"` to American).
     pub fn new(seed: i128, stone_name: &str) -> Result<Self, PyErr> {
-        let stone = PedantStone::try_from_name(stone_name)
-            .ok_or_else(|| PyValueError::new_err(format!("Unknown pedant stone: {stone_name}")))?;
-        Ok(Self {
+        Self::try_from_name(seed, stone_name)
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown pedant stone: {stone_name}")))
+    }
+
+    /// Like [`GrammarRuleOp::new`], returning `None` for unknown stone names.
+    pub(crate) fn try_from_name(seed: i128, stone_name: &str) -> Option<Self> {
+        PedantStone::try_from_name(stone_name).map(|stone| Self {
             root_seed: seed,
             stone,
         })
     }
 
+    /// Builds a spelling-conversion op for an explicit direction.
+    #[must_use]
+    pub const fn with_direction(seed: i128, direction: SpellingDirection) -> Self {
+        Self {
+            root_seed: seed,
+            stone: PedantStone::Spelling(direction),
+        }
+    }
+
     const fn lineage(&self) -> [&'static str; 3] {
         ["Pedant", self.stone.stone_name(), self.stone.form_name()]
     }
@@ -79,22 +136,28 @@ impl TextOperation for GrammarRuleOp {
         &self,
         buffer: &mut TextBuffer,
         _rng: &mut dyn OperationRng,
-    ) -> Result<(), OperationError> {
+    ) -> Result<OpOutcome, OperationError> {
         let original = buffer.to_string();
         let lineage = self.lineage();
-        let transformed = match self.stone {
-            PedantStone::Andi => apply_andi(&original),
-            PedantStone::Infinitoad => apply_infinitoad(&original, self.root_seed, &lineage)?,
-            PedantStone::Aetheria => apply_aetheria(&original, self.root_seed, &lineage)?,
-            PedantStone::Apostrofae => apply_curlite(&original, self.root_seed, &lineage)?,
-            PedantStone::Commama => apply_commama(&original),
-        };
-
-        if transformed != original {
-            *buffer = buffer.rebuild_with_patterns(transformed);
-        }
+        let mut edits = 0usize;
+        let rebuilt = buffer.map_mutable_runs(|run, _| {
+            let (result, rewrites) = match self.stone {
+                PedantStone::Andi => apply_andi(run),
+                PedantStone::Infinitoad => apply_infinitoad(run, self.root_seed, &lineage)?,
+                PedantStone::Aetheria => apply_aetheria(run, self.root_seed, &lineage)?,
+                PedantStone::Apostrofae => apply_curlite(run, self.root_seed, &lineage)?,
+                PedantStone::Commama => apply_commama(run),
+                PedantStone::Spelling(direction) => apply_spelling(run, direction),
+            };
+            edits += rewrites;
+            Ok::<_, OperationError>(result)
+        })?;
 
-        Ok(())
+        if rebuilt.to_string() == original {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+        *buffer = rebuilt;
+        Ok(OpOutcome::edits(edits))
     }
 }
 
@@ -103,7 +166,7 @@ impl TextOperation for GrammarRuleOp {
 /// Targets "X and me" / "me and X" after prepositions and overcorrects to "I".
 /// This mimics a common hypercorrection where speakers, taught that "John and me went"
 /// is wrong, overgeneralize to "for John and I" in object position.
-fn apply_andi(text: &str) -> String {
+fn apply_andi(text: &str) -> (String, usize) {
     // "X and me" after prepositions → "X and I"
     static COORD_AND_ME: LazyLock<Regex> = LazyLock::new(|| {
         Regex::new(
@@ -119,19 +182,23 @@ fn apply_andi(text: &str) -> String {
     });
 
     // "I" as a pronoun is always uppercase in English
+    let mut rewrites = 0usize;
     let result = COORD_AND_ME.replace_all(text, |caps: &Captures<'_>| {
+        rewrites += 1;
         let prep = caps.get(1).unwrap().as_str();
         let other = caps.get(2).unwrap().as_str();
         format!("{prep} {other} and I")
     });
 
-    ME_AND_COORD
+    let result = ME_AND_COORD
         .replace_all(&result, |caps: &Captures<'_>| {
+            rewrites += 1;
             let prep = caps.get(1).unwrap().as_str();
             let other = caps.get(3).unwrap().as_str();
             format!("{prep} I and {other}")
         })
-        .into_owned()
+        .into_owned();
+    (result, rewrites)
 }
 
 /// Split infinitive "correction".
@@ -143,14 +210,14 @@ fn apply_infinitoad(
     text: &str,
     root_seed: i128,
     lineage: &[&str],
-) -> Result<String, OperationError> {
+) -> Result<(String, usize), OperationError> {
     // Pattern: "to" + adverb ending in -ly + verb
     static SPLIT_INF: LazyLock<Regex> =
         LazyLock::new(|| Regex::new(r"(?i)\bto\s+(\w+ly)\s+(\w+)").expect("valid regex"));
 
     let matches: Vec<_> = SPLIT_INF.find_iter(text).collect();
     if matches.is_empty() {
-        return Ok(text.to_string());
+        return Ok((text.to_string(), 0));
     }
 
     let seed = derive_seed(
@@ -174,30 +241,154 @@ fn apply_infinitoad(
         })
         .into_owned();
 
-    Ok(result)
+    Ok((result, matches.len()))
 }
 
-fn apply_commama(text: &str) -> String {
+fn apply_commama(text: &str) -> (String, usize) {
     static SERIAL_REGEX: LazyLock<Regex> =
         LazyLock::new(|| Regex::new(r"(,\s*)([^,]+)\s+and\s+([^,]+)").expect("valid regex"));
 
-    SERIAL_REGEX
+    let mut rewrites = 0usize;
+    let result = SERIAL_REGEX
         .replace_all(text, |caps: &Captures<'_>| {
             let prefix = caps.get(1).unwrap().as_str();
             let penultimate = caps.get(2).unwrap().as_str();
             if penultimate.trim_end().ends_with(',') {
                 return caps.get(0).unwrap().as_str().to_string();
             }
+            rewrites += 1;
             let last = caps.get(3).unwrap().as_str();
             format!("{prefix}{penultimate}, and {last}")
         })
-        .into_owned()
+        .into_owned();
+    (result, rewrites)
+}
+
+/// American/British spelling pairs. Only words whose spelling is unambiguous in
+/// both directions are listed, so a round trip restores the original text.
+const SPELLING_PAIRS: &[(&str, &str)] = &[
+    ("color", "colour"),
+    ("colors", "colours"),
+    ("colored", "coloured"),
+    ("colorful", "colourful"),
+    ("favor", "favour"),
+    ("favors", "favours"),
+    ("favorite", "favourite"),
+    ("favorites", "favourites"),
+    ("honor", "honour"),
+    ("honors", "honours"),
+    ("honored", "honoured"),
+    ("humor", "humour"),
+    ("labor", "labour"),
+    ("neighbor", "neighbour"),
+    ("neighbors", "neighbours"),
+    ("neighborhood", "neighbourhood"),
+    ("behavior", "behaviour"),
+    ("behaviors", "behaviours"),
+    ("flavor", "flavour"),
+    ("flavors", "flavours"),
+    ("rumor", "rumour"),
+    ("harbor", "harbour"),
+    ("endeavor", "endeavour"),
+    ("center", "centre"),
+    ("centers", "centres"),
+    ("theater", "theatre"),
+    ("theaters", "theatres"),
+    ("fiber", "fibre"),
+    ("liter", "litre"),
+    ("liters", "litres"),
+    ("organize", "organise"),
+    ("organized", "organised"),
+    ("organizing", "organising"),
+    ("organization", "organisation"),
+    ("organizations", "organisations"),
+    ("realize", "realise"),
+    ("realized", "realised"),
+    ("recognize", "recognise"),
+    ("recognized", "recognised"),
+    ("apologize", "apologise"),
+    ("apologized", "apologised"),
+    ("criticize", "criticise"),
+    ("emphasize", "emphasise"),
+    ("analyze", "analyse"),
+    ("analyzed", "analysed"),
+    ("paralyze", "paralyse"),
+    ("catalog", "catalogue"),
+    ("defense", "defence"),
+    ("offense", "offence"),
+    ("traveled", "travelled"),
+    ("traveling", "travelling"),
+    ("traveler", "traveller"),
+    ("canceled", "cancelled"),
+    ("canceling", "cancelling"),
+    ("modeling", "modelling"),
+    ("jewelry", "jewellery"),
+    ("gray", "grey"),
+    ("aluminum", "aluminium"),
+    ("plow", "plough"),
+    ("pajamas", "pyjamas"),
+    ("cozy", "cosy"),
+    ("airplane", "aeroplane"),
+    ("maneuver", "manoeuvre"),
+    ("pediatric", "paediatric"),
+    ("encyclopedia", "encyclopaedia"),
+    ("sulfur", "sulphur"),
+    ("mustache", "moustache"),
+    ("skeptical", "sceptical"),
+    ("artifact", "artefact"),
+    ("gonorrhea", "gonorrhoea"),
+];
+
+static TO_BRITISH: LazyLock<HashMap<&'static str, &'static str>> =
+    LazyLock::new(|| SPELLING_PAIRS.iter().copied().collect());
+
+static TO_AMERICAN: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    SPELLING_PAIRS
+        .iter()
+        .map(|&(american, british)| (british, american))
+        .collect()
+});
+
+/// Converts word cores between American and British spelling, leaving
+/// surrounding punctuation untouched and matching each word's casing.
+fn apply_spelling(text: &str, direction: SpellingDirection) -> (String, usize) {
+    static WORD: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\b[A-Za-z]+\b").expect("valid regex"));
+
+    let table = match direction {
+        SpellingDirection::ToBritish => &*TO_BRITISH,
+        SpellingDirection::ToAmerican => &*TO_AMERICAN,
+    };
+
+    let mut rewrites = 0usize;
+    let result = WORD
+        .replace_all(text, |caps: &Captures<'_>| {
+            let word = caps.get(0).unwrap().as_str();
+            match table.get(word.to_ascii_lowercase().as_str()) {
+                Some(converted) => {
+                    rewrites += 1;
+                    match_casing(word, converted)
+                }
+                None => word.to_string(),
+            }
+        })
+        .into_owned();
+    (result, rewrites)
 }
 
+/// Returns the display names of every pedant stone.
+#[pyfunction]
+pub(crate) fn list_pedant_stones() -> Vec<&'static str> {
+    PedantStone::ALL.iter().map(|stone| stone.stone_name()).collect()
+}
 
-fn apply_curlite(text: &str, root_seed: i128, lineage: &[&str]) -> Result<String, OperationError> {
+fn apply_curlite(
+    text: &str,
+    root_seed: i128,
+    lineage: &[&str],
+) -> Result<(String, usize), OperationError> {
     if text.is_empty() {
-        return Ok(text.to_string());
+        return Ok((text.to_string(), 0));
     }
 
     let seed = derive_seed(
@@ -207,19 +398,25 @@ fn apply_curlite(text: &str, root_seed: i128, lineage: &[&str]) -> Result<String
     );
     let mut rng = DeterministicRng::new(seed);
     let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
-    let op = QuotePairsOp;
-    op.apply(&mut buffer, &mut rng)?;
-    Ok(buffer.to_string())
+    let op = QuotePairsOp::default();
+    let outcome = op.apply(&mut buffer, &mut rng)?;
+    Ok((buffer.to_string(), outcome.edits))
 }
 
-fn apply_aetheria(text: &str, root_seed: i128, lineage: &[&str]) -> Result<String, OperationError> {
+fn apply_aetheria(
+    text: &str,
+    root_seed: i128,
+    lineage: &[&str],
+) -> Result<(String, usize), OperationError> {
     static COOPERATE_REGEX: LazyLock<Regex> =
         LazyLock::new(|| Regex::new(r"(?i)cooperate").expect("valid regex"));
     static COORDINATE_REGEX: LazyLock<Regex> =
         LazyLock::new(|| Regex::new(r"(?i)coordinate").expect("valid regex"));
 
+    let mut rewrites = 0usize;
     let intermediate = COOPERATE_REGEX
         .replace_all(text, |caps: &Captures<'_>| {
+            rewrites += 1;
             cooperate_replacement(caps.get(0).unwrap().as_str())
         })
         .into_owned();
@@ -238,12 +435,16 @@ fn apply_aetheria(text: &str, root_seed: i128, lineage: &[&str]) -> Result<Strin
             root_seed,
             lineage,
         )?;
+        if replacement != mat.as_str() {
+            rewrites += 1;
+        }
         coordinated.push_str(&replacement);
         last = mat.end();
     }
     coordinated.push_str(&intermediate[last..]);
 
-    apply_ligatures(&coordinated, root_seed, lineage)
+    let (result, ligatures) = apply_ligatures(&coordinated, root_seed, lineage)?;
+    Ok((result, rewrites + ligatures))
 }
 
 fn cooperate_replacement(word: &str) -> String {
@@ -279,12 +480,16 @@ fn coordinate_replacement(
     }
 }
 
-fn apply_ligatures(text: &str, root_seed: i128, lineage: &[&str]) -> Result<String, OperationError> {
+fn apply_ligatures(
+    text: &str,
+    root_seed: i128,
+    lineage: &[&str],
+) -> Result<(String, usize), OperationError> {
     static AETHER_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)ae").expect("valid regex"));
 
     let matches: Vec<usize> = AETHER_REGEX.find_iter(text).map(|m| m.start()).collect();
     if matches.is_empty() {
-        return Ok(text.to_string());
+        return Ok((text.to_string(), 0));
     }
 
     let seed = derive_seed(
@@ -330,7 +535,7 @@ fn apply_ligatures(text: &str, root_seed: i128, lineage: &[&str]) -> Result<Stri
         }
     }
 
-    Ok(result)
+    Ok((result, chosen.len()))
 }
 
 fn apply_diaeresis(word: &str) -> String {
@@ -480,4 +685,33 @@ mod tests {
         assert_eq!(cooperate_replacement("Cooperate"), "Coöperate");
         assert_eq!(cooperate_replacement("COOPERATE"), "COÖPERATE");
     }
+
+    #[test]
+    fn spelling_round_trip_is_lossless() {
+        let american = "My favorite Color: \"gray\"! We ORGANIZED the theater's defense.";
+        let (british, rewrites) = apply_spelling(american, SpellingDirection::ToBritish);
+        assert_eq!(
+            british,
+            "My favourite Colour: \"grey\"! We ORGANISED the theatre's defence."
+        );
+        assert_eq!(rewrites, 6);
+        let (round_trip, rewrites) = apply_spelling(&british, SpellingDirection::ToAmerican);
+        assert_eq!(round_trip, american);
+        assert_eq!(rewrites, 6);
+    }
+
+    #[test]
+    fn stones_resolve_direction_labels() {
+        assert!(matches!(
+            PedantStone::try_from_name("uk"),
+            Some(PedantStone::Spelling(SpellingDirection::ToBritish))
+        ));
+        assert!(matches!(
+            PedantStone::try_from_name("US"),
+            Some(PedantStone::Spelling(SpellingDirection::ToAmerican))
+        ));
+        for name in list_pedant_stones() {
+            assert!(PedantStone::try_from_name(name).is_some());
+        }
+    }
 }
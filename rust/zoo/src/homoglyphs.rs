@@ -3,11 +3,11 @@ use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PySequence, PyString};
 use pyo3::Bound;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap, HashSet};
 use unicode_script::{Script, UnicodeScript};
 
-use crate::operations::{TextOperation, OperationError, OperationRng};
+use crate::operations::{TextOperation, OpOutcome, OperationError, OperationRng};
 use crate::text_buffer::TextBuffer;
 
 const RAW_HOMOGLYPHS: &str = include_str!(concat!(env!("OUT_DIR"), "/mim1c_homoglyphs.json"));
@@ -24,7 +24,7 @@ pub enum ConfusableType {
 }
 
 /// Substitution mode controlling which confusable types are allowed.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum HomoglyphMode {
     /// Only same-script confusables (safest option).
     SingleScript,
@@ -227,9 +227,49 @@ static HOMOGLYPH_TABLE: LazyLock<BTreeMap<char, Vec<HomoglyphEntry>>> = LazyLock
     table
 });
 
+/// Confusable classes drawn from the homoglyph table: each character and every
+/// glyph listed for it share a class, keyed by the class's smallest member.
+static CONFUSABLE_CLASSES: LazyLock<HashMap<char, char>> = LazyLock::new(|| {
+    fn root(parents: &mut HashMap<char, char>, ch: char) -> char {
+        let mut current = ch;
+        while let Some(&parent) = parents.get(&current) {
+            if parent == current {
+                break;
+            }
+            current = parent;
+        }
+        parents.insert(ch, current);
+        current
+    }
+
+    let mut parents: HashMap<char, char> = HashMap::new();
+    for (&ch, entries) in HOMOGLYPH_TABLE.iter() {
+        parents.entry(ch).or_insert(ch);
+        for entry in entries {
+            parents.entry(entry.glyph).or_insert(entry.glyph);
+            let left = root(&mut parents, ch);
+            let right = root(&mut parents, entry.glyph);
+            let (low, high) = if left < right { (left, right) } else { (right, left) };
+            parents.insert(high, low);
+        }
+    }
+
+    let members: Vec<char> = parents.keys().copied().collect();
+    members
+        .into_iter()
+        .map(|ch| (ch, root(&mut parents, ch)))
+        .collect()
+});
+
+/// Returns the representative of `ch`'s confusable class, or `ch` itself when
+/// the homoglyph table has no confusables for it.
+pub(crate) fn confusable_class(ch: char) -> char {
+    CONFUSABLE_CLASSES.get(&ch).copied().unwrap_or(ch)
+}
+
 const DEFAULT_CLASSES: &[&str] = &["LATIN", "GREEK", "CYRILLIC"];
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ClassSelection {
     Default,
     All,
@@ -249,16 +289,28 @@ impl ClassSelection {
 /// Default maximum consecutive substitutions for locality control.
 const DEFAULT_MAX_CONSECUTIVE: usize = 3;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HomoglyphOp {
     rate: f64,
     classes: ClassSelection,
     banned: Vec<String>,
     mode: HomoglyphMode,
     max_consecutive: usize,
+    /// When set, only these source characters are eligible for substitution.
+    #[serde(default)]
+    only_chars: Option<HashSet<char>>,
 }
 
 impl HomoglyphOp {
+    /// Multiplies the substitution rate by `factor`.
+    pub(crate) fn scale_rate(&mut self, factor: f64) {
+        self.rate *= factor;
+    }
+
+    pub(crate) const fn rate(&self) -> f64 {
+        self.rate
+    }
+
     pub fn new(rate: f64, classes: ClassSelection, banned: Vec<String>) -> Self {
         Self {
             rate,
@@ -266,6 +318,7 @@ impl HomoglyphOp {
             banned,
             mode: HomoglyphMode::default(),
             max_consecutive: DEFAULT_MAX_CONSECUTIVE,
+            only_chars: None,
         }
     }
 
@@ -282,15 +335,23 @@ impl HomoglyphOp {
             banned,
             mode,
             max_consecutive,
+            only_chars: None,
         }
     }
+
+    /// Restricts substitution to the given source characters.
+    #[must_use]
+    pub fn with_only_chars(mut self, only_chars: Option<HashSet<char>>) -> Self {
+        self.only_chars = only_chars;
+        self
+    }
 }
 
 impl TextOperation for HomoglyphOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
         let segments = buffer.segments();
         if segments.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         // Collect all replaceable characters across all segments
@@ -298,15 +359,21 @@ impl TextOperation for HomoglyphOp {
         let mut targets: Vec<(usize, usize, char, usize)> = Vec::new();
 
         for (seg_idx, segment) in segments.iter().enumerate() {
+            if !segment.is_mutable() {
+                continue;
+            }
             for (char_pos, (byte_offset, ch)) in segment.text().char_indices().enumerate() {
-                if ch.is_alphanumeric() && HOMOGLYPH_TABLE.contains_key(&ch) {
+                if ch.is_alphanumeric()
+                    && HOMOGLYPH_TABLE.contains_key(&ch)
+                    && self.only_chars.as_ref().is_none_or(|allowed| allowed.contains(&ch))
+                {
                     targets.push((seg_idx, byte_offset, ch, char_pos));
                 }
             }
         }
 
         if targets.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let rate = if self.rate.is_nan() {
@@ -315,7 +382,7 @@ impl TextOperation for HomoglyphOp {
             self.rate.max(0.0)
         };
         if rate == 0.0 {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let mut banned: HashSet<String> = HashSet::new();
@@ -328,12 +395,33 @@ impl TextOperation for HomoglyphOp {
         // Select characters to replace
         let mut replacements: Vec<(usize, usize, char, usize)> = Vec::new();
         let mut available = targets.len();
-        let requested = (targets.len() as f64 * rate).trunc() as usize;
+        let mut requested = (targets.len() as f64 * rate).trunc() as usize;
         let mut attempts = 0usize;
 
+        // Under a rate profile each target counts for its segment's scale and
+        // targets are visited in a scale-weighted order.
+        let mut weighted_order = None;
+        if buffer.has_rate_profile() {
+            let weights: Vec<(usize, f64)> = targets
+                .iter()
+                .enumerate()
+                .map(|(idx, target)| (idx, segments[target.0].rate_scale()))
+                .collect();
+            let scaled: f64 = weights.iter().map(|(_, scale)| scale).sum();
+            requested = (scaled * rate).trunc() as usize;
+            let order = rng.weighted_sample(&weights, weights.len())?;
+            available = order.len();
+            weighted_order = Some(order.into_iter());
+        }
+
         while attempts < requested && available > 0 {
-            let idx = rng.rand_index(available)?;
-            let (seg_idx, char_offset, ch, char_pos) = targets.swap_remove(idx);
+            let (seg_idx, char_offset, ch, char_pos) = match weighted_order.as_mut() {
+                Some(order) => match order.next() {
+                    Some(idx) => targets[idx],
+                    None => break,
+                },
+                None => targets.swap_remove(rng.rand_index(available)?),
+            };
             available -= 1;
 
             let Some(options) = HOMOGLYPH_TABLE.get(&ch) else {
@@ -381,7 +469,7 @@ impl TextOperation for HomoglyphOp {
         }
 
         if replacements.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         // Apply locality constraint (max_consecutive)
@@ -418,9 +506,11 @@ impl TextOperation for HomoglyphOp {
         }
 
         if filtered_replacements.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
+        let edits = filtered_replacements.len();
+
         // Group replacements by segment
         let mut by_segment: HashMap<usize, Vec<(usize, char)>> = HashMap::new();
         for (seg_idx, char_offset, replacement_char) in filtered_replacements {
@@ -460,7 +550,7 @@ impl TextOperation for HomoglyphOp {
         buffer.replace_segments_bulk(segment_replacements);
 
         buffer.reindex_if_needed();
-        Ok(())
+        Ok(OpOutcome::edits(edits))
     }
 }
 
@@ -563,6 +653,35 @@ pub fn parse_banned_characters(value: Option<Bound<'_, PyAny>>) -> PyResult<Vec<
     ))
 }
 
+/// Parse the `only_characters` allow-set. A string contributes each of its
+/// characters, as does every string in a sequence; `None` means no restriction.
+pub fn parse_only_characters(value: Option<Bound<'_, PyAny>>) -> PyResult<Option<HashSet<char>>> {
+    let Some(obj) = value else {
+        return Ok(None);
+    };
+
+    if obj.is_none() {
+        return Ok(None);
+    }
+
+    if let Ok(py_str) = obj.downcast::<PyString>() {
+        return Ok(Some(py_str.to_str()?.chars().collect()));
+    }
+
+    if let Ok(seq) = obj.downcast::<PySequence>() {
+        let mut allowed = HashSet::new();
+        for item in seq.try_iter()? {
+            let value: String = item?.extract()?;
+            allowed.extend(value.chars());
+        }
+        return Ok(Some(allowed));
+    }
+
+    Err(PyValueError::new_err(
+        "only_characters must be a string or iterable of strings",
+    ))
+}
+
 /// Parse mode string into HomoglyphMode, returning None for invalid input.
 pub fn parse_homoglyph_mode(value: Option<&str>) -> HomoglyphMode {
     match value {
@@ -571,7 +690,24 @@ pub fn parse_homoglyph_mode(value: Option<&str>) -> HomoglyphMode {
     }
 }
 
-#[pyfunction(name = "swap_homoglyphs", signature = (text, rate=None, classes=None, banned_characters=None, seed=None, mode=None, max_consecutive=None))]
+/// Returns the homoglyph table as `(glyph, alias)` candidates per character,
+/// in the order the operation samples from them.
+#[pyfunction]
+pub(crate) fn homoglyph_table() -> BTreeMap<char, Vec<(char, String)>> {
+    HOMOGLYPH_TABLE
+        .iter()
+        .map(|(&ch, entries)| {
+            let candidates = entries
+                .iter()
+                .map(|entry| (entry.glyph, entry.alias.clone()))
+                .collect();
+            (ch, candidates)
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+#[pyfunction(name = "swap_homoglyphs", signature = (text, rate=None, classes=None, banned_characters=None, seed=None, mode=None, max_consecutive=None, rng=None, only_characters=None, return_count=false))]
 pub(crate) fn swap_homoglyphs(
     text: &str,
     rate: Option<f64>,
@@ -580,14 +716,19 @@ pub(crate) fn swap_homoglyphs(
     seed: Option<u64>,
     mode: Option<&str>,
     max_consecutive: Option<usize>,
-) -> PyResult<String> {
+    rng: Option<Bound<'_, PyAny>>,
+    only_characters: Option<Bound<'_, PyAny>>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
     let rate = rate.unwrap_or(0.02);
     let classes = parse_class_selection(classes)?;
     let banned = parse_banned_characters(banned_characters)?;
     let mode = parse_homoglyph_mode(mode);
     let max_consecutive = max_consecutive.unwrap_or(DEFAULT_MAX_CONSECUTIVE);
-    let op = HomoglyphOp::with_mode(rate, classes, banned, mode, max_consecutive);
-    crate::apply_operation(text, op, seed).map_err(crate::operations::OperationError::into_pyerr)
+    let only_chars = parse_only_characters(only_characters)?;
+    let op = HomoglyphOp::with_mode(rate, classes, banned, mode, max_consecutive)
+        .with_only_chars(only_chars);
+    crate::py_rng::apply_with_optional_rng(text, op, seed, rng, return_count)
 }
 
 #[cfg(test)]
@@ -697,6 +838,51 @@ mod tests {
         assert_eq!(differences, vec![target_char_index]);
     }
 
+    #[test]
+    fn immutable_segments_are_left_untouched() {
+        let exclude = [regex::Regex::new("WORLD").unwrap()];
+        let mut buffer = TextBuffer::from_owned("hello WORLD hello".to_string(), &[], &exclude);
+        let mut rng = DeterministicRng::new(42);
+        let op = HomoglyphOp::new(1.0, ClassSelection::All, Vec::new());
+        op.apply(&mut buffer, &mut rng).expect("homoglyph swap succeeds");
+        let result = buffer.to_string();
+        assert!(result.contains(" WORLD "), "{result}");
+        assert!(!result.starts_with("hello"), "{result}");
+    }
+
+    #[test]
+    fn only_chars_restricts_substitution_to_the_allow_set() {
+        let mut buffer = TextBuffer::from_owned("amazon bluesky".to_string(), &[], &[]);
+        let mut rng = DeterministicRng::new(42);
+        let op = HomoglyphOp::with_mode(
+            1.0,
+            ClassSelection::All,
+            Vec::new(),
+            HomoglyphMode::Aggressive,
+            0,
+        )
+        .with_only_chars(Some("amazon".chars().collect()));
+        op.apply(&mut buffer, &mut rng)
+            .expect("mim1c operation succeeds");
+        let result = buffer.to_string();
+        assert!(result.ends_with(" bluesky"), "{result}");
+        assert!(!result.starts_with("amazon"), "{result}");
+    }
+
+    #[test]
+    fn homoglyph_table_mirrors_the_internal_table() {
+        let exported = homoglyph_table();
+        assert_eq!(exported.len(), HOMOGLYPH_TABLE.len());
+        for (ch, entries) in HOMOGLYPH_TABLE.iter() {
+            let candidates = exported.get(ch).expect("every character is exported");
+            let expected: Vec<(char, String)> = entries
+                .iter()
+                .map(|entry| (entry.glyph, entry.alias.clone()))
+                .collect();
+            assert_eq!(candidates, &expected);
+        }
+    }
+
     #[test]
     fn homoglyph_table_is_sorted_by_glyph() {
         // Verify that the homoglyph table entries are sorted by glyph codepoint
@@ -715,6 +901,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn confusable_classes_join_characters_with_their_glyphs() {
+        for (&ch, entries) in HOMOGLYPH_TABLE.iter() {
+            for entry in entries {
+                assert_eq!(confusable_class(ch), confusable_class(entry.glyph));
+            }
+        }
+        assert_ne!(confusable_class('a'), confusable_class('b'));
+        assert_eq!(confusable_class('\u{2603}'), '\u{2603}');
+    }
+
     #[test]
     fn e_homoglyphs_have_expected_order() {
         let entries = HOMOGLYPH_TABLE.get(&'E').expect("E should be in table");
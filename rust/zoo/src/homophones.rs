@@ -1,11 +1,12 @@
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
 
-use crate::operations::{TextOperation, OperationError, OperationRng};
+use crate::operations::{TextOperation, OpOutcome, OperationError, OperationRng};
 use crate::resources::{wherewolf_homophone_sets, is_whitespace_only, split_affixes};
 use crate::text_buffer::TextBuffer;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HomophoneWeighting {
     Flat,
 }
@@ -25,28 +26,87 @@ impl HomophoneWeighting {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HomophoneOp {
     pub rate: f64,
     pub weighting: HomophoneWeighting,
+    /// Caller-supplied groups merged over the bundled lookup for this op only.
+    overrides: Arc<HashMap<String, Vec<String>>>,
 }
 
-static HOMOPHONE_LOOKUP: LazyLock<HashMap<String, Vec<String>>> = LazyLock::new(|| {
-    let mut mapping: HashMap<String, Vec<String>> = HashMap::new();
+impl HomophoneOp {
+    #[must_use]
+    pub fn new(rate: f64, weighting: HomophoneWeighting) -> Self {
+        Self {
+            rate,
+            weighting,
+            overrides: Arc::default(),
+        }
+    }
 
-    for group in wherewolf_homophone_sets() {
-        let mut seen: HashSet<String> = HashSet::new();
-        let mut normalised: Vec<String> = Vec::new();
-        for word in group {
-            let lowered = word.to_lowercase();
-            if seen.insert(lowered.clone()) {
-                normalised.push(lowered);
+    /// Builds an op whose lookup also includes `extra_sets`.
+    ///
+    /// Words that already belong to a bundled group keep those homophones and
+    /// gain the extra ones.
+    #[must_use]
+    pub fn with_extra_sets<S: AsRef<str>>(
+        rate: f64,
+        weighting: HomophoneWeighting,
+        extra_sets: &[Vec<S>],
+    ) -> Self {
+        let mut overrides: HashMap<String, Vec<String>> = HashMap::new();
+        for group in extra_sets {
+            let Some(normalised) = normalise_group(group) else {
+                continue;
+            };
+            for word in &normalised {
+                let merged = overrides.entry(word.clone()).or_insert_with(|| {
+                    HOMOPHONE_LOOKUP.get(word).cloned().unwrap_or_default()
+                });
+                for candidate in &normalised {
+                    if !merged.contains(candidate) {
+                        merged.push(candidate.clone());
+                    }
+                }
             }
         }
 
-        if normalised.len() < 2 {
-            continue;
+        Self {
+            rate,
+            weighting,
+            overrides: Arc::new(overrides),
         }
+    }
+
+    fn lookup(&self, lowered: &str) -> Option<&Vec<String>> {
+        self.overrides
+            .get(lowered)
+            .or_else(|| HOMOPHONE_LOOKUP.get(lowered))
+    }
+}
+
+/// Lowercases and deduplicates a group, dropping groups with fewer than two
+/// distinct members.
+fn normalise_group<S: AsRef<str>>(group: &[S]) -> Option<Vec<String>> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut normalised: Vec<String> = Vec::new();
+    for word in group {
+        let lowered = word.as_ref().to_lowercase();
+        if seen.insert(lowered.clone()) {
+            normalised.push(lowered);
+        }
+    }
+
+    (normalised.len() >= 2).then_some(normalised)
+}
+
+static HOMOPHONE_LOOKUP: LazyLock<HashMap<String, Vec<String>>> = LazyLock::new(|| {
+    let mut mapping: HashMap<String, Vec<String>> = HashMap::new();
+
+    for group in wherewolf_homophone_sets() {
+        let Some(normalised) = normalise_group(group) else {
+            continue;
+        };
 
         for word in &normalised {
             mapping.insert(word.clone(), normalised.clone());
@@ -56,7 +116,7 @@ static HOMOPHONE_LOOKUP: LazyLock<HashMap<String, Vec<String>>> = LazyLock::new(
     mapping
 });
 
-fn apply_casing(template: &str, candidate: &str) -> String {
+pub(crate) fn apply_casing(template: &str, candidate: &str) -> String {
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     enum CasingPattern {
         Upper,
@@ -144,18 +204,18 @@ fn choose_alternative(
 }
 
 impl TextOperation for HomophoneOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
         if buffer.word_count() == 0 {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         if self.rate.is_nan() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let clamped_rate = self.rate.clamp(0.0, 1.0);
         if clamped_rate <= f64::EPSILON {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         // Collect all replacements first to avoid index shifting during mutation
@@ -165,6 +225,9 @@ impl TextOperation for HomophoneOp {
             let Some(segment) = buffer.word_segment(idx) else {
                 continue;
             };
+            if !segment.is_mutable() {
+                continue;
+            }
 
             let token = segment.text();
             if token.is_empty() || is_whitespace_only(token) {
@@ -177,11 +240,11 @@ impl TextOperation for HomophoneOp {
             }
 
             let lowered = core.to_lowercase();
-            let Some(group) = HOMOPHONE_LOOKUP.get(&lowered) else {
+            let Some(group) = self.lookup(&lowered) else {
                 continue;
             };
 
-            if rng.random()? >= clamped_rate {
+            if rng.random()? >= clamped_rate * segment.rate_scale() {
                 continue;
             }
 
@@ -195,11 +258,55 @@ impl TextOperation for HomophoneOp {
         }
 
         // Apply all replacements using bulk update
+        let edits = replacements.len();
         if !replacements.is_empty() {
-            buffer.replace_words_bulk(replacements.into_iter())?;
+            buffer.replace_words_bulk(replacements)?;
         }
 
         buffer.reindex_if_needed();
-        Ok(())
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HomophoneOp, HomophoneWeighting};
+    use crate::operations::TextOperation;
+    use crate::rng::DeterministicRng;
+    use crate::text_buffer::TextBuffer;
+
+    fn substitute(op: &HomophoneOp, text: &str) -> String {
+        let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+        let mut rng = DeterministicRng::new(151);
+        op.apply(&mut buffer, &mut rng).expect("substitution succeeds");
+        buffer.to_string()
+    }
+
+    #[test]
+    fn extra_sets_substitute_in_both_directions() {
+        let extra = vec![vec!["Kubernetes".to_string(), "k8s".to_string()]];
+        let op = HomophoneOp::with_extra_sets(1.0, HomophoneWeighting::Flat, &extra);
+        assert_eq!(substitute(&op, "Deploy kubernetes."), "Deploy k8s.");
+        assert_eq!(substitute(&op, "Deploy K8S."), "Deploy KUBERNETES.");
+    }
+
+    #[test]
+    fn extra_sets_are_scoped_to_the_op() {
+        let extra = vec![vec!["kubernetes".to_string(), "k8s".to_string()]];
+        let _custom = HomophoneOp::with_extra_sets(1.0, HomophoneWeighting::Flat, &extra);
+        let plain = HomophoneOp::new(1.0, HomophoneWeighting::Flat);
+        assert_eq!(substitute(&plain, "kubernetes"), "kubernetes");
+    }
+
+    #[test]
+    fn excluded_spans_are_left_untouched() {
+        let extra = vec![vec!["kubernetes".to_string(), "k8s".to_string()]];
+        let op = HomophoneOp::with_extra_sets(1.0, HomophoneWeighting::Flat, &extra);
+        let exclude = vec![regex::Regex::new(r"`[^`]*`").unwrap()];
+        let text = "Run kubernetes via `kubernetes apply` today.";
+        let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &exclude);
+        let mut rng = DeterministicRng::new(151);
+        op.apply(&mut buffer, &mut rng).expect("substitution succeeds");
+        assert_eq!(buffer.to_string(), "Run k8s via `kubernetes apply` today.");
     }
 }
@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::cache::{hash_layout_map, hash_shift_map, ContentCache};
-use crate::operations::{MotorWeighting, ShiftSlipConfig};
+use crate::operations::{KeyboardLayout, MotorWeighting, ShiftSlipConfig, ShiftSlipMode};
 
 fn layout_cache() -> &'static ContentCache<HashMap<String, Vec<String>>> {
     static CACHE: std::sync::OnceLock<ContentCache<HashMap<String, Vec<String>>>> =
@@ -49,8 +49,19 @@ pub(crate) fn extract_shift_map(
 pub(crate) fn build_shift_slip_config(
     shift_slip_rate: f64,
     shift_slip_exit_rate: Option<f64>,
+    shift_slip_min_hold: Option<usize>,
+    shift_slip_mode: Option<&str>,
     shift_map: Option<Arc<HashMap<String, String>>>,
 ) -> PyResult<Option<ShiftSlipConfig>> {
+    let mode = match shift_slip_mode {
+        Some(value) => ShiftSlipMode::parse(value).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "unsupported shift_slip_mode '{value}'; expected 'momentary' or 'caps_lock'"
+            ))
+        })?,
+        None => ShiftSlipMode::default(),
+    };
+
     let enter_rate = shift_slip_rate.max(0.0);
     if enter_rate <= f64::EPSILON {
         return Ok(None);
@@ -65,11 +76,66 @@ pub(crate) fn build_shift_slip_config(
     let exit_rate = shift_slip_exit_rate.unwrap_or(enter_rate * 0.5);
     // Clone the inner HashMap - this is efficient because the Arc caching ensures
     // we only ever store one copy of each unique shift map
-    Ok(Some(ShiftSlipConfig::new(enter_rate, exit_rate, (*map).clone())))
+    let mut config = ShiftSlipConfig::new(enter_rate, exit_rate, (*map).clone()).with_mode(mode);
+    if let Some(min_hold) = shift_slip_min_hold {
+        config = config.with_min_hold(min_hold);
+    }
+    Ok(Some(config))
+}
+
+/// Build a [`MotorWeighting::Custom`] from a `{transition: weight}` mapping.
+///
+/// Missing transitions keep a neutral weight of 1.0.
+pub(crate) fn extract_motor_weights(weights: &Bound<'_, PyDict>) -> PyResult<MotorWeighting> {
+    let mut values = [1.0_f64; 4];
+    for (entry_key, entry_value) in weights.iter() {
+        let key: String = entry_key.extract()?;
+        let slot = MotorWeighting::TRANSITION_KEYS
+            .iter()
+            .position(|name| *name == key)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "unknown motor weight '{key}'. Expected one of: {}",
+                    MotorWeighting::TRANSITION_KEYS.join(", ")
+                ))
+            })?;
+        values[slot] = entry_value
+            .extract()
+            .map_err(|_| PyValueError::new_err(format!("motor weight '{key}' must be a number")))?;
+    }
+    let [same_finger, same_hand, cross_hand, space] = values;
+    MotorWeighting::custom(same_finger, same_hand, cross_hand, space).map_err(PyValueError::new_err)
+}
+
+/// Resolve an optional keyboard name, falling back to US QWERTY only when no
+/// name was given.
+pub(crate) fn parse_keyboard(keyboard: Option<&str>) -> PyResult<KeyboardLayout> {
+    keyboard.map_or(Ok(KeyboardLayout::default()), |name| {
+        KeyboardLayout::parse(name).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "unknown keyboard '{name}'. Expected one of: qwerty, dvorak, colemak, azerty, \
+                 qwertz, spanish_qwerty, swedish_qwerty"
+            ))
+        })
+    })
+}
+
+/// Return the transition multipliers behind each named motor-weighting mode.
+#[pyfunction]
+pub(crate) fn motor_weighting_modes(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let modes = PyDict::new(py);
+    for (name, mode) in MotorWeighting::NAMED {
+        let weights = PyDict::new(py);
+        for (transition, weight) in mode.transition_weights() {
+            weights.set_item(transition, weight)?;
+        }
+        modes.set_item(name, weights)?;
+    }
+    Ok(modes)
 }
 
 #[allow(clippy::too_many_arguments)]
-#[pyfunction(name = "keyboard_typo", signature = (text, max_change_rate, layout, seed=None, shift_slip_rate=None, shift_slip_exit_rate=None, shift_map=None, motor_weighting=None))]
+#[pyfunction(name = "keyboard_typo", signature = (text, max_change_rate, layout, seed=None, shift_slip_rate=None, shift_slip_exit_rate=None, shift_map=None, motor_weighting=None, shift_slip_min_hold=None, shift_slip_mode=None, grapheme_aware=false, burstiness=0.0, homerow_bias=0.0, motor_weights=None, keyboard=None, syllable_swaps=false, return_count=false))]
 pub(crate) fn keyboard_typo(
     text: &str,
     max_change_rate: f64,
@@ -79,9 +145,18 @@ pub(crate) fn keyboard_typo(
     shift_slip_exit_rate: Option<f64>,
     shift_map: Option<&Bound<'_, PyDict>>,
     motor_weighting: Option<&str>,
-) -> PyResult<String> {
+    shift_slip_min_hold: Option<usize>,
+    shift_slip_mode: Option<&str>,
+    grapheme_aware: bool,
+    burstiness: f64,
+    homerow_bias: f64,
+    motor_weights: Option<&Bound<'_, PyDict>>,
+    keyboard: Option<&str>,
+    syllable_swaps: bool,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
     if text.is_empty() {
-        return Ok(String::new());
+        return Ok(crate::GlitchOutput::new(String::new(), 0, return_count));
     }
 
     let layout_arc = extract_layout_map(layout)?;
@@ -92,12 +167,15 @@ pub(crate) fn keyboard_typo(
     let shift_slip = build_shift_slip_config(
         shift_slip_rate.unwrap_or(0.0),
         shift_slip_exit_rate,
+        shift_slip_min_hold,
+        shift_slip_mode,
         shift_map_arc,
     )?;
 
-    let motor_weighting = match motor_weighting {
-        Some(s) => MotorWeighting::parse(s).unwrap_or_default(),
-        None => MotorWeighting::default(),
+    let motor_weighting = match (motor_weights, motor_weighting) {
+        (Some(weights), _) => extract_motor_weights(weights)?,
+        (None, Some(s)) => MotorWeighting::parse(s).unwrap_or_default(),
+        (None, None) => MotorWeighting::default(),
     };
 
     let op = crate::operations::TypoOp {
@@ -105,9 +183,14 @@ pub(crate) fn keyboard_typo(
         layout: (*layout_arc).clone(),
         shift_slip,
         motor_weighting,
+        grapheme_aware,
+        burstiness,
+        homerow_bias,
+        keyboard: parse_keyboard(keyboard)?,
+        syllable_swaps,
     };
 
-    crate::apply_operation(text, op, seed).map_err(crate::operations::OperationError::into_pyerr)
+    crate::apply_operation_py(text, op, seed, return_count)
 }
 
 #[pyfunction(signature = (text, enter_rate, exit_rate, shift_map, seed=None))]
@@ -11,27 +11,34 @@
 //!
 //! Additional dictionaries can be dropped into the assets/lexemes directory
 //! (or another directory pointed to by the `GLITCHLINGS_LEXEME_DIR` environment
-//! variable) without changing the code.
+//! variable) without changing the code, or registered at runtime from Python
+//! with `register_lexeme_dictionary`.
 //!
-//! Two modes are supported:
+//! Three modes are supported:
 //! - "literal": First entry in each word's alternatives (deterministic mapping)
 //! - "drift": Random selection from alternatives (probabilistic)
+//! - "antonym": Random selection from the bundled "antonyms" dictionary,
+//!   regardless of the configured lexemes
 
 use aho_corasick::{AhoCorasick, MatchKind};
-use crate::operations::{TextOperation, OperationError, OperationRng};
+use crate::operations::{TextOperation, OpOutcome, OperationError, OperationRng};
 use crate::rng::DeterministicRng;
 use crate::text_buffer::TextBuffer;
-use std::sync::LazyLock;
-use pyo3::exceptions::PyValueError;
+use std::sync::{Arc, LazyLock, PoisonError, RwLock};
+use pyo3::exceptions::{PyUserWarning, PyValueError};
 use pyo3::prelude::*;
 use std::collections::HashMap;
+use std::ffi::CString;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
 
 const RAW_LEXEMES: &str = include_str!(concat!(env!("OUT_DIR"), "/lexemes.json"));
 
-const VALID_MODE_MESSAGE: &str = "drift, literal";
+const VALID_MODE_MESSAGE: &str = "antonym, drift, literal";
+/// Dictionary consulted by [`JargoyleMode::Antonym`].
+const ANTONYM_LEXEMES: &str = "antonyms";
 const LEXEME_ENV_VAR: &str = "GLITCHLINGS_LEXEME_DIR";
 
 /// A single dictionary mapping words to their alternatives.
@@ -159,17 +166,14 @@ struct LexemeMatcher {
     pattern_keys: Vec<String>,
 }
 
-/// Pre-compiled Aho-Corasick matchers for each dictionary.
-static LEXEME_MATCHERS: LazyLock<HashMap<String, LexemeMatcher>> = LazyLock::new(|| {
-    let mut matchers: HashMap<String, LexemeMatcher> = HashMap::new();
-
-    for (dict_name, dict) in LEXEME_DICTIONARIES.iter() {
+impl LexemeMatcher {
+    fn build(dict: &LexemeDict) -> Option<Self> {
         let mut words: Vec<&str> = dict.keys().map(String::as_str).collect();
         // Sort by length descending so longer matches are preferred
         words.sort_by_key(|w| std::cmp::Reverse(w.len()));
 
         if words.is_empty() {
-            continue;
+            return None;
         }
 
         // Build Aho-Corasick with case-insensitive matching and leftmost-longest semantics
@@ -181,19 +185,67 @@ static LEXEME_MATCHERS: LazyLock<HashMap<String, LexemeMatcher>> = LazyLock::new
 
         let pattern_keys: Vec<String> = words.iter().copied().map(String::from).collect();
 
-        matchers.insert(dict_name.clone(), LexemeMatcher { automaton, pattern_keys });
+        Some(Self { automaton, pattern_keys })
     }
+}
 
-    matchers
+/// Pre-compiled Aho-Corasick matchers for each dictionary.
+static LEXEME_MATCHERS: LazyLock<HashMap<String, LexemeMatcher>> = LazyLock::new(|| {
+    LEXEME_DICTIONARIES
+        .iter()
+        .filter_map(|(dict_name, dict)| {
+            LexemeMatcher::build(dict).map(|matcher| (dict_name.clone(), matcher))
+        })
+        .collect()
 });
 
+/// A dictionary registered at runtime, paired with its compiled matcher.
+struct RegisteredLexeme {
+    dict: LexemeDict,
+    matcher: LexemeMatcher,
+}
+
+/// Dictionaries registered from Python. Consulted before the built-ins so a
+/// registration can shadow a bundled or environment-provided dictionary.
+/// Each insert is a single map write, so a poisoned lock still guards a
+/// consistent map and is read through rather than propagated.
+static REGISTERED_LEXEMES: LazyLock<RwLock<HashMap<String, Arc<RegisteredLexeme>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+fn registered_lexeme(name: &str) -> Option<Arc<RegisteredLexeme>> {
+    REGISTERED_LEXEMES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(name)
+        .cloned()
+}
+
+fn is_known_lexeme(name: &str) -> bool {
+    registered_lexeme(name).is_some() || LEXEME_DICTIONARIES.contains_key(name)
+}
+
+/// Stores `dict` under `name`, returning true when an earlier registration
+/// was replaced.
+fn register_lexeme(name: &str, dict: LexemeDict) -> Result<bool, String> {
+    let matcher = LexemeMatcher::build(&dict)
+        .ok_or_else(|| format!("lexeme dictionary '{name}' has no usable entries"))?;
+    let entry = Arc::new(RegisteredLexeme { dict, matcher });
+    let replaced = REGISTERED_LEXEMES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(name.to_string(), entry);
+    Ok(replaced.is_some())
+}
+
 /// Jargoyle operating mode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum JargoyleMode {
     /// First entry in alternatives (deterministic swap)
     Literal,
     /// Random selection from alternatives
     Drift,
+    /// Random selection from the antonym dictionary
+    Antonym,
 }
 
 impl JargoyleMode {
@@ -202,6 +254,7 @@ impl JargoyleMode {
         match normalized.as_str() {
             "" | "literal" => Ok(Self::Literal),
             "drift" => Ok(Self::Drift),
+            "antonym" | "antonyms" => Ok(Self::Antonym),
             _ => Err(format!(
                 "Unsupported Jargoyle mode '{mode}'. Expected one of: {VALID_MODE_MESSAGE}"
             )),
@@ -439,29 +492,54 @@ fn find_valid_matches(text: &str, matcher: &LexemeMatcher) -> Vec<ValidatedMatch
 }
 
 /// Transform text using the specified dictionary and mode.
+#[cfg(test)]
 fn transform_text(
     text: &str,
     dict_name: &str,
     mode: JargoyleMode,
     rate: f64,
-    mut rng: Option<&mut dyn OperationRng>,
+    rng: Option<&mut dyn OperationRng>,
 ) -> Result<String, OperationError> {
+    transform_text_counted(text, dict_name, mode, rate, rng).map(|(result, _)| result)
+}
+
+/// Like [`transform_text`], also returning the number of words replaced.
+fn transform_text_counted(
+    text: &str,
+    dict_name: &str,
+    mode: JargoyleMode,
+    rate: f64,
+    mut rng: Option<&mut dyn OperationRng>,
+) -> Result<(String, usize), OperationError> {
     if text.is_empty() {
-        return Ok(String::new());
+        return Ok((String::new(), 0));
     }
 
-    let Some(dict) = LEXEME_DICTIONARIES.get(dict_name) else {
-        return Ok(text.to_string()); // Unknown dictionary, return unchanged
+    let dict_name = if mode == JargoyleMode::Antonym {
+        ANTONYM_LEXEMES
+    } else {
+        dict_name
     };
 
-    let Some(matcher) = LEXEME_MATCHERS.get(dict_name) else {
-        return Ok(text.to_string());
+    let registered = registered_lexeme(dict_name);
+    let (dict, matcher) = if let Some(entry) = registered.as_deref() {
+        (&entry.dict, &entry.matcher)
+    } else {
+        let Some(dict) = LEXEME_DICTIONARIES.get(dict_name) else {
+            return Ok((text.to_string(), 0)); // Unknown dictionary, return unchanged
+        };
+
+        let Some(matcher) = LEXEME_MATCHERS.get(dict_name) else {
+            return Ok((text.to_string(), 0));
+        };
+
+        (dict, matcher)
     };
 
     // Find all valid matches with word boundary checks
     let matches = find_valid_matches(text, matcher);
     if matches.is_empty() {
-        return Ok(text.to_string());
+        return Ok((text.to_string(), 0));
     }
 
     // For rate-based selection, determine which matches to transform
@@ -484,7 +562,7 @@ fn transform_text(
 
         max_count = max_count.min(matches.len());
         if max_count == 0 {
-            return Ok(text.to_string());
+            return Ok((text.to_string(), 0));
         }
 
         // Sample indices
@@ -502,6 +580,7 @@ fn transform_text(
     let mut result = String::with_capacity(text.len() + estimated_growth);
     let mut cursor = 0usize;
     let mut transform_index = 0usize;
+    let mut replaced = 0usize;
 
     for (match_index, validated) in matches.iter().enumerate() {
         // Check if this match should be transformed
@@ -526,7 +605,7 @@ fn transform_text(
                         .and_then(|alts| alts.first())
                         .map(String::as_str)
                 }
-                JargoyleMode::Drift => {
+                JargoyleMode::Drift | JargoyleMode::Antonym => {
                     if let Some(ref mut r) = rng {
                         if let Some(alternatives) = dict.get(&validated.dict_key) {
                             if !alternatives.is_empty() {
@@ -551,6 +630,7 @@ fn transform_text(
                 let suffix_fragment = harmonize_suffix(base, replacement_base, suffix);
                 result.push_str(&adjusted);
                 result.push_str(&suffix_fragment);
+                replaced += 1;
             } else {
                 // No replacement found, keep original
                 result.push_str(&text[validated.start..validated.full_end]);
@@ -564,11 +644,11 @@ fn transform_text(
     }
 
     result.push_str(&text[cursor..]);
-    Ok(result)
+    Ok((result, replaced))
 }
 
 /// Jargoyle pipeline operation for the Gaggle system.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LexemeSubstitutionOp {
     pub lexemes: String,
     pub mode: JargoyleMode,
@@ -586,53 +666,95 @@ impl LexemeSubstitutionOp {
 }
 
 impl TextOperation for LexemeSubstitutionOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
-        // For the pipeline, we operate on the full text
-        let text = buffer.to_string();
-        let transformed = transform_text(&text, &self.lexemes, self.mode, self.rate, Some(rng))?;
-
-        // Replace the buffer content
-        *buffer = buffer.rebuild_with_patterns(transformed);
-        Ok(())
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        // For the pipeline, we operate on the full text of each mutable run
+        let mut edits = 0usize;
+        *buffer = buffer.map_mutable_runs(|run, scale| {
+            let rate = self.rate * scale;
+            let (result, replaced) =
+                transform_text_counted(run, &self.lexemes, self.mode, rate, Some(&mut *rng))?;
+            edits += replaced;
+            Ok::<_, OperationError>(result)
+        })?;
+        Ok(OpOutcome::edits(edits))
     }
 }
 
 /// Python-exposed function for lexeme substitution (word drift).
-#[pyfunction(name = "substitute_lexeme", signature = (text, lexemes, mode, rate, seed=None))]
+#[pyfunction(name = "substitute_lexeme", signature = (text, lexemes, mode, rate, seed=None, return_count=false))]
 pub(crate) fn substitute_lexeme(
     text: &str,
     lexemes: &str,
     mode: &str,
     rate: f64,
     seed: Option<u64>,
-) -> PyResult<String> {
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
     let parsed_mode = JargoyleMode::parse(mode).map_err(PyValueError::new_err)?;
     let normalized_lexemes = lexemes.to_ascii_lowercase();
 
     // Validate lexemes
-    if !LEXEME_DICTIONARIES.contains_key(&normalized_lexemes) {
-        let available = VALID_LEXEMES.join(", ");
+    if !is_known_lexeme(&normalized_lexemes) {
+        let available = list_lexeme_dictionaries().join(", ");
         return Err(PyValueError::new_err(format!(
             "Unknown lexemes dictionary '{lexemes}'. Available: {available}"
         )));
     }
 
-    match parsed_mode {
-        JargoyleMode::Literal => transform_text(text, &normalized_lexemes, parsed_mode, rate, None)
-            .map_err(OperationError::into_pyerr),
-        JargoyleMode::Drift => {
+    let (output, edits) = match parsed_mode {
+        JargoyleMode::Literal => {
+            transform_text_counted(text, &normalized_lexemes, parsed_mode, rate, None)
+        }
+        JargoyleMode::Drift | JargoyleMode::Antonym => {
             let seed_value = seed.unwrap_or(0);
             let mut rng = DeterministicRng::new(seed_value);
-            transform_text(text, &normalized_lexemes, parsed_mode, rate, Some(&mut rng))
-                .map_err(OperationError::into_pyerr)
+            transform_text_counted(text, &normalized_lexemes, parsed_mode, rate, Some(&mut rng))
         }
     }
+    .map_err(OperationError::into_pyerr)?;
+    Ok(crate::GlitchOutput::new(output, edits, return_count))
 }
 
-/// List available lexeme dictionaries.
+/// List available lexeme dictionaries, including runtime registrations.
 #[pyfunction]
 pub(crate) fn list_lexeme_dictionaries() -> Vec<String> {
-    VALID_LEXEMES.clone()
+    let mut names = VALID_LEXEMES.clone();
+    let registry = REGISTERED_LEXEMES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    names.extend(registry.keys().cloned());
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Register a lexeme dictionary for the rest of the process.
+///
+/// Keys are matched case-insensitively. Re-registering a name replaces the
+/// earlier dictionary and emits a `UserWarning`.
+#[pyfunction]
+pub(crate) fn register_lexeme_dictionary(
+    py: Python<'_>,
+    name: &str,
+    mapping: HashMap<String, Vec<String>>,
+) -> PyResult<()> {
+    let normalized = name.to_ascii_lowercase();
+    let mut dict: LexemeDict = HashMap::with_capacity(mapping.len());
+    for (word, alternatives) in mapping {
+        if !alternatives.is_empty() {
+            dict.insert(word.to_ascii_lowercase(), alternatives);
+        }
+    }
+
+    let replaced = register_lexeme(&normalized, dict).map_err(PyValueError::new_err)?;
+    if replaced {
+        let message = CString::new(format!(
+            "lexeme dictionary '{normalized}' was already registered and has been replaced"
+        ))
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        PyErr::warn(py, &py.get_type::<PyUserWarning>(), &message, 1)?;
+    }
+    Ok(())
 }
 
 /// List bundled (built-in) lexeme dictionaries embedded at compile time.
@@ -714,6 +836,53 @@ mod tests {
         assert_eq!(result1, result2);
     }
 
+    #[test]
+    fn test_registered_dictionary_takes_precedence() {
+        let dict: LexemeDict =
+            HashMap::from([("red".to_string(), vec!["crimson".to_string(), "scarlet".to_string()])]);
+        assert!(!register_lexeme("test-registry-colors", dict.clone()).expect("register"));
+        assert!(register_lexeme("test-registry-colors", dict).expect("re-register"));
+
+        let result = transform_text(
+            "Red balloon",
+            "test-registry-colors",
+            JargoyleMode::Literal,
+            1.0,
+            None,
+        )
+        .expect("transform should succeed");
+        assert_eq!(result, "Crimson balloon");
+        assert!(list_lexeme_dictionaries().contains(&"test-registry-colors".to_string()));
+
+        let drift = |seed| {
+            let mut rng = DeterministicRng::new(seed);
+            transform_text(
+                "red red red",
+                "test-registry-colors",
+                JargoyleMode::Drift,
+                1.0,
+                Some(&mut rng),
+            )
+            .expect("transform should succeed")
+        };
+        assert_eq!(drift(7), drift(7));
+    }
+
+    #[test]
+    fn test_antonym_mode_swaps_opposites() {
+        let mut rng = DeterministicRng::new(151);
+        let result = transform_text(
+            "Happy, quiet zebra",
+            "synonyms",
+            JargoyleMode::Antonym,
+            1.0,
+            Some(&mut rng),
+        )
+        .expect("transform should succeed");
+        assert_eq!(result, "Sad, loud zebra");
+        assert_eq!(JargoyleMode::parse("antonym"), Ok(JargoyleMode::Antonym));
+    }
+
     #[test]
     fn test_unknown_dictionary_unchanged() {
         let result = transform_text(
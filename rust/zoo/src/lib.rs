@@ -1,16 +1,36 @@
+mod autocorrect;
+mod bidi;
 mod cache;
+mod case;
+mod contractions;
+mod diacritics;
+mod disemvowel;
+mod double_words;
+mod fillers;
 mod homophones;
 mod operations;
 mod word_stretching;
 mod lexeme_substitution;
+mod markup;
 mod metrics;
 mod homoglyphs;
 mod grammar_rules;
+mod phonetic;
 mod pipeline;
+mod pipeline_builder;
+mod punctuation;
+mod py_rng;
 mod resources;
 mod rng;
+mod scramble;
+mod segmentation;
+mod schema;
+mod sentences;
 mod text_buffer;
 mod keyboard_typos;
+mod whitespace;
+mod word_boundaries;
+mod zalgo;
 mod zero_width;
 
 use pyo3::prelude::*;
@@ -19,26 +39,56 @@ use pyo3::Bound;
 use pyo3::{exceptions::PyValueError, FromPyObject};
 use rand::Rng;
 use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use homophones::{HomophoneOp, HomophoneWeighting};
 pub use operations::{
-    DeleteRandomWordsOp, TextOperation, OperationError, Operation, OperationRng, MotorWeighting,
-    OcrArtifactsOp, QuotePairsOp, RedactWordsOp, ReduplicateWordsOp, RushmoreComboMode,
-    RushmoreComboOp, ShiftSlipConfig, SwapAdjacentWordsOp, TypoOp, ZeroWidthOp,
+    DeleteRandomWordsOp, TextOperation, OpOutcome, OperationError, Operation, OperationRng,
+    KeyboardLayout, MotorWeighting,
+    OcrArtifactsOp, OcrMode, OcrSampling, QuotePairsOp, RedactWordsOp, ReduplicateWordsOp,
+    ReduplicationSeparator, RushmoreComboMode, RushmoreComboOp, ShiftSlipConfig, ShiftSlipMode, SwapAdjacentWordsOp, SwapWordsOp, TypoOp, WordWeighting, ZeroWidthOp,
 };
+#[cfg(debug_assertions)]
+pub use operations::{RngDraw, TracingRng};
 pub use word_stretching::WordStretchOp;
 use lexeme_substitution::{JargoyleMode, LexemeSubstitutionOp};
 use homoglyphs::{ClassSelection as MimicClassSelection, HomoglyphMode, HomoglyphOp};
-use grammar_rules::GrammarRuleOp;
-pub use pipeline::{derive_seed, OperationDescriptor, Pipeline, PipelineError};
+use grammar_rules::{GrammarRuleOp, SpellingDirection};
+pub use autocorrect::AutocorrectOp;
+pub use bidi::BidiOp;
+pub use case::{CaseMode, CaseOp};
+pub use contractions::{ContractionMode, ContractionOp};
+pub use disemvowel::DisemvowelOp;
+pub use double_words::DoubleWordOp;
+pub use scramble::ScrambleOp;
+pub use diacritics::{DiacriticMode, DiacriticOp};
+pub use fillers::FillerOp;
+pub use whitespace::WhitespaceJitterOp;
+pub use word_boundaries::{MergeWordsOp, SplitWordsOp};
+pub use zalgo::ZalgoOp;
+pub use markup::{detect_markup_ranges, MarkupKind};
+pub use metrics::{MetricTokenizer, TextMetric};
+pub use phonetic::PhoneticOp;
+pub use punctuation::DropPunctuationOp;
+pub use pipeline::{
+    derive_seed, parse_rate_markup, ChunkBoundary, OpReport, OperationDescriptor, Pipeline, PipelineError,
+    PlannedEdit, RateRegion,
+};
 pub use rng::{DeterministicRng, RngError};
-pub use text_buffer::{SegmentKind, TextBuffer, TextBufferError, TextSegment, TextSpan};
+pub use sentences::{DeleteSentencesOp, ShuffleSentencesOp};
+pub use text_buffer::{
+    RebuildPiece, SegmentKind, TextBuffer, TextBufferError, TextSegment, TextSpan,
+};
 
 fn resolve_seed(seed: Option<u64>) -> u64 {
     seed.unwrap_or_else(|| rand::thread_rng().gen())
 }
 
+/// Validates an operation run outside a pipeline, naming it by its `type`.
+fn validate_lone_operation(operation: &Operation) -> PyResult<()> {
+    pipeline::validate_operation(operation.kind(), operation).map_err(PipelineError::into_pyerr)
+}
+
 /// Operation descriptor extracted from Python dict.
 /// Uses PyO3's derive macro for automatic extraction from dict items.
 #[derive(Debug, FromPyObject)]
@@ -49,14 +99,12 @@ struct PyOperationDescriptor {
     operation: PyOperationConfig,
 }
 
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 
 type Layout = Vec<(String, Vec<String>)>;
 
-fn layout_cache() -> &'static cache::ContentCache<Layout> {
-    static CACHE: std::sync::OnceLock<cache::ContentCache<Layout>> = std::sync::OnceLock::new();
-    CACHE.get_or_init(cache::ContentCache::new)
-}
+/// Keyboard layouts keyed by content, shared across typo operations.
+static LAYOUT_CACHE: LazyLock<cache::ContentCache<Layout>> = LazyLock::new(cache::ContentCache::new);
 
 enum MissingFieldSuffix {
     Absent,
@@ -113,6 +161,34 @@ where
         .transpose()
 }
 
+fn parse_reduplication_separator(separator: &str) -> PyResult<ReduplicationSeparator> {
+    ReduplicationSeparator::parse(separator).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Unsupported reduplication separator '{separator}'. Expected one of: space, comma, hyphen"
+        ))
+    })
+}
+
+fn extract_reduplication_separator(dict: &Bound<'_, PyDict>) -> PyResult<ReduplicationSeparator> {
+    extract_optional_field::<String>(dict, "separator")?
+        .map_or(Ok(ReduplicationSeparator::default()), |separator| {
+            parse_reduplication_separator(&separator)
+        })
+}
+
+fn parse_word_weighting(weighting: &str) -> PyResult<WordWeighting> {
+    WordWeighting::parse(weighting).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Unsupported word weighting '{weighting}'. Expected one of: length, content"
+        ))
+    })
+}
+
+fn extract_word_weighting(dict: &Bound<'_, PyDict>) -> PyResult<WordWeighting> {
+    extract_optional_field::<String>(dict, "weighting")?
+        .map_or(Ok(WordWeighting::default()), |weighting| parse_word_weighting(&weighting))
+}
+
 fn extract_layout_vec(layout_dict: &Bound<'_, PyDict>) -> PyResult<Arc<Layout>> {
     // First, materialize to compute the content hash
     let mut materialised: Vec<(String, Vec<String>)> = Vec::with_capacity(layout_dict.len());
@@ -122,9 +198,13 @@ fn extract_layout_vec(layout_dict: &Bound<'_, PyDict>) -> PyResult<Arc<Layout>>
 
     // Use content-based caching - returns Arc for cheap access
     let hash = cache::hash_layout_vec(&materialised);
-    Ok(layout_cache().get_or_insert_with(hash, || materialised))
+    Ok(LAYOUT_CACHE.get_or_insert_matching(hash, materialised, |cached, fresh| {
+        cache::layout_vecs_match(cached, fresh)
+    }))
 }
 
+/// Converts Python descriptors into operations, validating each one as it is
+/// built and labelling failures with the descriptor's name.
 fn build_operation_descriptors(
     descriptors: Vec<PyOperationDescriptor>,
 ) -> PyResult<Vec<OperationDescriptor>> {
@@ -134,6 +214,8 @@ fn build_operation_descriptors(
             let operation = descriptor
                 .operation
                 .into_operation(descriptor.seed)?;
+            pipeline::validate_operation(&descriptor.name, &operation)
+                .map_err(PipelineError::into_pyerr)?;
             Ok(OperationDescriptor {
                 name: descriptor.name,
                 seed: descriptor.seed,
@@ -148,48 +230,205 @@ fn build_pipeline_from_py(
     master_seed: i128,
     include_only_patterns: Option<Vec<String>>,
     exclude_patterns: Option<Vec<String>>,
+    max_total_edits: Option<usize>,
 ) -> PyResult<Pipeline> {
     let operations = build_operation_descriptors(descriptors)?;
     let include_patterns = include_only_patterns.unwrap_or_default();
     let exclude_patterns = exclude_patterns.unwrap_or_default();
-    Pipeline::compile(master_seed, operations, include_patterns, exclude_patterns)
-        .map_err(PipelineError::into_pyerr)
+    Pipeline::compile(
+        master_seed,
+        operations,
+        include_patterns,
+        exclude_patterns,
+        max_total_edits,
+    )
+    .map_err(PipelineError::into_pyerr)
 }
 
 /// Threshold below which we don't release the GIL (overhead not worth it).
 /// Based on benchmarks: GIL release overhead is ~1-2μs, processing is ~50ns/char.
 const GIL_RELEASE_THRESHOLD: usize = 256;
 
+/// Runs pure-Rust pipeline work, releasing the GIL when `text` is long enough
+/// for other Python threads to benefit.
+///
+/// Every op draws from a `DeterministicRng` and never calls back into Python,
+/// so the work is safe to run detached.
+fn run_without_gil<T, F>(py: Python<'_>, text: &str, work: F) -> T
+where
+    T: Send,
+    F: FnOnce(&str) -> T + Send,
+{
+    if text.len() < GIL_RELEASE_THRESHOLD {
+        return work(text);
+    }
+    py.allow_threads(|| work(text))
+}
+
 #[pymethods]
 impl Pipeline {
     #[new]
-    #[pyo3(signature = (descriptors, master_seed, include_only_patterns=None, exclude_patterns=None))]
+    #[pyo3(signature = (descriptors, master_seed, include_only_patterns=None, exclude_patterns=None, max_total_edits=None))]
     fn py_new(
         descriptors: Vec<PyOperationDescriptor>,
         master_seed: i128,
         include_only_patterns: Option<Vec<String>>,
         exclude_patterns: Option<Vec<String>>,
+        max_total_edits: Option<usize>,
     ) -> PyResult<Self> {
         build_pipeline_from_py(
             descriptors,
             master_seed,
             include_only_patterns,
             exclude_patterns,
+            max_total_edits,
         )
     }
 
     #[pyo3(name = "run")]
     fn run_py(&self, py: Python<'_>, text: &str) -> PyResult<String> {
-        // For small texts, don't bother releasing GIL - overhead exceeds benefit
-        if text.len() < GIL_RELEASE_THRESHOLD {
-            return self.run(text).map_err(PipelineError::into_pyerr);
-        }
+        run_without_gil(py, text, |text| self.run(text)).map_err(PipelineError::into_pyerr)
+    }
 
-        let pipeline = self.clone();
-        let text_owned = text.to_string();
-        py.allow_threads(move || {
-            pipeline.run(&text_owned).map_err(PipelineError::into_pyerr)
+    /// Corrupt only the characters in `start..end`, leaving the rest untouched.
+    #[pyo3(name = "run_range")]
+    fn run_range_py(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        start: usize,
+        end: usize,
+    ) -> PyResult<String> {
+        run_without_gil(py, text, |text| self.run_range(text, start..end))
+            .map_err(PipelineError::into_pyerr)
+    }
+
+    /// Run with rates scaled by `(start, end, multiplier)` character regions.
+    #[pyo3(name = "run_with_rate_regions")]
+    fn run_with_rate_regions_py(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        regions: Vec<(usize, usize, f64)>,
+    ) -> PyResult<String> {
+        let regions: Vec<RateRegion> = regions
+            .into_iter()
+            .map(|(start, end, multiplier)| RateRegion {
+                range: start..end,
+                multiplier,
+            })
+            .collect();
+        run_without_gil(py, text, |text| self.run_with_rate_regions(text, &regions))
+            .map_err(PipelineError::into_pyerr)
+    }
+
+    /// Strip `{open}multiplier|content{close}` markup and run with the
+    /// resulting rate regions.
+    #[pyo3(name = "run_with_rate_markup", signature = (text, open="[[", close="]]"))]
+    fn run_with_rate_markup_py(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        open: &str,
+        close: &str,
+    ) -> PyResult<String> {
+        let (plain, regions) =
+            parse_rate_markup(text, open, close).map_err(PyValueError::new_err)?;
+        run_without_gil(py, &plain, |plain| self.run_with_rate_regions(plain, &regions))
+            .map_err(PipelineError::into_pyerr)
+    }
+
+    /// Serialise the pipeline (master seed, descriptors and patterns) to JSON.
+    #[pyo3(name = "to_json")]
+    fn to_json_py(&self) -> String {
+        self.to_json()
+    }
+
+    /// Rebuild a pipeline from the output of `to_json`.
+    #[staticmethod]
+    #[pyo3(name = "from_json")]
+    fn from_json_py(json: &str) -> PyResult<Self> {
+        Self::from_json(json).map_err(PipelineError::into_pyerr)
+    }
+
+    /// Corrupt one chunk of a streamed input, seeded by its index.
+    #[pyo3(name = "run_chunk")]
+    fn run_chunk_py(&self, py: Python<'_>, chunk: &str, index: usize) -> PyResult<String> {
+        run_without_gil(py, chunk, |chunk| self.run_chunk(chunk, index))
+            .map_err(PipelineError::into_pyerr)
+    }
+
+    /// Corrupt `text` chunk by chunk, exactly as `run_streaming` would.
+    #[pyo3(name = "run_chunked", signature = (text, boundary="line"))]
+    fn run_chunked_py(&self, py: Python<'_>, text: &str, boundary: &str) -> PyResult<String> {
+        let boundary = ChunkBoundary::parse_py(boundary)?;
+        run_without_gil(py, text, |text| {
+            let mut output = Vec::with_capacity(text.len());
+            self.run_streaming(text.as_bytes(), &mut output, boundary)?;
+            Ok(String::from_utf8(output).expect("pipeline output is valid UTF-8"))
         })
+        .map_err(PipelineError::into_pyerr)
+    }
+
+    /// Run the pipeline and return the output alongside one report dict per
+    /// operation (`name`, `edits`, `char_delta`, `word_delta`, `elapsed_seconds`).
+    #[pyo3(name = "run_instrumented")]
+    fn run_instrumented_py<'py>(
+        &self,
+        py: Python<'py>,
+        text: &str,
+    ) -> PyResult<(String, Vec<Bound<'py, PyDict>>)> {
+        let (output, reports) = run_without_gil(py, text, |text| self.run_instrumented(text))
+            .map_err(PipelineError::into_pyerr)?;
+        let dicts = reports
+            .into_iter()
+            .map(|report| {
+                let dict = PyDict::new(py);
+                dict.set_item("name", report.name)?;
+                dict.set_item("edits", report.edits)?;
+                dict.set_item("char_delta", report.char_delta)?;
+                dict.set_item("word_delta", report.word_delta)?;
+                dict.set_item("elapsed_seconds", report.elapsed.as_secs_f64())?;
+                Ok(dict)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok((output, dicts))
+    }
+
+    /// Run the operations one at a time and return `(name, value)` pairs
+    /// scoring each step against the original with the named metric.
+    #[pyo3(name = "run_with_metric_trace", signature = (text, metric="normalized_edit_distance"))]
+    fn run_with_metric_trace_py(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        metric: &str,
+    ) -> PyResult<Vec<(String, f64)>> {
+        let metric = TextMetric::parse(metric).map_err(PyValueError::new_err)?;
+        run_without_gil(py, text, |text| self.run_with_metric_trace(text, metric))
+            .map_err(PipelineError::into_pyerr)
+    }
+
+    /// Preview the pipeline: one dict per planned edit with `name`, `start`,
+    /// `end` (character offsets into that op's input), `original` and
+    /// `replacement`.
+    #[pyo3(name = "plan_edits")]
+    fn plan_edits_py<'py>(&self, py: Python<'py>, text: &str) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let edits = run_without_gil(py, text, |text| self.plan_edits(text))
+            .map_err(PipelineError::into_pyerr)?;
+        edits
+            .into_iter()
+            .map(|edit| {
+                let dict = PyDict::new(py);
+                dict.set_item("op_index", edit.op_index)?;
+                dict.set_item("name", edit.name)?;
+                dict.set_item("start", edit.range.start)?;
+                dict.set_item("end", edit.range.end)?;
+                dict.set_item("original", edit.original)?;
+                dict.set_item("replacement", edit.replacement)?;
+                Ok(dict)
+            })
+            .collect()
     }
 
     /// Process multiple texts in parallel.
@@ -220,30 +459,97 @@ struct PyPlanInput {
     order: i32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum PyOperationConfig {
     Reduplicate {
         rate: f64,
         unweighted: bool,
+        repeats: usize,
+        stutter: bool,
+        separator: ReduplicationSeparator,
     },
     Delete {
         rate: f64,
         unweighted: bool,
+        min_words: usize,
+        weighting: WordWeighting,
+        preserve_spacing: bool,
     },
     SwapAdjacent {
         rate: f64,
     },
+    SwapWords {
+        rate: f64,
+        max_distance: usize,
+    },
+    DeleteSentences {
+        rate: f64,
+    },
+    ShuffleSentences {
+        rate: f64,
+    },
+    Phonetic {
+        rate: f64,
+    },
+    Bidi {
+        rate: f64,
+    },
+    Fillers(FillerOp),
+    WhitespaceJitter(WhitespaceJitterOp),
+    Case {
+        rate: f64,
+        mode: CaseMode,
+    },
+    Contractions {
+        rate: f64,
+        mode: ContractionMode,
+    },
+    Disemvowel {
+        rate: f64,
+    },
+    DoubleWords {
+        rate: f64,
+    },
+    Scramble {
+        rate: f64,
+    },
+    DropPunctuation {
+        rate: f64,
+        lowercase_sentence_starts: bool,
+    },
+    Autocorrect {
+        rate: f64,
+    },
+    MergeWords {
+        rate: f64,
+    },
+    SplitWords {
+        rate: f64,
+        min_length: usize,
+    },
+    Diacritics {
+        rate: f64,
+        mode: DiacriticMode,
+    },
+    Zalgo {
+        intensity: usize,
+        rate: f64,
+    },
     RushmoreCombo {
         modes: Vec<String>,
         delete: Option<DeleteRandomWordsOp>,
         duplicate: Option<ReduplicateWordsOp>,
         swap: Option<SwapAdjacentWordsOp>,
+        shuffle_modes: bool,
     },
     Redact {
         replacement_char: String,
         rate: f64,
         merge_adjacent: bool,
+        merge_through_punctuation: bool,
+        fixed_width: Option<usize>,
         unweighted: bool,
+        weighting: WordWeighting,
     },
     Ocr {
         rate: f64,
@@ -257,12 +563,20 @@ enum PyOperationConfig {
         // Whitespace error parameters (Smith, 2007)
         space_drop_rate: f64,
         space_insert_rate: f64,
+        mode: OcrMode,
+        sampling: OcrSampling,
+        extra_confusions: Vec<(String, Vec<String>)>,
     },
     Typo {
         rate: f64,
         layout: Arc<Layout>,
         shift_slip: Option<ShiftSlipConfig>,
         motor_weighting: MotorWeighting,
+        grapheme_aware: bool,
+        burstiness: f64,
+        homerow_bias: f64,
+        keyboard: KeyboardLayout,
+        syllable_swaps: bool,
     },
     Mimic {
         rate: f64,
@@ -270,6 +584,7 @@ enum PyOperationConfig {
         banned: Vec<String>,
         mode: HomoglyphMode,
         max_consecutive: usize,
+        only_chars: Option<HashSet<char>>,
     },
     ZeroWidth {
         rate: f64,
@@ -283,7 +598,9 @@ enum PyOperationConfig {
         mode: JargoyleMode,
         rate: f64,
     },
-    QuotePairs,
+    QuotePairs {
+        contractions: bool,
+    },
     Hokey {
         rate: f64,
         extension_min: i32,
@@ -294,10 +611,13 @@ enum PyOperationConfig {
     Wherewolf {
         rate: f64,
         weighting: String,
+        extra_sets: Vec<Vec<String>>,
     },
     Pedant {
-        stone: String,
+        stone: Option<String>,
+        direction: Option<String>,
     },
+    Identity,
 }
 
 impl<'py> FromPyObject<'py> for PyOperationConfig {
@@ -308,17 +628,133 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
             "reduplicate" => {
                 let rate = extract_required_field(dict, "reduplicate operation", "rate")?;
                 let unweighted = extract_optional_field(dict, "unweighted")?.unwrap_or(false);
-                Ok(Self::Reduplicate { rate, unweighted })
+                let repeats = extract_optional_field(dict, "repeats")?.unwrap_or(1);
+                let stutter = extract_optional_field(dict, "stutter")?.unwrap_or(false);
+                let separator = extract_reduplication_separator(dict)?;
+                Ok(Self::Reduplicate {
+                    rate,
+                    unweighted,
+                    repeats,
+                    stutter,
+                    separator,
+                })
             }
             "delete" => {
                 let rate = extract_required_field(dict, "delete operation", "rate")?;
                 let unweighted = extract_optional_field(dict, "unweighted")?.unwrap_or(false);
-                Ok(Self::Delete { rate, unweighted })
+                let min_words = extract_optional_field(dict, "min_words")?.unwrap_or(0);
+                let weighting = extract_word_weighting(dict)?;
+                let preserve_spacing =
+                    extract_optional_field(dict, "preserve_spacing")?.unwrap_or(false);
+                Ok(Self::Delete {
+                    rate,
+                    unweighted,
+                    min_words,
+                    weighting,
+                    preserve_spacing,
+                })
             }
             "swap_adjacent" => {
                 let rate = extract_required_field(dict, "swap_adjacent operation", "rate")?;
                 Ok(Self::SwapAdjacent { rate })
             }
+            "swap_words" => {
+                let rate = extract_required_field(dict, "swap_words operation", "rate")?;
+                let max_distance =
+                    extract_optional_field(dict, "max_distance")?.unwrap_or(1);
+                Ok(Self::SwapWords { rate, max_distance })
+            }
+            "delete_sentences" => {
+                let rate = extract_required_field(dict, "delete_sentences operation", "rate")?;
+                Ok(Self::DeleteSentences { rate })
+            }
+            "shuffle_sentences" => {
+                let rate = extract_optional_field(dict, "rate")?.unwrap_or(1.0);
+                Ok(Self::ShuffleSentences { rate })
+            }
+            "phonetic" => {
+                let rate = extract_required_field(dict, "phonetic operation", "rate")?;
+                Ok(Self::Phonetic { rate })
+            }
+            "bidi" => {
+                let rate = extract_required_field(dict, "bidi operation", "rate")?;
+                Ok(Self::Bidi { rate })
+            }
+            "fillers" => {
+                let rate = extract_required_field(dict, "fillers operation", "rate")?;
+                let fillers = extract_optional_field(dict, "fillers")?;
+                let commas = extract_optional_field(dict, "commas")?.unwrap_or(false);
+                let trailing = extract_optional_field(dict, "trailing")?.unwrap_or(false);
+                let op = FillerOp::new(rate, fillers)
+                    .map_err(PyValueError::new_err)?
+                    .with_commas(commas)
+                    .with_trailing(trailing);
+                Ok(Self::Fillers(op))
+            }
+            "whitespace_jitter" => {
+                let rate = extract_required_field(dict, "whitespace_jitter operation", "rate")?;
+                let palette = extract_optional_field(dict, "palette")?;
+                let op = WhitespaceJitterOp::new(rate, palette).map_err(PyValueError::new_err)?;
+                Ok(Self::WhitespaceJitter(op))
+            }
+            "case" => {
+                let rate = extract_required_field(dict, "case operation", "rate")?;
+                let mode: String = extract_required_field(dict, "case operation", "mode")?;
+                let mode = CaseMode::parse(&mode).map_err(PyValueError::new_err)?;
+                Ok(Self::Case { rate, mode })
+            }
+            "contractions" => {
+                let rate = extract_required_field(dict, "contractions operation", "rate")?;
+                let mode: String = extract_required_field(dict, "contractions operation", "mode")?;
+                let mode = ContractionMode::parse(&mode).map_err(PyValueError::new_err)?;
+                Ok(Self::Contractions { rate, mode })
+            }
+            "disemvowel" => {
+                let rate = extract_required_field(dict, "disemvowel operation", "rate")?;
+                Ok(Self::Disemvowel { rate })
+            }
+            "double_words" => {
+                let rate = extract_required_field(dict, "double_words operation", "rate")?;
+                Ok(Self::DoubleWords { rate })
+            }
+            "scramble" => {
+                let rate = extract_required_field(dict, "scramble operation", "rate")?;
+                Ok(Self::Scramble { rate })
+            }
+            "drop_punctuation" => {
+                let rate = extract_required_field(dict, "drop_punctuation operation", "rate")?;
+                let lowercase_sentence_starts =
+                    extract_optional_field(dict, "lowercase_sentence_starts")?.unwrap_or(false);
+                Ok(Self::DropPunctuation {
+                    rate,
+                    lowercase_sentence_starts,
+                })
+            }
+            "autocorrect" => {
+                let rate = extract_required_field(dict, "autocorrect operation", "rate")?;
+                Ok(Self::Autocorrect { rate })
+            }
+            "merge_words" => {
+                let rate = extract_required_field(dict, "merge_words operation", "rate")?;
+                Ok(Self::MergeWords { rate })
+            }
+            "split_words" => {
+                let rate = extract_required_field(dict, "split_words operation", "rate")?;
+                let min_length = extract_optional_field(dict, "min_length")?
+                    .unwrap_or(word_boundaries::DEFAULT_SPLIT_MIN_LENGTH);
+                Ok(Self::SplitWords { rate, min_length })
+            }
+            "diacritics" => {
+                let rate = extract_required_field(dict, "diacritics operation", "rate")?;
+                let mode: String = extract_required_field(dict, "diacritics operation", "mode")?;
+                let mode = DiacriticMode::parse(&mode).map_err(PyValueError::new_err)?;
+                Ok(Self::Diacritics { rate, mode })
+            }
+            "zalgo" => {
+                let intensity = extract_required_field(dict, "zalgo operation", "intensity")?;
+                let rate = extract_required_field(dict, "zalgo operation", "rate")?;
+                Ok(Self::Zalgo { intensity, rate })
+            }
             "rushmore_combo" => {
                 let modes: Vec<String> =
                     extract_required_field(dict, "rushmore_combo operation", "modes")?;
@@ -331,7 +767,18 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
                             extract_required_field(mapping, "rushmore_combo delete", "rate")?;
                         let unweighted =
                             extract_optional_field(mapping, "unweighted")?.unwrap_or(false);
-                        Ok(DeleteRandomWordsOp { rate, unweighted })
+                        let min_words =
+                            extract_optional_field(mapping, "min_words")?.unwrap_or(0);
+                        let weighting = extract_word_weighting(mapping)?;
+                        let preserve_spacing =
+                            extract_optional_field(mapping, "preserve_spacing")?.unwrap_or(false);
+                        Ok(DeleteRandomWordsOp {
+                            rate,
+                            unweighted,
+                            min_words,
+                            weighting,
+                            preserve_spacing,
+                        })
                     })
                     .transpose()?;
 
@@ -343,7 +790,16 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
                             extract_required_field(mapping, "rushmore_combo duplicate", "rate")?;
                         let unweighted =
                             extract_optional_field(mapping, "unweighted")?.unwrap_or(false);
-                        Ok(ReduplicateWordsOp { rate, unweighted })
+                        let repeats = extract_optional_field(mapping, "repeats")?.unwrap_or(1);
+                        let stutter = extract_optional_field(mapping, "stutter")?.unwrap_or(false);
+                        let separator = extract_reduplication_separator(mapping)?;
+                        Ok(ReduplicateWordsOp {
+                            rate,
+                            unweighted,
+                            repeats,
+                            stutter,
+                            separator,
+                        })
                     })
                     .transpose()?;
 
@@ -355,12 +811,15 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
                         Ok(SwapAdjacentWordsOp { rate })
                     })
                     .transpose()?;
+                let shuffle_modes =
+                    extract_optional_field(dict, "shuffle_modes")?.unwrap_or(false);
 
                 Ok(Self::RushmoreCombo {
                     modes,
                     delete,
                     duplicate,
                     swap,
+                    shuffle_modes,
                 })
             }
             "redact" => {
@@ -369,12 +828,19 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
                 let rate = extract_required_field(dict, "redact operation", "rate")?;
                 let merge_adjacent =
                     extract_required_field(dict, "redact operation", "merge_adjacent")?;
+                let merge_through_punctuation =
+                    extract_optional_field(dict, "merge_through_punctuation")?.unwrap_or(false);
+                let fixed_width = extract_optional_field(dict, "fixed_width")?;
                 let unweighted = extract_optional_field(dict, "unweighted")?.unwrap_or(false);
+                let weighting = extract_word_weighting(dict)?;
                 Ok(Self::Redact {
                     replacement_char,
                     rate,
                     merge_adjacent,
+                    merge_through_punctuation,
+                    fixed_width,
                     unweighted,
+                    weighting,
                 })
             }
             "ocr" => {
@@ -389,6 +855,16 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
                 // Whitespace error parameters (Smith, 2007)
                 let space_drop_rate = extract_optional_field(dict, "space_drop_rate")?.unwrap_or(0.0);
                 let space_insert_rate = extract_optional_field(dict, "space_insert_rate")?.unwrap_or(0.0);
+                let mode = match extract_optional_field::<String>(dict, "mode")? {
+                    Some(mode) => parse_ocr_mode(&mode)?,
+                    None => OcrMode::Both,
+                };
+                let sampling = match extract_optional_field::<String>(dict, "sampling")? {
+                    Some(sampling) => parse_ocr_sampling(&sampling)?,
+                    None => OcrSampling::Count,
+                };
+                let extra_confusions =
+                    extract_optional_field(dict, "extra_confusions")?.unwrap_or_default();
                 Ok(Self::Ocr {
                     rate,
                     burst_enter,
@@ -398,6 +874,9 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
                     bias_beta,
                     space_drop_rate,
                     space_insert_rate,
+                    mode,
+                    sampling,
+                    extra_confusions,
                 })
             }
             "typo" => {
@@ -410,6 +889,9 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
                 let shift_slip_rate =
                     extract_optional_field(dict, "shift_slip_rate")?.unwrap_or(0.0);
                 let shift_slip_exit_rate = extract_optional_field(dict, "shift_slip_exit_rate")?;
+                let shift_slip_min_hold = extract_optional_field(dict, "shift_slip_min_hold")?;
+                let shift_slip_mode: Option<String> =
+                    extract_optional_field(dict, "shift_slip_mode")?;
                 let shift_map = dict
                     .get_item("shift_map")?
                     .map(|value| -> PyResult<Arc<HashMap<String, String>>> {
@@ -420,20 +902,43 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
                 let shift_slip = keyboard_typos::build_shift_slip_config(
                     shift_slip_rate,
                     shift_slip_exit_rate,
+                    shift_slip_min_hold,
+                    shift_slip_mode.as_deref(),
                     shift_map,
                 )?;
                 let motor_weighting_str: Option<String> =
                     extract_optional_field(dict, "motor_weighting")?;
-                let motor_weighting = motor_weighting_str
-                    .as_deref()
-                    .and_then(MotorWeighting::parse)
-                    .unwrap_or_default();
+                let motor_weights = dict
+                    .get_item("motor_weights")?
+                    .map(|value| -> PyResult<MotorWeighting> {
+                        keyboard_typos::extract_motor_weights(value.downcast::<PyDict>()?)
+                    })
+                    .transpose()?;
+                let motor_weighting = motor_weights.unwrap_or_else(|| {
+                    motor_weighting_str
+                        .as_deref()
+                        .and_then(MotorWeighting::parse)
+                        .unwrap_or_default()
+                });
+                let grapheme_aware =
+                    extract_optional_field(dict, "grapheme_aware")?.unwrap_or(false);
+                let burstiness = extract_optional_field(dict, "burstiness")?.unwrap_or(0.0);
+                let homerow_bias = extract_optional_field(dict, "homerow_bias")?.unwrap_or(0.0);
+                let keyboard_name: Option<String> = extract_optional_field(dict, "keyboard")?;
+                let keyboard = keyboard_typos::parse_keyboard(keyboard_name.as_deref())?;
+                let syllable_swaps =
+                    extract_optional_field(dict, "syllable_swaps")?.unwrap_or(false);
 
                 Ok(Self::Typo {
                     rate,
                     layout,
                     shift_slip,
                     motor_weighting,
+                    grapheme_aware,
+                    burstiness,
+                    homerow_bias,
+                    keyboard,
+                    syllable_swaps,
                 })
             }
             "mimic" => {
@@ -444,12 +949,15 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
                 let mode_str: Option<String> = extract_optional_field(dict, "mode")?;
                 let mode = homoglyphs::parse_homoglyph_mode(mode_str.as_deref());
                 let max_consecutive: usize = extract_optional_field(dict, "max_consecutive")?.unwrap_or(3);
+                let only_chars =
+                    homoglyphs::parse_only_characters(dict.get_item("only_characters")?)?;
                 Ok(Self::Mimic {
                     rate,
                     classes,
                     banned,
                     mode,
                     max_consecutive,
+                    only_chars,
                 })
             }
             "zwj" => {
@@ -486,13 +994,26 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
                 let rate = extract_required_field(dict, "wherewolf operation", "rate")?;
                 let weighting = extract_optional_field(dict, "weighting")?
                     .unwrap_or_else(|| HomophoneWeighting::Flat.as_str().to_string());
-                Ok(Self::Wherewolf { rate, weighting })
+                let extra_sets = extract_optional_field(dict, "extra_sets")?.unwrap_or_default();
+                Ok(Self::Wherewolf {
+                    rate,
+                    weighting,
+                    extra_sets,
+                })
             }
             "pedant" => {
-                let stone = extract_required_field(dict, "pedant operation", "stone")?;
-                Ok(Self::Pedant { stone })
+                let direction: Option<String> = extract_optional_field(dict, "direction")?;
+                let stone = if direction.is_some() {
+                    extract_optional_field(dict, "stone")?
+                } else {
+                    Some(extract_required_field(dict, "pedant operation", "stone")?)
+                };
+                Ok(Self::Pedant { stone, direction })
+            }
+            "apostrofae" | "quote_pairs" => {
+                let contractions = extract_optional_field(dict, "contractions")?.unwrap_or(false);
+                Ok(Self::QuotePairs { contractions })
             }
-            "apostrofae" | "quote_pairs" => Ok(Self::QuotePairs),
             "hokey" => {
                 let rate = extract_required_field(dict, "hokey operation", "rate")?;
                 let extension_min =
@@ -510,6 +1031,7 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
                     base_p,
                 })
             }
+            "identity" => Ok(Self::Identity),
             other => Err(PyValueError::new_err(format!(
                 "unsupported operation type: {other}"
             ))),
@@ -520,20 +1042,75 @@ impl<'py> FromPyObject<'py> for PyOperationConfig {
 impl PyOperationConfig {
     fn into_operation(self, seed: u64) -> PyResult<Operation> {
         let operation = match self {
-            Self::Reduplicate { rate, unweighted } => {
-                Operation::Reduplicate(operations::ReduplicateWordsOp { rate, unweighted })
-            }
-            Self::Delete { rate, unweighted } => {
-                Operation::Delete(operations::DeleteRandomWordsOp { rate, unweighted })
-            }
+            Self::Reduplicate {
+                rate,
+                unweighted,
+                repeats,
+                stutter,
+                separator,
+            } => Operation::Reduplicate(operations::ReduplicateWordsOp {
+                rate,
+                unweighted,
+                repeats,
+                stutter,
+                separator,
+            }),
+            Self::Delete {
+                rate,
+                unweighted,
+                min_words,
+                weighting,
+                preserve_spacing,
+            } => Operation::Delete(operations::DeleteRandomWordsOp {
+                rate,
+                unweighted,
+                min_words,
+                weighting,
+                preserve_spacing,
+            }),
             Self::SwapAdjacent { rate } => {
                 Operation::SwapAdjacent(operations::SwapAdjacentWordsOp { rate })
             }
+            Self::SwapWords { rate, max_distance } => {
+                Operation::SwapWords(operations::SwapWordsOp { rate, max_distance })
+            }
+            Self::DeleteSentences { rate } => {
+                Operation::DeleteSentences(DeleteSentencesOp { rate })
+            }
+            Self::ShuffleSentences { rate } => {
+                Operation::ShuffleSentences(ShuffleSentencesOp { rate })
+            }
+            Self::Phonetic { rate } => Operation::Phonetic(PhoneticOp { rate }),
+            Self::Bidi { rate } => Operation::Bidi(BidiOp { rate }),
+            Self::Fillers(op) => Operation::Fillers(op),
+            Self::WhitespaceJitter(op) => Operation::WhitespaceJitter(op),
+            Self::Case { rate, mode } => Operation::Case(CaseOp { rate, mode }),
+            Self::Contractions { rate, mode } => {
+                Operation::Contractions(ContractionOp { rate, mode })
+            }
+            Self::Disemvowel { rate } => Operation::Disemvowel(DisemvowelOp { rate }),
+            Self::DoubleWords { rate } => Operation::DoubleWords(DoubleWordOp { rate }),
+            Self::Scramble { rate } => Operation::Scramble(ScrambleOp { rate }),
+            Self::DropPunctuation {
+                rate,
+                lowercase_sentence_starts,
+            } => Operation::DropPunctuation(DropPunctuationOp {
+                rate,
+                lowercase_sentence_starts,
+            }),
+            Self::Autocorrect { rate } => Operation::Autocorrect(AutocorrectOp { rate }),
+            Self::MergeWords { rate } => Operation::MergeWords(MergeWordsOp { rate }),
+            Self::SplitWords { rate, min_length } => {
+                Operation::SplitWords(SplitWordsOp { rate, min_length })
+            }
+            Self::Diacritics { rate, mode } => Operation::Diacritics(DiacriticOp { rate, mode }),
+            Self::Zalgo { intensity, rate } => Operation::Zalgo(ZalgoOp { intensity, rate }),
             Self::RushmoreCombo {
                 modes,
                 delete,
                 duplicate,
                 swap,
+                shuffle_modes,
             } => {
                 let rushmore_modes = modes
                     .into_iter()
@@ -546,23 +1123,27 @@ impl PyOperationConfig {
                         ))),
                     })
                     .collect::<Result<Vec<_>, PyErr>>()?;
-                Operation::RushmoreCombo(operations::RushmoreComboOp::new(
-                    rushmore_modes,
-                    delete,
-                    duplicate,
-                    swap,
-                ))
+                Operation::RushmoreCombo(
+                    operations::RushmoreComboOp::new(rushmore_modes, delete, duplicate, swap)
+                        .with_shuffle_modes(shuffle_modes),
+                )
             }
             Self::Redact {
                 replacement_char,
                 rate,
                 merge_adjacent,
+                merge_through_punctuation,
+                fixed_width,
                 unweighted,
+                weighting,
             } => Operation::Redact(operations::RedactWordsOp {
                 replacement_char,
                 rate,
                 merge_adjacent,
+                merge_through_punctuation,
+                fixed_width,
                 unweighted,
+                weighting,
             }),
             Self::Ocr {
                 rate,
@@ -573,8 +1154,11 @@ impl PyOperationConfig {
                 bias_beta,
                 space_drop_rate,
                 space_insert_rate,
-            } => {
-                Operation::Ocr(operations::OcrArtifactsOp::with_params(
+                mode,
+                sampling,
+                extra_confusions,
+            } => Operation::Ocr(
+                operations::OcrArtifactsOp::with_params(
                     rate,
                     burst_enter,
                     burst_exit,
@@ -583,13 +1167,21 @@ impl PyOperationConfig {
                     bias_beta,
                     space_drop_rate,
                     space_insert_rate,
-                ))
-            }
+                )
+                .with_mode(mode)
+                .with_sampling(sampling)
+                .with_extra_confusions(extra_confusions),
+            ),
             Self::Typo {
                 rate,
                 layout,
                 shift_slip,
                 motor_weighting,
+                grapheme_aware,
+                burstiness,
+                homerow_bias,
+                keyboard,
+                syllable_swaps,
             } => {
                 // Clone from Arc-cached layout - cheap if same layout reused
                 let layout_map: HashMap<String, Vec<String>> = layout
@@ -601,6 +1193,11 @@ impl PyOperationConfig {
                     layout: layout_map,
                     shift_slip,
                     motor_weighting,
+                    grapheme_aware,
+                    burstiness,
+                    homerow_bias,
+                    keyboard,
+                    syllable_swaps,
                 })
             }
             Self::Mimic {
@@ -609,7 +1206,11 @@ impl PyOperationConfig {
                 banned,
                 mode,
                 max_consecutive,
-            } => Operation::Mimic(HomoglyphOp::with_mode(rate, classes, banned, mode, max_consecutive)),
+                only_chars,
+            } => Operation::Mimic(
+                HomoglyphOp::with_mode(rate, classes, banned, mode, max_consecutive)
+                    .with_only_chars(only_chars),
+            ),
             Self::ZeroWidth {
                 rate,
                 characters,
@@ -634,17 +1235,33 @@ impl PyOperationConfig {
                 mode,
                 rate,
             } => Operation::Jargoyle(LexemeSubstitutionOp::new(&lexemes, mode, rate)),
-            Self::Wherewolf { rate, weighting } => {
+            Self::Wherewolf {
+                rate,
+                weighting,
+                extra_sets,
+            } => {
                 let weighting = HomophoneWeighting::try_from_str(&weighting).ok_or_else(|| {
                     PyValueError::new_err(format!("unsupported weighting: {weighting}"))
                 })?;
-                Operation::Wherewolf(HomophoneOp { rate, weighting })
+                Operation::Wherewolf(HomophoneOp::with_extra_sets(rate, weighting, &extra_sets))
             }
-            Self::Pedant { stone } => {
-                let op = GrammarRuleOp::new(seed as i128, &stone)?;
+            Self::Pedant { stone, direction } => {
+                let op = match direction {
+                    Some(direction) => {
+                        let parsed = SpellingDirection::try_from_str(&direction).ok_or_else(|| {
+                            PyValueError::new_err(format!(
+                                "unsupported pedant direction: {direction} (expected uk or us)"
+                            ))
+                        })?;
+                        GrammarRuleOp::with_direction(seed as i128, parsed)
+                    }
+                    None => GrammarRuleOp::new(seed as i128, stone.as_deref().unwrap_or_default())?,
+                };
                 Operation::Pedant(op)
             }
-            Self::QuotePairs => Operation::QuotePairs(operations::QuotePairsOp),
+            Self::QuotePairs { contractions } => {
+                Operation::QuotePairs(operations::QuotePairsOp { contractions })
+            }
             Self::Hokey {
                 rate,
                 extension_min,
@@ -658,6 +1275,7 @@ impl PyOperationConfig {
                 word_length_threshold,
                 base_p,
             }),
+            Self::Identity => Operation::Identity,
         };
 
         Ok(operation)
@@ -669,66 +1287,165 @@ pub(crate) fn apply_operation<O>(
     op: O,
     seed: Option<u64>,
 ) -> Result<String, operations::OperationError>
+where
+    O: TextOperation,
+{
+    apply_operation_counted(text, op, seed).map(|(output, _)| output)
+}
+
+/// Like [`apply_operation`], also returning the number of edits the op made.
+pub(crate) fn apply_operation_counted<O>(
+    text: &str,
+    op: O,
+    seed: Option<u64>,
+) -> Result<(String, usize), operations::OperationError>
 where
     O: TextOperation,
 {
     let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
     let mut rng = DeterministicRng::new(resolve_seed(seed));
-    op.apply(&mut buffer, &mut rng)?;
-    Ok(buffer.to_string())
+    let outcome = op.apply(&mut buffer, &mut rng)?;
+    Ok((buffer.to_string(), outcome.edits))
+}
+
+/// Python-facing [`apply_operation_counted`]: the text, or `(text, edits)`
+/// when `return_count` is set.
+pub(crate) fn apply_operation_py<O>(
+    text: &str,
+    op: O,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<GlitchOutput>
+where
+    O: TextOperation,
+{
+    let (output, edits) =
+        apply_operation_counted(text, op, seed).map_err(operations::OperationError::into_pyerr)?;
+    Ok(GlitchOutput::new(output, edits, return_count))
 }
 
-#[pyfunction(signature = (text, rate, unweighted, seed=None))]
+#[allow(clippy::too_many_arguments)]
+#[pyfunction(signature = (text, rate, unweighted, seed=None, repeats=1, stutter=false, separator="space", return_count=false))]
 fn reduplicate_words(
     text: &str,
     rate: f64,
     unweighted: bool,
     seed: Option<u64>,
-) -> PyResult<String> {
-    let op = ReduplicateWordsOp { rate, unweighted };
-    apply_operation(text, op, seed).map_err(operations::OperationError::into_pyerr)
+    repeats: usize,
+    stutter: bool,
+    separator: &str,
+    return_count: bool,
+) -> PyResult<GlitchOutput> {
+    let separator = parse_reduplication_separator(separator)?;
+    let op = ReduplicateWordsOp {
+        rate,
+        unweighted,
+        repeats,
+        stutter,
+        separator,
+    };
+    apply_operation_py(text, op, seed, return_count)
 }
 
-#[pyfunction(signature = (text, rate, unweighted, seed=None))]
+#[allow(clippy::too_many_arguments)]
+#[pyfunction(signature = (text, rate, unweighted, seed=None, min_words=0, weighting="length", preserve_spacing=false, return_count=false))]
 fn delete_random_words(
     text: &str,
     rate: f64,
     unweighted: bool,
     seed: Option<u64>,
-) -> PyResult<String> {
-    let op = DeleteRandomWordsOp { rate, unweighted };
-    apply_operation(text, op, seed).map_err(operations::OperationError::into_pyerr)
+    min_words: usize,
+    weighting: &str,
+    preserve_spacing: bool,
+    return_count: bool,
+) -> PyResult<GlitchOutput> {
+    let op = DeleteRandomWordsOp {
+        rate,
+        unweighted,
+        min_words,
+        weighting: parse_word_weighting(weighting)?,
+        preserve_spacing,
+    };
+    apply_operation_py(text, op, seed, return_count)
 }
 
-#[pyfunction(signature = (text, rate, seed=None))]
-fn swap_adjacent_words(text: &str, rate: f64, seed: Option<u64>) -> PyResult<String> {
+#[pyfunction(signature = (text, rate, seed=None, return_count=false))]
+fn swap_adjacent_words(
+    text: &str,
+    rate: f64,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<GlitchOutput> {
     let op = SwapAdjacentWordsOp { rate };
-    apply_operation(text, op, seed).map_err(operations::OperationError::into_pyerr)
+    apply_operation_py(text, op, seed, return_count)
 }
 
-#[pyfunction(name = "substitute_homophones", signature = (text, rate, weighting, seed=None))]
+#[pyfunction(signature = (text, rate, max_distance, seed=None, return_count=false))]
+fn swap_words(
+    text: &str,
+    rate: f64,
+    max_distance: usize,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<GlitchOutput> {
+    let op = SwapWordsOp { rate, max_distance };
+    apply_operation_py(text, op, seed, return_count)
+}
+
+#[pyfunction(name = "substitute_homophones", signature = (text, rate, weighting, seed=None, extra_sets=None, return_count=false))]
 fn substitute_homophones(
     text: &str,
     rate: f64,
     weighting: &str,
     seed: Option<u64>,
-) -> PyResult<String> {
+    extra_sets: Option<Vec<Vec<String>>>,
+    return_count: bool,
+) -> PyResult<GlitchOutput> {
     let weighting = HomophoneWeighting::try_from_str(weighting)
         .ok_or_else(|| PyValueError::new_err(format!("unsupported weighting: {weighting}")))?;
-    let op = HomophoneOp { rate, weighting };
-    apply_operation(text, op, seed).map_err(operations::OperationError::into_pyerr)
+    let op = match extra_sets {
+        Some(sets) => HomophoneOp::with_extra_sets(rate, weighting, &sets),
+        None => HomophoneOp::new(rate, weighting),
+    };
+    apply_operation_py(text, op, seed, return_count)
 }
 
-#[pyfunction(name = "apply_grammar_rule", signature = (text, stone, seed))]
-fn apply_grammar_rule(text: &str, stone: &str, seed: i128) -> PyResult<String> {
+#[pyfunction(name = "apply_grammar_rule", signature = (text, stone, seed, return_count=false))]
+fn apply_grammar_rule(
+    text: &str,
+    stone: &str,
+    seed: i128,
+    return_count: bool,
+) -> PyResult<GlitchOutput> {
     let op = GrammarRuleOp::new(seed, stone)?;
-    apply_operation(text, op, None).map_err(operations::OperationError::into_pyerr)
+    apply_operation_py(text, op, None, return_count)
+}
+
+#[pyfunction(name = "normalize_quote_pairs", signature = (text, seed=None, contractions=false, return_count=false))]
+fn normalize_quote_pairs(
+    text: &str,
+    seed: Option<u64>,
+    contractions: bool,
+    return_count: bool,
+) -> PyResult<GlitchOutput> {
+    let op = QuotePairsOp { contractions };
+    apply_operation_py(text, op, seed, return_count)
 }
 
-#[pyfunction(name = "normalize_quote_pairs", signature = (text, seed=None))]
-fn normalize_quote_pairs(text: &str, seed: Option<u64>) -> PyResult<String> {
-    let op = QuotePairsOp;
-    apply_operation(text, op, seed).map_err(operations::OperationError::into_pyerr)
+fn parse_ocr_mode(mode: &str) -> PyResult<OcrMode> {
+    OcrMode::parse(mode).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Unsupported OCR mode '{mode}'. Expected one of: both, expand, contract"
+        ))
+    })
+}
+
+fn parse_ocr_sampling(sampling: &str) -> PyResult<OcrSampling> {
+    OcrSampling::parse(sampling).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Unsupported OCR sampling '{sampling}'. Expected one of: count, bernoulli"
+        ))
+    })
 }
 
 #[pyfunction(signature = (
@@ -741,7 +1458,11 @@ fn normalize_quote_pairs(text: &str, seed: Option<u64>) -> PyResult<String> {
     bias_k=None,
     bias_beta=None,
     space_drop_rate=None,
-    space_insert_rate=None
+    space_insert_rate=None,
+    extra_confusions=None,
+    mode="both",
+    sampling="count",
+    return_count=false
 ))]
 #[allow(clippy::too_many_arguments)]
 fn ocr_artifacts(
@@ -755,7 +1476,13 @@ fn ocr_artifacts(
     bias_beta: Option<f64>,
     space_drop_rate: Option<f64>,
     space_insert_rate: Option<f64>,
-) -> PyResult<String> {
+    extra_confusions: Option<Vec<(String, Vec<String>)>>,
+    mode: &str,
+    sampling: &str,
+    return_count: bool,
+) -> PyResult<GlitchOutput> {
+    let mode = parse_ocr_mode(mode)?;
+    let sampling = parse_ocr_sampling(sampling)?;
     let op = OcrArtifactsOp::with_params(
         rate,
         burst_enter.unwrap_or(0.0),
@@ -765,11 +1492,26 @@ fn ocr_artifacts(
         bias_beta.unwrap_or(2.0),
         space_drop_rate.unwrap_or(0.0),
         space_insert_rate.unwrap_or(0.0),
-    );
-    apply_operation(text, op, seed).map_err(operations::OperationError::into_pyerr)
+    )
+    .with_mode(mode)
+    .with_sampling(sampling)
+    .with_extra_confusions(extra_confusions.unwrap_or_default());
+    apply_operation_py(text, op, seed, return_count)
 }
 
-#[pyfunction(signature = (text, replacement_char, rate, merge_adjacent, unweighted, seed=None))]
+#[pyfunction(signature = (
+    text,
+    replacement_char,
+    rate,
+    merge_adjacent,
+    unweighted,
+    seed=None,
+    merge_through_punctuation=false,
+    fixed_width=None,
+    weighting="length",
+    return_count=false,
+))]
+#[allow(clippy::too_many_arguments)]
 fn redact_words(
     text: &str,
     replacement_char: &str,
@@ -777,14 +1519,21 @@ fn redact_words(
     merge_adjacent: bool,
     unweighted: bool,
     seed: Option<u64>,
-) -> PyResult<String> {
+    merge_through_punctuation: bool,
+    fixed_width: Option<usize>,
+    weighting: &str,
+    return_count: bool,
+) -> PyResult<GlitchOutput> {
     let op = RedactWordsOp {
         replacement_char: replacement_char.to_string(),
         rate,
         merge_adjacent,
+        merge_through_punctuation,
+        fixed_width,
         unweighted,
+        weighting: parse_word_weighting(weighting)?,
     };
-    apply_operation(text, op, seed).map_err(operations::OperationError::into_pyerr)
+    apply_operation_py(text, op, seed, return_count)
 }
 
 #[pyfunction(name = "plan_operations")]
@@ -811,7 +1560,130 @@ fn plan_operations(
         .collect())
 }
 
-#[pyfunction(name = "compose_operations", signature = (text, descriptors, master_seed, include_only_patterns=None, exclude_patterns=None))]
+/// Plan a gaggle like `plan_operations`, returning `GagglePlanStep` objects
+/// that carry each glitchling's index, name, scope, order and derived seed.
+#[pyfunction(name = "plan_operations_detailed")]
+fn plan_operations_detailed(
+    glitchlings: Vec<PyPlanInput>,
+    master_seed: i128,
+) -> Vec<pipeline::PlannedGlitchling> {
+    pipeline::plan_gaggle_steps(
+        glitchlings
+            .into_iter()
+            .enumerate()
+            .map(|(index, input)| pipeline::GagglePlanInput {
+                index,
+                name: input.name,
+                scope: input.scope,
+                order: input.order,
+            })
+            .collect(),
+        master_seed,
+    )
+}
+
+/// Returns the bundled OCR confusions, longest source first as the OCR
+/// operation matches them.
+#[pyfunction(name = "ocr_confusions")]
+fn ocr_confusions(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let table = PyDict::new(py);
+    for (source, choices) in resources::confusion_table() {
+        table.set_item(*source, choices.to_vec())?;
+    }
+    Ok(table)
+}
+
+/// Replaces unambiguous OCR confusions in `text` with their likely source.
+#[pyfunction(name = "ocr_correct")]
+fn ocr_correct(text: &str) -> String {
+    resources::ocr_correct(text)
+}
+
+/// Returns the bundled homophone sets used by Wherewolf.
+#[pyfunction(name = "homophone_sets")]
+fn homophone_sets() -> Vec<Vec<String>> {
+    resources::wherewolf_homophone_sets().to_vec()
+}
+
+/// Derive the seed a gaggle assigns to `glitchling_name` at `index`.
+#[pyfunction(name = "derive_seed")]
+fn derive_seed_py(master_seed: i128, glitchling_name: &str, index: i128) -> u64 {
+    derive_seed(master_seed, glitchling_name, index)
+}
+
+/// Output of [`apply_glitch`] and the per-op entry points: the text, or
+/// `(text, edits)` when the caller passed `return_count=True`.
+#[derive(IntoPyObject)]
+pub(crate) enum GlitchOutput {
+    Text(String),
+    Counted(String, usize),
+}
+
+impl GlitchOutput {
+    pub(crate) fn new(text: String, edits: usize, return_count: bool) -> Self {
+        if return_count {
+            Self::Counted(text, edits)
+        } else {
+            Self::Text(text)
+        }
+    }
+}
+
+/// Run a single operation described by an operation config dict (the
+/// `operation` entry of a pipeline descriptor) without building a pipeline.
+/// `seed` seeds the operation's RNG directly. With `return_count=True` the
+/// result is `(text, edits)`, where `edits` is the number of edits the op
+/// applied; zero means the text came back untouched.
+#[pyfunction(name = "apply_glitch", signature = (text, descriptor, seed=None, return_count=false))]
+fn apply_glitch(
+    py: Python<'_>,
+    text: &str,
+    descriptor: PyOperationConfig,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<GlitchOutput> {
+    let seed = resolve_seed(seed);
+    let operation = descriptor.into_operation(seed)?;
+    validate_lone_operation(&operation)?;
+    let (output, edits) =
+        run_without_gil(py, text, |text| apply_operation_counted(text, operation, Some(seed)))
+            .map_err(operations::OperationError::into_pyerr)?;
+    Ok(GlitchOutput::new(output, edits, return_count))
+}
+
+/// Bisect the `rate` field of an operation config dict until `metric`
+/// scores the output within `tolerance` of `target`, returning that rate.
+/// Every trial reuses one seed so runs differ only in their rate. Metrics that
+/// are not monotonic in the rate may not converge; the closest rate evaluated
+/// is returned in that case.
+#[pyfunction(name = "calibrate_rate", signature = (text, descriptor, metric, target, tolerance=0.01, seed=None))]
+fn calibrate_rate(
+    text: &str,
+    descriptor: &Bound<'_, PyDict>,
+    metric: &str,
+    target: f64,
+    tolerance: f64,
+    seed: Option<u64>,
+) -> PyResult<f64> {
+    let metric = metrics::TextMetric::parse(metric).map_err(PyValueError::new_err)?;
+    if !descriptor.contains("rate")? {
+        return Err(PyValueError::new_err(
+            "calibrate_rate requires an operation config with a top-level 'rate' field",
+        ));
+    }
+    let seed = resolve_seed(seed);
+    let config = descriptor.copy()?;
+    metrics::calibrate_rate(target, tolerance, |rate| {
+        config.set_item("rate", rate)?;
+        let operation = config.extract::<PyOperationConfig>()?.into_operation(seed)?;
+        validate_lone_operation(&operation)?;
+        let output = apply_operation(text, operation, Some(seed))
+            .map_err(operations::OperationError::into_pyerr)?;
+        Ok(metric.score(text, &output))
+    })
+}
+
+#[pyfunction(name = "compose_operations", signature = (text, descriptors, master_seed, include_only_patterns=None, exclude_patterns=None, max_total_edits=None))]
 fn compose_operations(
     py: Python<'_>,
     text: &str,
@@ -819,6 +1691,7 @@ fn compose_operations(
     master_seed: i128,
     include_only_patterns: Option<Vec<String>>,
     exclude_patterns: Option<Vec<String>>,
+    max_total_edits: Option<usize>,
 ) -> PyResult<String> {
     // Build pipeline while holding GIL (requires parsing Python objects)
     let pipeline = build_pipeline_from_py(
@@ -826,12 +1699,125 @@ fn compose_operations(
         master_seed,
         include_only_patterns,
         exclude_patterns,
+        max_total_edits,
     )?;
-    let text_owned = text.to_string();
-
     // Release GIL for the actual computation
+    run_without_gil(py, text, |text| pipeline.run(text)).map_err(PipelineError::into_pyerr)
+}
+
+/// Corrupt many documents with one compiled pipeline.
+///
+/// Each document gets its own seed derived from `master_seed` and its index,
+/// so results are reproducible per document. The GIL is released for the
+/// whole batch and results keep the input order. `max_total_edits` caps the
+/// edits made to each document.
+#[pyfunction(name = "compose_operations_batch", signature = (texts, descriptors, master_seed, include_only_patterns=None, exclude_patterns=None, max_total_edits=None))]
+fn compose_operations_batch(
+    py: Python<'_>,
+    texts: Vec<String>,
+    descriptors: Vec<PyOperationDescriptor>,
+    master_seed: i128,
+    include_only_patterns: Option<Vec<String>>,
+    exclude_patterns: Option<Vec<String>>,
+    max_total_edits: Option<usize>,
+) -> PyResult<Vec<String>> {
+    let pipeline = build_pipeline_from_py(
+        descriptors,
+        master_seed,
+        include_only_patterns,
+        exclude_patterns,
+        max_total_edits,
+    )?;
+    py.allow_threads(move || {
+        pipeline
+            .run_documents(&texts)
+            .map_err(PipelineError::into_pyerr)
+    })
+}
+
+/// Output of [`generate_pair`]: `(clean, corrupted)`, plus the token
+/// alignment between them when the caller asked for it.
+#[derive(IntoPyObject)]
+enum PairOutput<'py> {
+    Pair(String, String),
+    Aligned(String, String, Vec<Bound<'py, PyDict>>),
+}
+
+/// Build a `(clean, corrupted)` training pair from `text`.
+///
+/// `clean` is `text` with its whitespace normalised: runs of spaces collapse
+/// to one (runs holding a line break keep it verbatim), spaces before `.,:;`
+/// are dropped and the ends are trimmed. `corrupted` is the pipeline run over
+/// `clean`, exactly as `compose_operations(clean, ...)` would produce it.
+///
+/// With `aligned=True` a third item lists the token hunks that turn `clean`
+/// into `corrupted`, one dict each with `start`, `end` (character offsets into
+/// `clean`), `original` and `replacement`, as in `Pipeline.plan_edits`. Ops
+/// that move words rather than rewrite them align as wider replacements.
+#[pyfunction(name = "generate_pair", signature = (text, descriptors, master_seed, aligned=false, include_only_patterns=None, exclude_patterns=None, max_total_edits=None))]
+#[allow(clippy::too_many_arguments)]
+fn generate_pair<'py>(
+    py: Python<'py>,
+    text: &str,
+    descriptors: Vec<PyOperationDescriptor>,
+    master_seed: i128,
+    aligned: bool,
+    include_only_patterns: Option<Vec<String>>,
+    exclude_patterns: Option<Vec<String>>,
+    max_total_edits: Option<usize>,
+) -> PyResult<PairOutput<'py>> {
+    let pipeline = build_pipeline_from_py(
+        descriptors,
+        master_seed,
+        include_only_patterns,
+        exclude_patterns,
+        max_total_edits,
+    )?;
+    let (clean, corrupted) = run_without_gil(py, text, |text| pipeline.generate_pair(text))
+        .map_err(PipelineError::into_pyerr)?;
+    if !aligned {
+        return Ok(PairOutput::Pair(clean, corrupted));
+    }
+    let alignment = pipeline::diff_tokens(&clean, &corrupted)
+        .into_iter()
+        .map(|(range, original, replacement)| {
+            let dict = PyDict::new(py);
+            dict.set_item("start", range.start)?;
+            dict.set_item("end", range.end)?;
+            dict.set_item("original", original)?;
+            dict.set_item("replacement", replacement)?;
+            Ok(dict)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(PairOutput::Aligned(clean, corrupted, alignment))
+}
+
+/// Apply one operation to many strings, the batch form of the single-op
+/// functions (`reduplicate_words`, `ocr_artifacts`, ...).
+///
+/// `operation` is an operation config such as `{"type": "ocr", "rate": 0.1}`.
+/// Each text is seeded from `master_seed` and its index, as in
+/// `compose_operations_batch`, so any item can be reproduced on its own. The
+/// GIL is released for the whole batch and results keep the input order.
+#[pyfunction(name = "apply_operation_batch", signature = (texts, operation, master_seed))]
+fn apply_operation_batch(
+    py: Python<'_>,
+    texts: Vec<String>,
+    operation: PyOperationConfig,
+    master_seed: i128,
+) -> PyResult<Vec<String>> {
+    let operation = operation.into_operation(derive_seed(master_seed, "batch", 0))?;
+    validate_lone_operation(&operation)?;
+    let descriptor = OperationDescriptor {
+        name: "batch".to_string(),
+        seed: 0,
+        operation,
+    };
+    let pipeline = Pipeline::new(master_seed, vec![descriptor], Vec::new(), Vec::new());
     py.allow_threads(move || {
-        pipeline.run(&text_owned).map_err(PipelineError::into_pyerr)
+        pipeline
+            .run_documents(&texts)
+            .map_err(PipelineError::into_pyerr)
     })
 }
 
@@ -840,21 +1826,56 @@ fn _corruption_engine(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(reduplicate_words, m)?)?;
     m.add_function(wrap_pyfunction!(delete_random_words, m)?)?;
     m.add_function(wrap_pyfunction!(swap_adjacent_words, m)?)?;
+    m.add_function(wrap_pyfunction!(swap_words, m)?)?;
+    m.add_function(wrap_pyfunction!(sentences::delete_sentences, m)?)?;
+    m.add_function(wrap_pyfunction!(sentences::shuffle_sentences, m)?)?;
     m.add_function(wrap_pyfunction!(homoglyphs::swap_homoglyphs, m)?)?;
     m.add_function(wrap_pyfunction!(substitute_homophones, m)?)?;
+    m.add_function(wrap_pyfunction!(phonetic::phonetic, m)?)?;
     m.add_function(wrap_pyfunction!(apply_grammar_rule, m)?)?;
+    m.add_function(wrap_pyfunction!(grammar_rules::list_pedant_stones, m)?)?;
+    m.add_function(wrap_pyfunction!(markup::py_detect_markup_ranges, m)?)?;
     m.add_function(wrap_pyfunction!(normalize_quote_pairs, m)?)?;
     m.add_function(wrap_pyfunction!(ocr_artifacts, m)?)?;
     m.add_function(wrap_pyfunction!(redact_words, m)?)?;
     m.add_function(wrap_pyfunction!(lexeme_substitution::substitute_lexeme, m)?)?;
     m.add_function(wrap_pyfunction!(lexeme_substitution::list_lexeme_dictionaries, m)?)?;
+    m.add_function(wrap_pyfunction!(lexeme_substitution::register_lexeme_dictionary, m)?)?;
     m.add_function(wrap_pyfunction!(lexeme_substitution::list_bundled_lexeme_dictionaries, m)?)?;
     m.add_function(wrap_pyfunction!(lexeme_substitution::is_bundled_lexeme, m)?)?;
     m.add_function(wrap_pyfunction!(plan_operations, m)?)?;
+    m.add_function(wrap_pyfunction!(plan_operations_detailed, m)?)?;
+    m.add_function(wrap_pyfunction!(derive_seed_py, m)?)?;
+    m.add_function(wrap_pyfunction!(schema::list_operations, m)?)?;
+    m.add_function(wrap_pyfunction!(ocr_confusions, m)?)?;
+    m.add_function(wrap_pyfunction!(ocr_correct, m)?)?;
+    m.add_function(wrap_pyfunction!(homophone_sets, m)?)?;
+    m.add_function(wrap_pyfunction!(homoglyphs::homoglyph_table, m)?)?;
+    m.add_function(wrap_pyfunction!(apply_glitch, m)?)?;
+    m.add_function(wrap_pyfunction!(calibrate_rate, m)?)?;
     m.add_function(wrap_pyfunction!(compose_operations, m)?)?;
+    m.add_function(wrap_pyfunction!(compose_operations_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_pair, m)?)?;
+    m.add_function(wrap_pyfunction!(apply_operation_batch, m)?)?;
     m.add_function(wrap_pyfunction!(keyboard_typos::keyboard_typo, m)?)?;
     m.add_function(wrap_pyfunction!(keyboard_typos::slip_modifier, m)?)?;
+    m.add_function(wrap_pyfunction!(keyboard_typos::motor_weighting_modes, m)?)?;
     m.add_function(wrap_pyfunction!(zero_width::inject_zero_widths, m)?)?;
+    m.add_function(wrap_pyfunction!(zero_width::strip_zero_widths, m)?)?;
+    m.add_function(wrap_pyfunction!(bidi::inject_bidi, m)?)?;
+    m.add_function(wrap_pyfunction!(fillers::inject_fillers, m)?)?;
+    m.add_function(wrap_pyfunction!(whitespace::jitter_whitespace, m)?)?;
+    m.add_function(wrap_pyfunction!(case::corrupt_case, m)?)?;
+    m.add_function(wrap_pyfunction!(contractions::contractions, m)?)?;
+    m.add_function(wrap_pyfunction!(disemvowel::disemvowel, m)?)?;
+    m.add_function(wrap_pyfunction!(double_words::double_words, m)?)?;
+    m.add_function(wrap_pyfunction!(scramble::scramble_interior, m)?)?;
+    m.add_function(wrap_pyfunction!(punctuation::drop_punctuation, m)?)?;
+    m.add_function(wrap_pyfunction!(autocorrect::autocorrect, m)?)?;
+    m.add_function(wrap_pyfunction!(word_boundaries::merge_words, m)?)?;
+    m.add_function(wrap_pyfunction!(word_boundaries::split_words, m)?)?;
+    m.add_function(wrap_pyfunction!(diacritics::diacritics, m)?)?;
+    m.add_function(wrap_pyfunction!(zalgo::zalgo, m)?)?;
     m.add_function(wrap_pyfunction!(word_stretching::stretch_word, m)?)?;
     m.add_function(wrap_pyfunction!(metrics::jensen_shannon_divergence, m)?)?;
     m.add_function(wrap_pyfunction!(metrics::normalized_edit_distance, m)?)?;
@@ -868,10 +1889,36 @@ fn _corruption_engine(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         m
     )?)?;
     m.add_function(wrap_pyfunction!(metrics::batch_subsequence_retention, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::positional_jsd, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::batch_positional_jsd, m)?)?;
     m.add_function(wrap_pyfunction!(metrics::entropy_delta, m)?)?;
     m.add_function(wrap_pyfunction!(metrics::batch_entropy_delta, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::char_entropy_delta, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::batch_char_entropy_delta, m)?)?;
     m.add_function(wrap_pyfunction!(metrics::merge_split_index, m)?)?;
     m.add_function(wrap_pyfunction!(metrics::batch_merge_split_index, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::merge_split_signed, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::batch_merge_split_signed, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::char_ngram_jaccard, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::batch_char_ngram_jaccard, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::readability_delta, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::batch_readability_delta, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::word_error_rate, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::batch_word_error_rate, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::character_error_rate, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::batch_character_error_rate, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::confusable_edit_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::batch_confusable_edit_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::injection_rate, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::batch_injection_rate, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::jensen_shannon_divergence_str, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::normalized_edit_distance_str, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::subsequence_retention_str, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::positional_jsd_str, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::entropy_delta_str, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::merge_split_index_str, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::word_error_rate_str, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::batch_text_metric, m)?)?;
     // Tokenizer metrics
     m.add_function(wrap_pyfunction!(metrics::compression_ratio, m)?)?;
     m.add_function(wrap_pyfunction!(metrics::batch_compression_ratio, m)?)?;
@@ -884,5 +1931,8 @@ fn _corruption_engine(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(metrics::unknown_token_rate, m)?)?;
     m.add_function(wrap_pyfunction!(metrics::batch_unknown_token_rate, m)?)?;
     m.add("Pipeline", _py.get_type::<Pipeline>())?;
+    m.add("PipelineBuilder", _py.get_type::<pipeline_builder::PipelineBuilder>())?;
+    m.add("GagglePlanStep", _py.get_type::<pipeline::PlannedGlitchling>())?;
+    m.add("ChunkSplitter", _py.get_type::<pipeline::ChunkSplitter>())?;
     Ok(())
 }
@@ -0,0 +1,246 @@
+use std::ops::Range;
+use std::sync::LazyLock;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use regex::Regex;
+
+use crate::text_buffer::merge_spans;
+
+const VALID_SYNTAX_MESSAGE: &str = "markdown, html";
+
+/// Markup dialect recognised by [`detect_markup_ranges`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkupKind {
+    /// Emphasis markers, link and image syntax, code spans and fenced blocks.
+    Markdown,
+    /// Tags, comments and character entities.
+    Html,
+}
+
+impl MarkupKind {
+    pub fn parse(syntax: &str) -> Result<Self, String> {
+        match syntax.to_ascii_lowercase().as_str() {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            _ => Err(format!(
+                "Unsupported markup syntax '{syntax}'. Expected one of: {VALID_SYNTAX_MESSAGE}"
+            )),
+        }
+    }
+}
+
+/// Returns the sorted, disjoint byte ranges of structural markup in `text`.
+///
+/// Only syntax is reported, never prose: for `[text](url)` the brackets and
+/// the `](url)` tail are structural while "text" stays corruptible. Code spans
+/// and fenced blocks are reported whole. Convert the ranges to character
+/// offsets before handing them to [`crate::TextBuffer::from_owned_with_immutable`].
+///
+/// This is a focused scanner rather than a full `CommonMark` or HTML parser;
+/// unmatched delimiters are left alone.
+#[must_use]
+pub fn detect_markup_ranges(text: &str, syntax: MarkupKind) -> Vec<Range<usize>> {
+    match syntax {
+        MarkupKind::Markdown => markdown_ranges(text),
+        MarkupKind::Html => html_ranges(text),
+    }
+}
+
+fn markdown_ranges(text: &str) -> Vec<Range<usize>> {
+    static LINK: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"(!?\[)[^\[\]\n]*(\]\([^()\s]*(?:\s+"[^"\n]*")?\))"#).expect("valid regex")
+    });
+    static EMPHASIS: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\*{1,3}|_{1,3}|~~").expect("valid regex"));
+
+    let mut code = fenced_blocks(text);
+    code.extend(code_spans(text, &code));
+    let code = merge_spans(code);
+    let in_code = |start: usize| code.iter().any(|range| range.contains(&start));
+
+    let mut ranges = code.clone();
+    for captures in LINK.captures_iter(text) {
+        let (Some(open), Some(close)) = (captures.get(1), captures.get(2)) else {
+            continue;
+        };
+        if !in_code(open.start()) {
+            ranges.push(open.range());
+            ranges.push(close.range());
+        }
+    }
+
+    for marker in EMPHASIS.find_iter(text) {
+        if in_code(marker.start()) {
+            continue;
+        }
+        let before = text[..marker.start()].chars().next_back();
+        let after = text[marker.end()..].chars().next();
+        let flanks = |ch: Option<char>, test: fn(char) -> bool| ch.is_some_and(test);
+        let delimits = if marker.as_str().starts_with('_') {
+            // snake_case identifiers are not emphasis.
+            !(flanks(before, char::is_alphanumeric) && flanks(after, char::is_alphanumeric))
+        } else {
+            // Bullets and arithmetic ("2 * 3") have whitespace on both sides.
+            !(before.is_none_or(char::is_whitespace) && after.is_none_or(char::is_whitespace))
+        };
+        if delimits {
+            ranges.push(marker.range());
+        }
+    }
+
+    merge_spans(ranges)
+}
+
+/// Returns the ranges of fenced code blocks, from the opening fence line to
+/// the end of the closing fence (or the end of the text when unclosed).
+fn fenced_blocks(text: &str) -> Vec<Range<usize>> {
+    let mut blocks = Vec::new();
+    let mut open: Option<(usize, char, usize)> = None;
+    let mut line_start = 0usize;
+
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        let indent = content.len() - content.trim_start_matches(' ').len();
+        let body = &content[indent..];
+        let fence = body.chars().next().filter(|ch| matches!(ch, '`' | '~'));
+        let run = fence.map_or(0, |ch| body.len() - body.trim_start_matches(ch).len());
+
+        match (open, fence) {
+            (None, Some(ch)) if indent <= 3 && run >= 3 => open = Some((line_start, ch, run)),
+            (Some((start, ch, len)), Some(closing))
+                if closing == ch && indent <= 3 && run >= len && body[run..].trim().is_empty() =>
+            {
+                blocks.push(start..line_start + content.len());
+                open = None;
+            }
+            _ => {}
+        }
+        line_start += line.len();
+    }
+
+    if let Some((start, _, _)) = open {
+        blocks.push(start..text.len());
+    }
+    blocks
+}
+
+/// Returns backtick code spans outside `fenced`, pairing runs of equal length.
+fn code_spans(text: &str, fenced: &[Range<usize>]) -> Vec<Range<usize>> {
+    let bytes = text.as_bytes();
+    let mut spans = Vec::new();
+    let mut idx = 0usize;
+
+    while idx < bytes.len() {
+        if let Some(block) = fenced.iter().find(|range| range.contains(&idx)) {
+            idx = block.end;
+            continue;
+        }
+        if bytes[idx] != b'`' {
+            idx += 1;
+            continue;
+        }
+
+        let run = bytes[idx..].iter().take_while(|&&byte| byte == b'`').count();
+        let mut search = idx + run;
+        let mut closed = None;
+        while search < bytes.len() {
+            if bytes[search] != b'`' {
+                search += 1;
+                continue;
+            }
+            let candidate = bytes[search..].iter().take_while(|&&byte| byte == b'`').count();
+            if candidate == run {
+                closed = Some(search + candidate);
+                break;
+            }
+            search += candidate;
+        }
+
+        match closed {
+            Some(end) => {
+                spans.push(idx..end);
+                idx = end;
+            }
+            None => idx += run,
+        }
+    }
+    spans
+}
+
+fn html_ranges(text: &str) -> Vec<Range<usize>> {
+    static STRUCTURE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(
+            r#"(?s)<!--.*?-->|</?[A-Za-z][^<>"']*(?:(?:"[^"]*"|'[^']*')[^<>"']*)*>|<![A-Za-z][^<>]*>|&(?:#[0-9]+|#[xX][0-9A-Fa-f]+|[A-Za-z][A-Za-z0-9]*);"#,
+        )
+        .expect("valid regex")
+    });
+
+    merge_spans(STRUCTURE.find_iter(text).map(|found| found.range()).collect())
+}
+
+/// Python-facing wrapper returning `(start, end)` code-point offsets, which
+/// index Python strings directly.
+#[pyfunction(name = "detect_markup_ranges", signature = (text, syntax))]
+pub(crate) fn py_detect_markup_ranges(text: &str, syntax: &str) -> PyResult<Vec<(usize, usize)>> {
+    let syntax = MarkupKind::parse(syntax).map_err(PyValueError::new_err)?;
+    let ranges = detect_markup_ranges(text, syntax);
+
+    let mut converted = Vec::with_capacity(ranges.len());
+    let mut chars_before = 0usize;
+    let mut byte_cursor = 0usize;
+    for range in ranges {
+        chars_before += text[byte_cursor..range.start].chars().count();
+        let length = text[range.clone()].chars().count();
+        converted.push((chars_before, chars_before + length));
+        chars_before += length;
+        byte_cursor = range.end;
+    }
+    Ok(converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_markup_ranges, MarkupKind};
+
+    fn pieces(text: &str, syntax: MarkupKind) -> Vec<&str> {
+        detect_markup_ranges(text, syntax)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    #[test]
+    fn markdown_syntax_is_detected_without_prose() {
+        let text = "Some **bold** and _light_ text, a [link](https://x.io) and ![alt](a.png).";
+        assert_eq!(
+            pieces(text, MarkupKind::Markdown),
+            vec!["**", "**", "_", "_", "[", "](https://x.io)", "![", "](a.png)"]
+        );
+        assert_eq!(
+            pieces("snake_case * bullets and 2 * 3", MarkupKind::Markdown),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn code_is_protected_whole() {
+        let text = "Use `a * b` or ``x ` y``.\n```rust\nlet _x = *p;\n```\nafter **it**";
+        assert_eq!(
+            pieces(text, MarkupKind::Markdown),
+            vec!["`a * b`", "``x ` y``", "```rust\nlet _x = *p;\n```", "**", "**"]
+        );
+        assert_eq!(pieces("```\nopen fence", MarkupKind::Markdown), vec!["```\nopen fence"]);
+    }
+
+    #[test]
+    fn html_tags_comments_and_entities() {
+        let text = "<p class=\"a>b\">Caf&eacute; &amp; 3 < 4<!-- note --></p><br/>";
+        assert_eq!(
+            pieces(text, MarkupKind::Html),
+            vec!["<p class=\"a>b\">", "&eacute;", "&amp;", "<!-- note --></p><br/>"]
+        );
+        assert_eq!(MarkupKind::parse("MD"), Ok(MarkupKind::Markdown));
+        assert!(MarkupKind::parse("rst").unwrap_err().contains("markdown, html"));
+    }
+}
@@ -1,12 +1,18 @@
 use std::borrow::Cow;
 use std::cmp::max;
 use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyString;
 use rayon::prelude::*;
 
+use crate::homoglyphs::confusable_class;
+use crate::resources::split_affixes_ref;
+use crate::segmentation::sentence_ranges;
+use crate::word_stretching::is_vowel;
+
 /// Extract strings from Python string objects without deep copying.
 /// Returns Cow<str> which borrows when possible and owns when necessary.
 fn extract_str_refs<'py>(tokens: &'py [Bound<'py, PyString>]) -> PyResult<Vec<Cow<'py, str>>> {
@@ -72,6 +78,19 @@ pub fn subsequence_retention(
     Ok(compute_subsequence_retention(&inputs, &outputs))
 }
 
+#[pyfunction]
+pub fn positional_jsd(
+    _py: Python<'_>,
+    input_tokens: Vec<Bound<'_, PyString>>,
+    output_tokens: Vec<Bound<'_, PyString>>,
+    bins: usize,
+) -> PyResult<f64> {
+    guard_bin_count(bins)?;
+    let inputs = extract_str_refs(&input_tokens)?;
+    let outputs = extract_str_refs(&output_tokens)?;
+    Ok(compute_positional_jsd(&inputs, &outputs, bins))
+}
+
 #[pyfunction]
 pub fn batch_jensen_shannon_divergence(
     py: Python<'_>,
@@ -94,6 +113,37 @@ pub fn batch_jensen_shannon_divergence(
     }))
 }
 
+#[pyfunction]
+pub fn batch_positional_jsd(
+    py: Python<'_>,
+    inputs: Vec<Vec<Bound<'_, PyString>>>,
+    outputs: Vec<Vec<Bound<'_, PyString>>>,
+    bins: usize,
+) -> PyResult<Vec<f64>> {
+    guard_equal_batches(inputs.len(), outputs.len())?;
+    guard_bin_count(bins)?;
+
+    // Extract to owned strings while holding GIL
+    let input_owned = extract_batch_owned_strings(&inputs)?;
+    let output_owned = extract_batch_owned_strings(&outputs)?;
+
+    // Release GIL and process in parallel
+    Ok(py.allow_threads(|| {
+        input_owned
+            .par_iter()
+            .zip(output_owned.par_iter())
+            .map(|(input, output)| compute_positional_jsd(input, output, bins))
+            .collect()
+    }))
+}
+
+fn guard_bin_count(bins: usize) -> PyResult<()> {
+    if bins == 0 {
+        return Err(PyValueError::new_err("bins must be at least 1"));
+    }
+    Ok(())
+}
+
 #[pyfunction]
 pub fn batch_normalized_edit_distance(
     py: Python<'_>,
@@ -184,6 +234,40 @@ fn compute_jsd<S: AsRef<str>>(tokens1: &[S], tokens2: &[S]) -> f64 {
     0.5 * (kl_pm + kl_qm)
 }
 
+/// Assigns each token to one of `bins` equal-width buckets of relative
+/// position.
+fn positional_bins<S: AsRef<str>>(tokens: &[S], bins: usize) -> Vec<Vec<&str>> {
+    let mut buckets = vec![Vec::new(); bins];
+    let len = tokens.len();
+    for (index, token) in tokens.iter().enumerate() {
+        buckets[index * bins / len].push(token.as_ref());
+    }
+    buckets
+}
+
+/// Mean JSD between matching positional buckets of the two sequences, so that
+/// where corruption lands matters. Buckets empty on both sides are skipped.
+fn compute_positional_jsd<S: AsRef<str>>(tokens1: &[S], tokens2: &[S], bins: usize) -> f64 {
+    let left = positional_bins(tokens1, bins);
+    let right = positional_bins(tokens2, bins);
+
+    let mut total = 0.0;
+    let mut counted = 0usize;
+    for (left, right) in left.iter().zip(&right) {
+        if left.is_empty() && right.is_empty() {
+            continue;
+        }
+        total += compute_jsd(left, right);
+        counted += 1;
+    }
+
+    if counted == 0 {
+        0.0
+    } else {
+        total / counted as f64
+    }
+}
+
 fn compute_normalized_edit_distance<S: AsRef<str> + PartialEq>(tokens1: &[S], tokens2: &[S]) -> f64 {
     let n = tokens1.len();
     let m = tokens2.len();
@@ -195,22 +279,27 @@ fn compute_normalized_edit_distance<S: AsRef<str> + PartialEq>(tokens1: &[S], to
         return if n > 0 { 1.0 } else { 0.0 };
     }
 
-    // Levenshtein distance
+    let dist = levenshtein(tokens1, tokens2) as f64;
+    dist / (max(n, m) as f64)
+}
+
+/// Levenshtein distance between two sequences with unit edit costs.
+fn levenshtein<T: PartialEq>(seq1: &[T], seq2: &[T]) -> usize {
+    let m = seq2.len();
     let mut prev: Vec<usize> = (0..=m).collect();
     let mut curr: Vec<usize> = vec![0; m + 1];
 
-    for (i, t1) in tokens1.iter().enumerate() {
+    for (i, t1) in seq1.iter().enumerate() {
         curr[0] = i + 1;
-        for (j, t2) in tokens2.iter().enumerate() {
-            let cost = if t1.as_ref() == t2.as_ref() { 0 } else { 1 };
+        for (j, t2) in seq2.iter().enumerate() {
+            let cost = if t1 == t2 { 0 } else { 1 };
             curr[j + 1] =
                 std::cmp::min(std::cmp::min(curr[j] + 1, prev[j + 1] + 1), prev[j] + cost);
         }
         prev.copy_from_slice(&curr);
     }
 
-    let dist = prev[m] as f64;
-    dist / (max(n, m) as f64)
+    prev[m]
 }
 
 fn compute_subsequence_retention<S: AsRef<str>>(tokens1: &[S], tokens2: &[S]) -> f64 {
@@ -298,14 +387,14 @@ pub fn batch_entropy_delta(
     }))
 }
 
-fn shannon_entropy<S: AsRef<str>>(tokens: &[S]) -> f64 {
+fn shannon_entropy<T: Eq + Hash>(tokens: &[T]) -> f64 {
     if tokens.is_empty() {
         return 0.0;
     }
 
-    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let mut counts: HashMap<&T, usize> = HashMap::new();
     for token in tokens {
-        *counts.entry(token.as_ref()).or_insert(0) += 1;
+        *counts.entry(token).or_insert(0) += 1;
     }
 
     let total = tokens.len() as f64;
@@ -319,19 +408,13 @@ fn shannon_entropy<S: AsRef<str>>(tokens: &[S]) -> f64 {
     entropy
 }
 
-fn compute_entropy_delta<S: AsRef<str>>(tokens1: &[S], tokens2: &[S]) -> f64 {
+fn compute_entropy_delta<T: Eq + Hash>(tokens1: &[T], tokens2: &[T]) -> f64 {
     let h_orig = shannon_entropy(tokens1);
     let h_corr = shannon_entropy(tokens2);
     let delta = h_corr - h_orig;
 
     // Collect combined vocabulary
-    let mut vocab: HashSet<&str> = HashSet::new();
-    for token in tokens1 {
-        vocab.insert(token.as_ref());
-    }
-    for token in tokens2 {
-        vocab.insert(token.as_ref());
-    }
+    let vocab: HashSet<&T> = tokens1.iter().chain(tokens2).collect();
 
     if vocab.is_empty() {
         return 0.0;
@@ -350,6 +433,41 @@ fn compute_entropy_delta<S: AsRef<str>>(tokens1: &[S], tokens2: &[S]) -> f64 {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Character Entropy Delta
+// ---------------------------------------------------------------------------
+
+/// [`entropy_delta`] over the character distribution of each string, so
+/// sub-word corruption (typos, homoglyphs) registers even when the token
+/// distribution barely moves.
+#[pyfunction]
+pub fn char_entropy_delta(input: &str, output: &str) -> f64 {
+    compute_char_entropy_delta(input, output)
+}
+
+#[pyfunction]
+pub fn batch_char_entropy_delta(
+    py: Python<'_>,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+) -> PyResult<Vec<f64>> {
+    guard_equal_batches(inputs.len(), outputs.len())?;
+
+    Ok(py.allow_threads(|| {
+        inputs
+            .par_iter()
+            .zip(outputs.par_iter())
+            .map(|(input, output)| compute_char_entropy_delta(input, output))
+            .collect()
+    }))
+}
+
+fn compute_char_entropy_delta(input: &str, output: &str) -> f64 {
+    let input: Vec<char> = input.chars().collect();
+    let output: Vec<char> = output.chars().collect();
+    compute_entropy_delta(&input, &output)
+}
+
 // ---------------------------------------------------------------------------
 // Merge-Split Index
 // ---------------------------------------------------------------------------
@@ -387,6 +505,39 @@ pub fn batch_merge_split_index(
     }))
 }
 
+/// [`merge_split_index`] before the absolute value: positive for net splits,
+/// negative for net merges, zero when changes are pure substitutions.
+#[pyfunction]
+pub fn merge_split_signed(
+    _py: Python<'_>,
+    input_tokens: Vec<Bound<'_, PyString>>,
+    output_tokens: Vec<Bound<'_, PyString>>,
+) -> PyResult<f64> {
+    let inputs = extract_str_refs(&input_tokens)?;
+    let outputs = extract_str_refs(&output_tokens)?;
+    Ok(compute_merge_split_signed(&inputs, &outputs))
+}
+
+#[pyfunction]
+pub fn batch_merge_split_signed(
+    py: Python<'_>,
+    inputs: Vec<Vec<Bound<'_, PyString>>>,
+    outputs: Vec<Vec<Bound<'_, PyString>>>,
+) -> PyResult<Vec<f64>> {
+    guard_equal_batches(inputs.len(), outputs.len())?;
+
+    let input_owned = extract_batch_owned_strings(&inputs)?;
+    let output_owned = extract_batch_owned_strings(&outputs)?;
+
+    Ok(py.allow_threads(|| {
+        input_owned
+            .par_iter()
+            .zip(output_owned.par_iter())
+            .map(|(input, output)| compute_merge_split_signed(input, output))
+            .collect()
+    }))
+}
+
 fn lcs_length<S: AsRef<str>>(a: &[S], b: &[S]) -> usize {
     let m = a.len();
     let n = b.len();
@@ -415,14 +566,21 @@ fn lcs_length<S: AsRef<str>>(a: &[S], b: &[S]) -> usize {
 }
 
 fn compute_merge_split_index<S: AsRef<str>>(tokens1: &[S], tokens2: &[S]) -> f64 {
+    compute_merge_split_signed(tokens1, tokens2).abs()
+}
+
+fn compute_merge_split_signed<S: AsRef<str>>(tokens1: &[S], tokens2: &[S]) -> f64 {
     let m = tokens1.len();
     let n = tokens2.len();
 
     if m == 0 && n == 0 {
         return 0.0;
     }
-    if m == 0 || n == 0 {
-        return 1.0; // Complete transformation
+    if m == 0 {
+        return 1.0; // Complete transformation, all of it new tokens
+    }
+    if n == 0 {
+        return -1.0;
     }
 
     // Find preserved tokens via LCS
@@ -436,10 +594,627 @@ fn compute_merge_split_index<S: AsRef<str>>(tokens1: &[S], tokens2: &[S]) -> f64
     // - If orig_changed > corr_changed: merges occurred (k→1)
     // - If corr_changed > orig_changed: splits occurred (1→k)
     // - If orig_changed == corr_changed: substitutions only (no restructuring)
-    let merge_split_events = orig_changed.abs_diff(corr_changed);
+    let merge_split_events = corr_changed as f64 - orig_changed as f64;
 
     let max_len = max(m, n);
-    merge_split_events as f64 / max_len as f64
+    merge_split_events / max_len as f64
+}
+
+// ---------------------------------------------------------------------------
+// Character N-gram Jaccard
+// ---------------------------------------------------------------------------
+
+/// Jaccard similarity between the character n-gram sets of two strings.
+/// Robust to token re-splitting, so sub-word corruption registers even when
+/// token metrics barely move.
+#[pyfunction]
+pub fn char_ngram_jaccard(input: &str, output: &str, n: usize) -> PyResult<f64> {
+    guard_ngram_size(n)?;
+    Ok(compute_char_ngram_jaccard(input, output, n))
+}
+
+#[pyfunction]
+pub fn batch_char_ngram_jaccard(
+    py: Python<'_>,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+    n: usize,
+) -> PyResult<Vec<f64>> {
+    guard_equal_batches(inputs.len(), outputs.len())?;
+    guard_ngram_size(n)?;
+
+    Ok(py.allow_threads(|| {
+        inputs
+            .par_iter()
+            .zip(outputs.par_iter())
+            .map(|(input, output)| compute_char_ngram_jaccard(input, output, n))
+            .collect()
+    }))
+}
+
+fn guard_ngram_size(n: usize) -> PyResult<()> {
+    if n == 0 {
+        return Err(PyValueError::new_err("n-gram size must be at least 1"));
+    }
+    Ok(())
+}
+
+/// Collects the distinct `n`-char windows of `text`. Strings shorter than `n`
+/// contribute themselves as a single gram so they still compare sensibly.
+fn char_ngrams(text: &str, n: usize) -> HashSet<&str> {
+    let boundaries: Vec<usize> = text
+        .char_indices()
+        .map(|(idx, _)| idx)
+        .chain(std::iter::once(text.len()))
+        .collect();
+    let char_count = boundaries.len() - 1;
+    if char_count == 0 {
+        return HashSet::new();
+    }
+    if char_count < n {
+        return HashSet::from([text]);
+    }
+
+    (0..=char_count - n)
+        .map(|start| &text[boundaries[start]..boundaries[start + n]])
+        .collect()
+}
+
+fn compute_char_ngram_jaccard(input: &str, output: &str, n: usize) -> f64 {
+    let left = char_ngrams(input, n);
+    let right = char_ngrams(output, n);
+    if left.is_empty() && right.is_empty() {
+        return 1.0;
+    }
+
+    let intersection = left.intersection(&right).count();
+    let union = left.len() + right.len() - intersection;
+    intersection as f64 / union as f64
+}
+
+// ---------------------------------------------------------------------------
+// Readability Delta
+// ---------------------------------------------------------------------------
+
+/// Change in Flesch Reading Ease from `input` to `output`. Negative values
+/// mean the corrupted text reads harder; an input without words scores 0.0.
+#[pyfunction]
+pub fn readability_delta(input: &str, output: &str) -> f64 {
+    compute_readability_delta(input, output)
+}
+
+#[pyfunction]
+pub fn batch_readability_delta(
+    py: Python<'_>,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+) -> PyResult<Vec<f64>> {
+    guard_equal_batches(inputs.len(), outputs.len())?;
+
+    Ok(py.allow_threads(|| {
+        inputs
+            .par_iter()
+            .zip(outputs.par_iter())
+            .map(|(input, output)| compute_readability_delta(input, output))
+            .collect()
+    }))
+}
+
+/// Estimates syllables as vowel groups, dropping a silent final `e` ("make")
+/// but not `-le` ("table"). Every word counts at least one syllable.
+fn count_syllables(word: &str) -> usize {
+    let letters: Vec<char> = word
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect();
+
+    let mut groups = 0usize;
+    let mut previous_vowel = false;
+    for &ch in &letters {
+        let vowel = is_vowel(ch);
+        if vowel && !previous_vowel {
+            groups += 1;
+        }
+        previous_vowel = vowel;
+    }
+
+    if let [.., before, penultimate, 'e'] = letters.as_slice() {
+        let consonant_le = *penultimate == 'l' && !is_vowel(*before);
+        let silent = !is_vowel(*penultimate) && !consonant_le;
+        if silent && groups > 1 {
+            groups -= 1;
+        }
+    }
+
+    groups.max(1)
+}
+
+/// Flesch Reading Ease, or `None` when `text` has no words.
+fn flesch_reading_ease(text: &str) -> Option<f64> {
+    let words: Vec<&str> = text
+        .split_whitespace()
+        .filter(|token| token.chars().any(char::is_alphanumeric))
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+
+    let sentences = sentence_ranges(text)
+        .into_iter()
+        .filter(|range| text[range.clone()].chars().any(char::is_alphanumeric))
+        .count()
+        .max(1);
+    let syllables: usize = words.iter().map(|word| count_syllables(word)).sum();
+
+    let word_count = words.len() as f64;
+    Some(206.835 - 1.015 * (word_count / sentences as f64) - 84.6 * (syllables as f64 / word_count))
+}
+
+fn compute_readability_delta(input: &str, output: &str) -> f64 {
+    let Some(before) = flesch_reading_ease(input) else {
+        return 0.0;
+    };
+    let after = flesch_reading_ease(output).unwrap_or(0.0);
+    after - before
+}
+
+// ---------------------------------------------------------------------------
+// Word / Character Error Rate
+// ---------------------------------------------------------------------------
+
+/// Word error rate: substitutions, deletions and insertions needed to turn
+/// `reference_tokens` into `hypothesis_tokens`, over the reference length.
+/// Insertions can push it above 1.0. An empty reference scores 0.0 against an
+/// empty hypothesis and is rejected otherwise, since the rate is undefined.
+#[pyfunction]
+pub fn word_error_rate(
+    _py: Python<'_>,
+    reference_tokens: Vec<Bound<'_, PyString>>,
+    hypothesis_tokens: Vec<Bound<'_, PyString>>,
+) -> PyResult<f64> {
+    let reference = extract_str_refs(&reference_tokens)?;
+    let hypothesis = extract_str_refs(&hypothesis_tokens)?;
+    compute_error_rate(&reference, &hypothesis).ok_or_else(empty_reference_error)
+}
+
+#[pyfunction]
+pub fn batch_word_error_rate(
+    py: Python<'_>,
+    references: Vec<Vec<Bound<'_, PyString>>>,
+    hypotheses: Vec<Vec<Bound<'_, PyString>>>,
+) -> PyResult<Vec<f64>> {
+    guard_equal_batches(references.len(), hypotheses.len())?;
+
+    let reference_owned = extract_batch_owned_strings(&references)?;
+    let hypothesis_owned = extract_batch_owned_strings(&hypotheses)?;
+
+    let rates: Option<Vec<f64>> = py.allow_threads(|| {
+        reference_owned
+            .par_iter()
+            .zip(hypothesis_owned.par_iter())
+            .map(|(reference, hypothesis)| compute_error_rate(reference, hypothesis))
+            .collect()
+    });
+    rates.ok_or_else(empty_reference_error)
+}
+
+/// Character error rate: the character-level analogue of
+/// [`word_error_rate`], with the same empty-reference handling.
+#[pyfunction]
+pub fn character_error_rate(reference: &str, hypothesis: &str) -> PyResult<f64> {
+    compute_character_error_rate(reference, hypothesis).ok_or_else(empty_reference_error)
+}
+
+#[pyfunction]
+pub fn batch_character_error_rate(
+    py: Python<'_>,
+    references: Vec<String>,
+    hypotheses: Vec<String>,
+) -> PyResult<Vec<f64>> {
+    guard_equal_batches(references.len(), hypotheses.len())?;
+
+    let rates: Option<Vec<f64>> = py.allow_threads(|| {
+        references
+            .par_iter()
+            .zip(hypotheses.par_iter())
+            .map(|(reference, hypothesis)| compute_character_error_rate(reference, hypothesis))
+            .collect()
+    });
+    rates.ok_or_else(empty_reference_error)
+}
+
+fn empty_reference_error() -> PyErr {
+    PyValueError::new_err("error rate is undefined for an empty reference and non-empty hypothesis")
+}
+
+/// Edit distance over reference length, or `None` when the reference is empty
+/// but the hypothesis is not.
+fn compute_error_rate<T: PartialEq>(reference: &[T], hypothesis: &[T]) -> Option<f64> {
+    if reference.is_empty() {
+        return hypothesis.is_empty().then_some(0.0);
+    }
+    Some(levenshtein(reference, hypothesis) as f64 / reference.len() as f64)
+}
+
+fn compute_character_error_rate(reference: &str, hypothesis: &str) -> Option<f64> {
+    let reference: Vec<char> = reference.chars().collect();
+    let hypothesis: Vec<char> = hypothesis.chars().collect();
+    compute_error_rate(&reference, &hypothesis)
+}
+
+// ---------------------------------------------------------------------------
+// Confusable Edit Distance
+// ---------------------------------------------------------------------------
+
+/// Character-level normalised edit distance that treats characters from the
+/// same homoglyph confusable class as equal, so it measures how much *visible*
+/// change occurred. Pure homoglyph substitutions score 0.0.
+#[pyfunction]
+pub fn confusable_edit_distance(input: &str, output: &str) -> f64 {
+    compute_confusable_edit_distance(input, output)
+}
+
+#[pyfunction]
+pub fn batch_confusable_edit_distance(
+    py: Python<'_>,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+) -> PyResult<Vec<f64>> {
+    guard_equal_batches(inputs.len(), outputs.len())?;
+
+    Ok(py.allow_threads(|| {
+        inputs
+            .par_iter()
+            .zip(outputs.par_iter())
+            .map(|(input, output)| compute_confusable_edit_distance(input, output))
+            .collect()
+    }))
+}
+
+fn compute_confusable_edit_distance(input: &str, output: &str) -> f64 {
+    let left: Vec<char> = input.chars().map(confusable_class).collect();
+    let right: Vec<char> = output.chars().map(confusable_class).collect();
+    let longest = max(left.len(), right.len());
+    if longest == 0 {
+        return 0.0;
+    }
+    levenshtein(&left, &right) as f64 / longest as f64
+}
+
+// ---------------------------------------------------------------------------
+// Injection Rate
+// ---------------------------------------------------------------------------
+
+/// Fraction of `output` characters that are non-ASCII and absent from
+/// `input`: homoglyphs, zero-width characters and bidi controls injected by
+/// the corruption. An empty output scores 0.0.
+#[pyfunction]
+pub fn injection_rate(input: &str, output: &str) -> f64 {
+    compute_injection_rate(input, output)
+}
+
+#[pyfunction]
+pub fn batch_injection_rate(
+    py: Python<'_>,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+) -> PyResult<Vec<f64>> {
+    guard_equal_batches(inputs.len(), outputs.len())?;
+
+    Ok(py.allow_threads(|| {
+        inputs
+            .par_iter()
+            .zip(outputs.par_iter())
+            .map(|(input, output)| compute_injection_rate(input, output))
+            .collect()
+    }))
+}
+
+fn compute_injection_rate(input: &str, output: &str) -> f64 {
+    let original: HashSet<char> = input.chars().filter(|ch| !ch.is_ascii()).collect();
+    let mut total = 0usize;
+    let mut injected = 0usize;
+    for ch in output.chars() {
+        total += 1;
+        if !ch.is_ascii() && !original.contains(&ch) {
+            injected += 1;
+        }
+    }
+    if total == 0 {
+        return 0.0;
+    }
+    injected as f64 / total as f64
+}
+
+// ---------------------------------------------------------------------------
+// String Inputs
+// ---------------------------------------------------------------------------
+
+const VALID_TOKENIZER_MESSAGE: &str = "whitespace, words";
+
+/// How the `*_str` metric variants split raw text into tokens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MetricTokenizer {
+    /// Whitespace-delimited tokens: the buffer's word segments, punctuation
+    /// included.
+    #[default]
+    Whitespace,
+    /// Word cores with leading and trailing punctuation stripped, as word
+    /// operations see them. Punctuation-only tokens are dropped.
+    Words,
+}
+
+impl MetricTokenizer {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "whitespace" => Ok(Self::Whitespace),
+            "words" | "word" => Ok(Self::Words),
+            _ => Err(format!(
+                "Unsupported tokenizer '{name}'. Expected one of: {VALID_TOKENIZER_MESSAGE}"
+            )),
+        }
+    }
+
+    #[must_use]
+    pub fn tokenize(self, text: &str) -> Vec<&str> {
+        let tokens = text.split_whitespace();
+        match self {
+            Self::Whitespace => tokens.collect(),
+            Self::Words => tokens
+                .map(|token| split_affixes_ref(token).1)
+                .filter(|core| !core.is_empty())
+                .collect(),
+        }
+    }
+}
+
+fn tokenize_pair<'a>(
+    input: &'a str,
+    output: &'a str,
+    tokenizer: &str,
+) -> PyResult<(Vec<&'a str>, Vec<&'a str>)> {
+    let tokenizer = MetricTokenizer::parse(tokenizer).map_err(PyValueError::new_err)?;
+    Ok((tokenizer.tokenize(input), tokenizer.tokenize(output)))
+}
+
+/// [`jensen_shannon_divergence`] over raw strings tokenised in Rust.
+#[pyfunction]
+#[pyo3(signature = (input, output, tokenizer="whitespace"))]
+pub fn jensen_shannon_divergence_str(input: &str, output: &str, tokenizer: &str) -> PyResult<f64> {
+    let (inputs, outputs) = tokenize_pair(input, output, tokenizer)?;
+    Ok(compute_jsd(&inputs, &outputs))
+}
+
+/// [`normalized_edit_distance`] over raw strings tokenised in Rust.
+#[pyfunction]
+#[pyo3(signature = (input, output, tokenizer="whitespace"))]
+pub fn normalized_edit_distance_str(input: &str, output: &str, tokenizer: &str) -> PyResult<f64> {
+    let (inputs, outputs) = tokenize_pair(input, output, tokenizer)?;
+    Ok(compute_normalized_edit_distance(&inputs, &outputs))
+}
+
+/// [`subsequence_retention`] over raw strings tokenised in Rust.
+#[pyfunction]
+#[pyo3(signature = (input, output, tokenizer="whitespace"))]
+pub fn subsequence_retention_str(input: &str, output: &str, tokenizer: &str) -> PyResult<f64> {
+    let (inputs, outputs) = tokenize_pair(input, output, tokenizer)?;
+    Ok(compute_subsequence_retention(&inputs, &outputs))
+}
+
+/// [`positional_jsd`] over raw strings tokenised in Rust.
+#[pyfunction]
+#[pyo3(signature = (input, output, bins, tokenizer="whitespace"))]
+pub fn positional_jsd_str(
+    input: &str,
+    output: &str,
+    bins: usize,
+    tokenizer: &str,
+) -> PyResult<f64> {
+    guard_bin_count(bins)?;
+    let (inputs, outputs) = tokenize_pair(input, output, tokenizer)?;
+    Ok(compute_positional_jsd(&inputs, &outputs, bins))
+}
+
+/// [`entropy_delta`] over raw strings tokenised in Rust.
+#[pyfunction]
+#[pyo3(signature = (input, output, tokenizer="whitespace"))]
+pub fn entropy_delta_str(input: &str, output: &str, tokenizer: &str) -> PyResult<f64> {
+    let (inputs, outputs) = tokenize_pair(input, output, tokenizer)?;
+    Ok(compute_entropy_delta(&inputs, &outputs))
+}
+
+/// [`merge_split_index`] over raw strings tokenised in Rust.
+#[pyfunction]
+#[pyo3(signature = (input, output, tokenizer="whitespace"))]
+pub fn merge_split_index_str(input: &str, output: &str, tokenizer: &str) -> PyResult<f64> {
+    let (inputs, outputs) = tokenize_pair(input, output, tokenizer)?;
+    Ok(compute_merge_split_index(&inputs, &outputs))
+}
+
+/// [`word_error_rate`] over raw strings tokenised in Rust.
+#[pyfunction]
+#[pyo3(signature = (reference, hypothesis, tokenizer="whitespace"))]
+pub fn word_error_rate_str(reference: &str, hypothesis: &str, tokenizer: &str) -> PyResult<f64> {
+    let (reference, hypothesis) = tokenize_pair(reference, hypothesis, tokenizer)?;
+    compute_error_rate(&reference, &hypothesis).ok_or_else(empty_reference_error)
+}
+
+// ---------------------------------------------------------------------------
+// Named Metrics
+// ---------------------------------------------------------------------------
+
+const VALID_METRIC_MESSAGE: &str = "jensen_shannon_divergence, normalized_edit_distance, \
+subsequence_retention, entropy_delta, merge_split_index, readability_delta";
+
+/// A corruption metric selectable by name, for callers that score raw text
+/// rather than pre-tokenised input. Token metrics split on whitespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextMetric {
+    JensenShannonDivergence,
+    NormalizedEditDistance,
+    SubsequenceRetention,
+    EntropyDelta,
+    MergeSplitIndex,
+    ReadabilityDelta,
+}
+
+impl TextMetric {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        let normalized = name.to_ascii_lowercase().replace('-', "_");
+        match normalized.as_str() {
+            "jensen_shannon_divergence" | "jsd" => Ok(Self::JensenShannonDivergence),
+            "normalized_edit_distance" | "ned" => Ok(Self::NormalizedEditDistance),
+            "subsequence_retention" | "sr" => Ok(Self::SubsequenceRetention),
+            "entropy_delta" => Ok(Self::EntropyDelta),
+            "merge_split_index" | "msi" => Ok(Self::MergeSplitIndex),
+            "readability_delta" => Ok(Self::ReadabilityDelta),
+            _ => Err(format!(
+                "Unsupported metric '{name}'. Expected one of: {VALID_METRIC_MESSAGE}"
+            )),
+        }
+    }
+
+    /// Scores `output` against `input`.
+    #[must_use]
+    pub fn score(self, input: &str, output: &str) -> f64 {
+        if self == Self::ReadabilityDelta {
+            return compute_readability_delta(input, output);
+        }
+
+        let inputs = MetricTokenizer::Whitespace.tokenize(input);
+        let outputs = MetricTokenizer::Whitespace.tokenize(output);
+        match self {
+            Self::JensenShannonDivergence => compute_jsd(&inputs, &outputs),
+            Self::NormalizedEditDistance => compute_normalized_edit_distance(&inputs, &outputs),
+            Self::SubsequenceRetention => compute_subsequence_retention(&inputs, &outputs),
+            Self::EntropyDelta => compute_entropy_delta(&inputs, &outputs),
+            Self::MergeSplitIndex => compute_merge_split_index(&inputs, &outputs),
+            Self::ReadabilityDelta => unreachable!("handled above"),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Per-Sentence Batches
+// ---------------------------------------------------------------------------
+
+/// Scores each `(input, output)` document with the named [`TextMetric`].
+///
+/// With `per_sentence`, each inner vector holds one score per sentence of the
+/// input, split as [`crate::text_buffer::TextBuffer::sentences`] splits them.
+/// Output sentences pair with input sentences by position: extra output
+/// sentences fold into the last pair and missing ones score against "".
+/// Without it, each inner vector holds the single whole-document score.
+/// Documents with no input sentences give an empty vector either way.
+#[pyfunction]
+#[pyo3(signature = (metric, inputs, outputs, per_sentence=false))]
+pub fn batch_text_metric(
+    py: Python<'_>,
+    metric: &str,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+    per_sentence: bool,
+) -> PyResult<Vec<Vec<f64>>> {
+    let metric = TextMetric::parse(metric).map_err(PyValueError::new_err)?;
+    guard_equal_batches(inputs.len(), outputs.len())?;
+
+    Ok(py.allow_threads(|| {
+        inputs
+            .par_iter()
+            .zip(outputs.par_iter())
+            .map(|(input, output)| compute_document_scores(metric, input, output, per_sentence))
+            .collect()
+    }))
+}
+
+fn compute_document_scores(
+    metric: TextMetric,
+    input: &str,
+    output: &str,
+    per_sentence: bool,
+) -> Vec<f64> {
+    let input_sentences = sentence_ranges(input);
+    if input_sentences.is_empty() {
+        return Vec::new();
+    }
+    if !per_sentence {
+        return vec![metric.score(input, output)];
+    }
+
+    let output_sentences = sentence_ranges(output);
+    let last = input_sentences.len() - 1;
+    input_sentences
+        .iter()
+        .enumerate()
+        .map(|(idx, range)| {
+            let corrupted = match (output_sentences.get(idx), output_sentences.last()) {
+                (Some(first), Some(end)) if idx == last => &output[first.start..end.end],
+                (Some(sentence), _) => &output[sentence.clone()],
+                (None, _) => "",
+            };
+            metric.score(&input[range.clone()], corrupted)
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Rate Calibration
+// ---------------------------------------------------------------------------
+
+/// Bisection steps after which [`calibrate_rate`] settles for the closest rate.
+const CALIBRATION_MAX_ITERATIONS: usize = 32;
+
+/// Bisects a corruption rate in `[0, 1]` until `measure(rate)` lands within
+/// `tolerance` of `target`, returning that rate.
+///
+/// The metric's direction is read from the two endpoints, so metrics that
+/// fall as corruption grows (subsequence retention) work as well as those
+/// that rise. Bisection assumes the metric is monotonic in the rate: for
+/// non-monotonic metrics, noisy ops, or targets outside the reachable range
+/// the search may not converge, and the closest rate evaluated is returned.
+pub fn calibrate_rate<E>(
+    target: f64,
+    tolerance: f64,
+    mut measure: impl FnMut(f64) -> Result<f64, E>,
+) -> Result<f64, E> {
+    let tolerance = tolerance.abs();
+    let mut best_rate = 0.0;
+    let mut best_error = f64::INFINITY;
+    let mut evaluate = |rate: f64| -> Result<(f64, bool), E> {
+        let score = measure(rate)?;
+        let error = (score - target).abs();
+        if error < best_error {
+            best_error = error;
+            best_rate = rate;
+        }
+        Ok((score, error <= tolerance))
+    };
+
+    let (low_score, hit) = evaluate(0.0)?;
+    if hit {
+        return Ok(0.0);
+    }
+    let (high_score, hit) = evaluate(1.0)?;
+    if hit {
+        return Ok(1.0);
+    }
+
+    let increasing = high_score >= low_score;
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..CALIBRATION_MAX_ITERATIONS {
+        let mid = f64::midpoint(low, high);
+        let (score, hit) = evaluate(mid)?;
+        if hit {
+            return Ok(mid);
+        }
+        if (score < target) == increasing {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Ok(best_rate)
 }
 
 // ---------------------------------------------------------------------------
@@ -759,3 +1534,227 @@ pub fn batch_unknown_token_rate(
         })
         .collect())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        calibrate_rate, compute_char_entropy_delta, compute_char_ngram_jaccard,
+        compute_character_error_rate, compute_confusable_edit_distance, compute_document_scores,
+        compute_entropy_delta, compute_error_rate, compute_injection_rate, compute_jsd,
+        compute_merge_split_index, compute_merge_split_signed, compute_normalized_edit_distance,
+        compute_positional_jsd, compute_readability_delta, count_syllables, MetricTokenizer,
+        TextMetric,
+    };
+    use crate::operations::{DeleteRandomWordsOp, TextOperation, WordWeighting};
+    use crate::rng::DeterministicRng;
+    use crate::text_buffer::TextBuffer;
+
+    #[test]
+    fn char_ngram_jaccard_bounds() {
+        assert_eq!(compute_char_ngram_jaccard("glitch", "glitch", 3), 1.0);
+        assert_eq!(compute_char_ngram_jaccard("abcdef", "uvwxyz", 3), 0.0);
+        assert_eq!(compute_char_ngram_jaccard("", "", 3), 1.0);
+        let score = compute_char_ngram_jaccard("glitchling", "gl1tchling", 3);
+        assert!(score > 0.0 && score < 1.0);
+    }
+
+    #[test]
+    fn confusable_edit_distance_ignores_homoglyph_swaps() {
+        assert_eq!(compute_confusable_edit_distance("", ""), 0.0);
+        assert_eq!(compute_confusable_edit_distance("paypal", "\u{440}\u{430}ypal"), 0.0);
+        assert_eq!(compute_confusable_edit_distance("abcd", "abxd"), 0.25);
+        assert_eq!(compute_confusable_edit_distance("", "abc"), 1.0);
+    }
+
+    #[test]
+    fn error_rates_divide_by_the_reference_length() {
+        let reference = ["the", "cat", "sat", "down"];
+        assert_eq!(compute_error_rate(&reference, &reference), Some(0.0));
+        assert_eq!(compute_error_rate(&reference, &["the", "cat", "sat"]), Some(0.25));
+        assert_eq!(compute_error_rate(&reference, &["a", "the", "bat", "sat", "down"]), Some(0.5));
+        assert_eq!(compute_error_rate(&["hi"], &["oh", "hi", "there"]), Some(2.0));
+        let empty: [&str; 0] = [];
+        assert_eq!(compute_error_rate(&empty, &empty), Some(0.0));
+        assert_eq!(compute_error_rate(&empty, &["noise"]), None);
+        assert_eq!(compute_character_error_rate("kitten", "sitting"), Some(0.5));
+        assert_eq!(compute_character_error_rate("", "x"), None);
+    }
+
+    #[test]
+    fn injection_rate_counts_new_non_ascii_characters() {
+        assert_eq!(compute_injection_rate("hello", "hello"), 0.0);
+        assert_eq!(compute_injection_rate("hello", ""), 0.0);
+        assert_eq!(compute_injection_rate("hello", "h\u{435}llo"), 0.2);
+        // Zero-width and bidi controls count; non-ASCII already present does not.
+        assert_eq!(compute_injection_rate("ab", "a\u{200B}\u{202E}b"), 0.5);
+        assert_eq!(compute_injection_rate("café", "café!"), 0.0);
+    }
+
+    #[test]
+    fn metric_tokenizers_match_manual_tokenisation() {
+        let text = "Hello,  world! -- it's a \"test\".";
+        assert_eq!(
+            MetricTokenizer::Whitespace.tokenize(text),
+            ["Hello,", "world!", "--", "it's", "a", "\"test\"."]
+        );
+        assert_eq!(MetricTokenizer::Words.tokenize(text), ["Hello", "world", "it's", "a", "test"]);
+        assert_eq!(MetricTokenizer::parse("Words"), Ok(MetricTokenizer::Words));
+        assert!(MetricTokenizer::parse("bpe").is_err());
+
+        let corrupted = "Hello, wrold! it's a test.";
+        let by_hand = (
+            ["Hello", "world", "it's", "a", "test"],
+            ["Hello", "wrold", "it's", "a", "test"],
+        );
+        let tokenized = (
+            MetricTokenizer::Words.tokenize(text),
+            MetricTokenizer::Words.tokenize(corrupted),
+        );
+        assert_eq!(
+            compute_normalized_edit_distance(&tokenized.0, &tokenized.1),
+            compute_normalized_edit_distance(&by_hand.0, &by_hand.1)
+        );
+        assert_eq!(compute_jsd(&tokenized.0, &tokenized.1), compute_jsd(&by_hand.0, &by_hand.1));
+        assert_eq!(
+            compute_merge_split_index(&tokenized.0, &tokenized.1),
+            compute_merge_split_index(&by_hand.0, &by_hand.1)
+        );
+    }
+
+    #[test]
+    fn char_entropy_delta_sees_homoglyph_swaps() {
+        let clean = "banana band banana";
+        // Cyrillic "а" in place of one Latin "a": same token shape, new character
+        let swapped = "banаna band banаna";
+        let clean_tokens: Vec<&str> = clean.split_whitespace().collect();
+        let swapped_tokens: Vec<&str> = swapped.split_whitespace().collect();
+        assert!(compute_entropy_delta(&clean_tokens, &swapped_tokens).abs() < 1e-12);
+        assert!(compute_char_entropy_delta(clean, swapped) > 0.0);
+        assert!(compute_char_entropy_delta(swapped, clean) < 0.0);
+        assert!(compute_char_entropy_delta(clean, clean).abs() < 1e-12);
+        assert_eq!(compute_char_entropy_delta("", ""), 0.0);
+    }
+
+    #[test]
+    fn merge_split_signed_reports_the_direction() {
+        let clean = ["the", "quick", "brown", "fox", "jumps"];
+        // Word-internal spaces deleted: two merges
+        let merged = ["thequick", "brownfox", "jumps"];
+        let signed = compute_merge_split_signed(&clean, &merged);
+        assert!(signed < 0.0);
+        assert_eq!(signed.abs(), compute_merge_split_index(&clean, &merged));
+
+        let split = ["the", "qu", "ick", "brown", "fox", "jum", "ps"];
+        assert!(compute_merge_split_signed(&clean, &split) > 0.0);
+
+        let substituted = ["the", "quack", "brown", "fix", "jumps"];
+        assert_eq!(compute_merge_split_signed(&clean, &substituted), 0.0);
+
+        let empty: [&str; 0] = [];
+        assert_eq!(compute_merge_split_signed(&empty, &clean), 1.0);
+        assert_eq!(compute_merge_split_signed(&clean, &empty), -1.0);
+    }
+
+    #[test]
+    fn document_scores_align_to_input_sentences() {
+        let ned = TextMetric::NormalizedEditDistance;
+        let clean = "The cat sat. The dog ran far away! Birds sang.";
+        let corrupted = "The cat sat. The dgo ran fra away! Birds sang.";
+        let scores = compute_document_scores(ned, clean, corrupted, true);
+        assert_eq!(scores.len(), 3);
+        assert_eq!(scores[0], 0.0);
+        assert_eq!(scores[1], ned.score("The dog ran far away!", "The dgo ran fra away!"));
+        assert_eq!(scores[2], 0.0);
+        assert_eq!(
+            compute_document_scores(ned, clean, corrupted, false),
+            vec![ned.score(clean, corrupted)]
+        );
+
+        // Extra output sentences fold into the last; missing ones score against ""
+        let split =
+            compute_document_scores(ned, "One two. Three four", "One two. Three. Four", true);
+        assert_eq!(split[1], ned.score("Three four", "Three. Four"));
+        let short = compute_document_scores(ned, "One. Two.", "One.", true);
+        assert_eq!(short, vec![0.0, 1.0]);
+
+        assert!(compute_document_scores(ned, "", "anything", true).is_empty());
+        assert!(compute_document_scores(ned, "  ", "", false).is_empty());
+    }
+
+    #[test]
+    fn syllable_heuristic_handles_silent_e() {
+        assert_eq!(count_syllables("cat"), 1);
+        assert_eq!(count_syllables("make"), 1);
+        assert_eq!(count_syllables("table"), 2);
+        assert_eq!(count_syllables("readability"), 5);
+        assert_eq!(count_syllables("the"), 1);
+        assert_eq!(count_syllables("42"), 1);
+    }
+
+    #[test]
+    fn readability_delta_tracks_harder_text() {
+        let clean = "The cat sat on the mat. It was a good day.";
+        assert_eq!(compute_readability_delta(clean, clean), 0.0);
+        assert_eq!(compute_readability_delta("", "anything at all"), 0.0);
+        assert!(compute_readability_delta(clean, "Thecatsat onthemat itwas agoodday") < 0.0);
+    }
+
+    #[test]
+    fn positional_jsd_distinguishes_where_corruption_lands() {
+        let clean = ["a", "b", "c", "d", "e", "f", "g", "h"];
+        let early = ["x", "b", "c", "d", "e", "f", "g", "h"];
+        let late = ["a", "b", "c", "d", "e", "f", "g", "x"];
+        assert_eq!(compute_positional_jsd(&clean, &clean, 4), 0.0);
+        assert_eq!(compute_positional_jsd(&clean, &early, 1), compute_jsd(&clean, &early));
+        // Reordering is invisible to plain JSD but not to the positional form.
+        let reordered = ["h", "b", "c", "d", "e", "f", "g", "a"];
+        assert_eq!(compute_jsd(&clean, &reordered), 0.0);
+        assert!(compute_positional_jsd(&clean, &reordered, 4) > 0.0);
+        assert_eq!(
+            compute_positional_jsd(&clean, &early, 4),
+            compute_positional_jsd(&clean, &late, 4)
+        );
+        // More bins than tokens: empty buckets don't dilute the mean.
+        assert_eq!(
+            compute_positional_jsd(&["a", "b"], &["a", "x"], 10),
+            compute_positional_jsd(&["a", "b"], &["a", "x"], 2) 
+        );
+        let empty: [&str; 0] = [];
+        assert_eq!(compute_positional_jsd(&empty, &empty, 3), 0.0);
+    }
+
+    #[test]
+    fn calibration_finds_rates_for_either_metric_direction() {
+        let rising = calibrate_rate(0.3, 0.001, |rate| Ok::<_, ()>(rate * rate)).unwrap();
+        assert!((rising * rising - 0.3).abs() <= 0.001);
+        let falling = calibrate_rate(0.25, 0.001, |rate| Ok::<_, ()>(1.0 - rate)).unwrap();
+        assert!((falling - 0.75).abs() <= 0.001);
+        // Unreachable targets settle on the closest endpoint
+        assert_eq!(calibrate_rate(2.0, 0.01, Ok::<_, ()>).unwrap(), 1.0);
+        assert_eq!(calibrate_rate(0.5, 0.1, |_| Err("boom")), Err("boom"));
+    }
+
+    #[test]
+    fn calibration_tunes_a_real_operation() {
+        let text = "the quick brown fox jumps over the lazy dog while the cat sleeps \
+                    soundly beside a warm fire in the small old cottage by the sea";
+        let measure = |rate: f64| {
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(2073);
+            let op = DeleteRandomWordsOp {
+                rate,
+                unweighted: true,
+                min_words: 0,
+                weighting: WordWeighting::Length,
+                preserve_spacing: false,
+            };
+            op.apply(&mut buffer, &mut rng)?;
+            Ok::<_, crate::operations::OperationError>(
+                TextMetric::NormalizedEditDistance.score(text, &buffer.to_string()),
+            )
+        };
+        let rate = calibrate_rate(0.3, 0.05, measure).expect("calibration succeeds");
+        assert!(rate > 0.0 && rate < 1.0);
+        assert!((measure(rate).unwrap() - 0.3).abs() <= 0.05);
+    }
+}
@@ -6,30 +6,52 @@
 //! # Module Structure
 //!
 //! - **Core Types** (lines ~20-230): Error types, RNG trait, rate utilities
-//! - **Word Mutations** (lines ~240-580): Reduplicate, Delete, Swap, RushmoreCombo
+//! - **Word Mutations** (lines ~240-680): Reduplicate, Delete, Swap, RushmoreCombo
 //! - **Redaction** (lines ~590-720): RedactWordsOp
 //! - **OCR Simulation** (lines ~720-1120): OcrArtifactsOp with burst/bias models
 //! - **Zero-Width Characters** (lines ~1120-1640): ZeroWidthOp and related types
-//! - **Keyboard Typos** (lines ~1640-2360): TypoOp, ShiftSlipConfig, MotorWeighting
+//! - **Keyboard Typos** (lines ~1640-2360): TypoOp, ShiftSlipConfig, MotorWeighting,
+//!   KeyboardLayout
 //! - **Quote Normalization** (lines ~2360-2510): QuotePairsOp
 //! - **Operation Enum** (lines ~2510-2550): Type-erased Operation wrapper
 //! - **Tests** (lines ~2550+): Unit tests for operations
 
+use aho_corasick::AhoCorasick;
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::PyErr;
+use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use crate::homophones::HomophoneOp;
 use crate::lexeme_substitution::LexemeSubstitutionOp;
 use crate::homoglyphs::HomoglyphOp;
+use crate::autocorrect::AutocorrectOp;
+use crate::bidi::BidiOp;
+use crate::case::CaseOp;
+use crate::contractions::ContractionOp;
+use crate::diacritics::DiacriticOp;
+use crate::disemvowel::DisemvowelOp;
+use crate::double_words::DoubleWordOp;
+use crate::fillers::FillerOp;
+use crate::whitespace::WhitespaceJitterOp;
 use crate::grammar_rules::GrammarRuleOp;
+use crate::phonetic::PhoneticOp;
+use crate::punctuation::DropPunctuationOp;
+use crate::scramble::ScrambleOp;
+use crate::word_boundaries::{MergeWordsOp, SplitWordsOp};
+use crate::zalgo::ZalgoOp;
 use crate::resources::{
-    affix_bounds, apostrofae_pairs, confusion_table, is_whitespace_only, ocr_automaton,
-    split_affixes_ref,
+    affix_bounds, apostrofae_pairs, confusion_table, is_stopword, is_whitespace_only,
+    ocr_automaton, split_affixes_ref, ConfusionTable,
 };
+use crate::pipeline::derive_seed;
 use crate::rng::{DeterministicRng, RngError};
-use crate::text_buffer::{SegmentKind, TextBuffer, TextBufferError, TextSegment};
+use crate::sentences::{DeleteSentencesOp, ShuffleSentencesOp};
+use crate::text_buffer::{
+    RebuildPiece, Reduplication, SegmentKind, TextBuffer, TextBufferError, TextSegment,
+};
 
 /// Errors produced while applying a [`TextOperation`].
 #[derive(Debug)]
@@ -39,6 +61,9 @@ pub enum OperationError {
     ExcessiveRedaction { requested: usize, available: usize },
     Rng(RngError),
     Regex(String),
+    /// A Python callback (such as a caller-supplied RNG) raised; the caller
+    /// that installed the callback holds the original exception.
+    Callback(String),
 }
 
 impl OperationError {
@@ -54,6 +79,7 @@ impl OperationError {
             }
             Self::Rng(err) => PyValueError::new_err(err.to_string()),
             Self::Regex(message) => PyRuntimeError::new_err(message),
+            Self::Callback(message) => PyRuntimeError::new_err(message),
         }
     }
 }
@@ -76,6 +102,65 @@ pub trait OperationRng {
     fn rand_index(&mut self, upper: usize) -> Result<usize, OperationError>;
     #[allow(dead_code)]
     fn sample_indices(&mut self, population: usize, k: usize) -> Result<Vec<usize>, OperationError>;
+
+    /// Number of failures before the first success with probability `p`
+    /// (clamped to `(0, 1]`), drawn from a single `random()` call.
+    fn geometric(&mut self, p: f64) -> Result<usize, OperationError> {
+        Ok(crate::rng::geometric_from_uniform(self.random()?, p))
+    }
+
+    /// Weighted sampling without replacement using the Efraimidis-Spirakis algorithm.
+    ///
+    /// Picks `k` of the `(index, weight)` items and returns their indices in
+    /// no particular order. Items whose weight is zero, negative or NaN are
+    /// never selected; when fewer than `k` items remain eligible, all of them
+    /// are returned. Asking for more than `items.len()` is still an error.
+    ///
+    /// Each eligible item draws one `random()` value in input order and gets
+    /// key `u^(1/weight)`; the `k` largest keys win, in O(N log k) rather than
+    /// the naive O(k * N).
+    fn weighted_sample(&mut self, items: &[(usize, f64)], k: usize) -> Result<Vec<usize>, OperationError> {
+        if k == 0 || items.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if k > items.len() {
+            return Err(RngError::SampleSizeTooLarge {
+                requested: k,
+                population: items.len(),
+            }
+            .into());
+        }
+
+        // Generate keys for eligible items: key = u^(1/w) where u is uniform random (0,1)
+        // Higher weight = higher expected key = more likely to be selected
+        let mut keyed_items: Vec<(usize, f64)> = Vec::with_capacity(items.len());
+
+        for &(index, w) in items.iter().filter(|(_, weight)| *weight > 0.0) {
+            let u = self.random()?;
+            // Use log form for numerical stability: log(key) = log(u) / w
+            // Higher log(key) means higher key
+            let log_key = if u > 0.0 {
+                u.ln() / w
+            } else {
+                f64::NEG_INFINITY
+            };
+            keyed_items.push((index, log_key));
+        }
+
+        // Partial sort to get the k items with highest keys
+        // We use select_nth_unstable_by to partition around the k-th largest element
+        if k < keyed_items.len() {
+            let pivot = keyed_items.len() - k;
+            keyed_items.select_nth_unstable_by(pivot, |a, b| {
+                a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal)
+            });
+            // The elements from pivot onwards are the k largest
+            keyed_items.drain(0..pivot);
+        }
+
+        Ok(keyed_items.iter().map(|(idx, _)| *idx).collect())
+    }
 }
 
 impl OperationRng for DeterministicRng {
@@ -91,6 +176,78 @@ impl OperationRng for DeterministicRng {
     fn sample_indices(&mut self, population: usize, k: usize) -> Result<Vec<usize>, OperationError> {
         Self::sample_indices(self, population, k).map_err(OperationError::from)
     }
+
+    fn geometric(&mut self, p: f64) -> Result<usize, OperationError> {
+        Ok(Self::geometric(self, p))
+    }
+}
+
+/// One draw recorded by [`TracingRng`], naming the call and its bounds.
+#[cfg(debug_assertions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngDraw {
+    Random,
+    RandIndex { upper: usize },
+    SampleIndices { population: usize, k: usize },
+    Geometric,
+}
+
+/// Debug-build [`DeterministicRng`] wrapper that logs every draw.
+///
+/// Values are identical to the wrapped generator's, so comparing the logs of
+/// two builds shows whether a refactor changed how much randomness an
+/// operation consumes, and where. Helpers built on `random()` (such as
+/// [`OperationRng::weighted_sample`]) appear as their underlying draws.
+#[cfg(debug_assertions)]
+#[derive(Clone)]
+pub struct TracingRng {
+    inner: DeterministicRng,
+    draws: Vec<RngDraw>,
+}
+
+#[cfg(debug_assertions)]
+impl TracingRng {
+    #[must_use]
+    pub const fn new(inner: DeterministicRng) -> Self {
+        Self {
+            inner,
+            draws: Vec::new(),
+        }
+    }
+
+    /// Draws recorded so far, in order.
+    #[must_use]
+    pub fn draws(&self) -> &[RngDraw] {
+        &self.draws
+    }
+
+    #[must_use]
+    pub fn into_draws(self) -> Vec<RngDraw> {
+        self.draws
+    }
+}
+
+#[cfg(debug_assertions)]
+impl OperationRng for TracingRng {
+    fn random(&mut self) -> Result<f64, OperationError> {
+        self.draws.push(RngDraw::Random);
+        OperationRng::random(&mut self.inner)
+    }
+
+    fn rand_index(&mut self, upper: usize) -> Result<usize, OperationError> {
+        self.draws.push(RngDraw::RandIndex { upper });
+        OperationRng::rand_index(&mut self.inner, upper)
+    }
+
+    fn sample_indices(&mut self, population: usize, k: usize) -> Result<Vec<usize>, OperationError> {
+        self.draws.push(RngDraw::SampleIndices { population, k });
+        OperationRng::sample_indices(&mut self.inner, population, k)
+    }
+
+    fn geometric(&mut self, p: f64) -> Result<usize, OperationError> {
+        self.draws.push(RngDraw::Geometric);
+        OperationRng::geometric(&mut self.inner, p)
+    }
 }
 
 fn core_length_for_weight(core: &str, original: &str) -> usize {
@@ -121,6 +278,43 @@ fn direct_length_weight(core: &str, original: &str) -> f64 {
     core_length_for_weight(core, original) as f64
 }
 
+/// Selection weight for bundled stopwords under [`WordWeighting::Content`].
+const STOPWORD_WEIGHT: f64 = 0.1;
+
+/// Selection weight for every other word under [`WordWeighting::Content`].
+const CONTENT_WORD_WEIGHT: f64 = 1.0;
+
+fn content_word_weight(core: &str) -> f64 {
+    if is_stopword(core) {
+        STOPWORD_WEIGHT
+    } else {
+        CONTENT_WORD_WEIGHT
+    }
+}
+
+/// How word-level operations weight their candidates when not unweighted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WordWeighting {
+    /// Weight by word length, in the direction each operation prefers.
+    #[default]
+    Length,
+    /// Favour content words: function words from the bundled stopword list
+    /// are a tenth as likely to be picked as any other word.
+    Content,
+}
+
+impl WordWeighting {
+    /// Parse a word weighting mode from a string.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "length" => Some(Self::Length),
+            "content" => Some(Self::Content),
+            _ => None,
+        }
+    }
+}
+
 // ============================================================================
 // Rate and probability utilities
 // ============================================================================
@@ -133,6 +327,29 @@ const fn clamp_rate(rate: f64) -> f64 {
     rate.clamp(0.0, 1.0)
 }
 
+/// Picks one of the segment indices in `indices`, uniformly or, when
+/// `profiled`, in proportion to each segment's rate scale.
+///
+/// Returns `None` when nothing can be picked.
+fn pick_segment(
+    rng: &mut dyn OperationRng,
+    buffer: &TextBuffer,
+    indices: &[usize],
+    profiled: bool,
+) -> Result<Option<usize>, OperationError> {
+    if indices.is_empty() {
+        return Ok(None);
+    }
+    if !profiled {
+        return Ok(Some(indices[rng.rand_index(indices.len())?]));
+    }
+    let weights: Vec<(usize, f64)> = indices
+        .iter()
+        .map(|&index| (index, buffer.rate_scale(index)))
+        .collect();
+    Ok(rng.weighted_sample(&weights, 1)?.first().copied())
+}
+
 /// Computes the mean weight across a collection of weighted items.
 ///
 /// Returns 0.0 for empty collections to avoid division by zero.
@@ -174,6 +391,8 @@ struct ReduplicateCandidate {
     core: String,
     suffix: String,
     weight: f64,
+    /// Rate scale of the word's segment.
+    scale: f64,
 }
 
 #[derive(Debug)]
@@ -184,6 +403,8 @@ struct DeleteCandidate {
     /// Cached suffix (trailing punctuation) for efficient replacement during deletion.
     suffix: String,
     weight: f64,
+    /// Rate scale of the word's segment.
+    scale: f64,
 }
 
 #[derive(Debug)]
@@ -193,66 +414,53 @@ struct RedactCandidate {
     core_end: usize,
     repeat: usize,
     weight: f64,
+    /// Rate scale of the word's segment.
+    scale: f64,
 }
 
-/// Weighted sampling without replacement using the Efraimidis-Spirakis algorithm.
-///
-/// This is O(N log k) instead of the naive O(k * N) approach.
-/// Each item gets a key = random^(1/weight), and we select the k items with highest keys.
-fn weighted_sample_without_replacement(
-    rng: &mut dyn OperationRng,
-    items: &[(usize, f64)],
-    k: usize,
-) -> Result<Vec<usize>, OperationError> {
-    if k == 0 || items.is_empty() {
-        return Ok(Vec::new());
-    }
-
-    if k > items.len() {
-        return Err(OperationError::ExcessiveRedaction {
-            requested: k,
-            available: items.len(),
-        });
-    }
+/// What a single [`TextOperation::apply`] call did to the buffer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OpOutcome {
+    /// Number of edits applied. The unit is the op's own: a replaced word, a
+    /// deleted sentence, an inserted character, a swapped pair, ...
+    pub edits: usize,
+}
 
-    // Generate keys for all items: key = u^(1/w) where u is uniform random (0,1)
-    // Higher weight = higher expected key = more likely to be selected
-    let mut keyed_items: Vec<(usize, f64)> = Vec::with_capacity(items.len());
+impl OpOutcome {
+    /// The outcome of an op that left the buffer untouched.
+    pub const UNCHANGED: Self = Self { edits: 0 };
 
-    for &(index, weight) in items {
-        let w = weight.max(f64::EPSILON); // Avoid division by zero
-        let u = rng.random()?;
-        // Use log form for numerical stability: log(key) = log(u) / w
-        // Higher log(key) means higher key
-        let log_key = if u > 0.0 {
-            u.ln() / w
-        } else {
-            f64::NEG_INFINITY
-        };
-        keyed_items.push((index, log_key));
+    #[must_use]
+    pub const fn edits(edits: usize) -> Self {
+        Self { edits }
     }
 
-    // Partial sort to get the k items with highest keys
-    // We use select_nth_unstable_by to partition around the k-th largest element
-    if k < keyed_items.len() {
-        let pivot = keyed_items.len() - k;
-        keyed_items.select_nth_unstable_by(pivot, |a, b| {
-            a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal)
-        });
-        // The elements from pivot onwards are the k largest
-        keyed_items.drain(0..pivot);
+    /// Returns `true` when the op applied no edits.
+    #[must_use]
+    pub const fn is_unchanged(&self) -> bool {
+        self.edits == 0
     }
-
-    // Extract the indices
-    let selections: Vec<usize> = keyed_items.iter().map(|(idx, _)| *idx).collect();
-
-    Ok(selections)
 }
 
 /// Trait implemented by each text corruption operation so they can be sequenced
 /// by the pipeline.
 pub trait TextOperation {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError>;
+    /// Applies the op to `buffer` and reports how many edits it made.
+    fn apply(
+        &self,
+        buffer: &mut TextBuffer,
+        rng: &mut dyn OperationRng,
+    ) -> Result<OpOutcome, OperationError>;
+}
+
+/// Runs `op` once over `text` with a [`DeterministicRng`] seeded from `seed`,
+/// panicking if the op fails.
+#[cfg(test)]
+pub(crate) fn apply_seeded(op: &dyn TextOperation, text: &str, seed: u64) -> String {
+    let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+    let mut rng = DeterministicRng::new(seed);
+    op.apply(&mut buffer, &mut rng).expect("operation succeeds");
+    buffer.to_string()
 }
 
 // ============================================================================
@@ -262,17 +470,105 @@ pub trait TextOperation {
 // Operations that modify text at the word level: duplicating, deleting,
 // swapping, and combining these effects.
 
+/// How [`ReduplicateWordsOp`] joins a word to its copies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ReduplicationSeparator {
+    /// "yes yes"
+    #[default]
+    Space,
+    /// Emphatic repetition: "no, no"
+    Comma,
+    /// A single hyphenated word: "bye-bye"
+    Hyphen,
+}
+
+impl ReduplicationSeparator {
+    /// Parse a separator from its name or literal form.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "space" | " " => Some(Self::Space),
+            "comma" | "comma_space" | "comma-space" | ", " => Some(Self::Comma),
+            "hyphen" | "-" => Some(Self::Hyphen),
+            _ => None,
+        }
+    }
+}
+
 /// Repeats words to simulate stuttered speech.
-#[derive(Debug, Clone, Copy)]
+///
+/// Selected words are emitted `repeats + 1` times, joined according to
+/// `separator`. In `stutter` mode the word is kept once and its leading
+/// consonant cluster is prepended `repeats + 1` times instead, e.g.
+/// "ball" → "b-b-ball".
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct ReduplicateWordsOp {
     pub rate: f64,
     pub unweighted: bool,
+    pub repeats: usize,
+    pub stutter: bool,
+    #[serde(default)]
+    pub separator: ReduplicationSeparator,
+}
+
+impl ReduplicateWordsOp {
+    /// Builds the stuttered form of a word, or `None` when the core does not
+    /// start with a letter.
+    fn stutter_word(&self, prefix: &str, core: &str, suffix: &str) -> Option<String> {
+        let first = core.chars().next()?;
+        if !first.is_alphabetic() {
+            return None;
+        }
+
+        let cluster_len: usize = core
+            .chars()
+            .take_while(|ch| ch.is_alphabetic() && !is_stutter_vowel(*ch))
+            .map(char::len_utf8)
+            .sum();
+        let cluster = if cluster_len == 0 {
+            &core[..first.len_utf8()]
+        } else {
+            &core[..cluster_len]
+        };
+
+        // Only the opening fragment keeps title casing; shouted words stay shouted.
+        let shouted = core.chars().any(char::is_alphabetic)
+            && !core.chars().any(char::is_lowercase);
+        let echo = if shouted {
+            cluster.to_string()
+        } else {
+            cluster.to_lowercase()
+        };
+
+        let fragments = self.repeats + 1;
+        let mut output = String::with_capacity(
+            prefix.len() + core.len() + suffix.len() + fragments * (echo.len() + 1),
+        );
+        output.push_str(prefix);
+        output.push_str(cluster);
+        output.push('-');
+        for _ in 1..fragments {
+            output.push_str(&echo);
+            output.push('-');
+        }
+        output.push_str(core);
+        output.push_str(suffix);
+        Some(output)
+    }
+}
+
+#[inline]
+const fn is_stutter_vowel(ch: char) -> bool {
+    matches!(
+        ch,
+        'a' | 'e' | 'i' | 'o' | 'u' | 'A' | 'E' | 'I' | 'O' | 'U'
+    )
 }
 
 impl TextOperation for ReduplicateWordsOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
-        if buffer.word_count() == 0 {
-            return Ok(());
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.repeats == 0 {
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let total_words = buffer.word_count();
@@ -304,66 +600,124 @@ impl TextOperation for ReduplicateWordsOp {
                     core: core_ref.to_string(),
                     suffix: suffix_ref.to_string(),
                     weight,
+                    scale: segment.rate_scale(),
                 });
             }
         }
 
         if candidates.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let effective_rate = clamp_rate(self.rate);
         if effective_rate <= 0.0 {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let mean_weight = compute_mean_weight(&candidates, |c| c.weight);
 
         // Pre-allocate reduplications vector based on expected selections
         let expected_redups = ((candidates.len() as f64) * effective_rate).ceil() as usize;
-        let mut reduplications: Vec<(usize, String, String, Option<String>)> = Vec::with_capacity(expected_redups);
+        let mut reduplications: Vec<Reduplication> = Vec::with_capacity(expected_redups);
+        let mut stutters: Vec<(usize, String)> = Vec::new();
 
-        // Reuse separator allocation across iterations
+        // Reuse separator allocation across iterations; a comma stays on the
+        // preceding copy so separator segments remain pure whitespace
         let separator = Some(" ".to_string());
+        let joiner = match self.separator {
+            ReduplicationSeparator::Comma => ",",
+            ReduplicationSeparator::Space | ReduplicationSeparator::Hyphen => "",
+        };
 
         for candidate in candidates {
-            let probability = compute_weighted_probability(effective_rate, candidate.weight, mean_weight);
+            let probability = compute_weighted_probability(
+                effective_rate * candidate.scale,
+                candidate.weight,
+                mean_weight,
+            );
 
             if rng.random()? >= probability {
                 continue;
             }
 
+            if self.stutter {
+                if let Some(stuttered) =
+                    self.stutter_word(&candidate.prefix, &candidate.core, &candidate.suffix)
+                {
+                    stutters.push((candidate.index, stuttered));
+                    continue;
+                }
+            }
+
+            // Hyphenated copies form a single word replaced in place
+            if self.separator == ReduplicationSeparator::Hyphen {
+                let mut joined = candidate.prefix.clone();
+                for _ in 0..=self.repeats {
+                    joined.push_str(&candidate.core);
+                    joined.push('-');
+                }
+                joined.pop();
+                joined.push_str(&candidate.suffix);
+                stutters.push((candidate.index, joined));
+                continue;
+            }
+
             // Build first word: prefix + core
-            let mut first = String::with_capacity(candidate.prefix.len() + candidate.core.len());
+            let mut first = String::with_capacity(
+                candidate.prefix.len() + candidate.core.len() + joiner.len(),
+            );
             first.push_str(&candidate.prefix);
             first.push_str(&candidate.core);
+            first.push_str(joiner);
 
-            // Build second word: core + suffix
-            let mut second = String::with_capacity(candidate.core.len() + candidate.suffix.len());
-            second.push_str(&candidate.core);
-            second.push_str(&candidate.suffix);
+            // Middle copies are bare cores; the last copy carries the suffix
+            let mut followers: SmallVec<[String; 1]> = SmallVec::with_capacity(self.repeats);
+            for _ in 1..self.repeats {
+                followers.push(format!("{}{joiner}", candidate.core));
+            }
+            let mut last = String::with_capacity(candidate.core.len() + candidate.suffix.len());
+            last.push_str(&candidate.core);
+            last.push_str(&candidate.suffix);
+            followers.push(last);
+
+            reduplications.push((candidate.index, first, followers, separator.clone()));
+        }
+
+        let edits = stutters.len() + reduplications.len();
 
-            reduplications.push((candidate.index, first, second, separator.clone()));
+        // Stutters are in-place replacements, so apply them before indices shift
+        if !stutters.is_empty() {
+            buffer.replace_words_bulk(stutters)?;
         }
 
         // Apply all reduplications in a single bulk operation
         buffer.reduplicate_words_bulk(reduplications)?;
         buffer.reindex_if_needed();
-        Ok(())
+        Ok(OpOutcome::edits(edits))
     }
 }
 
 /// Deletes random words while preserving punctuation cleanup semantics.
-#[derive(Debug, Clone, Copy)]
+///
+/// Deletion stops once the surviving word count would drop below `min_words`.
+/// With `preserve_spacing`, each deleted word is spliced out with one adjacent
+/// separator and the rest of the whitespace is kept as-is instead of being
+/// collapsed to single spaces, which keeps tabular text aligned.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct DeleteRandomWordsOp {
     pub rate: f64,
     pub unweighted: bool,
+    pub min_words: usize,
+    #[serde(default)]
+    pub weighting: WordWeighting,
+    #[serde(default)]
+    pub preserve_spacing: bool,
 }
 
 impl TextOperation for DeleteRandomWordsOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
         if buffer.word_count() <= 1 {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let total_words = buffer.word_count();
@@ -381,50 +735,50 @@ impl TextOperation for DeleteRandomWordsOp {
                 }
                 // Use zero-allocation split_affixes_ref, only allocate prefix/suffix for candidates
                 let (prefix, core, suffix) = split_affixes_ref(text);
-                let weight = if self.unweighted {
-                    1.0
-                } else {
-                    inverse_length_weight(core, text)
+                let weight = match (self.unweighted, self.weighting) {
+                    (true, _) => 1.0,
+                    (false, WordWeighting::Length) => inverse_length_weight(core, text),
+                    (false, WordWeighting::Content) => content_word_weight(core),
                 };
                 candidates.push(DeleteCandidate {
                     index: idx,
                     prefix: prefix.trim().to_string(),
                     suffix: suffix.trim().to_string(),
                     weight,
+                    scale: segment.rate_scale(),
                 });
             }
         }
 
         if candidates.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let effective_rate = clamp_rate(self.rate);
         if effective_rate <= 0.0 {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
-        let allowed = ((candidates.len() as f64) * effective_rate).floor() as usize;
+        // Rate scales stretch or shrink each word's share of the quota.
+        let scaled_candidates: f64 = candidates.iter().map(|candidate| candidate.scale).sum();
+        let allowed = (scaled_candidates * effective_rate).floor() as usize;
+        let allowed = allowed.min(total_words.saturating_sub(self.min_words));
         if allowed == 0 {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
-        let mean_weight = compute_mean_weight(&candidates, |c| c.weight);
+        // Pick exactly `allowed` words, weighted but without positional bias.
+        let weighted_indices: Vec<(usize, f64)> = candidates
+            .iter()
+            .enumerate()
+            .map(|(idx, candidate)| (idx, candidate.weight * candidate.scale))
+            .collect();
+        let mut selections = rng.weighted_sample(&weighted_indices, allowed)?;
+        selections.sort_unstable();
 
-        // Pre-allocate deletion list with expected capacity
         let mut deletion_ops: Vec<(usize, Option<String>)> = Vec::with_capacity(allowed);
-        let mut deletions = 0usize;
-
-        for candidate in candidates {
-            if deletions >= allowed {
-                break;
-            }
-
-            let probability = compute_weighted_probability(effective_rate, candidate.weight, mean_weight);
-
-            if rng.random()? >= probability {
-                continue;
-            }
+        for selection in selections {
+            let candidate = &candidates[selection];
 
             // Build replacement: trimmed prefix + trimmed suffix (or None if empty/punctuation-only)
             let combined = if candidate.prefix.is_empty() && candidate.suffix.is_empty() {
@@ -442,54 +796,97 @@ impl TextOperation for DeleteRandomWordsOp {
                 }
             };
             deletion_ops.push((candidate.index, combined));
-            deletions += 1;
         }
 
         if deletion_ops.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
-        // Use bulk deletion API instead of rebuilding entire buffer
-        buffer.delete_words_bulk(deletion_ops)?;
+        let edits = deletion_ops.len();
+
+        if self.preserve_spacing {
+            buffer.splice_words_bulk(deletion_ops)?;
+        } else {
+            // Use bulk deletion API instead of rebuilding entire buffer
+            buffer.delete_words_bulk(deletion_ops)?;
 
-        // Normalize handles spacing around punctuation (.,:;) efficiently
-        buffer.normalize();
+            // Normalize handles spacing around punctuation (.,:;) efficiently
+            buffer.normalize();
+        }
 
         buffer.reindex_if_needed();
-        Ok(())
+        Ok(OpOutcome::edits(edits))
     }
 }
 
 /// Swaps adjacent word cores while keeping punctuation and spacing intact.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct SwapAdjacentWordsOp {
     pub rate: f64,
 }
 
 impl TextOperation for SwapAdjacentWordsOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        SwapWordsOp {
+            rate: self.rate,
+            max_distance: 1,
+        }
+        .apply(buffer, rng)
+    }
+}
+
+/// Swaps word cores that sit up to `max_distance` positions apart.
+///
+/// Each unvisited word is paired with a partner drawn from the following
+/// `max_distance` unvisited words; both are then retired for the rest of the
+/// pass. With `max_distance == 1` this is exactly [`SwapAdjacentWordsOp`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SwapWordsOp {
+    pub rate: f64,
+    pub max_distance: usize,
+}
+
+impl TextOperation for SwapWordsOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
         let total_words = buffer.word_count();
         if total_words < 2 {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let clamped = clamp_rate(self.rate);
         if clamped <= 0.0 {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
-        let mut index = 0usize;
+        let max_distance = self.max_distance.max(1);
+        let mut visited = vec![false; total_words];
+        let mut partners: SmallVec<[usize; 8]> = SmallVec::new();
         let mut replacements: SmallVec<[(usize, String); 8]> = SmallVec::new();
-        while index + 1 < total_words {
+
+        for index in 0..total_words - 1 {
+            if visited[index] {
+                continue;
+            }
+            visited[index] = true;
+
+            partners.clear();
+            let window_end = index.saturating_add(max_distance).min(total_words - 1);
+            partners.extend((index + 1..=window_end).filter(|&candidate| !visited[candidate]));
+            let partner = match partners.len() {
+                0 => continue,
+                1 => partners[0],
+                len => partners[rng.rand_index(len)?],
+            };
+            visited[partner] = true;
+
             let Some(left_segment) = buffer.word_segment(index) else {
                 break;
             };
-            let Some(right_segment) = buffer.word_segment(index + 1) else {
+            let Some(right_segment) = buffer.word_segment(partner) else {
                 break;
             };
 
             if !left_segment.is_mutable() || !right_segment.is_mutable() {
-                index += 2;
                 continue;
             }
 
@@ -501,11 +898,11 @@ impl TextOperation for SwapAdjacentWordsOp {
             let (right_prefix, right_core, right_suffix) = split_affixes_ref(right_text);
 
             if left_core.is_empty() || right_core.is_empty() {
-                index += 2;
                 continue;
             }
 
-            let should_swap = clamped >= 1.0 || rng.random()? < clamped;
+            let probability = clamped * left_segment.rate_scale();
+            let should_swap = probability >= 1.0 || rng.random()? < probability;
             if should_swap {
                 // Build replacements with pre-allocated capacity instead of format!
                 let mut left_replacement = String::with_capacity(
@@ -523,34 +920,47 @@ impl TextOperation for SwapAdjacentWordsOp {
                 right_replacement.push_str(right_suffix);
 
                 replacements.push((index, left_replacement));
-                replacements.push((index + 1, right_replacement));
+                replacements.push((partner, right_replacement));
             }
-
-            index += 2;
         }
 
+        // Each swap rewrites two words.
+        let edits = replacements.len() / 2;
         if !replacements.is_empty() {
-            buffer.replace_words_bulk(replacements.into_iter())?;
+            buffer.replace_words_bulk(replacements)?;
         }
 
         buffer.reindex_if_needed();
-        Ok(())
+        Ok(OpOutcome::edits(edits))
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RushmoreComboMode {
     Delete,
     Duplicate,
     Swap,
 }
 
-#[derive(Debug, Clone)]
+impl RushmoreComboMode {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Delete => "delete",
+            Self::Duplicate => "duplicate",
+            Self::Swap => "swap",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RushmoreComboOp {
     pub modes: Vec<RushmoreComboMode>,
     pub delete: Option<DeleteRandomWordsOp>,
     pub duplicate: Option<ReduplicateWordsOp>,
     pub swap: Option<SwapAdjacentWordsOp>,
+    /// Run the sub-ops in a seeded random order instead of the `modes` order.
+    #[serde(default)]
+    pub shuffle_modes: bool,
 }
 
 impl RushmoreComboOp {
@@ -566,34 +976,66 @@ impl RushmoreComboOp {
             delete,
             duplicate,
             swap,
+            shuffle_modes: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_shuffle_modes(mut self, shuffle_modes: bool) -> Self {
+        self.shuffle_modes = shuffle_modes;
+        self
+    }
+
+    /// The order the sub-ops run in, each paired with its index in `modes`.
+    /// Shuffling happens in one Fisher-Yates pass before any sub-op runs.
+    fn execution_order(
+        &self,
+        rng: &mut dyn OperationRng,
+    ) -> Result<Vec<(usize, RushmoreComboMode)>, OperationError> {
+        let mut order: Vec<(usize, RushmoreComboMode)> =
+            self.modes.iter().copied().enumerate().collect();
+        if self.shuffle_modes {
+            for idx in (1..order.len()).rev() {
+                let swap_with = rng.rand_index(idx + 1)?;
+                order.swap(idx, swap_with);
+            }
         }
+        Ok(order)
     }
 }
 
 impl TextOperation for RushmoreComboOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
-        for mode in &self.modes {
-            match mode {
-                RushmoreComboMode::Delete => {
-                    if let Some(op) = self.delete {
-                        op.apply(buffer, rng)?;
-                    }
-                }
-                RushmoreComboMode::Duplicate => {
-                    if let Some(op) = self.duplicate {
-                        op.apply(buffer, rng)?;
-                    }
-                }
-                RushmoreComboMode::Swap => {
-                    if let Some(op) = self.swap {
-                        op.apply(buffer, rng)?;
-                    }
-                }
-            }
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        // Each sub-op gets its own stream, derived from a single combo-level
+        // draw and the mode's declared index, so retuning one sub-op leaves
+        // the randomness of the others untouched.
+        let combo_seed = rng.rand_index(usize::MAX)? as u64;
+        let mut edits = 0usize;
+        for (index, mode) in self.execution_order(rng)? {
+            let mut sub_rng = DeterministicRng::new(derive_seed(
+                i128::from(combo_seed),
+                mode.name(),
+                index as i128,
+            ));
+            let outcome = match mode {
+                RushmoreComboMode::Delete => match self.delete {
+                    Some(op) => op.apply(buffer, &mut sub_rng)?,
+                    None => OpOutcome::UNCHANGED,
+                },
+                RushmoreComboMode::Duplicate => match self.duplicate {
+                    Some(op) => op.apply(buffer, &mut sub_rng)?,
+                    None => OpOutcome::UNCHANGED,
+                },
+                RushmoreComboMode::Swap => match self.swap {
+                    Some(op) => op.apply(buffer, &mut sub_rng)?,
+                    None => OpOutcome::UNCHANGED,
+                },
+            };
+            edits += outcome.edits;
         }
 
         buffer.reindex_if_needed();
-        Ok(())
+        Ok(OpOutcome::edits(edits))
     }
 }
 
@@ -602,16 +1044,26 @@ impl TextOperation for RushmoreComboOp {
 // ============================================================================
 
 /// Redacts words by replacing core characters with a replacement token.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RedactWordsOp {
     pub replacement_char: String,
     pub rate: f64,
     pub merge_adjacent: bool,
+    /// When merging, also absorb punctuation between adjacent redactions, so
+    /// "███, ███" becomes one block instead of two.
+    #[serde(default)]
+    pub merge_through_punctuation: bool,
+    /// Replace every redacted core with exactly this many replacement
+    /// characters, so block lengths no longer reveal word lengths.
+    #[serde(default)]
+    pub fixed_width: Option<usize>,
     pub unweighted: bool,
+    #[serde(default)]
+    pub weighting: WordWeighting,
 }
 
 impl TextOperation for RedactWordsOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
         if buffer.word_count() == 0 {
             return Err(OperationError::NoRedactableWords);
         }
@@ -635,10 +1087,10 @@ impl TextOperation for RedactWordsOp {
                 if repeat == 0 {
                     continue;
                 }
-                let weight = if self.unweighted {
-                    1.0
-                } else {
-                    direct_length_weight(core, text)
+                let weight = match (self.unweighted, self.weighting) {
+                    (true, _) => 1.0,
+                    (false, WordWeighting::Length) => direct_length_weight(core, text),
+                    (false, WordWeighting::Content) => content_word_weight(core),
                 };
                 candidates.push(RedactCandidate {
                     index: idx,
@@ -646,6 +1098,7 @@ impl TextOperation for RedactWordsOp {
                     core_end,
                     repeat,
                     weight,
+                    scale: segment.rate_scale(),
                 });
             }
         }
@@ -655,7 +1108,8 @@ impl TextOperation for RedactWordsOp {
         }
 
         let effective_rate = self.rate.max(0.0);
-        let mut num_to_redact = ((candidates.len() as f64) * effective_rate).floor() as usize;
+        let scaled_candidates: f64 = candidates.iter().map(|candidate| candidate.scale).sum();
+        let mut num_to_redact = (scaled_candidates * effective_rate).floor() as usize;
         if num_to_redact < 1 {
             num_to_redact = 1;
         }
@@ -669,11 +1123,10 @@ impl TextOperation for RedactWordsOp {
         let weighted_indices: Vec<(usize, f64)> = candidates
             .iter()
             .enumerate()
-            .map(|(idx, candidate)| (idx, candidate.weight))
+            .map(|(idx, candidate)| (idx, candidate.weight * candidate.scale))
             .collect();
 
-        let mut selections =
-            weighted_sample_without_replacement(rng, &weighted_indices, num_to_redact)?;
+        let mut selections = rng.weighted_sample(&weighted_indices, num_to_redact)?;
         selections.sort_unstable_by_key(|candidate_idx| candidates[*candidate_idx].index);
 
         // Collect (word_index, new_text) pairs for bulk replacement
@@ -689,10 +1142,11 @@ impl TextOperation for RedactWordsOp {
             };
             let text = segment.text();
 
-            // Re-validate bounds in case of any edge cases
-            let (core_start, core_end, repeat) = if candidate.core_end <= text.len()
-                && candidate.core_start <= candidate.core_end
-                && candidate.core_start <= text.len()
+            // Re-validate bounds in case of any edge cases; `get` also rejects offsets
+            // that fall inside a multi-byte character, which would panic when sliced.
+            let (core_start, core_end, repeat) = if text
+                .get(candidate.core_start..candidate.core_end)
+                .is_some()
             {
                 (candidate.core_start, candidate.core_end, candidate.repeat)
             } else if let Some((start, end)) = affix_bounds(text) {
@@ -705,30 +1159,33 @@ impl TextOperation for RedactWordsOp {
                 continue; // Skip this word - can't redact
             };
 
+            // A zero width would delete the word outright rather than redact it.
+            let width = self.fixed_width.map_or(repeat, |width| width.max(1));
             let prefix = &text[..core_start];
             let suffix = &text[core_end..];
             let redacted = format!(
                 "{}{}{}",
                 prefix,
-                self.replacement_char.repeat(repeat),
+                self.replacement_char.repeat(width),
                 suffix
             );
             replacements.push((word_idx, redacted));
         }
 
+        let edits = replacements.len();
+
         // Apply all redactions in a single bulk operation
-        buffer.replace_words_bulk(replacements.into_iter())?;
+        buffer.replace_words_bulk(replacements)?;
 
         // If merging is enabled, consolidate adjacent redacted words
         if self.merge_adjacent {
             buffer.reindex_if_needed();
-            buffer.merge_repeated_char_words(&self.replacement_char);
+            buffer
+                .merge_repeated_char_words(&self.replacement_char, self.merge_through_punctuation);
         }
 
         buffer.reindex_if_needed();
-        // Timing instrumentation disabled
-
-        Ok(())
+        Ok(OpOutcome::edits(edits))
     }
 }
 
@@ -763,13 +1220,23 @@ impl TextOperation for RedactWordsOp {
 /// - `space_drop_rate`: P(delete space, merging words)
 /// - `space_insert_rate`: P(insert spurious space)
 ///
+/// # Selection Order
+///
+/// Candidates are the leftmost non-overlapping confusion matches inside each
+/// mutable segment, ordered by their character position in the whole text.
+/// One Fisher-Yates pass over that order (always run to completion) fixes the
+/// visiting order; a candidate overlapping an already chosen one, measured in
+/// whole-text characters, is skipped without consuming randomness. The result
+/// depends only on the text, the seed and the parameters, not on how the
+/// buffer happens to be split into segments.
+///
 /// # References
 ///
 /// - Kanungo et al. (1994) - "Nonlinear Global and Local Document Degradation Models"
 /// - Rice et al. / UNLV-ISRI Annual Tests (1995) - Quality preset empirical basis
 /// - Smith (2007) - Tesseract architecture, segmentation as distinct failure mode
 /// - ICDAR Robust Reading Competitions - Segmentation/localization failure modes
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OcrArtifactsOp {
     /// Base probability of applying a confusion to any given candidate
     pub rate: f64,
@@ -794,42 +1261,180 @@ pub struct OcrArtifactsOp {
     /// Probability of inserting a spurious space: "together" → "to gether"
     pub space_insert_rate: f64,
 
+    /// Restricts confusions by direction; see [`OcrMode`]
+    pub mode: OcrMode,
+    /// How many candidates are edited; see [`OcrSampling`]
+    #[serde(default)]
+    pub sampling: OcrSampling,
+
+    // === Custom Confusions ===
+    /// Caller-supplied `(source, replacements)` pairs merged over the bundled table
+    pub extra_confusions: Vec<(String, Vec<String>)>,
+    /// Merged table and automaton, built when extras are set
+    #[serde(skip)]
+    confusions: Option<Arc<ConfusionTable>>,
+
     // === Precomputed Bias Selection ===
     /// Pre-selected pattern indices for document bias (populated at apply time)
+    #[serde(skip)]
     bias_patterns: Vec<usize>,
 }
 
-impl OcrArtifactsOp {
-    /// Creates a new OCR artifacts operation with default parameters.
-    #[must_use] 
-    pub const fn new(rate: f64) -> Self {
-        Self {
-            rate,
-            burst_enter: 0.0,
-            burst_exit: 0.3,
-            burst_multiplier: 3.0,
-            bias_k: 0,
-            bias_beta: 2.0,
-            space_drop_rate: 0.0,
-            space_insert_rate: 0.0,
-            bias_patterns: Vec::new(),
+/// Which confusions an [`OcrArtifactsOp`] may apply, by length change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum OcrMode {
+    /// Every confusion (the original behavior).
+    #[default]
+    Both,
+    /// Only confusions whose replacement is longer than the match ("m" → "rn").
+    Expand,
+    /// Only confusions whose replacement is shorter than the match ("rn" → "m").
+    Contract,
+}
+
+impl OcrMode {
+    /// Parse an OCR mode from a string.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "both" => Some(Self::Both),
+            "expand" => Some(Self::Expand),
+            "contract" => Some(Self::Contract),
+            _ => None,
         }
     }
 
-    /// Creates an OCR operation with all parameters specified.
-    #[allow(clippy::too_many_arguments)]
-    #[must_use] 
-    pub const fn with_params(
-        rate: f64,
-        burst_enter: f64,
-        burst_exit: f64,
-        burst_multiplier: f64,
-        bias_k: usize,
-        bias_beta: f64,
-        space_drop_rate: f64,
-        space_insert_rate: f64,
-    ) -> Self {
-        Self {
+    fn allows(self, source: &str, replacement: &str) -> bool {
+        let (from, to) = (source.chars().count(), replacement.chars().count());
+        match self {
+            Self::Both => true,
+            Self::Expand => to > from,
+            Self::Contract => to < from,
+        }
+    }
+}
+
+/// How an [`OcrArtifactsOp`] decides how many candidates to edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum OcrSampling {
+    /// Visit candidates in shuffled order until `floor(candidates * rate)`
+    /// are edited (the original behavior). Short texts may get no edits.
+    #[default]
+    Count,
+    /// Roll `random() < rate` for every candidate in text order, so the
+    /// number of edits is binomial rather than fixed.
+    Bernoulli,
+}
+
+impl OcrSampling {
+    /// Parse an OCR sampling strategy from a string.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "count" => Some(Self::Count),
+            "bernoulli" => Some(Self::Bernoulli),
+            _ => None,
+        }
+    }
+}
+
+/// A confusion match considered by [`OcrArtifactsOp`]: byte offsets inside
+/// its segment for editing, and whole-text char offsets for ordering.
+struct OcrCandidate {
+    segment: usize,
+    start: usize,
+    end: usize,
+    pattern: usize,
+    char_start: usize,
+    char_end: usize,
+}
+
+/// The confusion table an [`OcrArtifactsOp`] draws from.
+enum Confusions<'a> {
+    Bundled,
+    Custom(&'a ConfusionTable),
+}
+
+impl Confusions<'_> {
+    fn automaton(&self) -> &AhoCorasick {
+        match self {
+            Self::Bundled => ocr_automaton(),
+            Self::Custom(table) => table.automaton(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Bundled => confusion_table().len(),
+            Self::Custom(table) => table.entries().len(),
+        }
+    }
+
+    fn source(&self, pattern_idx: usize) -> &str {
+        match self {
+            Self::Bundled => confusion_table()[pattern_idx].0,
+            Self::Custom(table) => &table.entries()[pattern_idx].0,
+        }
+    }
+
+    fn choice_count(&self, pattern_idx: usize) -> usize {
+        match self {
+            Self::Bundled => confusion_table()[pattern_idx].1.len(),
+            Self::Custom(table) => table.entries()[pattern_idx].1.len(),
+        }
+    }
+
+    /// Indices of the choices for `pattern_idx` that `mode` permits.
+    fn allowed_choices(&self, pattern_idx: usize, mode: OcrMode) -> Vec<usize> {
+        let source = self.source(pattern_idx);
+        (0..self.choice_count(pattern_idx))
+            .filter(|&choice_idx| mode.allows(source, self.choice(pattern_idx, choice_idx)))
+            .collect()
+    }
+
+    fn choice(&self, pattern_idx: usize, choice_idx: usize) -> &str {
+        match self {
+            Self::Bundled => confusion_table()[pattern_idx].1[choice_idx],
+            Self::Custom(table) => &table.entries()[pattern_idx].1[choice_idx],
+        }
+    }
+}
+
+impl OcrArtifactsOp {
+    /// Creates a new OCR artifacts operation with default parameters.
+    #[must_use] 
+    pub const fn new(rate: f64) -> Self {
+        Self {
+            rate,
+            burst_enter: 0.0,
+            burst_exit: 0.3,
+            burst_multiplier: 3.0,
+            bias_k: 0,
+            bias_beta: 2.0,
+            space_drop_rate: 0.0,
+            space_insert_rate: 0.0,
+            mode: OcrMode::Both,
+            sampling: OcrSampling::Count,
+            extra_confusions: Vec::new(),
+            confusions: None,
+            bias_patterns: Vec::new(),
+        }
+    }
+
+    /// Creates an OCR operation with all parameters specified.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use] 
+    pub const fn with_params(
+        rate: f64,
+        burst_enter: f64,
+        burst_exit: f64,
+        burst_multiplier: f64,
+        bias_k: usize,
+        bias_beta: f64,
+        space_drop_rate: f64,
+        space_insert_rate: f64,
+    ) -> Self {
+        Self {
             rate,
             burst_enter,
             burst_exit,
@@ -838,10 +1443,41 @@ impl OcrArtifactsOp {
             bias_beta,
             space_drop_rate,
             space_insert_rate,
+            mode: OcrMode::Both,
+            sampling: OcrSampling::Count,
+            extra_confusions: Vec::new(),
+            confusions: None,
             bias_patterns: Vec::new(),
         }
     }
 
+    /// Restricts the op to length-increasing or length-decreasing confusions.
+    #[must_use]
+    pub const fn with_mode(mut self, mode: OcrMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Switches between a fixed edit count and independent per-candidate rolls.
+    #[must_use]
+    pub const fn with_sampling(mut self, sampling: OcrSampling) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Extends the bundled confusion table with `(source, replacements)`
+    /// pairs, e.g. a scanner's idiosyncratic `("5", ["S"])`.
+    #[must_use]
+    pub fn with_extra_confusions(mut self, extras: Vec<(String, Vec<String>)>) -> Self {
+        self.confusions = if extras.is_empty() {
+            None
+        } else {
+            Some(ConfusionTable::shared(&extras))
+        };
+        self.extra_confusions = extras;
+        self
+    }
+
     /// Selects K random patterns for document-level bias.
     fn select_bias_patterns(&mut self, rng: &mut dyn OperationRng, table_size: usize) -> Result<(), OperationError> {
         self.bias_patterns.clear();
@@ -872,17 +1508,18 @@ impl OcrArtifactsOp {
         &self,
         buffer: &mut TextBuffer,
         rng: &mut dyn OperationRng,
-    ) -> Result<(), OperationError> {
+    ) -> Result<usize, OperationError> {
         if self.space_drop_rate <= 0.0 && self.space_insert_rate <= 0.0 {
-            return Ok(());
+            return Ok(0);
         }
 
         let segments = buffer.segments();
         if segments.is_empty() {
-            return Ok(());
+            return Ok(0);
         }
 
         let mut segment_replacements: Vec<(usize, String)> = Vec::new();
+        let mut edits = 0usize;
 
         for (seg_idx, segment) in segments.iter().enumerate() {
             if !segment.is_mutable() {
@@ -897,13 +1534,16 @@ impl OcrArtifactsOp {
             let chars: Vec<char> = text.chars().collect();
             let mut modified = String::with_capacity(text.len());
             let mut changed = false;
+            let drop_rate = self.space_drop_rate * segment.rate_scale();
+            let insert_rate = self.space_insert_rate * segment.rate_scale();
 
             for (char_idx, &ch) in chars.iter().enumerate() {
-                if ch == ' ' && self.space_drop_rate > 0.0 {
+                if ch == ' ' && drop_rate > 0.0 {
                     // Potential space drop
-                    if rng.random()? < self.space_drop_rate {
+                    if rng.random()? < drop_rate {
                         // Drop this space (don't add to modified)
                         changed = true;
+                        edits += 1;
                         continue;
                     }
                 }
@@ -911,13 +1551,14 @@ impl OcrArtifactsOp {
                 modified.push(ch);
 
                 // Potential space insert (not after last char, not after/before existing space)
-                if self.space_insert_rate > 0.0
+                if insert_rate > 0.0
                     && char_idx + 1 < chars.len()
                     && !ch.is_whitespace()
                     && !chars[char_idx + 1].is_whitespace()
-                    && rng.random()? < self.space_insert_rate {
+                    && rng.random()? < insert_rate {
                         modified.push(' ');
                         changed = true;
+                        edits += 1;
                     }
             }
 
@@ -931,59 +1572,82 @@ impl OcrArtifactsOp {
             buffer.reindex_if_needed();
         }
 
-        Ok(())
+        Ok(edits)
     }
 }
 
 impl TextOperation for OcrArtifactsOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
         // Phase 1: Apply whitespace errors (segmentation failures) as pre-pass
         // This models the OCR pipeline where segmentation happens before character recognition.
         // Reference: Smith (2007) - Tesseract architecture
         let mut op = self.clone();
-        op.apply_whitespace_errors(buffer, rng)?;
+        let spacing_edits = op.apply_whitespace_errors(buffer, rng)?;
 
         let segments = buffer.segments();
         if segments.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::edits(spacing_edits));
         }
 
-        // Pre-fetch the confusion table and automaton for efficient lookup
-        let table = confusion_table();
-        let automaton = ocr_automaton();
+        // Pre-fetch the confusion table and automaton for efficient lookup.
+        // Deserialised ops carry extras without the merged table; fetch it.
+        let rebuilt;
+        let confusions = match (&self.confusions, self.extra_confusions.is_empty()) {
+            (_, true) => Confusions::Bundled,
+            (Some(table), false) => Confusions::Custom(table),
+            (None, false) => {
+                rebuilt = ConfusionTable::shared(&self.extra_confusions);
+                Confusions::Custom(&rebuilt)
+            }
+        };
+        let automaton = confusions.automaton();
+        let allowed: Vec<Vec<usize>> = (0..confusions.len())
+            .map(|pattern_idx| confusions.allowed_choices(pattern_idx, op.mode))
+            .collect();
 
         // Phase 2: Select document-level bias patterns
         // Reference: UNLV-ISRI Annual Tests (1995) - consistent error profiles
-        op.select_bias_patterns(rng, table.len())?;
+        op.select_bias_patterns(rng, confusions.len())?;
 
         // Estimate candidate capacity based on text length
         let total_chars: usize = segments.iter().map(|s| s.text().len()).sum();
         let estimated_candidates = total_chars / 3;
 
         // Find candidates across all segments using Aho-Corasick
-        // Track (seg_idx, start, end, pattern_idx, char_position) for burst model
-        let mut candidates: Vec<(usize, usize, usize, usize, usize)> =
-            Vec::with_capacity(estimated_candidates);
+        let mut candidates: Vec<OcrCandidate> = Vec::with_capacity(estimated_candidates);
 
         let mut global_char_pos = 0usize;
         for (seg_idx, segment) in segments.iter().enumerate() {
-            if !segment.is_mutable() {
-                global_char_pos += segment.text().chars().count();
-                continue;
-            }
             let seg_text = segment.text();
-            for mat in automaton.find_iter(seg_text) {
-                // Calculate approximate character position for this match
-                let char_pos = global_char_pos + seg_text[..mat.start()].chars().count();
-                candidates.push((seg_idx, mat.start(), mat.end(), mat.pattern().as_usize(), char_pos));
+            if segment.is_mutable() {
+                for mat in automaton.find_iter(seg_text) {
+                    if allowed[mat.pattern().as_usize()].is_empty() {
+                        continue;
+                    }
+                    let char_start = global_char_pos + seg_text[..mat.start()].chars().count();
+                    candidates.push(OcrCandidate {
+                        segment: seg_idx,
+                        start: mat.start(),
+                        end: mat.end(),
+                        pattern: mat.pattern().as_usize(),
+                        char_start,
+                        char_end: char_start + seg_text[mat.range()].chars().count(),
+                    });
+                }
             }
             global_char_pos += seg_text.chars().count();
         }
 
         if candidates.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::edits(spacing_edits));
         }
 
+        // Pin the order the shuffle starts from to text position, so it never
+        // depends on segment iteration.
+        candidates.sort_by_key(|candidate| {
+            (candidate.char_start, candidate.char_end, candidate.pattern)
+        });
+
         // Phase 3: Generate burst state sequence using HMM
         // Reference: Kanungo et al. (1994) - spatial correlation of defects
         let total_candidates = candidates.len();
@@ -993,14 +1657,7 @@ impl TextOperation for OcrArtifactsOp {
 
         if burst_enabled {
             // Walk through candidates in position order and simulate HMM
-            let mut sorted_by_pos: Vec<(usize, usize)> = candidates
-                .iter()
-                .enumerate()
-                .map(|(idx, (_, _, _, _, char_pos))| (idx, *char_pos))
-                .collect();
-            sorted_by_pos.sort_by_key(|(_, pos)| *pos);
-
-            for (candidate_idx, _char_pos) in sorted_by_pos {
+            for candidate_idx in 0..total_candidates {
                 // State transitions
                 if in_harsh_state {
                     if rng.random()? < op.burst_exit {
@@ -1022,17 +1679,21 @@ impl TextOperation for OcrArtifactsOp {
             // At least try to select one if rate > 0
         }
 
-        // Fisher-Yates shuffle - must complete for RNG determinism
+        // Count: Fisher-Yates shuffle - must complete for RNG determinism.
+        // Bernoulli: every candidate gets its own roll, in text order.
         let mut order: Vec<usize> = (0..total_candidates).collect();
-        for idx in (1..total_candidates).rev() {
-            let swap_with = rng.rand_index(idx + 1)?;
-            order.swap(idx, swap_with);
+        if op.sampling == OcrSampling::Count {
+            for idx in (1..total_candidates).rev() {
+                let swap_with = rng.rand_index(idx + 1)?;
+                order.swap(idx, swap_with);
+            }
         }
 
-        // Now select candidates in shuffled order with burst and bias modifiers
-        let num_segments = segments.len();
-        let mut occupied: Vec<Vec<(usize, usize)>> = vec![Vec::new(); num_segments];
-        let mut chosen: Vec<(usize, usize, usize, &'static str)> =
+        // Now select candidates in shuffled order with burst and bias modifiers.
+        // Chosen whole-text character ranges, keyed by start.
+        let mut occupied: std::collections::BTreeMap<usize, usize> =
+            std::collections::BTreeMap::new();
+        let mut chosen: Vec<(usize, usize, usize, &str)> =
             Vec::with_capacity(base_to_select.min(1024));
 
         // Track effective selections (burst increases the count we can select)
@@ -1055,27 +1716,33 @@ impl TextOperation for OcrArtifactsOp {
                 base_to_select
             };
 
-            if effective_selections >= target.max(base_to_select) {
+            if op.sampling == OcrSampling::Count
+                && effective_selections >= target.max(base_to_select)
+            {
                 break;
             }
 
-            let (seg_idx, start, end, pattern_idx, _char_pos) = candidates[candidate_idx];
-            let (_, choices) = table[pattern_idx];
+            let candidate = &candidates[candidate_idx];
+            let (seg_idx, start, end, pattern_idx) =
+                (candidate.segment, candidate.start, candidate.end, candidate.pattern);
+            let choices = &allowed[pattern_idx];
             if choices.is_empty() {
                 continue;
             }
 
-            // Check for overlap - use simple linear scan (few items per segment)
-            let seg_occupied = &occupied[seg_idx];
-            let overlaps = seg_occupied.iter().any(|&(s, e)| !(end <= s || e <= start));
-
+            // Chosen ranges are disjoint, so only the nearest one starting before
+            // this candidate ends can overlap it.
+            let overlaps = occupied
+                .range(..candidate.char_end)
+                .next_back()
+                .is_some_and(|(_, &chosen_end)| chosen_end > candidate.char_start);
             if overlaps {
                 continue;
             }
 
             // Apply selection probability with bias amplification
             // Reference: UNLV-ISRI - document-specific error profiles
-            let mut selection_weight = effective_rate;
+            let mut selection_weight = effective_rate * segments[seg_idx].rate_scale();
             if op.is_biased_pattern(pattern_idx) {
                 selection_weight *= op.bias_beta;
             }
@@ -1085,16 +1752,18 @@ impl TextOperation for OcrArtifactsOp {
                 continue;
             }
 
-            let choice_idx = rng.rand_index(choices.len())?;
-            chosen.push((seg_idx, start, end, choices[choice_idx]));
-            occupied[seg_idx].push((start, end));
+            let choice_idx = choices[rng.rand_index(choices.len())?];
+            chosen.push((seg_idx, start, end, confusions.choice(pattern_idx, choice_idx)));
+            occupied.insert(candidate.char_start, candidate.char_end);
             effective_selections += 1;
         }
 
         if chosen.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::edits(spacing_edits));
         }
 
+        let edits = spacing_edits + chosen.len();
+
         // Group replacements by segment
         let mut by_segment: std::collections::HashMap<usize, Vec<(usize, usize, &str)>> =
             std::collections::HashMap::new();
@@ -1136,7 +1805,7 @@ impl TextOperation for OcrArtifactsOp {
 
         buffer.replace_segments_bulk(segment_replacements);
         buffer.reindex_if_needed();
-        Ok(())
+        Ok(OpOutcome::edits(edits))
     }
 }
 
@@ -1148,7 +1817,7 @@ impl TextOperation for OcrArtifactsOp {
 // disrupt tokenization and string matching while remaining visually invisible.
 
 /// Visibility mode controlling which zero-width characters are included in the palette.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum VisibilityMode {
     /// Only true invisibles (ZWSP, ZWNJ, ZWJ, WJ, CGJ, BOM)
     #[default]
@@ -1201,7 +1870,7 @@ impl VisibilityMode {
 }
 
 /// Placement mode controlling where zero-width characters are inserted.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum PlacementMode {
     /// Insert between any adjacent non-whitespace characters (current behavior)
     #[default]
@@ -1274,7 +1943,7 @@ fn is_variation_selector(c: char) -> bool {
     ('\u{FE00}'..='\u{FE0F}').contains(&c)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ZeroWidthOp {
     pub rate: f64,
     pub characters: Vec<String>,
@@ -1516,22 +2185,22 @@ impl TextOperation for ZeroWidthOp {
         &self,
         buffer: &mut TextBuffer,
         rng: &mut dyn OperationRng,
-    ) -> Result<(), OperationError> {
+    ) -> Result<OpOutcome, OperationError> {
         let palette = self.effective_palette();
         if palette.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let segments = buffer.segments();
         if segments.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         // Collect insertion positions based on placement mode
         let positions = self.collect_positions(segments, &palette);
 
         if positions.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let clamped_rate = if self.rate.is_nan() {
@@ -1540,12 +2209,23 @@ impl TextOperation for ZeroWidthOp {
             self.rate.max(0.0)
         };
         if clamped_rate <= 0.0 {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let total = positions.len();
-        let mut count = (clamped_rate * total as f64).floor() as usize;
-        let remainder = clamped_rate * total as f64 - count as f64;
+        // Under a rate profile each position counts for its segment's scale.
+        let scales: Option<Vec<(usize, f64)>> = buffer.has_rate_profile().then(|| {
+            positions
+                .iter()
+                .enumerate()
+                .map(|(idx, (seg_idx, _, _))| (idx, segments[*seg_idx].rate_scale()))
+                .collect()
+        });
+        let scaled_total = scales
+            .as_ref()
+            .map_or(total as f64, |scales| scales.iter().map(|(_, scale)| scale).sum());
+        let mut count = (clamped_rate * scaled_total).floor() as usize;
+        let remainder = clamped_rate * scaled_total - count as f64;
         if remainder > 0.0 && rng.random()? < remainder {
             count += 1;
         }
@@ -1553,11 +2233,14 @@ impl TextOperation for ZeroWidthOp {
             count = total;
         }
         if count == 0 {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         // Sample positions to insert zero-width characters
-        let mut index_samples = rng.sample_indices(total, count)?;
+        let mut index_samples = match &scales {
+            Some(scales) => rng.weighted_sample(scales, count)?,
+            None => rng.sample_indices(total, count)?,
+        };
         index_samples.sort_unstable();
 
         // Collect (seg_idx, char_idx, zero_width_char) for selected positions
@@ -1574,8 +2257,9 @@ impl TextOperation for ZeroWidthOp {
         self.enforce_max_consecutive(&mut insertions);
 
         if insertions.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
+        let edits = insertions.len();
 
         // Group insertions by segment
         use std::collections::HashMap;
@@ -1628,7 +2312,7 @@ impl TextOperation for ZeroWidthOp {
         }
 
         buffer.reindex_if_needed();
-        Ok(())
+        Ok(OpOutcome::edits(edits))
     }
 }
 
@@ -1641,7 +2325,7 @@ impl TextOperation for ZeroWidthOp {
 // (Dhakal et al., 2018).
 
 /// Motor coordination weighting mode for typo sampling.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
 pub enum MotorWeighting {
     /// All neighbors equally likely (original behavior)
     #[default]
@@ -1650,6 +2334,13 @@ pub enum MotorWeighting {
     WetInk,
     /// Raw typing before correction - same-finger errors occur most often
     HastilyEdited,
+    /// Caller-supplied multipliers for each transition type
+    Custom {
+        same_finger: f64,
+        same_hand: f64,
+        cross_hand: f64,
+        space: f64,
+    },
 }
 
 impl MotorWeighting {
@@ -1664,10 +2355,53 @@ impl MotorWeighting {
         }
     }
 
+    /// Named modes accepted by [`MotorWeighting::parse`].
+    pub const NAMED: [(&'static str, Self); 3] = [
+        ("uniform", Self::Uniform),
+        ("wet_ink", Self::WetInk),
+        ("hastily_edited", Self::HastilyEdited),
+    ];
+
+    /// Keys understood by [`MotorWeighting::Custom`], in field order.
+    pub const TRANSITION_KEYS: [&'static str; 4] =
+        ["same_finger", "same_hand", "cross_hand", "space"];
+
+    /// Build a custom weighting, rejecting negative or non-finite multipliers.
+    pub fn custom(
+        same_finger: f64,
+        same_hand: f64,
+        cross_hand: f64,
+        space: f64,
+    ) -> Result<Self, String> {
+        let weights = [same_finger, same_hand, cross_hand, space];
+        for (key, value) in Self::TRANSITION_KEYS.iter().zip(weights) {
+            check_non_negative(&format!("motor_weights.{key}"), value)?;
+        }
+        Ok(Self::Custom { same_finger, same_hand, cross_hand, space })
+    }
+
+    /// Multipliers for each transition type, keyed like [`Self::TRANSITION_KEYS`].
+    #[must_use]
+    pub const fn transition_weights(&self) -> [(&'static str, f64); 4] {
+        [
+            ("same_finger", self.weight_for_transition(TransitionType::SameFinger)),
+            ("same_hand", self.weight_for_transition(TransitionType::SameHand)),
+            ("cross_hand", self.weight_for_transition(TransitionType::CrossHand)),
+            ("space", self.weight_for_transition(TransitionType::Space)),
+        ]
+    }
+
     /// Get the weight multiplier for a transition type.
     const fn weight_for_transition(&self, transition: TransitionType) -> f64 {
         match self {
             Self::Uniform => 1.0,
+            Self::Custom { same_finger, same_hand, cross_hand, space } => match transition {
+                TransitionType::SameFinger => *same_finger,
+                TransitionType::SameHand => *same_hand,
+                TransitionType::CrossHand => *cross_hand,
+                TransitionType::Space => *space,
+                TransitionType::Unknown => 1.0,
+            },
             Self::WetInk => match transition {
                 TransitionType::SameFinger => 0.858,
                 TransitionType::SameHand => 0.965,
@@ -1694,42 +2428,158 @@ enum TransitionType {
     Unknown,
 }
 
-/// Finger assignment: (hand, finger)
-/// hand: 0=left, 1=right, 2=thumb/space
-/// finger: 0=pinky, 1=ring, 2=middle, 3=index, 4=thumb
-const fn finger_for_char(ch: char) -> Option<(u8, u8)> {
-    // Use lowercase for lookup
-    let lower = ch.to_ascii_lowercase();
-    match lower {
-        // Left pinky (hand=0, finger=0)
-        '`' | '1' | 'q' | 'a' | 'z' | '~' | '!' => Some((0, 0)),
-        // Left ring (hand=0, finger=1)
-        '2' | 'w' | 's' | 'x' | '@' => Some((0, 1)),
-        // Left middle (hand=0, finger=2)
-        '3' | 'e' | 'd' | 'c' | '#' => Some((0, 2)),
-        // Left index - two columns (hand=0, finger=3)
-        '4' | 'r' | 'f' | 'v' | '5' | 't' | 'g' | 'b' | '$' | '%' => Some((0, 3)),
-        // Right index - two columns (hand=1, finger=3)
-        '6' | 'y' | 'h' | 'n' | '7' | 'u' | 'j' | 'm' | '^' | '&' => Some((1, 3)),
-        // Right middle (hand=1, finger=2)
-        '8' | 'i' | 'k' | ',' | '*' | '<' => Some((1, 2)),
-        // Right ring (hand=1, finger=1)
-        '9' | 'o' | 'l' | '.' | '(' | '>' => Some((1, 1)),
-        // Right pinky (hand=1, finger=0)
-        '0' | 'p' | ';' | '/' | '-' | '[' | '\'' | ')' | ':' | '?' | '_' | '{' | '"' | '=' | ']'
-        | '\\' | '+' | '}' | '|' => Some((1, 0)),
-        // Space - thumb (hand=2, finger=4)
-        ' ' => Some((2, 4)),
-        _ => None,
+/// Physical key rows as `(unshifted, shifted)` strings indexed by column.
+///
+/// Rows run from the number row down to the bottom row; `' '` marks a column
+/// with no key (e.g. the Tab slot on the top row).
+type KeyRows = [(&'static str, &'static str); 4];
+
+const US_ROWS: KeyRows = [
+    ("`1234567890-=", "~!@#$%^&*()_+"),
+    (" qwertyuiop[]\\", " QWERTYUIOP{}|"),
+    (" asdfghjkl;'", " ASDFGHJKL:\""),
+    (" zxcvbnm,./", " ZXCVBNM<>?"),
+];
+
+const DVORAK_ROWS: KeyRows = [
+    ("`1234567890[]", "~!@#$%^&*(){}"),
+    (" ',.pyfgcrl/=\\", " \"<>PYFGCRL?+|"),
+    (" aoeuidhtns-", " AOEUIDHTNS_"),
+    (" ;qjkxbmwvz", " :QJKXBMWVZ"),
+];
+
+const COLEMAK_ROWS: KeyRows = [
+    ("`1234567890-=", "~!@#$%^&*()_+"),
+    (" qwfpgjluy;[]\\", " QWFPGJLUY:{}|"),
+    (" arstdhneio'", " ARSTDHNEIO\""),
+    (" zxcvbkm,./", " ZXCVBKM<>?"),
+];
+
+const AZERTY_ROWS: KeyRows = [
+    ("²&é\"'(-è_çà)=", " 1234567890°+"),
+    (" azertyuiop^$", " AZERTYUIOP¨£"),
+    (" qsdfghjklmù*", " QSDFGHJKLM%µ"),
+    ("<wxcvbn,;:!", ">WXCVBN?./§"),
+];
+
+const QWERTZ_ROWS: KeyRows = [
+    ("^1234567890ß´", "°!\"§$%&/()=?`"),
+    (" qwertzuiopü+", " QWERTZUIOPÜ*"),
+    (" asdfghjklöä#", " ASDFGHJKLÖÄ'"),
+    ("<yxcvbnm,.-", ">YXCVBNM;:_"),
+];
+
+const SPANISH_ROWS: KeyRows = [
+    ("º1234567890'¡", "ª!\"·$%&/()=?¿"),
+    (" qwertyuiop´+", " QWERTYUIOP¨*"),
+    (" asdfghjklñ´", " ASDFGHJKLÑ¨"),
+    ("<zxcvbnm,.-", ">ZXCVBNM;:_"),
+];
+
+const SWEDISH_ROWS: KeyRows = [
+    ("§1234567890+´", "½!\"#¤%&/()=?`"),
+    (" qwertyuiopå¨", " QWERTYUIOPÅ^"),
+    (" asdfghjklöä'", " ASDFGHJKLÖÄ*"),
+    ("<zxcvbnm,.-", ">ZXCVBNM;:_"),
+];
+
+/// Physical keyboard used to assign fingers to keys for motor weighting.
+///
+/// Neighbour maps only say which keys are adjacent; the preset says where each
+/// key physically sits, so QWERTZ's `z` is typed by the right index finger
+/// rather than the left pinky.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum KeyboardLayout {
+    /// US ANSI QWERTY (also used for `CURATOR_QWERTY`)
+    #[default]
+    Qwerty,
+    Dvorak,
+    Colemak,
+    Azerty,
+    Qwertz,
+    SpanishQwerty,
+    SwedishQwerty,
+}
+
+impl KeyboardLayout {
+    /// Parse a layout preset from a keyboard name such as `"QWERTZ"`.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "qwerty" | "curator_qwerty" | "us" => Some(Self::Qwerty),
+            "dvorak" => Some(Self::Dvorak),
+            "colemak" => Some(Self::Colemak),
+            "azerty" => Some(Self::Azerty),
+            "qwertz" => Some(Self::Qwertz),
+            "spanish_qwerty" => Some(Self::SpanishQwerty),
+            "swedish_qwerty" => Some(Self::SwedishQwerty),
+            _ => None,
+        }
+    }
+
+    const fn rows(self) -> &'static KeyRows {
+        match self {
+            Self::Qwerty => &US_ROWS,
+            Self::Dvorak => &DVORAK_ROWS,
+            Self::Colemak => &COLEMAK_ROWS,
+            Self::Azerty => &AZERTY_ROWS,
+            Self::Qwertz => &QWERTZ_ROWS,
+            Self::SpanishQwerty => &SPANISH_ROWS,
+            Self::SwedishQwerty => &SWEDISH_ROWS,
+        }
+    }
+
+    /// Locate `ch` as `(row, column)`, checking unshifted keys before shifted ones.
+    fn position(self, ch: char) -> Option<(usize, usize)> {
+        if ch == ' ' {
+            return None;
+        }
+        let rows = self.rows();
+        let find = |pick: fn(&(&'static str, &'static str)) -> &'static str| {
+            rows.iter().enumerate().find_map(|(row, keys)| {
+                pick(keys).chars().position(|key| key == ch).map(|column| (row, column))
+            })
+        };
+        find(|keys| keys.0).or_else(|| find(|keys| keys.1))
+    }
+
+    /// Finger assignment: (hand, finger)
+    /// hand: 0=left, 1=right, 2=thumb/space
+    /// finger: 0=pinky, 1=ring, 2=middle, 3=index, 4=thumb
+    fn finger_for_char(self, ch: char) -> Option<(u8, u8)> {
+        if ch == ' ' {
+            return Some((2, 4));
+        }
+        let (_, column) = self.position(ch)?;
+        // Touch-typing columns: each index finger covers two, pinkies take the edges.
+        Some(match column {
+            0 | 1 => (0, 0),
+            2 => (0, 1),
+            3 => (0, 2),
+            4 | 5 => (0, 3),
+            6 | 7 => (1, 3),
+            8 => (1, 2),
+            9 => (1, 1),
+            _ => (1, 0),
+        })
+    }
+
+    /// Whether `ch` sits on the home row where the fingers rest (`asdfghjkl;` on QWERTY).
+    fn is_home_row(self, ch: char) -> bool {
+        matches!(self.position(ch), Some((2, 1..=10)))
     }
 }
 
 /// Classify the motor coordination required for a key transition.
-const fn classify_transition(prev_char: char, curr_char: char) -> TransitionType {
-    let Some(prev) = finger_for_char(prev_char) else {
+fn classify_transition(
+    keyboard: KeyboardLayout,
+    prev_char: char,
+    curr_char: char,
+) -> TransitionType {
+    let Some(prev) = keyboard.finger_for_char(prev_char) else {
         return TransitionType::Unknown;
     };
-    let Some(curr) = finger_for_char(curr_char) else {
+    let Some(curr) = keyboard.finger_for_char(curr_char) else {
         return TransitionType::Unknown;
     };
 
@@ -1775,10 +2625,28 @@ enum TypoAction {
     CollapseDuplicate = 6,
     /// Duplicate a character
     RepeatChar = 7,
+    /// Transpose a commonly reversed letter pair or two adjacent 2-letter chunks
+    SwapSyllable = 8,
 }
 
+/// Letter pairs typists commonly reverse ("freind", "recieve", "teh").
+const TRANSPOSED_PAIRS: &[[char; 2]] = &[
+    ['i', 'e'],
+    ['e', 'i'],
+    ['o', 'u'],
+    ['e', 'r'],
+    ['r', 'e'],
+    ['l', 'e'],
+    ['h', 'e'],
+    ['a', 'e'],
+    ['e', 'a'],
+];
+
 impl TypoAction {
-    const COUNT: usize = 8;
+    const COUNT: usize = 9;
+    /// Actions drawn without `syllable_swaps`; a fixed range keeps older seeds
+    /// mapping to the same actions.
+    const BASE_COUNT: usize = 8;
 
     const fn from_index(idx: usize) -> Self {
         match idx {
@@ -1790,6 +2658,7 @@ impl TypoAction {
             5 => Self::InsertSpace,
             6 => Self::CollapseDuplicate,
             7 => Self::RepeatChar,
+            8 => Self::SwapSyllable,
             _ => Self::SwapAdjacent, // Fallback (shouldn't happen)
         }
     }
@@ -1802,19 +2671,133 @@ impl TypoAction {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TypoOp {
     pub rate: f64,
     pub layout: HashMap<String, Vec<String>>,
     pub shift_slip: Option<ShiftSlipConfig>,
     pub motor_weighting: MotorWeighting,
+    /// Edit whole grapheme clusters instead of `char`s, so deletions and swaps
+    /// never separate combining marks or split emoji sequences.
+    #[serde(default)]
+    pub grapheme_aware: bool,
+    /// Chance that a character-level typo snowballs into one or two further
+    /// correlated slips beside it ("hellllo", "thier"), as in a sloppy burst
+    /// of typing. Zero keeps every typo isolated.
+    #[serde(default)]
+    pub burstiness: f64,
+    /// Extra selection weight for home-row neighbours in neighbour insertions
+    /// and replacements: their weight is multiplied by `1 + homerow_bias`, on
+    /// top of any motor weighting. Zero disables the bias.
+    #[serde(default)]
+    pub homerow_bias: f64,
+    /// Physical layout used to assign fingers and the home row to keys.
+    #[serde(default)]
+    pub keyboard: KeyboardLayout,
+    /// Adds a syllable-swap action that transposes common letter pairs or two
+    /// adjacent 2-letter chunks. Off by default so existing seeds reproduce.
+    #[serde(default)]
+    pub syllable_swaps: bool,
+}
+
+/// The unit [`TypoOp`] edits: a `char`, or a grapheme cluster borrowed from
+/// the buffer (or the layout) when `grapheme_aware` is set.
+trait TypoUnit<'a>: Copy + PartialEq {
+    const SPACE: Self;
+
+    fn units(text: &'a str) -> Vec<Self>;
+
+    fn count(text: &str) -> usize;
+
+    /// Leading scalar, used for layout lookups and word-character checks.
+    fn base(self) -> char;
+
+    fn is_whitespace(self) -> bool;
+
+    fn push_onto(self, output: &mut String);
+}
+
+impl<'a> TypoUnit<'a> for char {
+    const SPACE: Self = ' ';
+
+    fn units(text: &'a str) -> Vec<Self> {
+        text.chars().collect()
+    }
+
+    fn count(text: &str) -> usize {
+        text.chars().count()
+    }
+
+    fn base(self) -> char {
+        self
+    }
+
+    fn is_whitespace(self) -> bool {
+        char::is_whitespace(self)
+    }
+
+    fn push_onto(self, output: &mut String) {
+        output.push(self);
+    }
+}
+
+impl<'a> TypoUnit<'a> for &'a str {
+    const SPACE: Self = " ";
+
+    fn units(text: &'a str) -> Vec<Self> {
+        use unicode_segmentation::UnicodeSegmentation;
+        text.graphemes(true).collect()
+    }
+
+    fn count(text: &str) -> usize {
+        use unicode_segmentation::UnicodeSegmentation;
+        text.graphemes(true).count()
+    }
+
+    fn base(self) -> char {
+        self.chars().next().unwrap_or(' ')
+    }
+
+    fn is_whitespace(self) -> bool {
+        self.chars().all(char::is_whitespace)
+    }
+
+    fn push_onto(self, output: &mut String) {
+        output.push_str(self);
+    }
+}
+
+/// How a shift slip burst behaves once it starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ShiftSlipMode {
+    /// A held shift key: every character after the guaranteed hold rolls
+    /// `exit_rate` to release it, and the shift map applies to all keys.
+    #[default]
+    Momentary,
+    /// A stuck caps lock: only letters are uppercased, and the exit roll is
+    /// made only at word boundaries, so the lock persists for whole words.
+    CapsLock,
+}
+
+impl ShiftSlipMode {
+    /// Parse a shift slip mode from a string.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "momentary" | "shift" => Some(Self::Momentary),
+            "caps_lock" | "capslock" | "caps" => Some(Self::CapsLock),
+            _ => None,
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShiftSlipConfig {
     pub enter_rate: f64,
     pub exit_rate: f64,
+    /// Characters emitted shifted before the first exit roll.
     pub min_hold: usize,
+    pub mode: ShiftSlipMode,
     pub shift_map: HashMap<String, String>,
 }
 
@@ -1825,11 +2808,27 @@ impl ShiftSlipConfig {
             enter_rate: enter_rate.max(0.0),
             exit_rate: exit_rate.max(0.0),
             min_hold: 1,
+            mode: ShiftSlipMode::Momentary,
             shift_map,
         }
     }
 
+    #[must_use]
+    pub const fn with_min_hold(mut self, min_hold: usize) -> Self {
+        self.min_hold = min_hold;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_mode(mut self, mode: ShiftSlipMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     fn shifted_for_char(&self, ch: char) -> String {
+        if self.mode == ShiftSlipMode::CapsLock {
+            return ch.to_uppercase().collect();
+        }
         let key: String = ch.to_lowercase().collect();
         if let Some(mapped) = self.shift_map.get(&key) {
             return mapped.clone();
@@ -1861,9 +2860,15 @@ impl ShiftSlipConfig {
 
             if shift_held {
                 result.push_str(&self.shifted_for_char(ch));
+                let may_exit = match self.mode {
+                    ShiftSlipMode::Momentary => true,
+                    ShiftSlipMode::CapsLock => !ch.is_alphanumeric(),
+                };
                 if guaranteed > 0 {
                     guaranteed -= 1;
-                } else if exit_rate >= 1.0 || (exit_rate > 0.0 && rng.random()? < exit_rate) {
+                } else if may_exit
+                    && (exit_rate >= 1.0 || (exit_rate > 0.0 && rng.random()? < exit_rate))
+                {
                     shift_held = false;
                 }
             } else {
@@ -1880,19 +2885,19 @@ impl TypoOp {
         c.is_alphanumeric() || c == '_'
     }
 
-    fn eligible_idx(chars: &[char], idx: usize) -> bool {
+    fn eligible_idx<'a, U: TypoUnit<'a>>(chars: &[U], idx: usize) -> bool {
         if idx == 0 || idx + 1 >= chars.len() {
             return false;
         }
-        if !Self::is_word_char(chars[idx]) {
+        if !Self::is_word_char(chars[idx].base()) {
             return false;
         }
-        Self::is_word_char(chars[idx - 1]) && Self::is_word_char(chars[idx + 1])
+        Self::is_word_char(chars[idx - 1].base()) && Self::is_word_char(chars[idx + 1].base())
     }
 
-    fn draw_eligible_index(
+    fn draw_eligible_index<'a, U: TypoUnit<'a>>(
         rng: &mut dyn OperationRng,
-        chars: &[char],
+        chars: &[U],
         max_tries: usize,
     ) -> Result<Option<usize>, OperationError> {
         let n = chars.len();
@@ -1934,9 +2939,10 @@ impl TypoOp {
 
     /// Select a neighbor using motor coordination weights.
     ///
-    /// When motor_weighting is Uniform, this behaves identically to uniform random selection.
-    /// For other modes, it weights the selection based on the finger/hand transition
-    /// from the previous character to each potential neighbor.
+    /// When motor_weighting is Uniform and there is no home-row bias, this behaves identically
+    /// to uniform random selection. Otherwise it weights the selection based on the finger/hand
+    /// transition from the previous character to each potential neighbor, boosting home-row
+    /// neighbors by `homerow_bias`.
     fn select_weighted_neighbor(
         &self,
         prev_char: char,
@@ -1944,7 +2950,7 @@ impl TypoOp {
         rng: &mut dyn OperationRng,
     ) -> Result<usize, OperationError> {
         // Fast path for uniform weighting
-        if self.motor_weighting == MotorWeighting::Uniform {
+        if self.motor_weighting == MotorWeighting::Uniform && self.homerow_bias <= 0.0 {
             return rng.rand_index(neighbors.len());
         }
 
@@ -1955,8 +2961,11 @@ impl TypoOp {
         for neighbor in neighbors {
             // Get the first character of the neighbor (typically single char)
             let neighbor_char = neighbor.chars().next().unwrap_or(' ');
-            let transition = classify_transition(prev_char, neighbor_char);
-            let weight = self.motor_weighting.weight_for_transition(transition);
+            let transition = classify_transition(self.keyboard, prev_char, neighbor_char);
+            let mut weight = self.motor_weighting.weight_for_transition(transition);
+            if self.homerow_bias > 0.0 && self.keyboard.is_home_row(neighbor_char) {
+                weight *= 1.0 + self.homerow_bias;
+            }
             weights.push(weight);
             total_weight += weight;
         }
@@ -1980,10 +2989,57 @@ impl TypoOp {
         Ok(neighbors.len() - 1)
     }
 
-    fn remove_space(rng: &mut dyn OperationRng, chars: &mut Vec<char>) -> Result<(), OperationError> {
+    /// Follows a character-level typo at `idx` with one or two correlated
+    /// slips within a character of it: a double tap, a transposition or a
+    /// stray neighbouring key. Only interior word characters are touched, so a
+    /// burst never crosses a word boundary. Returns the number of edits made.
+    fn burst<'a, U: TypoUnit<'a>>(
+        &'a self,
+        rng: &mut dyn OperationRng,
+        chars: &mut Vec<U>,
+        idx: usize,
+    ) -> Result<usize, OperationError> {
+        let mut edits = 0usize;
+        let follow_ups = 1 + rng.rand_index(2)?;
+        for _ in 0..follow_ups {
+            let pos = (idx + rng.rand_index(3)?).saturating_sub(1);
+            if !Self::eligible_idx(chars, pos) {
+                continue;
+            }
+            match rng.rand_index(3)? {
+                0 => {
+                    let unit = chars[pos];
+                    chars.insert(pos, unit);
+                    edits += 1;
+                }
+                1 => {
+                    edits += usize::from(chars[pos] != chars[pos + 1]);
+                    chars.swap(pos, pos + 1);
+                }
+                _ => {
+                    let neighbors = self
+                        .neighbors_for_char(chars[pos].base())
+                        .filter(|neighbors| !neighbors.is_empty());
+                    if let Some(neighbors) = neighbors {
+                        let prev_char = chars[pos - 1].base();
+                        let choice = self.select_weighted_neighbor(prev_char, neighbors, rng)?;
+                        let stray = U::units(&neighbors[choice]);
+                        edits += usize::from(!stray.is_empty());
+                        chars.splice(pos..pos, stray);
+                    }
+                }
+            }
+        }
+        Ok(edits)
+    }
+
+    fn remove_space<'a, U: TypoUnit<'a>>(
+        rng: &mut dyn OperationRng,
+        chars: &mut Vec<U>,
+    ) -> Result<(), OperationError> {
         let mut count = 0usize;
         for ch in chars.iter() {
-            if *ch == ' ' {
+            if *ch == U::SPACE {
                 count += 1;
             }
         }
@@ -1994,7 +3050,7 @@ impl TypoOp {
         let mut seen = 0usize;
         let mut target: Option<usize> = None;
         for (idx, ch) in chars.iter().enumerate() {
-            if *ch == ' ' {
+            if *ch == U::SPACE {
                 if seen == choice {
                     target = Some(idx);
                     break;
@@ -2010,18 +3066,24 @@ impl TypoOp {
         Ok(())
     }
 
-    fn insert_space(rng: &mut dyn OperationRng, chars: &mut Vec<char>) -> Result<(), OperationError> {
+    fn insert_space<'a, U: TypoUnit<'a>>(
+        rng: &mut dyn OperationRng,
+        chars: &mut Vec<U>,
+    ) -> Result<(), OperationError> {
         if chars.len() < 2 {
             return Ok(());
         }
         let idx = rng.rand_index(chars.len() - 1)? + 1;
         if idx <= chars.len() {
-            chars.insert(idx, ' ');
+            chars.insert(idx, U::SPACE);
         }
         Ok(())
     }
 
-    fn repeat_char(rng: &mut dyn OperationRng, chars: &mut Vec<char>) -> Result<(), OperationError> {
+    fn repeat_char<'a, U: TypoUnit<'a>>(
+        rng: &mut dyn OperationRng,
+        chars: &mut Vec<U>,
+    ) -> Result<(), OperationError> {
         let mut count = 0usize;
         for ch in chars.iter() {
             if !ch.is_whitespace() {
@@ -2046,9 +3108,9 @@ impl TypoOp {
         Ok(())
     }
 
-    fn collapse_duplicate(
+    fn collapse_duplicate<'a, U: TypoUnit<'a>>(
         rng: &mut dyn OperationRng,
-        chars: &mut Vec<char>,
+        chars: &mut Vec<U>,
     ) -> Result<(), OperationError> {
         if chars.len() < 3 {
             return Ok(());
@@ -2056,7 +3118,7 @@ impl TypoOp {
         let mut matches: Vec<usize> = Vec::new();
         let mut i = 0;
         while i + 2 < chars.len() {
-            if chars[i] == chars[i + 1] && Self::is_word_char(chars[i + 2]) {
+            if chars[i] == chars[i + 1] && Self::is_word_char(chars[i + 2].base()) {
                 matches.push(i);
                 i += 2;
             } else {
@@ -2073,35 +3135,56 @@ impl TypoOp {
         }
         Ok(())
     }
-}
 
-impl TextOperation for TypoOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
-        if let Some(config) = &self.shift_slip {
-            let mut replacements: Vec<(usize, String)> = Vec::new();
-            for (index, segment) in buffer.segments().iter().enumerate() {
-                if !segment.is_mutable() {
-                    continue;
-                }
-                let slipped = config.apply(segment.text(), rng)?;
-                if slipped != segment.text() {
-                    replacements.push((index, slipped));
+    /// Transposes either a commonly reversed pair from [`TRANSPOSED_PAIRS`]
+    /// or two adjacent 2-letter chunks ("sylalbe"), never crossing a non-word
+    /// character. Returns whether anything moved.
+    fn swap_syllable<'a, U: TypoUnit<'a>>(
+        rng: &mut dyn OperationRng,
+        chars: &mut [U],
+    ) -> Result<bool, OperationError> {
+        let is_word = |unit: &U| Self::is_word_char(unit.base());
+        // (start, width): width 2 reverses a pair, width 4 swaps two chunks.
+        let mut candidates: Vec<(usize, usize)> = Vec::new();
+        for start in 0..chars.len() {
+            let window = &chars[start..];
+            if window.len() >= 2 && window[..2].iter().all(is_word) {
+                let pair = [window[0].base(), window[1].base()].map(|c| c.to_ascii_lowercase());
+                if TRANSPOSED_PAIRS.contains(&pair) {
+                    candidates.push((start, 2));
                 }
             }
-            if !replacements.is_empty() {
-                buffer.replace_segments_bulk(replacements);
-                buffer.reindex_if_needed();
+            if window.len() >= 4 && window[..4].iter().all(is_word) && window[..2] != window[2..4]
+            {
+                candidates.push((start, 4));
             }
         }
+        if candidates.is_empty() {
+            return Ok(false);
+        }
+        let (start, width) = candidates[rng.rand_index(candidates.len())?];
+        chars[start..start + width].rotate_left(width / 2);
+        Ok(true)
+    }
+}
 
+impl TypoOp {
+    /// Runs the typo actions over `U` units and returns the pieces to rebuild
+    /// the buffer from with the number of typos made, or `None` when no
+    /// segment was touched.
+    fn apply_units<'a, U: TypoUnit<'a>>(
+        &'a self,
+        buffer: &'a TextBuffer,
+        rng: &mut dyn OperationRng,
+    ) -> Result<Option<(Vec<RebuildPiece>, usize)>, OperationError> {
         let total_chars = buffer
             .segments()
             .iter()
             .filter(|segment| segment.is_mutable())
-            .map(|segment| segment.text().chars().count())
+            .map(|segment| U::count(segment.text()))
             .sum::<usize>();
         if total_chars == 0 {
-            return Ok(());
+            return Ok(None);
         }
 
         let clamped_rate = if self.rate.is_nan() {
@@ -2110,18 +3193,32 @@ impl TextOperation for TypoOp {
             self.rate.max(0.0)
         };
         if clamped_rate <= 0.0 {
-            return Ok(());
+            return Ok(None);
         }
 
-        let max_changes = (total_chars as f64 * clamped_rate).ceil() as usize;
+        // Under a rate profile each segment's units count for its scale, and
+        // segments are picked in proportion to it.
+        let profiled = buffer.has_rate_profile();
+        let scaled_chars = if profiled {
+            buffer
+                .segments()
+                .iter()
+                .filter(|segment| segment.is_mutable())
+                .map(|segment| U::count(segment.text()) as f64 * segment.rate_scale())
+                .sum::<f64>()
+        } else {
+            total_chars as f64
+        };
+        let max_changes = (scaled_chars * clamped_rate).ceil() as usize;
         if max_changes == 0 {
-            return Ok(());
+            return Ok(None);
         }
 
         // Track modified segment characters to avoid repeated String parsing
-        let mut segment_chars: HashMap<usize, Vec<char>> = HashMap::new();
+        let mut segment_chars: HashMap<usize, Vec<U>> = HashMap::new();
 
-        let mut scratch = SmallVec::<[char; 4]>::new();
+        let mut scratch = SmallVec::<[U; 4]>::new();
+        let mut typos = 0usize;
 
         // Pre-calculate segment indices to avoid O(N) scan inside the loop
         let word_indices: Vec<usize> = buffer
@@ -2140,84 +3237,91 @@ impl TextOperation for TypoOp {
             .map(|(i, _)| i)
             .collect();
 
+        let action_count = if self.syllable_swaps {
+            TypoAction::COUNT
+        } else {
+            TypoAction::BASE_COUNT
+        };
+
         for _ in 0..max_changes {
-            let action = TypoAction::from_index(rng.rand_index(TypoAction::COUNT)?);
+            let action = TypoAction::from_index(rng.rand_index(action_count)?);
 
             if action.is_char_level() {
                 // Character-level operations within Word segments only
-                if word_indices.is_empty() {
+                let Some(seg_idx) = pick_segment(rng, buffer, &word_indices, profiled)? else {
                     continue;
-                }
-
-                // Pick a random word segment
-                let choice = rng.rand_index(word_indices.len())?;
-                let seg_idx = word_indices[choice];
+                };
                 let segment = &buffer.segments()[seg_idx];
 
                 // Get mutable chars for this segment
                 let chars = segment_chars
                     .entry(seg_idx)
-                    .or_insert_with(|| segment.text().chars().collect());
+                    .or_insert_with(|| U::units(segment.text()));
 
                 // Try to find an eligible index within this segment
                 if let Some(idx) = Self::draw_eligible_index(rng, chars, 16)? {
+                    let before = typos;
                     match action {
-                        TypoAction::SwapAdjacent => {
-                            if idx + 1 < chars.len() {
-                                chars.swap(idx, idx + 1);
-                            }
+                        TypoAction::SwapAdjacent if idx + 1 < chars.len() => {
+                            typos += usize::from(chars[idx] != chars[idx + 1]);
+                            chars.swap(idx, idx + 1);
                         }
-                        TypoAction::Delete => {
-                            if idx < chars.len() {
-                                chars.remove(idx);
-                            }
+                        TypoAction::Delete if idx < chars.len() => {
+                            chars.remove(idx);
+                            typos += 1;
                         }
-                        TypoAction::InsertNeighbor => {
-                            if idx < chars.len() {
-                                let ch = chars[idx];
-                                scratch.clear();
-                                match self.neighbors_for_char(ch) {
-                                    Some(neighbors) if !neighbors.is_empty() => {
-                                        // Use previous char for transition weighting
-                                        // (idx > 0 guaranteed by eligible_idx)
-                                        let prev_char = chars[idx - 1];
-                                        let choice =
-                                            self.select_weighted_neighbor(prev_char, neighbors, rng)?;
-                                        scratch.extend(neighbors[choice].chars());
-                                    }
-                                    _ => {
-                                        // Maintain deterministic RNG advancement when no replacements are available.
-                                        rng.rand_index(1)?;
-                                        scratch.push(ch);
-                                    }
+                        TypoAction::InsertNeighbor if idx < chars.len() => {
+                            let ch = chars[idx];
+                            scratch.clear();
+                            match self.neighbors_for_char(ch.base()) {
+                                Some(neighbors) if !neighbors.is_empty() => {
+                                    // Use previous char for transition weighting
+                                    // (idx > 0 guaranteed by eligible_idx)
+                                    let prev_char = chars[idx - 1].base();
+                                    let choice =
+                                        self.select_weighted_neighbor(prev_char, neighbors, rng)?;
+                                    scratch.extend(U::units(&neighbors[choice]));
                                 }
-                                if !scratch.is_empty() {
-                                    chars.splice(idx..idx, scratch.iter().copied());
+                                _ => {
+                                    // Maintain deterministic RNG advancement when no replacements are available.
+                                    rng.rand_index(1)?;
+                                    scratch.push(ch);
                                 }
                             }
+                            if !scratch.is_empty() {
+                                chars.splice(idx..idx, scratch.iter().copied());
+                                typos += 1;
+                            }
                         }
-                        TypoAction::ReplaceNeighbor => {
-                            if idx < chars.len() {
-                                if let Some(neighbors) = self.neighbors_for_char(chars[idx]) {
-                                    if !neighbors.is_empty() {
-                                        // Use previous char for transition weighting
-                                        // (idx > 0 guaranteed by eligible_idx)
-                                        let prev_char = chars[idx - 1];
-                                        let choice =
-                                            self.select_weighted_neighbor(prev_char, neighbors, rng)?;
-                                        scratch.clear();
-                                        scratch.extend(neighbors[choice].chars());
-                                        if !scratch.is_empty() {
-                                            chars.splice(idx..idx + 1, scratch.iter().copied());
-                                        }
-                                    } else {
-                                        rng.rand_index(1)?;
+                        TypoAction::ReplaceNeighbor if idx < chars.len() => {
+                            if let Some(neighbors) = self.neighbors_for_char(chars[idx].base()) {
+                                if !neighbors.is_empty() {
+                                    // Use previous char for transition weighting
+                                    // (idx > 0 guaranteed by eligible_idx)
+                                    let prev_char = chars[idx - 1].base();
+                                    let choice =
+                                        self.select_weighted_neighbor(prev_char, neighbors, rng)?;
+                                    scratch.clear();
+                                    scratch.extend(U::units(&neighbors[choice]));
+                                    if !scratch.is_empty() {
+                                        chars.splice(idx..idx + 1, scratch.iter().copied());
+                                        typos += 1;
                                     }
+                                } else {
+                                    rng.rand_index(1)?;
                                 }
                             }
                         }
                         _ => {}
                     }
+                    // Only roll for a burst when asked to, so burstiness zero
+                    // leaves the RNG stream untouched.
+                    if typos > before
+                        && self.burstiness > 0.0
+                        && rng.random()? < self.burstiness
+                    {
+                        typos += self.burst(rng, chars, idx)?;
+                    }
                 }
                 continue;
             }
@@ -2225,67 +3329,76 @@ impl TextOperation for TypoOp {
             match action {
                 TypoAction::RemoveSpace => {
                     // Remove space from Separator segments
-                    if sep_indices.is_empty() {
+                    let Some(seg_idx) = pick_segment(rng, buffer, &sep_indices, profiled)? else {
                         continue;
-                    }
-
-                    let choice = rng.rand_index(sep_indices.len())?;
-                    let seg_idx = sep_indices[choice];
+                    };
                     let segment = &buffer.segments()[seg_idx];
 
                     let chars = segment_chars
                         .entry(seg_idx)
-                        .or_insert_with(|| segment.text().chars().collect());
+                        .or_insert_with(|| U::units(segment.text()));
 
+                    let before = chars.len();
                     Self::remove_space(rng, chars)?;
+                    typos += usize::from(chars.len() != before);
                 }
                 TypoAction::InsertSpace => {
                     // Insert space into a Word segment (splitting it)
-                    if word_indices.is_empty() {
+                    let Some(seg_idx) = pick_segment(rng, buffer, &word_indices, profiled)? else {
                         continue;
-                    }
-
-                    let choice = rng.rand_index(word_indices.len())?;
-                    let seg_idx = word_indices[choice];
+                    };
                     let segment = &buffer.segments()[seg_idx];
 
                     let chars = segment_chars
                         .entry(seg_idx)
-                        .or_insert_with(|| segment.text().chars().collect());
+                        .or_insert_with(|| U::units(segment.text()));
 
+                    let before = chars.len();
                     Self::insert_space(rng, chars)?;
+                    typos += usize::from(chars.len() != before);
                 }
                 TypoAction::CollapseDuplicate => {
                     // Collapse duplicate within Word segments
-                    if word_indices.is_empty() {
+                    let Some(seg_idx) = pick_segment(rng, buffer, &word_indices, profiled)? else {
                         continue;
-                    }
-
-                    let choice = rng.rand_index(word_indices.len())?;
-                    let seg_idx = word_indices[choice];
+                    };
                     let segment = &buffer.segments()[seg_idx];
 
                     let chars = segment_chars
                         .entry(seg_idx)
-                        .or_insert_with(|| segment.text().chars().collect());
+                        .or_insert_with(|| U::units(segment.text()));
 
+                    let before = chars.len();
                     Self::collapse_duplicate(rng, chars)?;
+                    typos += usize::from(chars.len() != before);
                 }
                 TypoAction::RepeatChar => {
                     // Repeat char within Word segments
-                    if word_indices.is_empty() {
+                    let Some(seg_idx) = pick_segment(rng, buffer, &word_indices, profiled)? else {
                         continue;
-                    }
-
-                    let choice = rng.rand_index(word_indices.len())?;
-                    let seg_idx = word_indices[choice];
+                    };
                     let segment = &buffer.segments()[seg_idx];
 
                     let chars = segment_chars
                         .entry(seg_idx)
-                        .or_insert_with(|| segment.text().chars().collect());
+                        .or_insert_with(|| U::units(segment.text()));
 
+                    let before = chars.len();
                     Self::repeat_char(rng, chars)?;
+                    typos += usize::from(chars.len() != before);
+                }
+                TypoAction::SwapSyllable => {
+                    // Transpose letters within Word segments
+                    let Some(seg_idx) = pick_segment(rng, buffer, &word_indices, profiled)? else {
+                        continue;
+                    };
+                    let segment = &buffer.segments()[seg_idx];
+
+                    let chars = segment_chars
+                        .entry(seg_idx)
+                        .or_insert_with(|| U::units(segment.text()));
+
+                    typos += usize::from(Self::swap_syllable(rng, chars)?);
                 }
                 // Character-level actions already handled above
                 _ => {}
@@ -2294,21 +3407,64 @@ impl TextOperation for TypoOp {
 
         // Rebuild buffer from modified segments
         if segment_chars.is_empty() {
-            return Ok(());
+            return Ok(None);
         }
 
-        let mut result = String::new();
-        for (idx, segment) in buffer.segments().iter().enumerate() {
+        let mut pieces = Vec::with_capacity(buffer.segments().len());
+        for (idx, span) in buffer.spans().iter().enumerate() {
             if let Some(modified_chars) = segment_chars.get(&idx) {
-                result.extend(modified_chars);
+                let mut text = String::new();
+                for unit in modified_chars {
+                    unit.push_onto(&mut text);
+                }
+                pieces.push(RebuildPiece::Fresh(span.byte_range.clone(), text));
             } else {
-                result.push_str(segment.text());
+                pieces.push(RebuildPiece::Kept(span.byte_range.clone()));
+            }
+        }
+
+        Ok(Some((pieces, typos)))
+    }
+}
+
+impl TextOperation for TypoOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        let mut edits = 0usize;
+        if let Some(config) = &self.shift_slip {
+            let mut replacements: Vec<(usize, String)> = Vec::new();
+            for (index, segment) in buffer.segments().iter().enumerate() {
+                if !segment.is_mutable() {
+                    continue;
+                }
+                let slipped = config.apply(segment.text(), rng)?;
+                if slipped != segment.text() {
+                    // Each shifted character counts as one edit.
+                    edits += segment
+                        .text()
+                        .chars()
+                        .zip(slipped.chars())
+                        .filter(|(original, shifted)| original != shifted)
+                        .count();
+                    replacements.push((index, slipped));
+                }
+            }
+            if !replacements.is_empty() {
+                buffer.replace_segments_bulk(replacements);
             }
         }
 
-        *buffer = buffer.rebuild_with_patterns(result);
         buffer.reindex_if_needed();
-        Ok(())
+        let rebuilt = if self.grapheme_aware {
+            self.apply_units::<&str>(buffer, rng)?
+        } else {
+            self.apply_units::<char>(buffer, rng)?
+        };
+        if let Some((pieces, typos)) = rebuilt {
+            *buffer = buffer.rebuild_from_pieces(pieces);
+            buffer.reindex_if_needed();
+            edits += typos;
+        }
+        Ok(OpOutcome::edits(edits))
     }
 }
 
@@ -2351,6 +3507,16 @@ impl QuoteKind {
             Self::Backtick => 2,
         }
     }
+
+    /// The glyph whose styles are used for quotes nested inside this kind:
+    /// double quotes nest single ones and vice versa.
+    const fn nested(self) -> Self {
+        match self {
+            Self::Double => Self::Single,
+            Self::Single => Self::Double,
+            Self::Backtick => Self::Backtick,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -2358,6 +3524,10 @@ struct QuotePair {
     start: usize,
     end: usize,
     kind: QuoteKind,
+    /// Number of quotes still open when this pair opened.
+    depth: usize,
+    /// Start of the innermost enclosing quote, if any.
+    parent: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -2367,39 +3537,103 @@ struct Replacement {
     value: String,
 }
 
-#[derive(Debug, Default, Clone, Copy)]
-pub struct QuotePairsOp;
+/// Right single quotation mark used for contraction apostrophes ("don’t").
+const CONTRACTION_APOSTROPHE: &str = "\u{2019}";
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct QuotePairsOp {
+    /// Also curl apostrophes inside words ("it's" → "it’s"). Such apostrophes
+    /// are then excluded from pair detection.
+    pub contractions: bool,
+}
 
 impl QuotePairsOp {
-    fn collect_pairs(text: &str) -> Vec<QuotePair> {
+    /// Returns true when the apostrophe at `idx` sits between two word
+    /// characters, as in a contraction or possessive.
+    fn is_contraction(text: &str, idx: usize) -> bool {
+        let before = text[..idx].chars().next_back();
+        let after = text[idx + 1..].chars().next();
+        matches!((before, after), (Some(left), Some(right)) if left.is_alphanumeric() && right.is_alphanumeric())
+    }
+
+    /// Picks the style table and entry for `pair`.
+    ///
+    /// Top-level pairs keep their own drawn entry. Double or single quotes
+    /// nested inside another such pair switch to the secondary glyph and reuse
+    /// the parent's entry where one exists, so “outer” wraps ‘inner’ and
+    /// „outer“ wraps ‚inner‘.
+    fn nested_style(
+        pair: QuotePair,
+        choice: usize,
+        parent_style: Option<(QuoteKind, usize)>,
+        table: &HashMap<char, Vec<(String, String)>>,
+    ) -> (QuoteKind, usize) {
+        let own = (pair.kind, choice);
+        if pair.depth == 0 || matches!(pair.kind, QuoteKind::Backtick) {
+            return own;
+        }
+        let Some((parent_kind, parent_choice)) = parent_style else {
+            return own;
+        };
+        if matches!(parent_kind, QuoteKind::Backtick) {
+            return own;
+        }
+
+        let kind = parent_kind.nested();
+        let options_len = table.get(&kind.as_char()).map_or(0, Vec::len);
+        if parent_choice < options_len {
+            (kind, parent_choice)
+        } else {
+            own
+        }
+    }
+
+    /// Pairs quotes and, when enabled, collects contraction apostrophes.
+    fn collect_pairs(text: &str, contractions: bool) -> (Vec<QuotePair>, Vec<usize>) {
         let mut pairs: Vec<QuotePair> = Vec::new();
+        let mut apostrophes: Vec<usize> = Vec::new();
         let mut stack: [Option<usize>; 3] = [None, None, None];
+        let mut depths: [usize; 3] = [0; 3];
+        let mut open: Vec<usize> = Vec::new();
 
         for (idx, ch) in text.char_indices() {
             if let Some(kind) = QuoteKind::from_char(ch) {
+                if contractions && ch == '\'' && Self::is_contraction(text, idx) {
+                    apostrophes.push(idx);
+                    continue;
+                }
                 let slot = kind.index();
                 if let Some(start) = stack[slot] {
+                    let position = open.iter().rposition(|&candidate| candidate == start);
+                    let parent = position.and_then(|pos| pos.checked_sub(1)).map(|pos| open[pos]);
+                    if let Some(pos) = position {
+                        open.remove(pos);
+                    }
                     pairs.push(QuotePair {
                         start,
                         end: idx,
                         kind,
+                        depth: depths[slot],
+                        parent,
                     });
                     stack[slot] = None;
                 } else {
                     stack[slot] = Some(idx);
+                    depths[slot] = open.len();
+                    open.push(idx);
                 }
             }
         }
 
-        pairs
+        (pairs, apostrophes)
     }
 }
 
 impl TextOperation for QuotePairsOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
         let segments = buffer.segments();
         if segments.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         // Build mapping from global byte index to (segment_index, byte_offset_in_segment)
@@ -2413,28 +3647,49 @@ impl TextOperation for QuotePairsOp {
 
         // Build full text for quote pair detection (we need to find pairs across segments)
         let text = buffer.to_string();
-        let pairs = Self::collect_pairs(&text);
-        if pairs.is_empty() {
-            return Ok(());
+        let (pairs, apostrophes) = Self::collect_pairs(&text, self.contractions);
+        if pairs.is_empty() && apostrophes.is_empty() {
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         let table = apostrofae_pairs();
-        if table.is_empty() {
-            return Ok(());
-        }
 
         // Collect replacements with global byte positions
-        let mut replacements: Vec<Replacement> = Vec::with_capacity(pairs.len() * 2);
+        let mut replacements: Vec<Replacement> =
+            Vec::with_capacity(pairs.len() * 2 + apostrophes.len());
+
+        // Draw a style for every pair in closing order, then resolve nested
+        // pairs outermost-first so each can follow its parent's style.
+        let mut drawn: Vec<Option<usize>> = Vec::with_capacity(pairs.len());
+        for pair in &pairs {
+            let options_len = table.get(&pair.kind.as_char()).map_or(0, Vec::len);
+            drawn.push(if options_len == 0 {
+                None
+            } else {
+                Some(rng.rand_index(options_len)?)
+            });
+        }
+
+        let mut order: Vec<usize> = (0..pairs.len()).collect();
+        order.sort_by_key(|&index| pairs[index].start);
+        let mut styles: HashMap<usize, (QuoteKind, usize)> = HashMap::new();
+        for index in order {
+            let pair = pairs[index];
+            let Some(choice) = drawn[index] else {
+                continue;
+            };
+            let parent_style = pair.parent.and_then(|start| styles.get(&start)).copied();
+            let style = Self::nested_style(pair, choice, parent_style, table);
+            styles.insert(pair.start, style);
+        }
 
         for pair in pairs {
-            let key = pair.kind.as_char();
-            let Some(options) = table.get(&key) else {
+            let Some(&(style_kind, choice)) = styles.get(&pair.start) else {
                 continue;
             };
-            if options.is_empty() {
+            let Some(options) = table.get(&style_kind.as_char()) else {
                 continue;
-            }
-            let choice = rng.rand_index(options.len())?;
+            };
             let (left, right) = &options[choice];
             let glyph_len = pair.kind.as_char().len_utf8();
             replacements.push(Replacement {
@@ -2449,13 +3704,22 @@ impl TextOperation for QuotePairsOp {
             });
         }
 
+        for start in apostrophes {
+            replacements.push(Replacement {
+                start,
+                end: start + 1,
+                value: CONTRACTION_APOSTROPHE.to_string(),
+            });
+        }
+
         if replacements.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
         // Group replacements by segment
         let mut by_segment: std::collections::HashMap<usize, Vec<(usize, usize, String)>> =
             std::collections::HashMap::new();
+        let mut edits = 0usize;
 
         for replacement in replacements {
             if replacement.start < byte_to_segment.len() {
@@ -2474,6 +3738,10 @@ impl TextOperation for QuotePairsOp {
                 }
                 let byte_offset_in_seg = replacement.start - segment_byte_start;
                 let byte_end_in_seg = byte_offset_in_seg + (replacement.end - replacement.start);
+                let original = text.get(replacement.start..replacement.end);
+                if original != Some(replacement.value.as_str()) {
+                    edits += 1;
+                }
 
                 by_segment.entry(seg_idx).or_default().push((
                     byte_offset_in_seg,
@@ -2516,7 +3784,7 @@ impl TextOperation for QuotePairsOp {
         buffer.replace_segments_bulk(segment_replacements);
 
         buffer.reindex_if_needed();
-        Ok(())
+        Ok(OpOutcome::edits(edits))
     }
 }
 
@@ -2528,11 +3796,29 @@ impl TextOperation for QuotePairsOp {
 // enabling heterogeneous collections and dynamic dispatch in the pipeline.
 
 /// Type-erased text corruption operation for pipeline sequencing.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Operation {
     Reduplicate(ReduplicateWordsOp),
     Delete(DeleteRandomWordsOp),
     SwapAdjacent(SwapAdjacentWordsOp),
+    SwapWords(SwapWordsOp),
+    DeleteSentences(DeleteSentencesOp),
+    ShuffleSentences(ShuffleSentencesOp),
+    Phonetic(PhoneticOp),
+    Bidi(BidiOp),
+    Fillers(FillerOp),
+    WhitespaceJitter(WhitespaceJitterOp),
+    Case(CaseOp),
+    Contractions(ContractionOp),
+    Disemvowel(DisemvowelOp),
+    DoubleWords(DoubleWordOp),
+    Scramble(ScrambleOp),
+    DropPunctuation(DropPunctuationOp),
+    Autocorrect(AutocorrectOp),
+    MergeWords(MergeWordsOp),
+    SplitWords(SplitWordsOp),
+    Diacritics(DiacriticOp),
+    Zalgo(ZalgoOp),
     RushmoreCombo(RushmoreComboOp),
     Redact(RedactWordsOp),
     Ocr(OcrArtifactsOp),
@@ -2544,14 +3830,235 @@ pub enum Operation {
     Hokey(crate::word_stretching::WordStretchOp),
     Wherewolf(HomophoneOp),
     Pedant(GrammarRuleOp),
+    /// Leaves the text untouched. It still takes a slot in the plan, and so a
+    /// derived seed, which keeps the seeds of later operations aligned across
+    /// pipeline variants.
+    Identity,
+}
+
+impl Operation {
+    /// Multiplies the operation's corruption rate by `factor`.
+    ///
+    /// Operations without a rate (quote normalisation, grammar rules, the
+    /// identity) are unaffected; Rushmore combos scale each enabled sub-operation.
+    pub fn scale_rate(&mut self, factor: f64) {
+        match self {
+            Self::Reduplicate(op) => op.rate *= factor,
+            Self::Delete(op) => op.rate *= factor,
+            Self::SwapAdjacent(op) => op.rate *= factor,
+            Self::SwapWords(op) => op.rate *= factor,
+            Self::DeleteSentences(op) => op.rate *= factor,
+            Self::ShuffleSentences(op) => op.rate *= factor,
+            Self::Phonetic(op) => op.rate *= factor,
+            Self::Bidi(op) => op.rate *= factor,
+            Self::Fillers(op) => op.rate *= factor,
+            Self::WhitespaceJitter(op) => op.rate *= factor,
+            Self::Case(op) => op.rate *= factor,
+            Self::Contractions(op) => op.rate *= factor,
+            Self::Disemvowel(op) => op.rate *= factor,
+            Self::DoubleWords(op) => op.rate *= factor,
+            Self::Scramble(op) => op.rate *= factor,
+            Self::DropPunctuation(op) => op.rate *= factor,
+            Self::Autocorrect(op) => op.rate *= factor,
+            Self::MergeWords(op) => op.rate *= factor,
+            Self::SplitWords(op) => op.rate *= factor,
+            Self::Diacritics(op) => op.rate *= factor,
+            Self::Zalgo(op) => op.rate *= factor,
+            Self::RushmoreCombo(op) => {
+                if let Some(delete) = op.delete.as_mut() {
+                    delete.rate *= factor;
+                }
+                if let Some(duplicate) = op.duplicate.as_mut() {
+                    duplicate.rate *= factor;
+                }
+                if let Some(swap) = op.swap.as_mut() {
+                    swap.rate *= factor;
+                }
+            }
+            Self::Redact(op) => op.rate *= factor,
+            Self::Ocr(op) => op.rate *= factor,
+            Self::Typo(op) => op.rate *= factor,
+            Self::Mimic(op) => op.scale_rate(factor),
+            Self::ZeroWidth(op) => op.rate *= factor,
+            Self::Jargoyle(op) => op.rate *= factor,
+            Self::Hokey(op) => op.rate *= factor,
+            Self::Wherewolf(op) => op.rate *= factor,
+            Self::QuotePairs(_) | Self::Pedant(_) | Self::Identity => {}
+        }
+    }
+
+    /// The descriptor `type` this operation is parsed from.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Reduplicate(_) => "reduplicate",
+            Self::Delete(_) => "delete",
+            Self::SwapAdjacent(_) => "swap_adjacent",
+            Self::SwapWords(_) => "swap_words",
+            Self::DeleteSentences(_) => "delete_sentences",
+            Self::ShuffleSentences(_) => "shuffle_sentences",
+            Self::Phonetic(_) => "phonetic",
+            Self::Bidi(_) => "bidi",
+            Self::Fillers(_) => "fillers",
+            Self::WhitespaceJitter(_) => "whitespace_jitter",
+            Self::Case(_) => "case",
+            Self::Contractions(_) => "contractions",
+            Self::Disemvowel(_) => "disemvowel",
+            Self::DoubleWords(_) => "double_words",
+            Self::Scramble(_) => "scramble",
+            Self::DropPunctuation(_) => "drop_punctuation",
+            Self::Autocorrect(_) => "autocorrect",
+            Self::MergeWords(_) => "merge_words",
+            Self::SplitWords(_) => "split_words",
+            Self::Diacritics(_) => "diacritics",
+            Self::Zalgo(_) => "zalgo",
+            Self::RushmoreCombo(_) => "rushmore_combo",
+            Self::Redact(_) => "redact",
+            Self::Ocr(_) => "ocr",
+            Self::Typo(_) => "typo",
+            Self::Mimic(_) => "mimic",
+            Self::ZeroWidth(_) => "zwj",
+            Self::Jargoyle(_) => "jargoyle",
+            Self::QuotePairs(_) => "apostrofae",
+            Self::Hokey(_) => "hokey",
+            Self::Wherewolf(_) => "wherewolf",
+            Self::Pedant(_) => "pedant",
+            Self::Identity => "identity",
+        }
+    }
+
+    /// Checks parameters that the ops would otherwise clamp or misread at run
+    /// time, returning a message naming the offending field.
+    ///
+    /// Rates and probabilities must lie in `[0, 1]`; NaN is rejected.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::Reduplicate(op) => check_unit_interval("rate", op.rate),
+            Self::Delete(op) => check_unit_interval("rate", op.rate),
+            Self::SwapAdjacent(op) => check_unit_interval("rate", op.rate),
+            Self::SwapWords(op) => {
+                check_unit_interval("rate", op.rate)?;
+                if op.max_distance == 0 {
+                    return Err("max_distance must be ≥ 1".to_string());
+                }
+                Ok(())
+            }
+            Self::DeleteSentences(op) => check_unit_interval("rate", op.rate),
+            Self::ShuffleSentences(op) => check_unit_interval("rate", op.rate),
+            Self::Phonetic(op) => check_unit_interval("rate", op.rate),
+            Self::Bidi(op) => check_unit_interval("rate", op.rate),
+            Self::Fillers(op) => check_unit_interval("rate", op.rate),
+            Self::WhitespaceJitter(op) => check_unit_interval("rate", op.rate),
+            Self::Case(op) => check_unit_interval("rate", op.rate),
+            Self::Contractions(op) => check_unit_interval("rate", op.rate),
+            Self::Disemvowel(op) => check_unit_interval("rate", op.rate),
+            Self::DoubleWords(op) => check_unit_interval("rate", op.rate),
+            Self::Scramble(op) => check_unit_interval("rate", op.rate),
+            Self::DropPunctuation(op) => check_unit_interval("rate", op.rate),
+            Self::Autocorrect(op) => check_unit_interval("rate", op.rate),
+            Self::MergeWords(op) => check_unit_interval("rate", op.rate),
+            Self::SplitWords(op) => check_unit_interval("rate", op.rate),
+            Self::Diacritics(op) => check_unit_interval("rate", op.rate),
+            Self::Zalgo(op) => check_unit_interval("rate", op.rate),
+            Self::RushmoreCombo(op) => {
+                if let Some(delete) = &op.delete {
+                    check_unit_interval("delete rate", delete.rate)?;
+                }
+                if let Some(duplicate) = &op.duplicate {
+                    check_unit_interval("duplicate rate", duplicate.rate)?;
+                }
+                if let Some(swap) = &op.swap {
+                    check_unit_interval("swap rate", swap.rate)?;
+                }
+                Ok(())
+            }
+            Self::Redact(op) => check_unit_interval("rate", op.rate),
+            Self::Ocr(op) => {
+                check_unit_interval("rate", op.rate)?;
+                check_unit_interval("burst_enter", op.burst_enter)?;
+                check_unit_interval("burst_exit", op.burst_exit)?;
+                check_non_negative("burst_multiplier", op.burst_multiplier)?;
+                check_non_negative("bias_beta", op.bias_beta)?;
+                check_unit_interval("space_drop_rate", op.space_drop_rate)?;
+                check_unit_interval("space_insert_rate", op.space_insert_rate)
+            }
+            Self::Typo(op) => {
+                check_unit_interval("rate", op.rate)?;
+                check_unit_interval("burstiness", op.burstiness)?;
+                check_non_negative("homerow_bias", op.homerow_bias)?;
+                if let MotorWeighting::Custom { same_finger, same_hand, cross_hand, space } =
+                    op.motor_weighting
+                {
+                    MotorWeighting::custom(same_finger, same_hand, cross_hand, space)?;
+                }
+                if let Some(slip) = &op.shift_slip {
+                    check_unit_interval("shift_slip_rate", slip.enter_rate)?;
+                    check_unit_interval("shift_slip_exit_rate", slip.exit_rate)?;
+                }
+                Ok(())
+            }
+            Self::Mimic(op) => check_unit_interval("rate", op.rate()),
+            Self::ZeroWidth(op) => check_unit_interval("rate", op.rate),
+            Self::Jargoyle(op) => check_unit_interval("rate", op.rate),
+            Self::Hokey(op) => {
+                check_unit_interval("rate", op.rate)?;
+                if op.extension_min < 0 {
+                    return Err(format!("extension_min must be ≥ 0, got {}", op.extension_min));
+                }
+                if op.extension_max < op.extension_min {
+                    return Err(format!(
+                        "extension_max must be ≥ extension_min ({}), got {}",
+                        op.extension_min, op.extension_max
+                    ));
+                }
+                check_unit_interval("base_p", op.base_p)
+            }
+            Self::Wherewolf(op) => check_unit_interval("rate", op.rate),
+            Self::QuotePairs(_) | Self::Pedant(_) | Self::Identity => Ok(()),
+        }
+    }
+}
+
+fn check_unit_interval(field: &str, value: f64) -> Result<(), String> {
+    if (0.0..=1.0).contains(&value) {
+        Ok(())
+    } else {
+        Err(format!("{field} must be in [0, 1], got {value}"))
+    }
+}
+
+fn check_non_negative(field: &str, value: f64) -> Result<(), String> {
+    if value >= 0.0 && value.is_finite() {
+        Ok(())
+    } else {
+        Err(format!("{field} must be a finite number ≥ 0, got {value}"))
+    }
 }
 
 impl TextOperation for Operation {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
         match self {
             Self::Reduplicate(op) => op.apply(buffer, rng),
             Self::Delete(op) => op.apply(buffer, rng),
             Self::SwapAdjacent(op) => op.apply(buffer, rng),
+            Self::SwapWords(op) => op.apply(buffer, rng),
+            Self::DeleteSentences(op) => op.apply(buffer, rng),
+            Self::ShuffleSentences(op) => op.apply(buffer, rng),
+            Self::Phonetic(op) => op.apply(buffer, rng),
+            Self::Bidi(op) => op.apply(buffer, rng),
+            Self::Fillers(op) => op.apply(buffer, rng),
+            Self::WhitespaceJitter(op) => op.apply(buffer, rng),
+            Self::Case(op) => op.apply(buffer, rng),
+            Self::Contractions(op) => op.apply(buffer, rng),
+            Self::Disemvowel(op) => op.apply(buffer, rng),
+            Self::DoubleWords(op) => op.apply(buffer, rng),
+            Self::Scramble(op) => op.apply(buffer, rng),
+            Self::DropPunctuation(op) => op.apply(buffer, rng),
+            Self::Autocorrect(op) => op.apply(buffer, rng),
+            Self::MergeWords(op) => op.apply(buffer, rng),
+            Self::SplitWords(op) => op.apply(buffer, rng),
+            Self::Diacritics(op) => op.apply(buffer, rng),
+            Self::Zalgo(op) => op.apply(buffer, rng),
             Self::RushmoreCombo(op) => op.apply(buffer, rng),
             Self::Redact(op) => op.apply(buffer, rng),
             Self::Ocr(op) => op.apply(buffer, rng),
@@ -2563,6 +4070,7 @@ impl TextOperation for Operation {
             Self::Hokey(op) => op.apply(buffer, rng),
             Self::Wherewolf(op) => op.apply(buffer, rng),
             Self::Pedant(op) => op.apply(buffer, rng),
+            Self::Identity => Ok(OpOutcome::UNCHANGED),
         }
     }
 }
@@ -2574,12 +4082,383 @@ impl TextOperation for Operation {
 #[cfg(test)]
 mod tests {
     use super::{
-        DeleteRandomWordsOp, TextOperation, OperationError, OcrArtifactsOp, RedactWordsOp,
-        ReduplicateWordsOp, SwapAdjacentWordsOp,
+        DeleteRandomWordsOp, TextOperation, OpOutcome, OperationError, OperationRng, OcrArtifactsOp,
+        OcrMode, OcrSampling,
+        QuotePairsOp, RedactWordsOp, ReduplicateWordsOp, ReduplicationSeparator, RushmoreComboMode,
+        RushmoreComboOp, ShiftSlipConfig, ShiftSlipMode, SwapAdjacentWordsOp, SwapWordsOp, TypoOp,
+        WordWeighting,
     };
+    use std::collections::HashMap;
+    use crate::pipeline::derive_seed;
     use crate::rng::DeterministicRng;
     use crate::text_buffer::TextBuffer;
 
+    fn shift_map() -> HashMap<String, String> {
+        [("1", "!"), ("-", "_")]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn shift_slip_honours_min_hold() {
+        let config = ShiftSlipConfig::new(1.0, 1.0, shift_map()).with_min_hold(3);
+        let mut rng = DeterministicRng::new(1);
+        assert_eq!(config.apply("abcdef", &mut rng).unwrap(), "ABCDef");
+    }
+
+    #[test]
+    fn shift_slip_defaults_match_explicit_momentary() {
+        let text = "quick brown-fox 1 jumps";
+        let base = ShiftSlipConfig::new(0.3, 0.4, shift_map());
+        let explicit = base
+            .clone()
+            .with_min_hold(1)
+            .with_mode(ShiftSlipMode::Momentary);
+        for seed in 0..8 {
+            let mut rng_a = DeterministicRng::new(seed);
+            let mut rng_b = DeterministicRng::new(seed);
+            assert_eq!(
+                base.apply(text, &mut rng_a).unwrap(),
+                explicit.apply(text, &mut rng_b).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn caps_lock_holds_whole_words_and_skips_shift_map() {
+        let config = ShiftSlipConfig::new(1.0, 1.0, shift_map()).with_mode(ShiftSlipMode::CapsLock);
+        let mut rng = DeterministicRng::new(1);
+        assert_eq!(config.apply("ab1-cd ef", &mut rng).unwrap(), "AB1-cd ef");
+        assert_eq!(ShiftSlipMode::parse("Caps-Lock"), Some(ShiftSlipMode::CapsLock));
+        assert_eq!(ShiftSlipMode::parse("sticky"), None);
+    }
+
+    fn typo_splits_a_flag(text: &str, grapheme_aware: bool, seed: u64) -> bool {
+        use unicode_segmentation::UnicodeSegmentation;
+        let layout = HashMap::from([
+            ("a".to_string(), vec!["s".to_string()]),
+            ("e".to_string(), vec!["w".to_string(), "r".to_string()]),
+        ]);
+        let op = TypoOp {
+            rate: 0.5,
+            layout,
+            shift_slip: None,
+            motor_weighting: super::MotorWeighting::default(),
+            grapheme_aware,
+            burstiness: 0.0,
+            homerow_bias: 0.0,
+            keyboard: super::KeyboardLayout::Qwerty,
+            syllable_swaps: false,
+        };
+        let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+        let mut rng = DeterministicRng::new(seed);
+        op.apply(&mut buffer, &mut rng).expect("typo succeeds");
+        let is_indicator = |ch: char| matches!(ch, '\u{1F1E6}'..='\u{1F1FF}');
+        buffer.to_string().graphemes(true).any(|grapheme| {
+            let indicators = grapheme.chars().filter(|&ch| is_indicator(ch)).count();
+            indicators != 0 && indicators != 2
+        })
+    }
+
+    #[test]
+    fn grapheme_aware_typos_never_split_flags() {
+        let text = "go\u{1F1FA}\u{1F1F8}\u{1F1FA}\u{1F1F8}team \u{1F1EB}\u{1F1F7}\u{1F1E9}\u{1F1EA} e\u{0301}te\u{0301}";
+        assert!((0..64).all(|seed| !typo_splits_a_flag(text, true, seed)));
+        assert!((0..64).any(|seed| typo_splits_a_flag(text, false, seed)));
+    }
+
+    #[test]
+    fn typo_bursts_stay_inside_the_word() {
+        let layout: HashMap<String, Vec<String>> = ('a'..='z')
+            .map(|ch| (ch.to_string(), vec!["x".to_string()]))
+            .collect();
+        let op = TypoOp {
+            rate: 0.0,
+            layout,
+            shift_slip: None,
+            motor_weighting: super::MotorWeighting::default(),
+            grapheme_aware: false,
+            burstiness: 1.0,
+            homerow_bias: 0.0,
+            keyboard: super::KeyboardLayout::Qwerty,
+            syllable_swaps: false,
+        };
+        for seed in 0..64 {
+            let mut chars: Vec<char> = "abcdef".chars().collect();
+            let mut rng = DeterministicRng::new(seed);
+            let edits = op.burst(&mut rng, &mut chars, 2).expect("burst succeeds");
+            assert!(edits >= 1, "seed {seed}");
+            assert_eq!(chars.first(), Some(&'a'));
+            assert_eq!(chars.last(), Some(&'f'));
+        }
+    }
+
+    #[test]
+    fn burstiness_adds_correlated_typos() {
+        let layout: HashMap<String, Vec<String>> = ('a'..='z')
+            .map(|ch| (ch.to_string(), vec!["x".to_string()]))
+            .collect();
+        let typos = |burstiness: f64, seed: u64| {
+            let op = TypoOp {
+                rate: 0.05,
+                layout: layout.clone(),
+                shift_slip: None,
+                motor_weighting: super::MotorWeighting::default(),
+                grapheme_aware: false,
+                burstiness,
+                homerow_bias: 0.0,
+                keyboard: super::KeyboardLayout::Qwerty,
+                syllable_swaps: false,
+            };
+            let mut buffer = TextBuffer::from_owned(
+                "the quick brown fox jumps over the lazy dog again and again".to_string(),
+                &[],
+                &[],
+            );
+            let mut rng = DeterministicRng::new(seed);
+            let edits = op.apply(&mut buffer, &mut rng).expect("typo succeeds").edits;
+            (edits, buffer.to_string())
+        };
+        let calm: usize = (0..32).map(|seed| typos(0.0, seed).0).sum();
+        let bursty: usize = (0..32).map(|seed| typos(1.0, seed).0).sum();
+        assert!(bursty > calm, "bursty {bursty} vs calm {calm}");
+        assert_eq!(typos(0.7, 2103), typos(0.7, 2103));
+    }
+
+    #[test]
+    fn syllable_swaps_transpose_pairs_and_chunks() {
+        let swapped = |word: &str, seed: u64| {
+            let mut chars: Vec<char> = word.chars().collect();
+            let mut rng = DeterministicRng::new(seed);
+            let changed = TypoOp::swap_syllable(&mut rng, &mut chars).expect("swap succeeds");
+            (changed, chars.into_iter().collect::<String>())
+        };
+        assert_eq!(swapped("ie", 0), (true, "ei".to_string()));
+        assert_eq!(swapped("abcd", 0), (true, "cdab".to_string()));
+        assert_eq!(swapped("a-bc", 0), (false, "a-bc".to_string()));
+        for seed in 0..32 {
+            let (changed, word) = swapped("receive", seed);
+            assert!(changed && word != "receive", "seed {seed}");
+            let mut letters: Vec<char> = word.chars().collect();
+            letters.sort_unstable();
+            assert_eq!(letters, ['c', 'e', 'e', 'e', 'i', 'r', 'v']);
+        }
+
+        // The action is only drawn when enabled, so older seeds keep their output.
+        let typo = |syllable_swaps: bool, seed: u64| {
+            let op = TypoOp {
+                rate: 0.2,
+                layout: HashMap::new(),
+                shift_slip: None,
+                motor_weighting: super::MotorWeighting::default(),
+                grapheme_aware: false,
+                burstiness: 0.0,
+                homerow_bias: 0.0,
+                keyboard: super::KeyboardLayout::Qwerty,
+                syllable_swaps,
+            };
+            let mut buffer =
+                TextBuffer::from_owned("my friend will receive their letter".to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(seed);
+            op.apply(&mut buffer, &mut rng).expect("typo succeeds");
+            buffer.to_string()
+        };
+        assert!((0..16).any(|seed| typo(true, seed) != typo(false, seed)));
+        assert_eq!(typo(true, 2107), typo(true, 2107));
+    }
+
+    #[test]
+    fn homerow_bias_favours_home_row_neighbours() {
+        let neighbors: Vec<String> =
+            ["w", "s", "x", "d"].iter().map(|key| (*key).to_string()).collect();
+        let typo = |homerow_bias: f64| TypoOp {
+            rate: 0.1,
+            layout: HashMap::new(),
+            shift_slip: None,
+            motor_weighting: super::MotorWeighting::default(),
+            grapheme_aware: false,
+            burstiness: 0.0,
+            homerow_bias,
+            keyboard: super::KeyboardLayout::Qwerty,
+            syllable_swaps: false,
+        };
+        let home_row_picks = |homerow_bias: f64| {
+            let op = typo(homerow_bias);
+            let mut rng = DeterministicRng::new(2104);
+            (0..2000)
+                .map(|_| op.select_weighted_neighbor('e', &neighbors, &mut rng).unwrap())
+                .filter(|&choice| matches!(neighbors[choice].as_str(), "s" | "d"))
+                .count()
+        };
+        let unbiased = home_row_picks(0.0);
+        assert!((850..1150).contains(&unbiased), "unbiased {unbiased}");
+        // A bias of three makes each home-row key four times as likely: 8/10.
+        let biased = home_row_picks(3.0);
+        assert!(biased > 1500, "biased {biased}");
+
+        // No bias keeps the uniform fast path and its RNG stream.
+        let op = typo(0.0);
+        let mut rng = DeterministicRng::new(7);
+        let mut reference = DeterministicRng::new(7);
+        for _ in 0..16 {
+            assert_eq!(
+                op.select_weighted_neighbor('e', &neighbors, &mut rng).unwrap(),
+                reference.rand_index(neighbors.len()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn custom_motor_weights_drive_neighbour_selection() {
+        use super::MotorWeighting;
+
+        // From 'e': 'd' shares the finger, 'w'/'s'/'x' share the hand.
+        let neighbors: Vec<String> =
+            ["w", "s", "x", "d"].iter().map(|key| (*key).to_string()).collect();
+        let op = TypoOp {
+            rate: 0.1,
+            layout: HashMap::new(),
+            shift_slip: None,
+            motor_weighting: MotorWeighting::custom(0.0, 1.0, 1.0, 1.0).unwrap(),
+            grapheme_aware: false,
+            burstiness: 0.0,
+            homerow_bias: 0.0,
+            keyboard: super::KeyboardLayout::Qwerty,
+            syllable_swaps: false,
+        };
+        let mut rng = DeterministicRng::new(2105);
+        for _ in 0..500 {
+            let choice = op.select_weighted_neighbor('e', &neighbors, &mut rng).unwrap();
+            assert_ne!(neighbors[choice], "d");
+        }
+
+        assert_eq!(
+            MotorWeighting::WetInk.transition_weights()[0],
+            ("same_finger", 0.858)
+        );
+        let error = MotorWeighting::custom(1.0, -0.5, 1.0, 1.0).unwrap_err();
+        assert!(error.starts_with("motor_weights.same_hand"), "{error}");
+        assert!(MotorWeighting::custom(1.0, 1.0, f64::NAN, 1.0).is_err());
+
+        // Deserialised descriptors bypass `custom`, so validation re-checks them.
+        let invalid = super::Operation::Typo(TypoOp {
+            motor_weighting: MotorWeighting::Custom {
+                same_finger: 1.0,
+                same_hand: 1.0,
+                cross_hand: 1.0,
+                space: f64::INFINITY,
+            },
+            ..op
+        });
+        let error = invalid.validate().unwrap_err();
+        assert!(error.starts_with("motor_weights.space"), "{error}");
+    }
+
+    #[test]
+    fn finger_map_follows_the_physical_layout() {
+        use super::{classify_transition, KeyboardLayout, TransitionType};
+
+        // On US QWERTY, 'z' is the left pinky; QWERTZ moves it to the right index.
+        let us = KeyboardLayout::Qwerty;
+        let de = KeyboardLayout::parse("QWERTZ").unwrap();
+        assert_eq!(us.finger_for_char('z'), Some((0, 0)));
+        assert_eq!(de.finger_for_char('z'), Some((1, 3)));
+        assert_eq!(de.finger_for_char('Y'), Some((0, 0)));
+        assert_eq!(classify_transition(us, 't', 'z'), TransitionType::SameHand);
+        assert_eq!(classify_transition(de, 't', 'z'), TransitionType::CrossHand);
+        assert_eq!(classify_transition(de, 'h', 'z'), TransitionType::SameFinger);
+
+        // Shifted symbols and non-ASCII keys resolve through their physical key.
+        assert_eq!(us.finger_for_char('?'), Some((1, 0)));
+        assert_eq!(de.finger_for_char('Ö'), Some((1, 0)));
+        assert_eq!(us.finger_for_char(' '), Some((2, 4)));
+        assert_eq!(us.finger_for_char('é'), None);
+
+        assert!(us.is_home_row(';') && !us.is_home_row('\''));
+        let dvorak = KeyboardLayout::parse("dvorak").unwrap();
+        assert!(dvorak.is_home_row('o') && !dvorak.is_home_row('p'));
+        assert!(!dvorak.is_home_row('-'));
+        assert_eq!(KeyboardLayout::parse("CURATOR_QWERTY"), Some(KeyboardLayout::Qwerty));
+        assert_eq!(KeyboardLayout::parse("workman"), None);
+    }
+
+    #[test]
+    fn weighted_sample_picks_exactly_k_distinct_items() {
+        let items: Vec<(usize, f64)> = (0..10).map(|idx| (idx * 3, 1.0 + idx as f64)).collect();
+        let mut rng = DeterministicRng::new(2044);
+        let mut picked = rng.weighted_sample(&items, 4).expect("sampling succeeds");
+        assert_eq!(picked.len(), 4);
+        picked.sort_unstable();
+        picked.dedup();
+        assert_eq!(picked.len(), 4);
+        assert!(picked.iter().all(|idx| idx % 3 == 0 && *idx < 30));
+        assert!(rng.weighted_sample(&items, 11).is_err());
+        assert!(rng.weighted_sample(&items, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn weighted_sample_never_picks_non_positive_weights() {
+        let items = [(0usize, 0.0), (1, 2.0), (2, -1.0), (3, f64::NAN), (4, 0.5)];
+        let mut rng = DeterministicRng::new(2096);
+        for _ in 0..200 {
+            let picked = rng.weighted_sample(&items, 2).expect("sampling succeeds");
+            assert!(picked.iter().all(|idx| *idx == 1 || *idx == 4), "picked {picked:?}");
+        }
+        // Fewer eligible items than requested: return every eligible one.
+        let mut picked = rng.weighted_sample(&items, 4).expect("sampling succeeds");
+        picked.sort_unstable();
+        assert_eq!(picked, [1, 4]);
+        assert!(rng.weighted_sample(&[(0, 0.0), (1, -3.0)], 1).unwrap().is_empty());
+        assert!(rng.weighted_sample(&items, 6).is_err());
+    }
+
+    #[test]
+    fn weighted_sample_favours_heavier_items() {
+        let items = [(0usize, 1.0), (1, 9.0)];
+        let mut rng = DeterministicRng::new(7);
+        let heavy = (0..2000)
+            .filter(|_| rng.weighted_sample(&items, 1).unwrap() == [1])
+            .count();
+        // P(heavy) = 9 / 10 for a single draw.
+        assert!((1700..=1900).contains(&heavy), "heavy picked {heavy} times");
+    }
+
+    #[test]
+    fn delete_random_words_removes_exactly_allowed_without_positional_bias() {
+        let words: Vec<String> = (0..11).map(|idx| format!("w{idx}")).collect();
+        let text = words.join(" ");
+        let op = DeleteRandomWordsOp {
+            rate: 0.3,
+            unweighted: true,
+            min_words: 0,
+            weighting: WordWeighting::Length,
+            preserve_spacing: false,
+        };
+        let trials = 3000;
+        let mut deleted_at = [0usize; 11];
+        for seed in 0..trials {
+            let mut buffer = TextBuffer::from_owned(text.clone(), &[], &[]);
+            let mut rng = DeterministicRng::new(seed);
+            op.apply(&mut buffer, &mut rng).expect("deletion works");
+            let result = buffer.to_string();
+            let remaining: Vec<&str> = result.split_whitespace().collect();
+            // Ten candidates (the first word is never deleted) at rate 0.3.
+            assert_eq!(remaining.len(), 8, "seed {seed}: {result}");
+            for (idx, word) in words.iter().enumerate() {
+                if !remaining.contains(&word.as_str()) {
+                    deleted_at[idx] += 1;
+                }
+            }
+        }
+        assert_eq!(deleted_at[0], 0);
+        let expected = trials as f64 * 0.3;
+        for (idx, count) in deleted_at.iter().enumerate().skip(1) {
+            let ratio = *count as f64 / expected;
+            assert!((0.9..=1.1).contains(&ratio), "position {idx} deleted {count} times");
+        }
+    }
+
     #[test]
     fn reduplication_inserts_duplicate_with_space() {
         let mut buffer = TextBuffer::from_owned("Hello world".to_string(), &[], &[]);
@@ -2587,12 +4466,71 @@ mod tests {
         let op = ReduplicateWordsOp {
             rate: 1.0,
             unweighted: false,
+            repeats: 1,
+            stutter: false,
+            separator: ReduplicationSeparator::Space,
         };
         op.apply(&mut buffer, &mut rng)
             .expect("reduplication works");
         assert_eq!(buffer.to_string(), "Hello Hello world world");
     }
 
+    #[test]
+    fn reduplication_honours_separator() {
+        let run = |text: &str, repeats: usize, separator: ReduplicationSeparator| {
+            let exclude = vec![regex::Regex::new("world").unwrap()];
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &exclude);
+            let mut rng = DeterministicRng::new(2072);
+            let op = ReduplicateWordsOp {
+                rate: 1.0,
+                unweighted: true,
+                repeats,
+                stutter: false,
+                separator,
+            };
+            op.apply(&mut buffer, &mut rng).expect("reduplication works");
+            buffer.to_string()
+        };
+        assert_eq!(run("yes world", 1, ReduplicationSeparator::Comma), "yes, yes world");
+        assert_eq!(run("(no!) world", 2, ReduplicationSeparator::Comma), "(no, no, no!) world");
+        assert_eq!(run("bye. world", 1, ReduplicationSeparator::Hyphen), "bye-bye. world");
+        assert_eq!(run("yes world", 1, ReduplicationSeparator::Space), "yes yes world");
+        assert_eq!(ReduplicationSeparator::parse(", "), Some(ReduplicationSeparator::Comma));
+        assert_eq!(ReduplicationSeparator::parse("dash"), None);
+    }
+
+    #[test]
+    fn reduplication_honours_repeat_count() {
+        let mut buffer = TextBuffer::from_owned("Hello, world".to_string(), &[], &[]);
+        let mut rng = DeterministicRng::new(151);
+        let op = ReduplicateWordsOp {
+            rate: 1.0,
+            unweighted: false,
+            repeats: 2,
+            stutter: false,
+            separator: ReduplicationSeparator::Space,
+        };
+        op.apply(&mut buffer, &mut rng)
+            .expect("reduplication works");
+        assert_eq!(buffer.to_string(), "Hello Hello Hello, world world world");
+    }
+
+    #[test]
+    fn stutter_prepends_leading_consonant_cluster() {
+        let mut buffer = TextBuffer::from_owned("ball Stop ART".to_string(), &[], &[]);
+        let mut rng = DeterministicRng::new(151);
+        let op = ReduplicateWordsOp {
+            rate: 1.0,
+            unweighted: false,
+            repeats: 1,
+            stutter: true,
+            separator: ReduplicationSeparator::Space,
+        };
+        op.apply(&mut buffer, &mut rng).expect("stutter works");
+        assert_eq!(buffer.to_string(), "b-b-ball St-st-Stop A-A-ART");
+        assert_eq!(buffer.word_count(), 3);
+    }
+
     #[test]
     fn swap_adjacent_words_swaps_cores() {
         let mut buffer = TextBuffer::from_owned("Alpha, beta! Gamma delta".to_string(), &[], &[]);
@@ -2617,6 +4555,136 @@ mod tests {
         assert_eq!(buffer.to_string(), original);
     }
 
+    #[test]
+    fn rushmore_combo_shuffles_mode_order_per_seed() {
+        let modes = vec![
+            RushmoreComboMode::Delete,
+            RushmoreComboMode::Duplicate,
+            RushmoreComboMode::Swap,
+        ];
+        let op = RushmoreComboOp::new(modes.clone(), None, None, None);
+        let order_for = |op: &RushmoreComboOp, seed: u64| -> Vec<RushmoreComboMode> {
+            op.execution_order(&mut DeterministicRng::new(seed))
+                .expect("ordering succeeds")
+                .into_iter()
+                .map(|(_, mode)| mode)
+                .collect()
+        };
+
+        // Without shuffling the declared order always wins.
+        assert!((0..16).all(|seed| order_for(&op, seed) == modes));
+
+        let shuffled = op.with_shuffle_modes(true);
+        assert_eq!(order_for(&shuffled, 5), order_for(&shuffled, 5));
+        let orders: Vec<Vec<RushmoreComboMode>> =
+            (0..16).map(|seed| order_for(&shuffled, seed)).collect();
+        assert!(
+            orders.iter().any(|order| order != &orders[0]),
+            "seeds should reorder the modes: {orders:?}"
+        );
+        assert!(orders.iter().all(|order| {
+            order.len() == modes.len() && modes.iter().all(|mode| order.contains(mode))
+        }));
+    }
+
+    #[test]
+    fn rushmore_combo_sub_ops_draw_from_independent_streams() {
+        let text = "One two three four five six seven eight nine ten eleven twelve";
+        let delete = DeleteRandomWordsOp {
+            rate: 0.4,
+            unweighted: true,
+            min_words: 0,
+            weighting: WordWeighting::Length,
+            preserve_spacing: false,
+        };
+        let run_combo = |swap_rate: f64, seed: u64| {
+            let op = RushmoreComboOp::new(
+                vec![RushmoreComboMode::Swap, RushmoreComboMode::Delete],
+                Some(delete),
+                None,
+                Some(SwapAdjacentWordsOp { rate: swap_rate }),
+            );
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            op.apply(&mut buffer, &mut DeterministicRng::new(seed))
+                .expect("combo succeeds");
+            buffer.to_string()
+        };
+        // Replays the combo by hand: one draw seeds the combo, then every
+        // sub-op runs on a stream derived from that seed and its mode index.
+        let run_by_hand = |swap_rate: f64, seed: u64| {
+            let combo_seed = DeterministicRng::new(seed)
+                .rand_index(usize::MAX)
+                .expect("seed draw succeeds") as u64;
+            let sub_rng = |name: &str, index: i128| {
+                DeterministicRng::new(derive_seed(i128::from(combo_seed), name, index))
+            };
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            SwapAdjacentWordsOp { rate: swap_rate }
+                .apply(&mut buffer, &mut sub_rng("swap", 0))
+                .expect("swap succeeds");
+            delete
+                .apply(&mut buffer, &mut sub_rng("delete", 1))
+                .expect("delete succeeds");
+            buffer.to_string()
+        };
+
+        for seed in [3u64, 151, 2088] {
+            for swap_rate in [0.0, 0.5, 1.0] {
+                assert_eq!(run_combo(swap_rate, seed), run_by_hand(swap_rate, seed));
+            }
+        }
+    }
+
+    #[test]
+    fn swap_words_with_unit_distance_matches_adjacent_swap() {
+        let text = "Alpha, beta! Gamma delta epsilon zeta eta theta";
+        for seed in [1u64, 7, 42, 151, 9001] {
+            let mut adjacent = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(seed);
+            SwapAdjacentWordsOp { rate: 0.5 }
+                .apply(&mut adjacent, &mut rng)
+                .expect("adjacent swap succeeds");
+
+            let mut distant = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(seed);
+            SwapWordsOp {
+                rate: 0.5,
+                max_distance: 1,
+            }
+            .apply(&mut distant, &mut rng)
+            .expect("swap succeeds");
+
+            assert_eq!(adjacent.to_string(), distant.to_string());
+        }
+    }
+
+    #[test]
+    fn swap_words_reaches_beyond_neighbours() {
+        let text = "one two three four five six seven eight nine ten";
+        let mut reached_far = false;
+        for seed in 0u64..32 {
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(seed);
+            SwapWordsOp {
+                rate: 1.0,
+                max_distance: 3,
+            }
+            .apply(&mut buffer, &mut rng)
+            .expect("swap succeeds");
+
+            let result = buffer.to_string();
+            let original: Vec<&str> = text.split(' ').collect();
+            for (position, word) in result.split(' ').enumerate() {
+                let source = original
+                    .iter()
+                    .position(|candidate| candidate == &word)
+                    .expect("swapping must only permute words");
+                reached_far |= source.abs_diff(position) > 1;
+            }
+        }
+        assert!(reached_far, "expected at least one swap across more than one position");
+    }
+
     #[test]
     fn delete_random_words_cleans_up_spacing() {
         let mut buffer = TextBuffer::from_owned("One two three four five".to_string(), &[], &[]);
@@ -2624,6 +4692,9 @@ mod tests {
         let op = DeleteRandomWordsOp {
             rate: 0.75,
             unweighted: false,
+            min_words: 0,
+            weighting: WordWeighting::Length,
+            preserve_spacing: false,
         };
         let original_words = buffer.to_string().split_whitespace().count();
         op.apply(&mut buffer, &mut rng).expect("deletion works");
@@ -2632,6 +4703,117 @@ mod tests {
         assert!(!result.contains("  "));
     }
 
+    #[test]
+    fn delete_random_words_preserves_line_breaks() {
+        const TEXT: &str = "The first paragraph ends here.\n\nA second one\nspans two lines.";
+        for seed in 0u64..16 {
+            let mut buffer = TextBuffer::from_owned(TEXT.to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(seed);
+            let op = DeleteRandomWordsOp {
+                rate: 0.5,
+                unweighted: true,
+                min_words: 0,
+                weighting: WordWeighting::Length,
+                preserve_spacing: false,
+            };
+            op.apply(&mut buffer, &mut rng).expect("deletion works");
+            let result = buffer.to_string();
+            assert!(result.split_whitespace().count() < TEXT.split_whitespace().count());
+            assert_eq!(result.matches("\n\n").count(), 1, "paragraph break lost: {result:?}");
+            // A deleted line may fold its break into the paragraph break
+            assert!(result.matches('\n').count() >= 2, "line break lost: {result:?}");
+        }
+    }
+
+    #[test]
+    fn delete_random_words_can_preserve_spacing() {
+        const TEXT: &str = "apple    12    red\npear     7     green\n  plum     30    blue";
+        for seed in 0u64..16 {
+            let mut buffer = TextBuffer::from_owned(TEXT.to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(seed);
+            let op = DeleteRandomWordsOp {
+                rate: 0.5,
+                unweighted: true,
+                min_words: 0,
+                weighting: WordWeighting::Length,
+                preserve_spacing: true,
+            };
+            op.apply(&mut buffer, &mut rng).expect("deletion works");
+            let result = buffer.to_string();
+            assert!(result.split_whitespace().count() < TEXT.split_whitespace().count());
+            // Every run that survives is one of the original runs, untouched
+            let runs: Vec<&str> = result.split(|c: char| !c.is_whitespace()).collect();
+            assert!(runs.iter().filter(|run| !run.is_empty()).all(|run| TEXT.contains(run)));
+            assert!(result.contains("    "), "spacing collapsed: {result:?}");
+        }
+    }
+
+    #[test]
+    fn delete_random_words_keeps_minimum_words() {
+        for seed in 0u64..16 {
+            let mut buffer =
+                TextBuffer::from_owned("One two three four five".to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(seed);
+            let op = DeleteRandomWordsOp {
+                rate: 1.0,
+                unweighted: false,
+                min_words: 2,
+                weighting: WordWeighting::Length,
+                preserve_spacing: false,
+            };
+            op.apply(&mut buffer, &mut rng).expect("deletion works");
+            assert!(buffer.to_string().split_whitespace().count() >= 2);
+        }
+    }
+
+    #[test]
+    fn content_weighting_spares_stopwords() {
+        const TEXT: &str = "alpha the beta of gamma and delta to epsilon in zeta a eta";
+        const STOPWORDS: [&str; 6] = ["the", "of", "and", "to", "in", "a"];
+        let stopword_share = |weighting: WordWeighting, redact: bool| {
+            let (mut stopwords, mut total) = (0usize, 0usize);
+            for seed in 0u64..64 {
+                let mut buffer = TextBuffer::from_owned(TEXT.to_string(), &[], &[]);
+                let mut rng = DeterministicRng::new(seed);
+                if redact {
+                    RedactWordsOp {
+                        replacement_char: "█".to_string(),
+                        rate: 0.25,
+                        merge_adjacent: false,
+                        merge_through_punctuation: false,
+                        fixed_width: None,
+                        unweighted: false,
+                        weighting,
+                    }
+                    .apply(&mut buffer, &mut rng)
+                    .expect("redaction works");
+                } else {
+                    let op = DeleteRandomWordsOp {
+                        rate: 0.25,
+                        unweighted: false,
+                        min_words: 0,
+                        weighting,
+                        preserve_spacing: false,
+                    };
+                    op.apply(&mut buffer, &mut rng).expect("deletion works");
+                }
+                let result = buffer.to_string();
+                let survivors: Vec<&str> = result.split_whitespace().collect();
+                let lost = |word: &str| !survivors.contains(&word);
+                stopwords += STOPWORDS.iter().filter(|word| lost(word)).count();
+                total += TEXT.split_whitespace().filter(|word| lost(word)).count();
+            }
+            stopwords as f64 / total as f64
+        };
+
+        for redact in [false, true] {
+            let content = stopword_share(WordWeighting::Content, redact);
+            assert!(content < 0.25, "content weighting picked {content:.2} stopwords");
+        }
+        // Inverse-length deletion favours short words, function words included.
+        assert!(stopword_share(WordWeighting::Length, false) > 0.5);
+    }
+
     #[test]
     fn redact_words_respects_sample_and_merge() {
         let mut buffer = TextBuffer::from_owned("Keep secrets safe".to_string(), &[], &[]);
@@ -2640,7 +4822,10 @@ mod tests {
             replacement_char: "█".to_string(),
             rate: 0.8,
             merge_adjacent: true,
+            merge_through_punctuation: false,
+            fixed_width: None,
             unweighted: false,
+            weighting: WordWeighting::Length,
         };
         op.apply(&mut buffer, &mut rng).expect("redaction works");
         let result = buffer.to_string();
@@ -2655,7 +4840,10 @@ mod tests {
             replacement_char: "█".to_string(),
             rate: 0.5,
             merge_adjacent: false,
+            merge_through_punctuation: false,
+            fixed_width: None,
             unweighted: false,
+            weighting: WordWeighting::Length,
         };
         let error = op.apply(&mut buffer, &mut rng).unwrap_err();
         match error {
@@ -2665,15 +4853,30 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "TODO: Update seed/expectations after deferred reindexing optimization"]
     fn ocr_artifacts_replaces_expected_regions() {
         let mut buffer = TextBuffer::from_owned("Hello rn world".to_string(), &[], &[]);
         let mut rng = DeterministicRng::new(151);
         let op = OcrArtifactsOp::new(1.0);
         op.apply(&mut buffer, &mut rng).expect("ocr works");
-        let text = buffer.to_string();
-        assert_ne!(text, "Hello rn world");
-        assert!(text.contains('m') || text.contains('h'));
+        assert_eq!(buffer.to_string(), "He1Io m vvorIcl");
+    }
+
+    #[test]
+    fn ocr_selection_ignores_segmentation() {
+        let op = OcrArtifactsOp::new(1.0);
+        let mut merged = TextBuffer::from_owned("x y".to_string(), &[], &[]);
+        merged.replace_segments_bulk(vec![(0, "Hello rn".to_string())]);
+        merged.reindex_if_needed();
+        assert_eq!(merged.segments().len(), 3);
+        let fresh = TextBuffer::from_owned("Hello rn y".to_string(), &[], &[]);
+
+        for seed in 0..16 {
+            let mut left = merged.clone();
+            let mut right = fresh.clone();
+            op.apply(&mut left, &mut DeterministicRng::new(seed)).expect("ocr works");
+            op.apply(&mut right, &mut DeterministicRng::new(seed)).expect("ocr works");
+            assert_eq!(left.to_string(), right.to_string(), "seed {seed}");
+        }
     }
 
     #[test]
@@ -2683,6 +4886,9 @@ mod tests {
         let op = ReduplicateWordsOp {
             rate: 0.5,
             unweighted: false,
+            repeats: 1,
+            stutter: false,
+            separator: ReduplicationSeparator::Space,
         };
         op.apply(&mut buffer, &mut rng)
             .expect("reduplication succeeds");
@@ -2706,6 +4912,9 @@ mod tests {
         let op = DeleteRandomWordsOp {
             rate: 0.5,
             unweighted: false,
+            min_words: 0,
+            weighting: WordWeighting::Length,
+            preserve_spacing: false,
         };
         let original_count = buffer.to_string().split_whitespace().count();
         op.apply(&mut buffer, &mut rng).expect("deletion succeeds");
@@ -2721,7 +4930,10 @@ mod tests {
             replacement_char: "█".to_string(),
             rate: 0.5,
             merge_adjacent: false,
+            merge_through_punctuation: false,
+            fixed_width: None,
             unweighted: false,
+            weighting: WordWeighting::Length,
         };
         op.apply(&mut buffer, &mut rng).expect("redaction succeeds");
         let result = buffer.to_string();
@@ -2737,7 +4949,10 @@ mod tests {
             replacement_char: "█".to_string(),
             rate: 1.0,
             merge_adjacent: true,
+            merge_through_punctuation: false,
+            fixed_width: None,
             unweighted: false,
+            weighting: WordWeighting::Length,
         };
         op.apply(&mut buffer, &mut rng).expect("redaction succeeds");
         let result = buffer.to_string();
@@ -2745,6 +4960,57 @@ mod tests {
         assert!(result.chars().all(|ch| ch == '█'));
     }
 
+    #[test]
+    fn redact_merge_through_punctuation_absorbs_inner_punctuation() {
+        let redact = |text: &str, merge_through_punctuation: bool| {
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            RedactWordsOp {
+                replacement_char: "█".to_string(),
+                rate: 1.0,
+                merge_adjacent: true,
+                merge_through_punctuation,
+                fixed_width: None,
+                unweighted: false,
+                weighting: WordWeighting::Length,
+            }
+            .apply(&mut buffer, &mut DeterministicRng::new(7))
+            .expect("redaction succeeds");
+            buffer.to_string()
+        };
+
+        assert_eq!(redact("foo, bar", false), "███, ███");
+        assert_eq!(redact("foo, bar", true), "███████");
+        // Punctuation outside the merged block stays put.
+        assert_eq!(redact("(foo; bar) baz.", true), "(███████████.");
+        assert_eq!(redact("(foo; bar) baz.", false), "(███; ███) ███.");
+    }
+
+    #[test]
+    fn redact_fixed_width_hides_word_lengths() {
+        let redact = |text: &str, fixed_width: Option<usize>| {
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            RedactWordsOp {
+                replacement_char: "█".to_string(),
+                rate: 1.0,
+                merge_adjacent: false,
+                merge_through_punctuation: false,
+                fixed_width,
+                unweighted: false,
+                weighting: WordWeighting::Length,
+            }
+            .apply(&mut buffer, &mut DeterministicRng::new(3))
+            .expect("redaction succeeds");
+            buffer.to_string()
+        };
+
+        assert_eq!(redact("a", Some(5)), "█████");
+        assert_eq!(redact("antidisestablishmentarianism", Some(5)), "█████");
+        assert_eq!(redact("a, bb.", Some(3)), "███, ███.");
+        // A zero width still leaves a visible block behind.
+        assert_eq!(redact("word", Some(0)), "█");
+        assert_eq!(redact("word", None), "████");
+    }
+
     #[test]
     fn ocr_produces_consistent_results_for_seed() {
         let mut buffer = TextBuffer::from_owned("The m rn".to_string(), &[], &[]);
@@ -2755,4 +5021,508 @@ mod tests {
         assert_ne!(result, "The m rn");
         assert!(result.contains('r'));
     }
+
+    #[test]
+    fn ocr_applies_extra_confusions() {
+        let text = "quiet quay quota";
+        let run = |op: &OcrArtifactsOp| {
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(2062);
+            op.apply(&mut buffer, &mut rng).expect("ocr succeeds");
+            buffer.to_string()
+        };
+
+        assert_eq!(run(&OcrArtifactsOp::new(1.0)), text);
+        let scanner = OcrArtifactsOp::new(1.0)
+            .with_extra_confusions(vec![("q".to_string(), vec!["g".to_string()])]);
+        assert_eq!(run(&scanner), "guiet guay guota");
+
+        // A deserialised op rebuilds its table from the stored extras.
+        let partial = OcrArtifactsOp::new(0.5)
+            .with_extra_confusions(vec![("q".to_string(), vec!["g".to_string()])]);
+        let mut restored = partial.clone();
+        restored.confusions = None;
+        assert_eq!(run(&restored), run(&partial));
+    }
+
+    #[test]
+    fn ocr_mode_restricts_confusion_direction() {
+        let text = "modern rnarvel";
+        let run = |mode: OcrMode| {
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(2063);
+            OcrArtifactsOp::new(1.0)
+                .with_mode(mode)
+                .apply(&mut buffer, &mut rng)
+                .expect("ocr succeeds");
+            buffer.to_string()
+        };
+
+        let expanded = run(OcrMode::Expand);
+        assert!(expanded.chars().count() > text.chars().count());
+        assert!(expanded.contains("rnarvel"));
+        let contracted = run(OcrMode::Contract);
+        assert!(contracted.chars().count() < text.chars().count());
+        assert!(contracted.starts_with("modern") || contracted.starts_with("modem"));
+        assert_eq!(OcrMode::parse("Expand"), Some(OcrMode::Expand));
+        assert_eq!(OcrMode::parse("sideways"), None);
+    }
+
+    #[test]
+    fn ocr_bernoulli_sampling_varies_the_edit_count() {
+        // "Z" is the only confusion source here, so there are two candidates.
+        let text = "Z Z";
+        let edits = |sampling: OcrSampling, rate: f64, seed: u64| {
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(seed);
+            OcrArtifactsOp::new(rate)
+                .with_sampling(sampling)
+                .apply(&mut buffer, &mut rng)
+                .expect("ocr succeeds");
+            buffer.to_string().matches('2').count()
+        };
+
+        let mut seen = [false; 3];
+        for seed in 0..64 {
+            seen[edits(OcrSampling::Bernoulli, 0.5, seed)] = true;
+            assert!(edits(OcrSampling::Count, 0.5, seed) <= 1);
+            assert_eq!(edits(OcrSampling::Count, 0.4, seed), 0);
+        }
+        assert_eq!(seen, [true; 3]);
+        assert_eq!(edits(OcrSampling::Bernoulli, 1.0, 0), 2);
+        assert_eq!(OcrSampling::parse("Bernoulli"), Some(OcrSampling::Bernoulli));
+        assert_eq!(OcrSampling::parse("poisson"), None);
+    }
+
+    #[test]
+    fn quote_pairs_curl_contractions_without_breaking_pairs() {
+        let text = "'Don't,' she said. It's Jo's café's 'key'.";
+        let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+        let mut rng = DeterministicRng::new(7);
+        QuotePairsOp { contractions: true }
+            .apply(&mut buffer, &mut rng)
+            .expect("quote pairing succeeds");
+        let result = buffer.to_string();
+        assert!(result.contains("Don\u{2019}t"));
+        assert!(result.contains("It\u{2019}s Jo\u{2019}s café\u{2019}s"));
+        assert!(!result.contains('\''));
+
+        let mut plain = TextBuffer::from_owned("It's fine".to_string(), &[], &[]);
+        QuotePairsOp::default()
+            .apply(&mut plain, &mut rng)
+            .expect("quote pairing succeeds");
+        assert_eq!(plain.to_string(), "It's fine");
+    }
+
+    #[test]
+    fn nested_quotes_use_outer_and_inner_styles() {
+        let text = "\"He said 'hi' to me\" and 'she said \"bye\"'";
+        let mut outer_primary = false;
+        for seed in 0u64..32 {
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(seed);
+            QuotePairsOp::default()
+                .apply(&mut buffer, &mut rng)
+                .expect("quote pairing succeeds");
+            let result = buffer.to_string();
+            if result.starts_with('\u{201C}') {
+                outer_primary = true;
+                assert!(result.contains("\u{2018}hi\u{2019}"), "{result}");
+                assert!(result.contains(" to me\u{201D}"), "{result}");
+            }
+            if result.contains("\u{2018}she said") {
+                assert!(result.contains("\u{201C}bye\u{201D}\u{2019}"), "{result}");
+            }
+        }
+        assert!(outer_primary);
+    }
+
+    /// Degenerate and hostile inputs every operation must survive: empty and
+    /// whitespace-only text, lone control and format characters, orphaned
+    /// combining marks, multi-codepoint emoji, RTL and CJK scripts.
+    const ADVERSARIAL_INPUTS: &[&str] = &[
+        "",
+        " ",
+        "\n\n",
+        "\r\n",
+        "\t \t",
+        "\0",
+        "\u{7}\u{1B}[0m\u{7F}",
+        "\u{FEFF}",
+        "\u{200B}\u{200D}",
+        "\u{0301}\u{0301}\u{0301}",
+        "a\u{0301} e\u{0308}",
+        "...",
+        "\"'",
+        "🇯🇵🇫🇷",
+        "👨\u{200D}👩\u{200D}👧 👍🏽!",
+        "שלום עולם",
+        "\u{202E}abc\u{202C}",
+        "日本語のテキスト",
+        "x",
+        "Pneumonoultramicroscopicsilicovolcanoconiosis",
+        "It's  \"fine\", (really)\u{00A0}- ok?\n\nNext—line…",
+    ];
+
+    /// Runs `operation` over [`ADVERSARIAL_INPUTS`] with several seeds.
+    ///
+    /// Errors such as [`OperationError::NoRedactableWords`] are acceptable
+    /// outcomes; panics are not, and any output must be deterministic per seed
+    /// and reparse into a buffer that renders back to the same string.
+    fn assert_survives_adversarial_inputs(name: &str, operation: &super::Operation) {
+        for &input in ADVERSARIAL_INPUTS {
+            for seed in [0u64, 1, 2074] {
+                let run = || {
+                    let mut buffer = TextBuffer::from_owned(input.to_string(), &[], &[]);
+                    let mut rng = DeterministicRng::new(seed);
+                    operation
+                        .apply(&mut buffer, &mut rng)
+                        .map(|_| buffer.to_string())
+                };
+                let Ok(output) = run() else {
+                    continue;
+                };
+                let repeat = run().ok();
+                assert_eq!(repeat.as_ref(), Some(&output), "{name} is not deterministic on {input:?}");
+                let reparsed = TextBuffer::from_owned(output.clone(), &[], &[]);
+                assert_eq!(reparsed.to_string(), output, "{name} produced unparseable {output:?}");
+            }
+        }
+    }
+
+    /// One fully-enabled instance of every [`super::Operation`] variant (and
+    /// each mode of the moded ops), for harnesses that must cover them all.
+    fn every_operation() -> Vec<(&'static str, super::Operation)> {
+        use super::{
+            KeyboardLayout, MotorWeighting, Operation, RushmoreComboMode, RushmoreComboOp,
+            ZeroWidthOp,
+        };
+        use crate::autocorrect::AutocorrectOp;
+        use crate::bidi::BidiOp;
+        use crate::case::{CaseMode, CaseOp};
+        use crate::contractions::{ContractionMode, ContractionOp};
+        use crate::diacritics::{DiacriticMode, DiacriticOp};
+        use crate::disemvowel::DisemvowelOp;
+        use crate::double_words::DoubleWordOp;
+        use crate::fillers::FillerOp;
+        use crate::grammar_rules::{list_pedant_stones, GrammarRuleOp};
+        use crate::homoglyphs::{ClassSelection, HomoglyphOp};
+        use crate::homophones::{HomophoneOp, HomophoneWeighting};
+        use crate::lexeme_substitution::{JargoyleMode, LexemeSubstitutionOp};
+        use crate::phonetic::PhoneticOp;
+        use crate::punctuation::DropPunctuationOp;
+        use crate::scramble::ScrambleOp;
+        use crate::sentences::{DeleteSentencesOp, ShuffleSentencesOp};
+        use crate::whitespace::WhitespaceJitterOp;
+        use crate::word_boundaries::{MergeWordsOp, SplitWordsOp};
+        use crate::word_stretching::WordStretchOp;
+        use crate::zalgo::ZalgoOp;
+
+        let delete = DeleteRandomWordsOp {
+            rate: 1.0,
+            unweighted: false,
+            min_words: 0,
+            weighting: WordWeighting::Length,
+            preserve_spacing: false,
+        };
+        let duplicate = ReduplicateWordsOp {
+            rate: 1.0,
+            unweighted: false,
+            repeats: 2,
+            stutter: true,
+            separator: ReduplicationSeparator::Hyphen,
+        };
+        let swap = SwapAdjacentWordsOp { rate: 1.0 };
+        let layout: HashMap<String, Vec<String>> = [("a", "qs"), ("e", "wr"), ("t", "ry")]
+            .into_iter()
+            .map(|(key, neighbours)| {
+                (key.to_string(), neighbours.chars().map(String::from).collect())
+            })
+            .collect();
+
+        let mut operations = vec![
+            ("reduplicate", Operation::Reduplicate(duplicate)),
+            ("delete", Operation::Delete(delete)),
+            ("swap_adjacent", Operation::SwapAdjacent(swap)),
+            ("swap_words", Operation::SwapWords(SwapWordsOp { rate: 1.0, max_distance: 3 })),
+            ("delete_sentences", Operation::DeleteSentences(DeleteSentencesOp { rate: 1.0 })),
+            ("shuffle_sentences", Operation::ShuffleSentences(ShuffleSentencesOp { rate: 1.0 })),
+            ("phonetic", Operation::Phonetic(PhoneticOp { rate: 1.0 })),
+            ("bidi", Operation::Bidi(BidiOp { rate: 1.0 })),
+            (
+                "fillers",
+                Operation::Fillers(FillerOp::new(1.0, None).unwrap().with_commas(true)),
+            ),
+            (
+                "whitespace_jitter",
+                Operation::WhitespaceJitter(WhitespaceJitterOp::new(1.0, None).unwrap()),
+            ),
+            ("autocorrect", Operation::Autocorrect(AutocorrectOp { rate: 1.0 })),
+            ("merge_words", Operation::MergeWords(MergeWordsOp { rate: 1.0 })),
+            ("split_words", Operation::SplitWords(SplitWordsOp { rate: 1.0, min_length: 4 })),
+            ("zalgo", Operation::Zalgo(ZalgoOp { intensity: 3, rate: 1.0 })),
+            (
+                "rushmore",
+                Operation::RushmoreCombo(RushmoreComboOp::new(
+                    vec![
+                        RushmoreComboMode::Delete,
+                        RushmoreComboMode::Duplicate,
+                        RushmoreComboMode::Swap,
+                    ],
+                    Some(delete),
+                    Some(duplicate),
+                    Some(swap),
+                )),
+            ),
+            (
+                "redact",
+                Operation::Redact(RedactWordsOp {
+                    replacement_char: "█".to_string(),
+                    rate: 1.0,
+                    merge_adjacent: true,
+                    merge_through_punctuation: true,
+                    fixed_width: None,
+                    unweighted: false,
+                    weighting: WordWeighting::Length,
+                }),
+            ),
+            ("ocr", Operation::Ocr(OcrArtifactsOp::new(1.0))),
+            (
+                "mimic",
+                Operation::Mimic(HomoglyphOp::new(1.0, ClassSelection::All, Vec::new())),
+            ),
+            (
+                "zero_width",
+                Operation::ZeroWidth(ZeroWidthOp::new(
+                    1.0,
+                    vec!["\u{200B}".to_string(), "\u{2060}".to_string()],
+                )),
+            ),
+            (
+                "jargoyle",
+                Operation::Jargoyle(LexemeSubstitutionOp::new("synonyms", JargoyleMode::Drift, 1.0)),
+            ),
+            ("quote_pairs", Operation::QuotePairs(QuotePairsOp { contractions: true })),
+            (
+                "hokey",
+                Operation::Hokey(WordStretchOp {
+                    rate: 1.0,
+                    extension_min: 1,
+                    extension_max: 4,
+                    word_length_threshold: 6,
+                    base_p: 0.5,
+                }),
+            ),
+            (
+                "wherewolf",
+                Operation::Wherewolf(HomophoneOp::new(1.0, HomophoneWeighting::Flat)),
+            ),
+        ];
+        for mode in [CaseMode::Random, CaseMode::Alternating, CaseMode::RandomWords] {
+            operations.push(("case", Operation::Case(CaseOp { rate: 1.0, mode })));
+        }
+        for mode in [ContractionMode::Contract, ContractionMode::Expand] {
+            let op = ContractionOp { rate: 1.0, mode };
+            operations.push(("contractions", Operation::Contractions(op)));
+        }
+        operations.push(("disemvowel", Operation::Disemvowel(DisemvowelOp { rate: 1.0 })));
+        operations.push(("double_words", Operation::DoubleWords(DoubleWordOp { rate: 1.0 })));
+        operations.push(("scramble", Operation::Scramble(ScrambleOp { rate: 1.0 })));
+        for lowercase_sentence_starts in [false, true] {
+            operations.push((
+                "drop_punctuation",
+                Operation::DropPunctuation(DropPunctuationOp {
+                    rate: 1.0,
+                    lowercase_sentence_starts,
+                }),
+            ));
+        }
+        for mode in [DiacriticMode::Strip, DiacriticMode::Add] {
+            operations.push(("diacritics", Operation::Diacritics(DiacriticOp { rate: 1.0, mode })));
+        }
+        let typo_variants = [
+            (false, 0.0, 0.0, false),
+            (true, 0.0, 0.0, false),
+            (false, 0.5, 2.0, true),
+            (true, 0.5, 2.0, true),
+        ];
+        for (grapheme_aware, burstiness, homerow_bias, syllable_swaps) in typo_variants {
+            operations.push((
+                "typo",
+                Operation::Typo(TypoOp {
+                    rate: 1.0,
+                    layout: layout.clone(),
+                    shift_slip: None,
+                    motor_weighting: MotorWeighting::Uniform,
+                    grapheme_aware,
+                    burstiness,
+                    homerow_bias,
+                    keyboard: KeyboardLayout::Qwerty,
+                    syllable_swaps,
+                }),
+            ));
+        }
+        for stone in list_pedant_stones() {
+            let op = GrammarRuleOp::try_from_name(2074, stone).expect("bundled stones resolve");
+            operations.push(("pedant", Operation::Pedant(op)));
+        }
+        operations.push(("identity", Operation::Identity));
+
+        operations
+    }
+
+    #[test]
+    fn every_operation_survives_adversarial_inputs() {
+        for (name, operation) in &every_operation() {
+            assert_survives_adversarial_inputs(name, operation);
+        }
+    }
+
+    #[test]
+    fn every_operation_validates_under_a_documented_kind() {
+        for (name, operation) in &every_operation() {
+            assert_eq!(operation.validate(), Ok(()), "{name}");
+            assert!(
+                crate::schema::OPERATION_SPECS
+                    .iter()
+                    .any(|spec| spec.op_type == operation.kind()),
+                "{name} has undocumented kind {}",
+                operation.kind()
+            );
+        }
+    }
+
+    #[test]
+    fn validation_names_the_out_of_range_field() {
+        use super::Operation;
+        use crate::word_stretching::WordStretchOp;
+
+        let swap = Operation::SwapAdjacent(SwapAdjacentWordsOp { rate: 2.5 });
+        assert_eq!(swap.validate(), Err("rate must be in [0, 1], got 2.5".to_string()));
+        let swap = Operation::SwapAdjacent(SwapAdjacentWordsOp { rate: f64::NAN });
+        assert!(swap.validate().is_err());
+
+        let mut hokey = WordStretchOp {
+            rate: 0.5,
+            extension_min: -1,
+            extension_max: 5,
+            word_length_threshold: 6,
+            base_p: 0.45,
+        };
+        let error = Operation::Hokey(hokey.clone()).validate().unwrap_err();
+        assert!(error.starts_with("extension_min must be ≥ 0"), "{error}");
+        hokey.extension_min = 6;
+        let error = Operation::Hokey(hokey).validate().unwrap_err();
+        assert!(error.starts_with("extension_max"), "{error}");
+
+        let combo = RushmoreComboOp::new(
+            vec![RushmoreComboMode::Swap],
+            None,
+            None,
+            Some(SwapAdjacentWordsOp { rate: -0.1 }),
+        );
+        let error = Operation::RushmoreCombo(combo).validate().unwrap_err();
+        assert!(error.starts_with("swap rate"), "{error}");
+    }
+
+    /// Builds a random text of words, punctuation and `[[...]]` protected
+    /// spans, returning it together with the protected spans in order.
+    fn text_with_protected_spans(rng: &mut DeterministicRng) -> (String, Vec<String>) {
+        const WORDS: &[&str] = &[
+            "the", "quick", "brown", "fox", "their", "there", "colour", "isn't", "café",
+            "jumped", "over", "lazy", "dogs.", "Hello,", "world!", "\"quoted\"", "42",
+        ];
+        const SEPARATORS: &[&str] = &[" ", " ", " ", "  ", "\n", ". "];
+        const PROTECTED: &[&str] = &[
+            "[[KEEP]]", "[[the fox]]", "[[café, there]]", "[[isn't 42]]", "[[x]]",
+        ];
+
+        let mut text = String::new();
+        let mut protected = Vec::new();
+        let tokens = 1 + rng.rand_index(24).unwrap();
+        for position in 0..tokens {
+            if position > 0 {
+                text.push_str(SEPARATORS[rng.rand_index(SEPARATORS.len()).unwrap()]);
+            }
+            if rng.random() < 0.25 {
+                let span = PROTECTED[rng.rand_index(PROTECTED.len()).unwrap()];
+                text.push_str(span);
+                protected.push(span.to_string());
+            } else {
+                text.push_str(WORDS[rng.rand_index(WORDS.len()).unwrap()]);
+            }
+        }
+        (text, protected)
+    }
+
+    #[test]
+    fn every_operation_preserves_immutable_segments() {
+        let exclude = vec![regex::Regex::new(r"\[\[[^\]]*\]\]").unwrap()];
+        let operations = every_operation();
+        let mut cases = DeterministicRng::new(2075);
+        for _ in 0..64 {
+            let (text, protected) = text_with_protected_spans(&mut cases);
+            for (name, operation) in &operations {
+                let mut buffer = TextBuffer::from_owned(text.clone(), &[], &exclude);
+                let mut rng = DeterministicRng::new(cases.rand_index(1 << 16).unwrap() as u64);
+                if operation.apply(&mut buffer, &mut rng).is_err() {
+                    continue;
+                }
+                let output = buffer.to_string();
+                let mut rest = output.as_str();
+                for span in &protected {
+                    let Some(found) = rest.find(span.as_str()) else {
+                        panic!("{name} altered {span:?}: {text:?} -> {output:?}");
+                    };
+                    rest = &rest[found + span.len()..];
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zero_edit_outcomes_leave_the_text_untouched() {
+        let operations = every_operation();
+        let mut cases = DeterministicRng::new(2082);
+        for _ in 0..64 {
+            let (text, _) = text_with_protected_spans(&mut cases);
+            for (name, operation) in &operations {
+                let mut buffer = TextBuffer::from_owned(text.clone(), &[], &[]);
+                let mut rng = DeterministicRng::new(cases.rand_index(1 << 16).unwrap() as u64);
+                let Ok(outcome) = operation.apply(&mut buffer, &mut rng) else {
+                    continue;
+                };
+                if outcome.is_unchanged() {
+                    assert_eq!(buffer.to_string(), text, "{name} reported no edits");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn edit_counts_match_the_edits_made() {
+        let mut buffer = TextBuffer::from_owned("one two three four".to_string(), &[], &[]);
+        let outcome = ReduplicateWordsOp {
+            rate: 1.0,
+            unweighted: true,
+            repeats: 1,
+            stutter: false,
+            separator: ReduplicationSeparator::Space,
+        }
+        .apply(&mut buffer, &mut DeterministicRng::new(7))
+        .expect("reduplication succeeds");
+        assert_eq!(outcome, OpOutcome::edits(4));
+
+        let mut buffer = TextBuffer::from_owned("alpha beta gamma delta".to_string(), &[], &[]);
+        let outcome = SwapAdjacentWordsOp { rate: 1.0 }
+            .apply(&mut buffer, &mut DeterministicRng::new(7))
+            .expect("swap succeeds");
+        assert_eq!(outcome.edits, 2);
+
+        let mut buffer = TextBuffer::from_owned("no change here".to_string(), &[], &[]);
+        let outcome = SwapAdjacentWordsOp { rate: 0.0 }
+            .apply(&mut buffer, &mut DeterministicRng::new(7))
+            .expect("swap succeeds");
+        assert!(outcome.is_unchanged());
+    }
 }
@@ -0,0 +1,314 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::homophones::apply_casing;
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::resources::split_affixes_ref;
+use crate::text_buffer::TextBuffer;
+
+/// Spelling rewrites tried when respelling a word. A rewrite is only kept when
+/// the result shares the original's Metaphone key, so rules that would change
+/// the pronunciation of a particular word are filtered out automatically.
+const RESPELLINGS: &[(&str, &str)] = &[
+    ("ph", "f"),
+    ("f", "ph"),
+    ("tion", "shun"),
+    ("sion", "shun"),
+    ("ck", "k"),
+    ("c", "k"),
+    ("ght", "t"),
+    ("dge", "j"),
+    ("x", "ks"),
+    ("ce", "se"),
+    ("s", "z"),
+    ("z", "s"),
+    ("ee", "ea"),
+    ("ea", "ee"),
+    ("oo", "u"),
+    ("ai", "ay"),
+    ("ay", "ai"),
+    ("wr", "r"),
+    ("kn", "n"),
+];
+
+/// Suffix-only rewrites ("happy" → "happie", "table" → "tabel").
+const SUFFIX_RESPELLINGS: &[(&str, &str)] = &[("y", "ie"), ("le", "el"), ("ed", "t")];
+
+#[inline]
+const fn is_vowel(ch: u8) -> bool {
+    matches!(ch, b'A' | b'E' | b'I' | b'O' | b'U')
+}
+
+/// Computes the original (Philips, 1990) Metaphone key for an ASCII word.
+///
+/// Non-alphabetic characters are ignored. `0` encodes "th" and `X` encodes
+/// "sh"/"ch" as in the reference algorithm.
+pub(crate) fn metaphone(word: &str) -> String {
+    let letters: Vec<u8> = word
+        .bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .map(|byte| byte.to_ascii_uppercase())
+        .collect();
+    if letters.is_empty() {
+        return String::new();
+    }
+
+    let at = |index: usize| letters.get(index).copied().unwrap_or(0);
+    let mut key = String::with_capacity(letters.len());
+    let mut start = 0usize;
+
+    match (at(0), at(1)) {
+        (b'A', b'E') | (b'G', b'N') | (b'K', b'N') | (b'P', b'N') | (b'W', b'R') => start = 1,
+        (b'X', _) => {
+            key.push('S');
+            start = 1;
+        }
+        (b'W', b'H') => {
+            key.push('W');
+            start = 2;
+        }
+        _ => {}
+    }
+
+    let last = letters.len() - 1;
+    for (index, &current) in letters.iter().enumerate().skip(start) {
+        let prev = if index > 0 { at(index - 1) } else { 0 };
+        let next = at(index + 1);
+        let after = at(index + 2);
+
+        if current == prev && current != b'C' {
+            continue;
+        }
+
+        match current {
+            b'A' | b'E' | b'I' | b'O' | b'U' => {
+                if index == 0 {
+                    key.push(current as char);
+                }
+            }
+            b'B' => {
+                if !(index == last && prev == b'M') {
+                    key.push('B');
+                }
+            }
+            b'C' => {
+                if next == b'I' && after == b'A' {
+                    key.push('X');
+                } else if next == b'H' {
+                    key.push(if prev == b'S' { 'K' } else { 'X' });
+                } else if matches!(next, b'I' | b'E' | b'Y') {
+                    if prev != b'S' {
+                        key.push('S');
+                    }
+                } else {
+                    key.push('K');
+                }
+            }
+            b'D' => {
+                if next == b'G' && matches!(after, b'E' | b'I' | b'Y') {
+                    key.push('J');
+                } else {
+                    key.push('T');
+                }
+            }
+            b'G' => {
+                let silent_gh = next == b'H' && index + 1 != last && !is_vowel(after);
+                let silent_gn = next == b'N'
+                    && (index + 1 == last || (after == b'E' && at(index + 3) == b'D'));
+                let after_d = prev == b'D' && matches!(next, b'E' | b'I' | b'Y');
+                if silent_gh || silent_gn || after_d {
+                    continue;
+                }
+                if matches!(next, b'I' | b'E' | b'Y') {
+                    key.push('J');
+                } else {
+                    key.push('K');
+                }
+            }
+            b'H' => {
+                let after_modifier = matches!(prev, b'C' | b'S' | b'P' | b'T' | b'G');
+                if after_modifier || (is_vowel(prev) && !is_vowel(next)) {
+                    continue;
+                }
+                key.push('H');
+            }
+            b'K' => {
+                if prev != b'C' {
+                    key.push('K');
+                }
+            }
+            b'P' => key.push(if next == b'H' { 'F' } else { 'P' }),
+            b'Q' => key.push('K'),
+            b'S' => {
+                if next == b'H' || (next == b'I' && matches!(after, b'O' | b'A')) {
+                    key.push('X');
+                } else {
+                    key.push('S');
+                }
+            }
+            b'T' => {
+                if next == b'I' && matches!(after, b'O' | b'A') {
+                    key.push('X');
+                } else if next == b'H' {
+                    key.push('0');
+                } else if !(next == b'C' && after == b'H') {
+                    key.push('T');
+                }
+            }
+            b'V' => key.push('F'),
+            b'W' | b'Y' => {
+                if is_vowel(next) {
+                    key.push(current as char);
+                }
+            }
+            b'X' => key.push_str("KS"),
+            b'Z' => key.push('S'),
+            other => key.push(other as char),
+        }
+    }
+
+    key
+}
+
+/// Returns every distinct respelling of `lowered` that keeps its Metaphone key.
+fn respellings(lowered: &str) -> Vec<String> {
+    let target = metaphone(lowered);
+    let mut variants: Vec<String> = Vec::new();
+    let mut consider = |candidate: String| {
+        if candidate != lowered && !variants.contains(&candidate) && metaphone(&candidate) == target
+        {
+            variants.push(candidate);
+        }
+    };
+
+    for (from, to) in RESPELLINGS {
+        if lowered.contains(from) {
+            consider(lowered.replace(from, to));
+        }
+    }
+    for (from, to) in SUFFIX_RESPELLINGS {
+        if let Some(stem) = lowered.strip_suffix(from) {
+            if !stem.is_empty() {
+                consider(format!("{stem}{to}"));
+            }
+        }
+    }
+
+    variants
+}
+
+/// Respells words phonetically ("phone" → "fone", "nation" → "nashun").
+///
+/// Candidate spellings come from a small rule set and are accepted only when
+/// they share the original word's Metaphone key.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PhoneticOp {
+    pub rate: f64,
+}
+
+impl TextOperation for PhoneticOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx) else {
+                continue;
+            };
+            if !segment.is_mutable() {
+                continue;
+            }
+
+            let (prefix, core, suffix) = split_affixes_ref(segment.text());
+            if core.len() < 2 || !core.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+                continue;
+            }
+
+            let variants = respellings(&core.to_ascii_lowercase());
+            if variants.is_empty() {
+                continue;
+            }
+
+            if rng.random()? >= rate * segment.rate_scale() {
+                continue;
+            }
+
+            let choice = &variants[rng.rand_index(variants.len())?];
+            let replacement = format!("{prefix}{}{suffix}", apply_casing(core, choice));
+            replacements.push((idx, replacement));
+        }
+
+        let edits = replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_words_bulk(replacements)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, seed=None, return_count=false))]
+pub(crate) fn phonetic(
+    text: &str,
+    rate: f64,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = PhoneticOp { rate };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{metaphone, respellings, PhoneticOp};
+    use crate::operations::TextOperation;
+    use crate::rng::DeterministicRng;
+    use crate::text_buffer::TextBuffer;
+
+    #[test]
+    fn metaphone_matches_reference_keys() {
+        assert_eq!(metaphone("thumb"), "0M");
+        assert_eq!(metaphone("knight"), "NT");
+        assert_eq!(metaphone("phone"), "FN");
+        assert_eq!(metaphone("nation"), "NXN");
+        assert_eq!(metaphone("judge"), "JJ");
+        assert_eq!(metaphone("science"), "SNS");
+    }
+
+    #[test]
+    fn respellings_preserve_the_metaphone_key() {
+        let variants = respellings("nation");
+        assert!(variants.contains(&"nashun".to_string()));
+        assert!(respellings("phone").contains(&"fone".to_string()));
+        // "city" → "kity" changes the key and must be rejected.
+        assert!(!respellings("city").contains(&"kity".to_string()));
+    }
+
+    #[test]
+    fn phonetic_op_respects_affixes_and_casing() {
+        let mut buffer = TextBuffer::from_owned("\"Phone\" the NATION!".to_string(), &[], &[]);
+        let mut rng = DeterministicRng::new(151);
+        PhoneticOp { rate: 1.0 }
+            .apply(&mut buffer, &mut rng)
+            .expect("respelling succeeds");
+        let result = buffer.to_string();
+        assert!(result.starts_with('"'));
+        assert!(result.ends_with('!'));
+        assert_ne!(result, "\"Phone\" the NATION!");
+        for word in result.split_whitespace() {
+            let core: String = word.chars().filter(char::is_ascii_alphabetic).collect();
+            assert!(
+                core.chars().all(|ch| ch.is_uppercase())
+                    || core.chars().skip(1).all(|ch| ch.is_lowercase())
+            );
+        }
+    }
+}
@@ -3,25 +3,189 @@ use pyo3::prelude::*;
 use pyo3::PyErr;
 use rayon::prelude::*;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, Write};
+use std::ops::Range;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use crate::operations::{TextOperation, OperationError, Operation};
+use crate::metrics::TextMetric;
+use crate::operations::{OpOutcome, TextOperation, OperationError, OperationRng, Operation};
+#[cfg(debug_assertions)]
+use crate::operations::{RngDraw, TracingRng};
+use crate::resources::split_with_separators;
 use crate::rng::DeterministicRng;
 use crate::text_buffer::TextBuffer;
 
 /// Descriptor describing an operation to run as part of the pipeline.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OperationDescriptor {
     pub name: String,
+    /// Seeds a fresh RNG for this operation alone, so its draws never depend
+    /// on how many draws earlier operations made.
     pub seed: u64,
     pub operation: Operation,
 }
 
+/// Measurements for a single operation captured by [`Pipeline::run_instrumented`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpReport {
+    pub name: String,
+    /// Number of edits the op reported applying.
+    pub edits: usize,
+    /// Change in character count (negative when the op removed text).
+    pub char_delta: isize,
+    /// Change in word-segment count.
+    pub word_delta: isize,
+    pub elapsed: Duration,
+}
+
+/// Per-operation draw logs produced by [`Pipeline::run_with_rng_trace`].
+#[cfg(debug_assertions)]
+pub type RngTrace = Vec<(String, Vec<RngDraw>)>;
+
+/// An edit an operation would make, as reported by [`Pipeline::plan_edits`].
+///
+/// `range` is a character range into the text the operation receives, i.e.
+/// after every earlier operation in the pipeline has run, not into the
+/// pipeline's input. `op_index` is the operation's position in the pipeline,
+/// so edits can be replayed one intermediate text at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedEdit {
+    pub op_index: usize,
+    pub name: String,
+    pub range: Range<usize>,
+    pub original: String,
+    pub replacement: String,
+}
+
+/// Scales every operation's rate over a character range of the input, as
+/// consumed by [`Pipeline::run_with_rate_regions`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateRegion {
+    pub range: Range<usize>,
+    pub multiplier: f64,
+}
+
+/// Where [`Pipeline::run_streaming`] cuts its input into chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChunkBoundary {
+    /// Every line (with its terminator) is its own chunk.
+    #[default]
+    Line,
+    /// Paragraphs: a chunk runs up to and including the blank lines that
+    /// follow it. Blank lines before the first paragraph join it.
+    Paragraph,
+}
+
+impl ChunkBoundary {
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "line" | "lines" => Some(Self::Line),
+            "paragraph" | "paragraphs" => Some(Self::Paragraph),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn parse_py(value: &str) -> PyResult<Self> {
+        Self::parse(value).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Unsupported chunk boundary '{value}'. Expected one of: line, paragraph"
+            ))
+        })
+    }
+}
+
+/// Cuts a stream of lines into chunks according to a [`ChunkBoundary`].
+///
+/// Lines are pushed one at a time with their terminators; a chunk is handed
+/// back as soon as the line that ends it (or starts the next one) arrives.
+#[derive(Debug, Clone, Default)]
+#[pyclass(module = "_corruption_engine", name = "ChunkSplitter")]
+pub struct ChunkSplitter {
+    boundary: ChunkBoundary,
+    chunk: String,
+    /// Whether the current chunk holds a non-blank line yet; blank lines
+    /// before that do not end a paragraph.
+    has_content: bool,
+    /// Whether a blank line followed the current chunk's content.
+    seen_blank: bool,
+}
+
+impl ChunkSplitter {
+    #[must_use]
+    pub fn new(boundary: ChunkBoundary) -> Self {
+        Self {
+            boundary,
+            ..Self::default()
+        }
+    }
+
+    /// Adds `line` and returns the chunk it completes, if any.
+    pub fn push_line(&mut self, line: &str) -> Option<String> {
+        if self.boundary == ChunkBoundary::Line {
+            return Some(line.to_string());
+        }
+
+        let blank = line.trim().is_empty();
+        let completed = if self.seen_blank && !blank {
+            self.seen_blank = false;
+            Some(std::mem::take(&mut self.chunk))
+        } else {
+            None
+        };
+        if !blank {
+            self.has_content = true;
+        } else if self.has_content {
+            self.seen_blank = true;
+        }
+        self.chunk.push_str(line);
+        completed
+    }
+
+    /// Returns whatever is left once the input is exhausted.
+    pub fn finish(&mut self) -> Option<String> {
+        self.has_content = false;
+        self.seen_blank = false;
+        (!self.chunk.is_empty()).then(|| std::mem::take(&mut self.chunk))
+    }
+}
+
+#[pymethods]
+impl ChunkSplitter {
+    #[new]
+    #[pyo3(signature = (boundary="line"))]
+    fn py_new(boundary: &str) -> PyResult<Self> {
+        ChunkBoundary::parse_py(boundary).map(Self::new)
+    }
+
+    /// Add one line (with its terminator); returns the chunk it completes.
+    #[pyo3(name = "push")]
+    fn push_py(&mut self, line: &str) -> Option<String> {
+        self.push_line(line)
+    }
+
+    /// Return the final chunk, if any.
+    #[pyo3(name = "finish")]
+    fn finish_py(&mut self) -> Option<String> {
+        self.finish()
+    }
+}
+
+/// Largest token grid diffed exactly; bigger changes collapse into one edit.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
 /// Errors emitted by the pipeline executor.
 #[derive(Debug)]
 pub enum PipelineError {
     OperationFailure { name: String, source: OperationError },
     InvalidPattern { pattern: String, message: String },
+    Io(io::Error),
+    InvalidJson(String),
+    /// A descriptor parameter is out of range; `name` is the descriptor (or,
+    /// for a lone operation, its `type`).
+    InvalidParameter { name: String, message: String },
 }
 
 impl PipelineError {
@@ -32,21 +196,45 @@ impl PipelineError {
             Self::InvalidPattern { pattern, message } => {
                 PyValueError::new_err(format!("invalid regex '{pattern}': {message}"))
             }
+            Self::Io(err) => err.into(),
+            Self::InvalidJson(message) => {
+                PyValueError::new_err(format!("invalid pipeline JSON: {message}"))
+            }
+            Self::InvalidParameter { name, message } => {
+                PyValueError::new_err(format!("{name}: {message}"))
+            }
         }
     }
 }
 
+/// Serialised form of a [`Pipeline`]; patterns are stored as their sources.
+#[derive(Serialize, Deserialize)]
+struct PipelineSpec {
+    master_seed: i128,
+    descriptors: Vec<OperationDescriptor>,
+    include_only_patterns: Vec<String>,
+    exclude_patterns: Vec<String>,
+    #[serde(default)]
+    max_total_edits: Option<usize>,
+}
+
 /// Deterministic glitchling pipeline mirroring the Python orchestrator contract.
 ///
+/// Every operation draws from its own [`DeterministicRng`] seeded by its
+/// descriptor, so retuning one operation only affects later ones through the
+/// text it hands them.
+///
 /// Pattern vectors are wrapped in Arc for cheap cloning when releasing the GIL.
 /// This avoids expensive deep copies of compiled regex patterns.
 #[derive(Debug, Clone)]
 #[pyclass(module = "_corruption_engine")]
 pub struct Pipeline {
-    _master_seed: i128,
+    master_seed: i128,
     descriptors: Vec<OperationDescriptor>,
     include_only_patterns: Arc<Vec<Regex>>,
     exclude_patterns: Arc<Vec<Regex>>,
+    /// Edit budget per document; see [`Pipeline::compile`].
+    max_total_edits: Option<usize>,
 }
 
 impl Pipeline {
@@ -58,51 +246,278 @@ impl Pipeline {
         exclude_patterns: Vec<Regex>,
     ) -> Self {
         Self {
-            _master_seed: master_seed,
+            master_seed,
             descriptors,
             include_only_patterns: Arc::new(include_only_patterns),
             exclude_patterns: Arc::new(exclude_patterns),
+            max_total_edits: None,
         }
     }
 
+    /// Validates every descriptor's parameters (see [`Operation::validate`])
+    /// and compiles the pattern sources.
+    ///
+    /// `max_total_edits` bounds the corruption of each text: operations run in
+    /// order, summing the edit counts they report, and once the total reaches
+    /// the budget the remaining operations are skipped. The operation that
+    /// crosses the budget still runs in full, so the total may overshoot it,
+    /// and later operations may be left unrun. Each document, chunk or
+    /// rate-region run gets its own budget.
     pub fn compile(
         master_seed: i128,
         descriptors: Vec<OperationDescriptor>,
         include_only_patterns: Vec<String>,
         exclude_patterns: Vec<String>,
+        max_total_edits: Option<usize>,
     ) -> Result<Self, PipelineError> {
+        for descriptor in &descriptors {
+            validate_operation(&descriptor.name, &descriptor.operation)?;
+        }
         let include = compile_patterns(include_only_patterns)?;
         let exclude = compile_patterns(exclude_patterns)?;
-        Ok(Self::new(master_seed, descriptors, include, exclude))
+        let mut pipeline = Self::new(master_seed, descriptors, include, exclude);
+        pipeline.max_total_edits = max_total_edits;
+        Ok(pipeline)
     }
 
-    #[must_use] 
+    /// Serialises the master seed, descriptors (names, seeds and operation
+    /// parameters) and pattern sources so [`Pipeline::from_json`] can rebuild
+    /// a pipeline with identical output.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let spec = PipelineSpec {
+            master_seed: self.master_seed,
+            descriptors: self.descriptors.clone(),
+            include_only_patterns: pattern_sources(&self.include_only_patterns),
+            exclude_patterns: pattern_sources(&self.exclude_patterns),
+            max_total_edits: self.max_total_edits,
+        };
+        serde_json::to_string(&spec).expect("pipeline specs always serialise")
+    }
+
+    /// Rebuilds a pipeline written by [`Pipeline::to_json`].
+    pub fn from_json(json: &str) -> Result<Self, PipelineError> {
+        let spec: PipelineSpec =
+            serde_json::from_str(json).map_err(|err| PipelineError::InvalidJson(err.to_string()))?;
+        Self::compile(
+            spec.master_seed,
+            spec.descriptors,
+            spec.include_only_patterns,
+            spec.exclude_patterns,
+            spec.max_total_edits,
+        )
+    }
+
+    #[must_use]
     pub fn descriptors(&self) -> &[OperationDescriptor] {
         &self.descriptors
     }
 
-    pub fn apply(&self, buffer: &mut TextBuffer) -> Result<(), PipelineError> {
+    #[must_use]
+    pub const fn max_total_edits(&self) -> Option<usize> {
+        self.max_total_edits
+    }
+
+    /// Whether `spent` edits use up the budget, so later operations are skipped.
+    fn budget_exhausted(&self, spent: usize) -> bool {
+        self.max_total_edits.is_some_and(|budget| spent >= budget)
+    }
+
+    /// Runs each operation in order over `buffer`, stopping once the edit
+    /// budget is spent.
+    ///
+    /// Operations draw from `make_rng` applied to their descriptor's seed or,
+    /// given a `document_seed`, to a seed derived from both as in
+    /// [`Pipeline::run_document`]. `before` snapshots the buffer ahead of each
+    /// operation; `after` receives that snapshot with the operation's outcome
+    /// and its spent RNG.
+    fn drive_ops<R, S>(
+        &self,
+        buffer: &mut TextBuffer,
+        document_seed: Option<u64>,
+        mut make_rng: impl FnMut(u64) -> R,
+        mut before: impl FnMut(&mut TextBuffer) -> S,
+        mut after: impl FnMut(&OperationDescriptor, &mut TextBuffer, S, OpOutcome, R),
+    ) -> Result<(), PipelineError>
+    where
+        R: OperationRng,
+    {
+        let mut spent = 0;
         for descriptor in &self.descriptors {
-            let mut rng = DeterministicRng::new(descriptor.seed);
-            descriptor
+            if self.budget_exhausted(spent) {
+                break;
+            }
+            let seed = document_seed.map_or(descriptor.seed, |document_seed| {
+                derive_seed(
+                    i128::from(document_seed),
+                    &descriptor.name,
+                    i128::from(descriptor.seed),
+                )
+            });
+            let snapshot = before(buffer);
+            let mut rng = make_rng(seed);
+            let outcome = descriptor
                 .operation
                 .apply(buffer, &mut rng)
                 .map_err(|source| PipelineError::OperationFailure {
                     name: descriptor.name.clone(),
                     source,
                 })?;
+            spent += outcome.edits;
+            after(descriptor, buffer, snapshot, outcome, rng);
         }
         Ok(())
     }
 
+    pub fn apply(&self, buffer: &mut TextBuffer) -> Result<(), PipelineError> {
+        self.drive_ops(buffer, None, DeterministicRng::new, |_| (), |_, _, (), _, _| {})
+    }
+
+    /// Like [`Pipeline::apply`], but records a report for each operation.
+    pub fn apply_instrumented(&self, buffer: &mut TextBuffer) -> Result<Vec<OpReport>, PipelineError> {
+        let mut reports = Vec::with_capacity(self.descriptors.len());
+        self.drive_ops(
+            buffer,
+            None,
+            DeterministicRng::new,
+            |buffer| {
+                buffer.reindex_if_needed();
+                (buffer.char_len(), buffer.word_count(), Instant::now())
+            },
+            |descriptor, buffer, (chars_before, words_before, started), outcome, _| {
+                let elapsed = started.elapsed();
+                buffer.reindex_if_needed();
+                reports.push(OpReport {
+                    name: descriptor.name.clone(),
+                    edits: outcome.edits,
+                    char_delta: buffer.char_len() as isize - chars_before as isize,
+                    word_delta: buffer.word_count() as isize - words_before as isize,
+                    elapsed,
+                });
+            },
+        )?;
+        Ok(reports)
+    }
+
     pub fn run(&self, text: &str) -> Result<String, PipelineError> {
-        let mut buffer = TextBuffer::from_owned(
+        let mut buffer = self.buffer_for(text);
+        self.apply(&mut buffer)?;
+        Ok(buffer.to_string())
+    }
+
+    /// Runs the pipeline and reports the effect and cost of each operation.
+    pub fn run_instrumented(&self, text: &str) -> Result<(String, Vec<OpReport>), PipelineError> {
+        let mut buffer = self.buffer_for(text);
+        let reports = self.apply_instrumented(&mut buffer)?;
+        Ok((buffer.to_string(), reports))
+    }
+
+    /// Runs the operations one at a time, scoring the text after each step
+    /// against the original with `metric`.
+    ///
+    /// Seeds match [`Pipeline::run`], so the last step scores the real output.
+    /// Returns one `(operation name, metric value)` pair per operation run;
+    /// operations skipped by the edit budget have no entry.
+    pub fn run_with_metric_trace(
+        &self,
+        text: &str,
+        metric: TextMetric,
+    ) -> Result<Vec<(String, f64)>, PipelineError> {
+        let mut buffer = self.buffer_for(text);
+        let mut trace = Vec::with_capacity(self.descriptors.len());
+        self.drive_ops(
+            &mut buffer,
+            None,
+            DeterministicRng::new,
+            |_| (),
+            |descriptor, buffer, (), _, _| {
+                trace.push((descriptor.name.clone(), metric.score(text, &buffer.to_string())));
+            },
+        )?;
+        Ok(trace)
+    }
+
+    /// Runs the pipeline while logging every RNG draw each operation makes.
+    ///
+    /// Seeds and output match [`Pipeline::run`]. Returns the output and one
+    /// `(operation name, draws)` entry per operation; tests can pin the log
+    /// to catch refactors that silently change RNG consumption.
+    #[cfg(debug_assertions)]
+    pub fn run_with_rng_trace(
+        &self,
+        text: &str,
+    ) -> Result<(String, RngTrace), PipelineError> {
+        let mut buffer = self.buffer_for(text);
+        let mut trace = Vec::with_capacity(self.descriptors.len());
+        self.drive_ops(
+            &mut buffer,
+            None,
+            |seed| TracingRng::new(DeterministicRng::new(seed)),
+            |_| (),
+            |descriptor, _, (), _, rng: TracingRng| {
+                trace.push((descriptor.name.clone(), rng.into_draws()));
+            },
+        )?;
+        Ok((buffer.to_string(), trace))
+    }
+
+    /// Reports the edits each operation would make without returning the
+    /// corrupted text.
+    ///
+    /// Operations run on a scratch buffer with their usual seeds, so the plan
+    /// matches a real [`Pipeline::run`] exactly. Edits are whitespace-delimited
+    /// token hunks found by diffing each operation's input and output.
+    pub fn plan_edits(&self, text: &str) -> Result<Vec<PlannedEdit>, PipelineError> {
+        let mut buffer = self.buffer_for(text);
+        let mut edits = Vec::new();
+        let mut op_index = 0;
+        self.drive_ops(
+            &mut buffer,
+            None,
+            DeterministicRng::new,
+            |buffer| buffer.to_string(),
+            |descriptor, buffer, before, _, _| {
+                let index = op_index;
+                op_index += 1;
+                let after = buffer.to_string();
+                if before == after {
+                    return;
+                }
+                edits.extend(diff_tokens(&before, &after).into_iter().map(
+                    |(range, original, replacement)| PlannedEdit {
+                        op_index: index,
+                        name: descriptor.name.clone(),
+                        range,
+                        original,
+                        replacement,
+                    },
+                ));
+            },
+        )?;
+        Ok(edits)
+    }
+
+    /// Builds a `(clean, corrupted)` pair for training denoisers.
+    ///
+    /// `clean` is `text` after [`TextBuffer::normalize`]: whitespace runs
+    /// collapse to a single space unless they hold a line break, which is kept
+    /// verbatim; spaces before `.`, `,`, `:` and `;` are dropped; and the ends
+    /// are trimmed. `corrupted` is [`Pipeline::run`] over `clean`, so the pair
+    /// differs only by the pipeline's edits.
+    pub fn generate_pair(&self, text: &str) -> Result<(String, String), PipelineError> {
+        let mut buffer = self.buffer_for(text);
+        buffer.normalize();
+        let clean = buffer.to_string();
+        let corrupted = self.run(&clean)?;
+        Ok((clean, corrupted))
+    }
+
+    fn buffer_for(&self, text: &str) -> TextBuffer {
+        TextBuffer::from_owned(
             text.to_string(),
             &self.include_only_patterns,
             &self.exclude_patterns,
-        );
-        self.apply(&mut buffer)?;
-        Ok(buffer.to_string())
+        )
     }
 
     /// Process multiple texts in parallel.
@@ -115,6 +530,197 @@ impl Pipeline {
             .map(|text| self.run(text))
             .collect()
     }
+
+    /// Runs the pipeline over the document at `index` in a corpus.
+    ///
+    /// Each operation is reseeded from a per-document seed derived from the
+    /// master seed and `index`, so documents corrupt independently while any
+    /// one of them can be reproduced from the master seed and its index alone.
+    pub fn run_document(&self, text: &str, index: usize) -> Result<String, PipelineError> {
+        let document_seed = derive_seed(self.master_seed, "document", index as i128);
+        let mut buffer = self.buffer_for(text);
+        self.drive_ops(
+            &mut buffer,
+            Some(document_seed),
+            DeterministicRng::new,
+            |_| (),
+            |_, _, (), _, _| {},
+        )?;
+        Ok(buffer.to_string())
+    }
+
+    /// Runs the pipeline with operation rates scaled inside character ranges.
+    ///
+    /// The regions become a rate profile on one [`TextBuffer`] (see
+    /// [`TextBuffer::set_rate_profile`]): overlapping regions multiply, and a
+    /// segment is scaled by every region holding its first character. The
+    /// pipeline then runs once with the usual seeds, so context-aware
+    /// operations (quote pairing, sentence ops) see the whole text and a zero
+    /// multiplier leaves its region untouched. Operations that roll once per
+    /// word or character leave text outside every region exactly as
+    /// [`Pipeline::run`] would; operations that draw a fixed quota spread it
+    /// by the scales. Without regions this is exactly [`Pipeline::run`].
+    pub fn run_with_rate_regions(
+        &self,
+        text: &str,
+        regions: &[RateRegion],
+    ) -> Result<String, PipelineError> {
+        let mut buffer = self.buffer_for(text);
+        let profile: Vec<(Range<usize>, f64)> = regions
+            .iter()
+            .map(|region| (region.range.clone(), region.multiplier))
+            .collect();
+        buffer.set_rate_profile(&profile);
+        self.apply(&mut buffer)?;
+        Ok(buffer.to_string())
+    }
+
+    /// Corrupts only the characters in `range`, leaving the rest of `text`
+    /// byte-identical.
+    ///
+    /// The passage is run exactly as [`Pipeline::run`] would run it alone, so
+    /// seeds, include/exclude patterns and the edit budget behave as if it were
+    /// the whole input. Ranges past the end are clamped; an empty or inverted
+    /// range returns `text` unchanged.
+    pub fn run_range(&self, text: &str, range: Range<usize>) -> Result<String, PipelineError> {
+        let byte_offset = |char_index: usize| {
+            text.char_indices()
+                .nth(char_index)
+                .map_or(text.len(), |(offset, _)| offset)
+        };
+        let start = byte_offset(range.start);
+        let end = byte_offset(range.end).max(start);
+        if start == end {
+            return Ok(text.to_string());
+        }
+        let mut output = String::with_capacity(text.len());
+        output.push_str(&text[..start]);
+        output.push_str(&self.run(&text[start..end])?);
+        output.push_str(&text[end..]);
+        Ok(output)
+    }
+
+    /// Corrupts the chunk at `index` of a streamed input.
+    ///
+    /// Seeds match [`Pipeline::run_document`], so any chunk can be reproduced
+    /// from the master seed and its index alone. Whitespace-only chunks pass
+    /// through untouched.
+    pub fn run_chunk(&self, chunk: &str, index: usize) -> Result<String, PipelineError> {
+        if chunk.trim().is_empty() {
+            return Ok(chunk.to_string());
+        }
+        self.run_document(chunk, index)
+    }
+
+    /// Corrupts `reader` into `writer` one chunk at a time, holding only the
+    /// current chunk in memory.
+    ///
+    /// Chunks are cut at `boundary` and processed with
+    /// [`Pipeline::run_chunk`]. Every operation is chunk-local: quote pairing,
+    /// sentence operations and rate quotas only see the current chunk.
+    pub fn run_streaming<R: BufRead, W: Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+        boundary: ChunkBoundary,
+    ) -> Result<(), PipelineError> {
+        let mut splitter = ChunkSplitter::new(boundary);
+        let mut index = 0usize;
+        let mut write_chunk = |chunk: String| -> Result<(), PipelineError> {
+            let corrupted = self.run_chunk(&chunk, index)?;
+            writer
+                .write_all(corrupted.as_bytes())
+                .map_err(PipelineError::Io)?;
+            index += 1;
+            Ok(())
+        };
+        let mut line = String::new();
+        while reader.read_line(&mut line).map_err(PipelineError::Io)? > 0 {
+            if let Some(chunk) = splitter.push_line(&line) {
+                write_chunk(chunk)?;
+            }
+            line.clear();
+        }
+        if let Some(chunk) = splitter.finish() {
+            write_chunk(chunk)?;
+        }
+        writer.flush().map_err(PipelineError::Io)
+    }
+
+    /// Process a corpus in parallel with [`Pipeline::run_document`], seeding
+    /// each text by its position. Results keep the input order.
+    pub fn run_documents<S: AsRef<str> + Sync>(&self, texts: &[S]) -> Result<Vec<String>, PipelineError> {
+        texts
+            .par_iter()
+            .enumerate()
+            .map(|(index, text)| self.run_document(text.as_ref(), index))
+            .collect()
+    }
+}
+
+/// Strips inline rate markup, returning the plain text and its regions.
+///
+/// A marked span reads `{open}{multiplier}|{content}{close}`, e.g.
+/// `[[0.2|Quarterly report]]` with `open = "[["` and `close = "]]"`. Spans do
+/// not nest. Region ranges are character offsets into the returned text.
+pub fn parse_rate_markup(
+    text: &str,
+    open: &str,
+    close: &str,
+) -> Result<(String, Vec<RateRegion>), String> {
+    if open.is_empty() || close.is_empty() {
+        return Err("rate markup delimiters must not be empty".to_string());
+    }
+
+    let mut plain = String::with_capacity(text.len());
+    let mut plain_chars = 0usize;
+    let mut regions = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(open) {
+        let before = &rest[..start];
+        plain.push_str(before);
+        plain_chars += before.chars().count();
+
+        let marked = &rest[start + open.len()..];
+        let end = marked
+            .find(close)
+            .ok_or_else(|| format!("unterminated rate markup starting at {before:?}{open}"))?;
+        let body = &marked[..end];
+        let (multiplier, content) = body
+            .split_once('|')
+            .ok_or_else(|| format!("rate markup {open}{body}{close} is missing '|'"))?;
+        let multiplier: f64 = multiplier
+            .trim()
+            .parse()
+            .ok()
+            .filter(|value: &f64| value.is_finite() && *value >= 0.0)
+            .ok_or_else(|| format!("invalid rate multiplier {multiplier:?} in rate markup"))?;
+
+        let content_chars = content.chars().count();
+        regions.push(RateRegion {
+            range: plain_chars..plain_chars + content_chars,
+            multiplier,
+        });
+        plain.push_str(content);
+        plain_chars += content_chars;
+        rest = &marked[end + close.len()..];
+    }
+    plain.push_str(rest);
+    Ok((plain, regions))
+}
+
+fn pattern_sources(patterns: &[Regex]) -> Vec<String> {
+    patterns.iter().map(|pattern| pattern.as_str().to_string()).collect()
+}
+
+/// Runs [`Operation::validate`], labelling any failure with `name`.
+pub(crate) fn validate_operation(name: &str, operation: &Operation) -> Result<(), PipelineError> {
+    operation
+        .validate()
+        .map_err(|message| PipelineError::InvalidParameter {
+            name: name.to_string(),
+            message,
+        })
 }
 
 fn compile_patterns(patterns: Vec<String>) -> Result<Vec<Regex>, PipelineError> {
@@ -129,6 +735,83 @@ fn compile_patterns(patterns: Vec<String>) -> Result<Vec<Regex>, PipelineError>
     Ok(compiled)
 }
 
+/// Diffs two texts over word/separator tokens, returning each changed hunk as
+/// `(char range in before, original, replacement)`.
+pub(crate) fn diff_tokens(before: &str, after: &str) -> Vec<(Range<usize>, String, String)> {
+    let old = split_with_separators(before);
+    let new = split_with_separators(after);
+
+    let prefix = old
+        .iter()
+        .zip(&new)
+        .take_while(|(left, right)| left == right)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(left, right)| left == right)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut offsets = Vec::with_capacity(old.len() + 1);
+    offsets.push(0usize);
+    for token in &old {
+        offsets.push(offsets[offsets.len() - 1] + token.chars().count());
+    }
+
+    let mut hunks = Vec::new();
+    let mut emit = |old_range: Range<usize>, new_range: Range<usize>| {
+        if old_range.is_empty() && new_range.is_empty() {
+            return;
+        }
+        hunks.push((
+            offsets[prefix + old_range.start]..offsets[prefix + old_range.end],
+            old_mid[old_range].concat(),
+            new_mid[new_range].concat(),
+        ));
+    };
+
+    let (rows, cols) = (old_mid.len(), new_mid.len());
+    if (rows + 1).saturating_mul(cols + 1) > MAX_DIFF_CELLS {
+        emit(0..rows, 0..cols);
+        return hunks;
+    }
+
+    // lcs[i][j] holds the LCS length of old_mid[i..] and new_mid[j..].
+    let width = cols + 1;
+    let mut lcs = vec![0u32; (rows + 1) * width];
+    for i in (0..rows).rev() {
+        for j in (0..cols).rev() {
+            lcs[i * width + j] = if old_mid[i] == new_mid[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0usize, 0usize);
+    let (mut hunk_i, mut hunk_j) = (0usize, 0usize);
+    while i < rows || j < cols {
+        if i < rows && j < cols && old_mid[i] == new_mid[j] {
+            emit(hunk_i..i, hunk_j..j);
+            i += 1;
+            j += 1;
+            hunk_i = i;
+            hunk_j = j;
+        } else if j == cols || (i < rows && lcs[(i + 1) * width + j] >= lcs[i * width + j + 1]) {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    emit(hunk_i..rows, hunk_j..cols);
+
+    hunks
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GagglePlanEntry {
     pub index: usize,
@@ -143,15 +826,40 @@ pub struct GagglePlanInput {
     pub order: i32,
 }
 
-struct PlannedGlitchling {
-    index: usize,
-    name: String,
-    scope: i32,
-    order: i32,
-    seed: u64,
+/// One step of a gaggle plan with the inputs that produced its seed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[pyclass(module = "_corruption_engine", name = "GagglePlanStep", get_all, frozen)]
+pub struct PlannedGlitchling {
+    pub index: usize,
+    pub name: String,
+    pub scope: i32,
+    pub order: i32,
+    pub seed: u64,
+}
+
+#[pymethods]
+impl PlannedGlitchling {
+    fn __repr__(&self) -> String {
+        format!(
+            "GagglePlanStep(index={}, name={:?}, scope={}, order={}, seed={})",
+            self.index, self.name, self.scope, self.order, self.seed
+        )
+    }
 }
 
 pub fn plan_gaggle(inputs: Vec<GagglePlanInput>, master_seed: i128) -> Vec<GagglePlanEntry> {
+    plan_gaggle_steps(inputs, master_seed)
+        .into_iter()
+        .map(|item| GagglePlanEntry {
+            index: item.index,
+            seed: item.seed,
+        })
+        .collect()
+}
+
+/// Orders glitchlings by scope, order, name and index, deriving each seed
+/// from `master_seed`, the glitchling's name and its input index.
+pub fn plan_gaggle_steps(inputs: Vec<GagglePlanInput>, master_seed: i128) -> Vec<PlannedGlitchling> {
     let mut planned: Vec<PlannedGlitchling> = inputs
         .into_iter()
         .map(|input| PlannedGlitchling {
@@ -172,12 +880,6 @@ pub fn plan_gaggle(inputs: Vec<GagglePlanInput>, master_seed: i128) -> Vec<Gaggl
     });
 
     planned
-        .into_iter()
-        .map(|item| GagglePlanEntry {
-            index: item.index,
-            seed: item.seed,
-        })
-        .collect()
 }
 
 /// FNV-1a constants for 64-bit hashing
@@ -230,11 +932,18 @@ pub fn derive_seed(master_seed: i128, glitchling_name: &str, index: i128) -> u64
 #[cfg(test)]
 mod tests {
     use super::{
-        derive_seed, plan_gaggle, GagglePlanEntry, GagglePlanInput, OperationDescriptor, Pipeline,
+        derive_seed, diff_tokens, parse_rate_markup, plan_gaggle, plan_gaggle_steps, ChunkBoundary,
+        ChunkSplitter, GagglePlanEntry, GagglePlanInput, OperationDescriptor, Pipeline, PipelineError,
+        RateRegion,
     };
+    use crate::case::{CaseMode, CaseOp};
+    use crate::disemvowel::DisemvowelOp;
+    use crate::homoglyphs::{ClassSelection, HomoglyphOp};
+    use crate::homophones::{HomophoneOp, HomophoneWeighting};
+    use crate::metrics::TextMetric;
     use crate::operations::{
         DeleteRandomWordsOp, Operation, OcrArtifactsOp, RedactWordsOp, ReduplicateWordsOp,
-        SwapAdjacentWordsOp,
+        ReduplicationSeparator, RngDraw, SwapAdjacentWordsOp, WordWeighting,
     };
 
     #[test]
@@ -256,6 +965,9 @@ mod tests {
                 operation: Operation::Reduplicate(ReduplicateWordsOp {
                     rate: 1.0,
                     unweighted: false,
+                    repeats: 1,
+                    stutter: false,
+                    separator: ReduplicationSeparator::Space,
                 }),
             },
             OperationDescriptor {
@@ -265,7 +977,10 @@ mod tests {
                     replacement_char: "█".to_string(),
                     rate: 0.5,
                     merge_adjacent: false,
+                    merge_through_punctuation: false,
+                    fixed_width: None,
                     unweighted: false,
+                    weighting: WordWeighting::Length,
                 }),
             },
         ];
@@ -273,7 +988,589 @@ mod tests {
         let output = pipeline.run("Guard the vault").expect("pipeline succeeds");
         // After reduplication: "Guard Guard the the vault vault"
         // After redaction at rate 0.5 with this seed: specific words get redacted
-        assert_eq!(output, "Guard Guard ███ ███ vault █████");
+        assert_eq!(output, "█████ █████ the ███ vault vault");
+    }
+
+    #[test]
+    fn upstream_rate_changes_leave_downstream_streams_alone() {
+        let master_seed = 151i128;
+        let swap = |rate: f64| OperationDescriptor {
+            name: "Rushmore-Swap".to_string(),
+            seed: derive_seed(master_seed, "Rushmore-Swap", 0),
+            operation: Operation::SwapAdjacent(SwapAdjacentWordsOp { rate }),
+        };
+        let redact = OperationDescriptor {
+            name: "Redactyl".to_string(),
+            seed: derive_seed(master_seed, "Redactyl", 1),
+            operation: Operation::Redact(RedactWordsOp {
+                replacement_char: "█".to_string(),
+                rate: 0.5,
+                merge_adjacent: false,
+                merge_through_punctuation: false,
+                fixed_width: None,
+                unweighted: false,
+                weighting: WordWeighting::Length,
+            }),
+        };
+        let run = |descriptors: Vec<OperationDescriptor>, text: &str| {
+            Pipeline::new(master_seed, descriptors, Vec::new(), Vec::new())
+                .run(text)
+                .expect("pipeline succeeds")
+        };
+        let text = "Guard the vault with care tonight";
+
+        // Redactyl sees only the text Rushmore hands it, never Rushmore's draws.
+        for rate in [0.0, 0.25, 0.5, 1.0] {
+            let upstream = run(vec![swap(rate)], text);
+            assert_eq!(
+                run(vec![swap(rate), redact.clone()], text),
+                run(vec![redact.clone()], &upstream),
+                "rate {rate}"
+            );
+        }
+        assert_eq!(run(vec![swap(0.0), redact.clone()], text), "█████ ███ vault ████ care tonight");
+        assert_eq!(run(vec![swap(1.0), redact], text), "███ █████ with █████ tonight care");
+    }
+
+    #[test]
+    fn compile_rejects_out_of_range_parameters_by_descriptor_name() {
+        let descriptor = |name: &str, rate: f64| OperationDescriptor {
+            name: name.to_string(),
+            seed: 7,
+            operation: Operation::SwapAdjacent(SwapAdjacentWordsOp { rate }),
+        };
+        let error = Pipeline::compile(
+            151,
+            vec![descriptor("Rushmore", 0.5), descriptor("Rushmore-Swap", 2.5)],
+            Vec::new(),
+            Vec::new(),
+            None,
+        )
+        .expect_err("rate above one is rejected");
+        match error {
+            PipelineError::InvalidParameter { name, message } => {
+                assert_eq!(name, "Rushmore-Swap");
+                assert_eq!(message, "rate must be in [0, 1], got 2.5");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        let valid = vec![descriptor("Rushmore", 1.0)];
+        assert!(Pipeline::compile(151, valid, Vec::new(), Vec::new(), None).is_ok());
+    }
+
+    #[test]
+    fn identity_holds_a_plan_slot_without_touching_the_text() {
+        let master_seed = 151i128;
+        let redact = |index: i128| OperationDescriptor {
+            name: "Redactyl".to_string(),
+            seed: derive_seed(master_seed, "Redactyl", index),
+            operation: Operation::Redact(RedactWordsOp {
+                replacement_char: "█".to_string(),
+                rate: 0.5,
+                merge_adjacent: false,
+                merge_through_punctuation: false,
+                fixed_width: None,
+                unweighted: false,
+                weighting: WordWeighting::Length,
+            }),
+        };
+        let identity = OperationDescriptor {
+            name: "Identity".to_string(),
+            seed: derive_seed(master_seed, "Identity", 0),
+            operation: Operation::Identity,
+        };
+
+        let alone = Pipeline::new(master_seed, vec![identity.clone()], Vec::new(), Vec::new());
+        assert_eq!(alone.run("Guard the vault").expect("pipeline succeeds"), "Guard the vault");
+
+        let padded = Pipeline::new(master_seed, vec![identity, redact(1)], Vec::new(), Vec::new());
+        let direct = Pipeline::new(master_seed, vec![redact(1)], Vec::new(), Vec::new());
+        assert_eq!(
+            padded.run("Guard the vault").expect("pipeline succeeds"),
+            direct.run("Guard the vault").expect("pipeline succeeds"),
+        );
+    }
+
+    #[test]
+    fn run_instrumented_reports_each_operation() {
+        let master_seed = 151i128;
+        let descriptors = vec![
+            OperationDescriptor {
+                name: "Rushmore-Duplicate".to_string(),
+                seed: derive_seed(master_seed, "Rushmore-Duplicate", 0),
+                operation: Operation::Reduplicate(ReduplicateWordsOp {
+                    rate: 1.0,
+                    unweighted: false,
+                    repeats: 1,
+                    stutter: false,
+                    separator: ReduplicationSeparator::Space,
+                }),
+            },
+            OperationDescriptor {
+                name: "Rushmore-Swap".to_string(),
+                seed: derive_seed(master_seed, "Rushmore-Swap", 1),
+                operation: Operation::SwapAdjacent(SwapAdjacentWordsOp { rate: 1.0 }),
+            },
+        ];
+        let pipeline = Pipeline::new(master_seed, descriptors, Vec::new(), Vec::new());
+        let (output, reports) = pipeline
+            .run_instrumented("Guard the vault")
+            .expect("pipeline succeeds");
+        assert_eq!(output, pipeline.run("Guard the vault").expect("pipeline succeeds"));
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].name, "Rushmore-Duplicate");
+        assert_eq!(reports[0].edits, 3);
+        assert_eq!(reports[0].word_delta, 3);
+        assert_eq!(reports[0].char_delta, 16);
+        assert_eq!(reports[1].edits, 3);
+        assert_eq!(reports[1].word_delta, 0);
+        assert_eq!(reports[1].char_delta, 0);
+    }
+
+    #[test]
+    fn edit_budget_skips_operations_once_spent() {
+        let master_seed = 151i128;
+        let descriptors = vec![
+            OperationDescriptor {
+                name: "Rushmore-Duplicate".to_string(),
+                seed: derive_seed(master_seed, "Rushmore-Duplicate", 0),
+                operation: Operation::Reduplicate(ReduplicateWordsOp {
+                    rate: 1.0,
+                    unweighted: false,
+                    repeats: 1,
+                    stutter: false,
+                    separator: ReduplicationSeparator::Space,
+                }),
+            },
+            OperationDescriptor {
+                name: "Rushmore-Swap".to_string(),
+                seed: derive_seed(master_seed, "Rushmore-Swap", 1),
+                operation: Operation::SwapAdjacent(SwapAdjacentWordsOp { rate: 1.0 }),
+            },
+        ];
+        let capped = |budget: Option<usize>| {
+            Pipeline::compile(master_seed, descriptors.clone(), Vec::new(), Vec::new(), budget)
+                .expect("pipeline compiles")
+        };
+        let first_only =
+            Pipeline::new(master_seed, descriptors[..1].to_vec(), Vec::new(), Vec::new());
+        let text = "Guard the vault";
+
+        // The duplicate pass makes three edits, overshooting a budget of two,
+        // so the swap never runs.
+        let (output, reports) = capped(Some(2)).run_instrumented(text).expect("run succeeds");
+        assert_eq!(reports.len(), 1);
+        assert_eq!(output, first_only.run(text).expect("run succeeds"));
+        assert_eq!(
+            capped(Some(2)).run_document(text, 4).expect("run succeeds"),
+            first_only.run_document(text, 4).expect("run succeeds")
+        );
+        assert_eq!(capped(Some(0)).run(text).expect("run succeeds"), text);
+        assert_eq!(
+            capped(Some(6)).run(text).expect("run succeeds"),
+            capped(None).run(text).expect("run succeeds")
+        );
+    }
+
+    #[test]
+    fn metric_trace_scores_each_step_against_the_original() {
+        let master_seed = 151i128;
+        let descriptors = vec![
+            OperationDescriptor {
+                name: "Rushmore-Duplicate".to_string(),
+                seed: derive_seed(master_seed, "Rushmore-Duplicate", 0),
+                operation: Operation::Reduplicate(ReduplicateWordsOp {
+                    rate: 1.0,
+                    unweighted: false,
+                    repeats: 1,
+                    stutter: false,
+                    separator: ReduplicationSeparator::Space,
+                }),
+            },
+            OperationDescriptor {
+                name: "Rushmore-Swap".to_string(),
+                seed: derive_seed(master_seed, "Rushmore-Swap", 1),
+                operation: Operation::SwapAdjacent(SwapAdjacentWordsOp { rate: 0.0 }),
+            },
+        ];
+        let pipeline = Pipeline::new(master_seed, descriptors, Vec::new(), Vec::new());
+        let text = "Guard the vault";
+        let trace = pipeline
+            .run_with_metric_trace(text, TextMetric::NormalizedEditDistance)
+            .expect("pipeline succeeds");
+
+        let names: Vec<&str> = trace.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["Rushmore-Duplicate", "Rushmore-Swap"]);
+        assert!(trace[0].1 > 0.0);
+        assert_eq!(trace[0].1, trace[1].1);
+        let output = pipeline.run(text).expect("pipeline succeeds");
+        assert_eq!(trace[1].1, TextMetric::NormalizedEditDistance.score(text, &output));
+    }
+
+    #[test]
+    fn rng_trace_logs_each_operations_draws() {
+        let master_seed = 2078i128;
+        let descriptors = vec![
+            OperationDescriptor {
+                name: "Rushmore-Duplicate".to_string(),
+                seed: derive_seed(master_seed, "Rushmore-Duplicate", 0),
+                operation: Operation::Reduplicate(ReduplicateWordsOp {
+                    rate: 1.0,
+                    unweighted: false,
+                    repeats: 1,
+                    stutter: false,
+                    separator: ReduplicationSeparator::Space,
+                }),
+            },
+            OperationDescriptor {
+                name: "Rushmore-Swap".to_string(),
+                seed: derive_seed(master_seed, "Rushmore-Swap", 1),
+                operation: Operation::SwapAdjacent(SwapAdjacentWordsOp { rate: 0.0 }),
+            },
+        ];
+        let pipeline = Pipeline::new(master_seed, descriptors, Vec::new(), Vec::new());
+        let text = "Guard the vault";
+        let (output, trace) = pipeline.run_with_rng_trace(text).expect("pipeline succeeds");
+        assert_eq!(output, pipeline.run(text).expect("pipeline succeeds"));
+
+        let names: Vec<&str> = trace.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["Rushmore-Duplicate", "Rushmore-Swap"]);
+        assert_eq!(trace[0].1, vec![RngDraw::Random; 3]);
+        assert!(trace[1].1.is_empty());
+    }
+
+    #[test]
+    fn json_round_trip_reproduces_output() {
+        let master_seed = 2061i128;
+        let operations = [
+            Operation::Reduplicate(ReduplicateWordsOp {
+                rate: 0.4,
+                unweighted: false,
+                repeats: 1,
+                stutter: true,
+                separator: ReduplicationSeparator::Space,
+            }),
+            Operation::Ocr(OcrArtifactsOp::with_params(0.3, 0.1, 0.3, 3.0, 2, 2.0, 0.05, 0.05)),
+            Operation::Mimic(HomoglyphOp::new(0.3, ClassSelection::All, Vec::new())),
+            Operation::Wherewolf(HomophoneOp::with_extra_sets(
+                1.0,
+                HomophoneWeighting::Flat,
+                &[vec!["vault".to_string(), "volt".to_string()]],
+            )),
+            Operation::Case(CaseOp {
+                rate: 0.3,
+                mode: CaseMode::Alternating,
+            }),
+        ];
+        let descriptors = operations
+            .into_iter()
+            .enumerate()
+            .map(|(index, operation)| OperationDescriptor {
+                name: format!("op-{index}"),
+                seed: derive_seed(master_seed, "op", index as i128),
+                operation,
+            })
+            .collect();
+        let pipeline = Pipeline::compile(
+            master_seed,
+            descriptors,
+            Vec::new(),
+            vec![r"`[^`]*`".to_string()],
+            Some(64),
+        )
+        .expect("patterns compile");
+
+        let json = pipeline.to_json();
+        let restored = Pipeline::from_json(&json).expect("round trip parses");
+        assert_eq!(restored.max_total_edits(), Some(64));
+        let text = "Guard the vault, then `keep this` and their other vault.";
+        assert_eq!(
+            restored.run(text).expect("restored run succeeds"),
+            pipeline.run(text).expect("run succeeds")
+        );
+        assert!(restored.run(text).unwrap().contains("`keep this`"));
+        assert!(Pipeline::from_json("{\"master_seed\": 1}").is_err());
+    }
+
+    #[test]
+    fn plan_edits_describe_a_real_run() {
+        let master_seed = 151i128;
+        let descriptors = vec![
+            OperationDescriptor {
+                name: "Rushmore".to_string(),
+                seed: derive_seed(master_seed, "Rushmore", 0),
+                operation: Operation::Delete(DeleteRandomWordsOp {
+                    rate: 0.5,
+                    unweighted: false,
+                    min_words: 0,
+                    weighting: WordWeighting::Length,
+                    preserve_spacing: false,
+                }),
+            },
+            OperationDescriptor {
+                name: "Redactyl".to_string(),
+                seed: derive_seed(master_seed, "Redactyl", 1),
+                operation: Operation::Redact(RedactWordsOp {
+                    replacement_char: "█".to_string(),
+                    rate: 0.5,
+                    merge_adjacent: false,
+                    merge_through_punctuation: false,
+                    fixed_width: None,
+                    unweighted: false,
+                    weighting: WordWeighting::Length,
+                }),
+            },
+        ];
+        let pipeline = Pipeline::new(master_seed, descriptors, Vec::new(), Vec::new());
+        let text = "Guard the vault at midnight with care";
+        let expected = pipeline.run(text).expect("pipeline succeeds");
+        let edits = pipeline.plan_edits(text).expect("planning succeeds");
+        assert!(!edits.is_empty());
+
+        // Replaying the edits op by op must reproduce the real output.
+        let mut replayed = text.to_string();
+        for (op_index, name) in ["Rushmore", "Redactyl"].into_iter().enumerate() {
+            let mut chars: Vec<char> = replayed.chars().collect();
+            let op_edits = edits.iter().rev().filter(|edit| edit.op_index == op_index);
+            for edit in op_edits {
+                assert_eq!(edit.name, name);
+                let original: String = chars[edit.range.clone()].iter().collect();
+                assert_eq!(original, edit.original);
+                chars.splice(edit.range.clone(), edit.replacement.chars());
+            }
+            replayed = chars.into_iter().collect();
+        }
+        assert_eq!(replayed, expected);
+    }
+
+    #[test]
+    fn generate_pair_corrupts_the_normalized_text() {
+        let descriptors = vec![OperationDescriptor {
+            name: "Redactyl".to_string(),
+            seed: 23,
+            operation: Operation::Redact(RedactWordsOp {
+                replacement_char: "█".to_string(),
+                rate: 0.5,
+                merge_adjacent: false,
+                merge_through_punctuation: false,
+                fixed_width: None,
+                unweighted: false,
+                weighting: WordWeighting::Length,
+            }),
+        }];
+        let pipeline = Pipeline::new(23, descriptors, Vec::new(), Vec::new());
+        let text = "  Guard the   vault ,\n\nat midnight with care ";
+        let (clean, corrupted) = pipeline.generate_pair(text).expect("pairing succeeds");
+        assert_eq!(clean, "Guard the vault,\n\nat midnight with care");
+        assert_eq!(corrupted, pipeline.run(&clean).expect("pipeline succeeds"));
+        assert_ne!(corrupted, clean);
+
+        // The alignment hunks turn the clean side into the corrupted one.
+        let mut chars: Vec<char> = clean.chars().collect();
+        for (range, original, replacement) in diff_tokens(&clean, &corrupted).into_iter().rev() {
+            assert_eq!(chars[range.clone()].iter().collect::<String>(), original);
+            chars.splice(range, replacement.chars());
+        }
+        assert_eq!(chars.into_iter().collect::<String>(), corrupted);
+    }
+
+    #[test]
+    fn rate_regions_scale_rates_per_run() {
+        let descriptors = vec![OperationDescriptor {
+            name: "Redactyl".to_string(),
+            seed: 5,
+            operation: Operation::Redact(RedactWordsOp {
+                replacement_char: "█".to_string(),
+                rate: 1.0,
+                merge_adjacent: false,
+                merge_through_punctuation: false,
+                fixed_width: None,
+                unweighted: true,
+                weighting: WordWeighting::Length,
+            }),
+        }];
+        let pipeline = Pipeline::new(5, descriptors, Vec::new(), Vec::new());
+        let (text, regions) =
+            parse_rate_markup("[[0|Keep this heading]]\nredact all body words", "[[", "]]")
+                .expect("markup parses");
+        assert_eq!(text, "Keep this heading\nredact all body words");
+        assert_eq!(
+            regions,
+            vec![RateRegion {
+                range: 0..17,
+                multiplier: 0.0,
+            }]
+        );
+
+        let output = pipeline
+            .run_with_rate_regions(&text, &regions)
+            .expect("regions run");
+        assert_eq!(output, "Keep this heading\n██████ ███ ████ █████");
+        assert_eq!(
+            pipeline.run_with_rate_regions(&text, &[]).unwrap(),
+            pipeline.run(&text).unwrap()
+        );
+    }
+
+    #[test]
+    fn rate_regions_leave_text_outside_them_as_a_plain_run() {
+        let descriptors = vec![OperationDescriptor {
+            name: "Disemvoweler".to_string(),
+            seed: 11,
+            operation: Operation::Disemvowel(DisemvowelOp { rate: 0.5 }),
+        }];
+        let pipeline = Pipeline::new(11, descriptors, Vec::new(), Vec::new());
+        let text = "quick brown foxes jumped over lazy dogs while seven sleepy \
+                    wolves watched from behind tall green hedges near the river";
+        let quiet_start = text.find("seven").unwrap();
+        let loud_start = text.find("green").unwrap();
+        let regions = vec![
+            RateRegion {
+                range: quiet_start..quiet_start + "seven sleepy wolves".len(),
+                multiplier: 0.0,
+            },
+            RateRegion {
+                range: loud_start..text.len(),
+                multiplier: 2.0,
+            },
+        ];
+
+        let plain = pipeline.run(text).expect("plain run");
+        let scaled = pipeline
+            .run_with_rate_regions(text, &regions)
+            .expect("regions run");
+        let words = |value: &str| value.split(' ').map(str::to_string).collect::<Vec<_>>();
+        let (original, plain, scaled) = (words(text), words(&plain), words(&scaled));
+        assert_eq!(scaled.len(), original.len());
+        for (index, word) in original.iter().enumerate() {
+            match index {
+                8..=10 => assert_eq!(&scaled[index], word),
+                15.. => assert_ne!(&scaled[index], word),
+                _ => assert_eq!(scaled[index], plain[index]),
+            }
+        }
+    }
+
+    #[test]
+    fn run_range_corrupts_the_passage_as_if_standalone() {
+        let descriptors = vec![OperationDescriptor {
+            name: "Redactyl".to_string(),
+            seed: 5,
+            operation: Operation::Redact(RedactWordsOp {
+                replacement_char: "█".to_string(),
+                rate: 0.5,
+                merge_adjacent: false,
+                merge_through_punctuation: false,
+                fixed_width: None,
+                unweighted: true,
+                weighting: WordWeighting::Length,
+            }),
+        }];
+        let pipeline = Pipeline::new(5, descriptors, Vec::new(), Vec::new());
+        let text = "Café notes: «quoted passage with several words» — end.";
+        let start = text.chars().position(|c| c == '«').unwrap() + 1;
+        let end = text.chars().position(|c| c == '»').unwrap();
+        let passage: String = text.chars().skip(start).take(end - start).collect();
+
+        let output = pipeline.run_range(text, start..end).expect("range run");
+        let corrupted = pipeline.run(&passage).expect("passage run");
+        assert_ne!(corrupted, passage);
+        assert_eq!(output, text.replacen(&passage, &corrupted, 1));
+
+        assert_eq!(pipeline.run_range(text, 8..8).unwrap(), text);
+        let inverted = std::ops::Range { start: 9, end: 3 };
+        assert_eq!(pipeline.run_range(text, inverted).unwrap(), text);
+        assert_eq!(
+            pipeline.run_range(text, 0..usize::MAX).unwrap(),
+            pipeline.run(text).unwrap()
+        );
+    }
+
+    #[test]
+    fn rate_markup_rejects_malformed_spans() {
+        assert!(parse_rate_markup("[[0.5 no bar]]", "[[", "]]").is_err());
+        assert!(parse_rate_markup("[[0.5|open", "[[", "]]").is_err());
+        assert!(parse_rate_markup("[[-1|neg]]", "[[", "]]").is_err());
+        assert!(parse_rate_markup("x", "", "]]").is_err());
+    }
+
+    #[test]
+    fn streaming_matches_per_chunk_runs() {
+        let descriptors = vec![OperationDescriptor {
+            name: "Rushmore-Swap".to_string(),
+            seed: 11,
+            operation: Operation::SwapAdjacent(SwapAdjacentWordsOp { rate: 0.5 }),
+        }];
+        let pipeline = Pipeline::new(11, descriptors, Vec::new(), Vec::new());
+        let text = "one two three four\nfive six seven\n\n\neight nine ten eleven\ntwelve";
+
+        let mut lines = Vec::new();
+        pipeline
+            .run_streaming(text.as_bytes(), &mut lines, ChunkBoundary::Line)
+            .expect("line streaming succeeds");
+        let expected: String = text
+            .split_inclusive('\n')
+            .enumerate()
+            .map(|(index, chunk)| pipeline.run_chunk(chunk, index).unwrap())
+            .collect();
+        assert_eq!(String::from_utf8(lines).unwrap(), expected);
+
+        let mut paragraphs = Vec::new();
+        pipeline
+            .run_streaming(text.as_bytes(), &mut paragraphs, ChunkBoundary::Paragraph)
+            .expect("paragraph streaming succeeds");
+        let first = pipeline
+            .run_chunk("one two three four\nfive six seven\n\n\n", 0)
+            .unwrap();
+        let second = pipeline.run_chunk("eight nine ten eleven\ntwelve", 1).unwrap();
+        assert_eq!(String::from_utf8(paragraphs).unwrap(), first + &second);
+    }
+
+    #[test]
+    fn paragraph_streaming_keeps_leading_blank_lines_with_first_paragraph() {
+        let mut splitter = ChunkSplitter::new(ChunkBoundary::Paragraph);
+        let mut chunks: Vec<String> = "\nA1\nA2\n\nB1\n"
+            .split_inclusive('\n')
+            .filter_map(|line| splitter.push_line(line))
+            .collect();
+        chunks.extend(splitter.finish());
+        assert_eq!(chunks, vec!["\nA1\nA2\n\n", "B1\n"]);
+
+        let descriptors = vec![OperationDescriptor {
+            name: "Rushmore-Swap".to_string(),
+            seed: 11,
+            operation: Operation::SwapAdjacent(SwapAdjacentWordsOp { rate: 0.5 }),
+        }];
+        let pipeline = Pipeline::new(11, descriptors, Vec::new(), Vec::new());
+        let text = "\n\none two three\nfour five six\n\nseven eight nine\n";
+        let mut output = Vec::new();
+        pipeline
+            .run_streaming(text.as_bytes(), &mut output, ChunkBoundary::Paragraph)
+            .expect("paragraph streaming succeeds");
+        let first = pipeline
+            .run_chunk("\n\none two three\nfour five six\n\n", 0)
+            .unwrap();
+        let second = pipeline.run_chunk("seven eight nine\n", 1).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), first + &second);
+    }
+
+    #[test]
+    fn run_documents_seeds_each_document_by_index() {
+        let master_seed = 77i128;
+        let descriptors = vec![OperationDescriptor {
+            name: "Rushmore-Swap".to_string(),
+            seed: derive_seed(master_seed, "Rushmore-Swap", 0),
+            operation: Operation::SwapAdjacent(SwapAdjacentWordsOp { rate: 0.5 }),
+        }];
+        let pipeline = Pipeline::new(master_seed, descriptors, Vec::new(), Vec::new());
+        let text = "one two three four five six seven eight nine ten";
+        let texts = vec![text; 6];
+        let outputs = pipeline.run_documents(&texts).expect("batch succeeds");
+        assert_eq!(outputs.len(), texts.len());
+        for (index, output) in outputs.iter().enumerate() {
+            assert_eq!(output, &pipeline.run_document(text, index).expect("document succeeds"));
+        }
+        assert!(outputs.iter().any(|output| output != &outputs[0]));
     }
 
     #[test]
@@ -285,6 +1582,9 @@ mod tests {
             operation: Operation::Reduplicate(ReduplicateWordsOp {
                 rate: 0.5,
                 unweighted: false,
+                repeats: 1,
+                stutter: false,
+                separator: ReduplicationSeparator::Space,
             }),
         }];
         let pipeline = Pipeline::new(master_seed, descriptors, Vec::new(), Vec::new());
@@ -304,6 +1604,9 @@ mod tests {
                 operation: Operation::Reduplicate(ReduplicateWordsOp {
                     rate: 0.4,
                     unweighted: false,
+                    repeats: 1,
+                    stutter: false,
+                    separator: ReduplicationSeparator::Space,
                 }),
             },
             OperationDescriptor {
@@ -312,6 +1615,9 @@ mod tests {
                 operation: Operation::Delete(DeleteRandomWordsOp {
                     rate: 0.3,
                     unweighted: false,
+                    min_words: 0,
+                    weighting: WordWeighting::Length,
+                    preserve_spacing: false,
                 }),
             },
             OperationDescriptor {
@@ -321,7 +1627,10 @@ mod tests {
                     replacement_char: "█".to_string(),
                     rate: 0.6,
                     merge_adjacent: true,
+                    merge_through_punctuation: false,
+                    fixed_width: None,
                     unweighted: false,
+                    weighting: WordWeighting::Length,
                 }),
             },
             OperationDescriptor {
@@ -401,4 +1710,26 @@ mod tests {
         ];
         assert_eq!(plan, expected);
     }
+
+    #[test]
+    fn plan_steps_match_plan_entries() {
+        let inputs: Vec<GagglePlanInput> = ["Typogre", "Rushmore", "Mim1c"]
+            .iter()
+            .enumerate()
+            .map(|(index, name)| GagglePlanInput {
+                index,
+                name: (*name).to_string(),
+                scope: 5 - index as i32,
+                order: 1,
+            })
+            .collect();
+        let entries = plan_gaggle(inputs.clone(), 404);
+        let steps = plan_gaggle_steps(inputs, 404);
+        assert_eq!(entries.len(), steps.len());
+        for (entry, step) in entries.iter().zip(&steps) {
+            assert_eq!((entry.index, entry.seed), (step.index, step.seed));
+            assert_eq!(step.seed, derive_seed(404, &step.name, step.index as i128));
+        }
+        assert_eq!(steps[0].name, "Mim1c");
+    }
 }
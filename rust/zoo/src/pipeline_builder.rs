@@ -0,0 +1,490 @@
+//! Chainable pipeline construction for Python.
+//!
+//! [`PipelineBuilder`] assembles the same [`PyOperationConfig`] values the
+//! descriptor-dict parser produces, so `PipelineBuilder().typo(rate=0.1, ...)`
+//! compiles to exactly the pipeline a `{"type": "typo", "rate": 0.1, ...}`
+//! descriptor would, without going through stringly-typed dicts.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::keyboard_typos;
+use crate::pipeline::{derive_seed, OperationDescriptor, Pipeline, PipelineError};
+use crate::{
+    extract_layout_vec, parse_ocr_mode, parse_ocr_sampling, parse_reduplication_separator,
+    parse_word_weighting, CaseMode, ContractionMode, DiacriticMode, MotorWeighting, OcrMode,
+    OcrSampling, PyOperationConfig,
+};
+
+/// A queued operation; its seed is derived at build time unless pinned.
+#[derive(Debug, Clone)]
+struct BuilderStep {
+    name: String,
+    seed: Option<u64>,
+    config: PyOperationConfig,
+}
+
+/// Builds a [`Pipeline`] one operation at a time.
+///
+/// Every method appends an operation and returns the builder, so calls chain.
+/// Each accepts optional `name` (defaults to the operation type) and `seed`;
+/// unpinned seeds are derived from the master seed, the name and the
+/// operation's position when [`PipelineBuilder::build`] runs.
+#[derive(Debug, Clone, Default)]
+#[pyclass(module = "_corruption_engine")]
+pub(crate) struct PipelineBuilder {
+    steps: Vec<BuilderStep>,
+}
+
+impl PipelineBuilder {
+    fn descriptors(&self, master_seed: i128) -> PyResult<Vec<OperationDescriptor>> {
+        self.steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| {
+                let seed = step
+                    .seed
+                    .unwrap_or_else(|| derive_seed(master_seed, &step.name, index as i128));
+                Ok(OperationDescriptor {
+                    name: step.name.clone(),
+                    seed,
+                    operation: step.config.clone().into_operation(seed)?,
+                })
+            })
+            .collect()
+    }
+}
+
+fn push<'py>(
+    mut slf: PyRefMut<'py, PipelineBuilder>,
+    op_type: &str,
+    name: Option<String>,
+    seed: Option<u64>,
+    config: PyOperationConfig,
+) -> PyRefMut<'py, PipelineBuilder> {
+    slf.steps.push(BuilderStep {
+        name: name.unwrap_or_else(|| op_type.to_string()),
+        seed,
+        config,
+    });
+    slf
+}
+
+#[pymethods]
+impl PipelineBuilder {
+    #[new]
+    fn py_new() -> Self {
+        Self::default()
+    }
+
+    fn __len__(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Compile the queued operations, validating their parameters.
+    #[pyo3(signature = (master_seed, include_only_patterns=None, exclude_patterns=None, max_total_edits=None))]
+    fn build(
+        &self,
+        master_seed: i128,
+        include_only_patterns: Option<Vec<String>>,
+        exclude_patterns: Option<Vec<String>>,
+        max_total_edits: Option<usize>,
+    ) -> PyResult<Pipeline> {
+        Pipeline::compile(
+            master_seed,
+            self.descriptors(master_seed)?,
+            include_only_patterns.unwrap_or_default(),
+            exclude_patterns.unwrap_or_default(),
+            max_total_edits,
+        )
+        .map_err(PipelineError::into_pyerr)
+    }
+
+    /// Append an operation from a descriptor dict, for types without a method.
+    #[pyo3(signature = (operation, name=None, seed=None))]
+    fn operation<'py>(
+        slf: PyRefMut<'py, Self>,
+        operation: &Bound<'py, PyDict>,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let op_type: String = operation
+            .get_item("type")?
+            .ok_or_else(|| PyValueError::new_err("operation missing 'type' field"))?
+            .extract()?;
+        let config = operation.extract::<PyOperationConfig>()?;
+        Ok(push(slf, &op_type, name, seed, config))
+    }
+
+    #[pyo3(signature = (rate, unweighted=false, repeats=1, stutter=false, separator="space", name=None, seed=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn reduplicate<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        unweighted: bool,
+        repeats: usize,
+        stutter: bool,
+        separator: &str,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let separator = parse_reduplication_separator(separator)?;
+        let config =
+            PyOperationConfig::Reduplicate { rate, unweighted, repeats, stutter, separator };
+        Ok(push(slf, "reduplicate", name, seed, config))
+    }
+
+    #[pyo3(signature = (rate, unweighted=false, min_words=0, weighting="length", preserve_spacing=false, name=None, seed=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn delete<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        unweighted: bool,
+        min_words: usize,
+        weighting: &str,
+        preserve_spacing: bool,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let weighting = parse_word_weighting(weighting)?;
+        let config =
+            PyOperationConfig::Delete { rate, unweighted, min_words, weighting, preserve_spacing };
+        Ok(push(slf, "delete", name, seed, config))
+    }
+
+    #[pyo3(signature = (rate, name=None, seed=None))]
+    fn swap_adjacent<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "swap_adjacent", name, seed, PyOperationConfig::SwapAdjacent { rate })
+    }
+
+    #[pyo3(signature = (rate, max_distance=1, name=None, seed=None))]
+    fn swap_words<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        max_distance: usize,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        let config = PyOperationConfig::SwapWords { rate, max_distance };
+        push(slf, "swap_words", name, seed, config)
+    }
+
+    #[pyo3(signature = (rate, name=None, seed=None))]
+    fn delete_sentences<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "delete_sentences", name, seed, PyOperationConfig::DeleteSentences { rate })
+    }
+
+    #[pyo3(signature = (rate=1.0, name=None, seed=None))]
+    fn shuffle_sentences<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "shuffle_sentences", name, seed, PyOperationConfig::ShuffleSentences { rate })
+    }
+
+    #[pyo3(signature = (rate, name=None, seed=None))]
+    fn phonetic<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "phonetic", name, seed, PyOperationConfig::Phonetic { rate })
+    }
+
+    #[pyo3(signature = (rate, name=None, seed=None))]
+    fn bidi<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "bidi", name, seed, PyOperationConfig::Bidi { rate })
+    }
+
+    #[pyo3(signature = (rate, mode, name=None, seed=None))]
+    fn case<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        mode: &str,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let mode = CaseMode::parse(mode).map_err(PyValueError::new_err)?;
+        Ok(push(slf, "case", name, seed, PyOperationConfig::Case { rate, mode }))
+    }
+
+    #[pyo3(signature = (rate, mode, name=None, seed=None))]
+    fn contractions<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        mode: &str,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let mode = ContractionMode::parse(mode).map_err(PyValueError::new_err)?;
+        let config = PyOperationConfig::Contractions { rate, mode };
+        Ok(push(slf, "contractions", name, seed, config))
+    }
+
+    #[pyo3(signature = (rate, name=None, seed=None))]
+    fn disemvowel<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "disemvowel", name, seed, PyOperationConfig::Disemvowel { rate })
+    }
+
+    #[pyo3(signature = (rate, name=None, seed=None))]
+    fn double_words<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "double_words", name, seed, PyOperationConfig::DoubleWords { rate })
+    }
+
+    #[pyo3(signature = (rate, name=None, seed=None))]
+    fn scramble<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "scramble", name, seed, PyOperationConfig::Scramble { rate })
+    }
+
+    #[pyo3(signature = (rate, lowercase_sentence_starts=false, name=None, seed=None))]
+    fn drop_punctuation<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        lowercase_sentence_starts: bool,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        let config = PyOperationConfig::DropPunctuation { rate, lowercase_sentence_starts };
+        push(slf, "drop_punctuation", name, seed, config)
+    }
+
+    #[pyo3(signature = (rate, name=None, seed=None))]
+    fn autocorrect<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "autocorrect", name, seed, PyOperationConfig::Autocorrect { rate })
+    }
+
+    #[pyo3(signature = (rate, name=None, seed=None))]
+    fn merge_words<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "merge_words", name, seed, PyOperationConfig::MergeWords { rate })
+    }
+
+    #[pyo3(signature = (rate, min_length=crate::word_boundaries::DEFAULT_SPLIT_MIN_LENGTH, name=None, seed=None))]
+    fn split_words<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        min_length: usize,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        let config = PyOperationConfig::SplitWords { rate, min_length };
+        push(slf, "split_words", name, seed, config)
+    }
+
+    #[pyo3(signature = (rate, mode, name=None, seed=None))]
+    fn diacritics<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        mode: &str,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let mode = DiacriticMode::parse(mode).map_err(PyValueError::new_err)?;
+        Ok(push(slf, "diacritics", name, seed, PyOperationConfig::Diacritics { rate, mode }))
+    }
+
+    #[pyo3(signature = (rate, intensity, name=None, seed=None))]
+    fn zalgo<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        intensity: usize,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "zalgo", name, seed, PyOperationConfig::Zalgo { intensity, rate })
+    }
+
+    #[pyo3(signature = (rate, replacement_char="\u{2588}".to_string(), merge_adjacent=false, merge_through_punctuation=false, fixed_width=None, unweighted=false, weighting="length", name=None, seed=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn redact<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        replacement_char: String,
+        merge_adjacent: bool,
+        merge_through_punctuation: bool,
+        fixed_width: Option<usize>,
+        unweighted: bool,
+        weighting: &str,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let weighting = parse_word_weighting(weighting)?;
+        let config = PyOperationConfig::Redact {
+            replacement_char,
+            rate,
+            merge_adjacent,
+            merge_through_punctuation,
+            fixed_width,
+            unweighted,
+            weighting,
+        };
+        Ok(push(slf, "redact", name, seed, config))
+    }
+
+    #[pyo3(signature = (rate, burst_enter=0.0, burst_exit=0.3, burst_multiplier=3.0, bias_k=0, bias_beta=2.0, space_drop_rate=0.0, space_insert_rate=0.0, mode=None, sampling=None, extra_confusions=None, name=None, seed=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn ocr<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        burst_enter: f64,
+        burst_exit: f64,
+        burst_multiplier: f64,
+        bias_k: usize,
+        bias_beta: f64,
+        space_drop_rate: f64,
+        space_insert_rate: f64,
+        mode: Option<&str>,
+        sampling: Option<&str>,
+        extra_confusions: Option<Vec<(String, Vec<String>)>>,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let mode = mode.map_or(Ok(OcrMode::Both), parse_ocr_mode)?;
+        let sampling = sampling.map_or(Ok(OcrSampling::Count), parse_ocr_sampling)?;
+        let config = PyOperationConfig::Ocr {
+            rate,
+            burst_enter,
+            burst_exit,
+            burst_multiplier,
+            bias_k,
+            bias_beta,
+            space_drop_rate,
+            space_insert_rate,
+            mode,
+            sampling,
+            extra_confusions: extra_confusions.unwrap_or_default(),
+        };
+        Ok(push(slf, "ocr", name, seed, config))
+    }
+
+    #[pyo3(signature = (rate, layout, keyboard=None, motor_weighting=None, motor_weights=None, shift_slip_rate=0.0, shift_slip_exit_rate=None, shift_slip_min_hold=None, shift_slip_mode=None, shift_map=None, grapheme_aware=false, burstiness=0.0, homerow_bias=0.0, syllable_swaps=false, name=None, seed=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn typo<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        layout: &Bound<'py, PyDict>,
+        keyboard: Option<&str>,
+        motor_weighting: Option<&str>,
+        motor_weights: Option<&Bound<'py, PyDict>>,
+        shift_slip_rate: f64,
+        shift_slip_exit_rate: Option<f64>,
+        shift_slip_min_hold: Option<usize>,
+        shift_slip_mode: Option<&str>,
+        shift_map: Option<&Bound<'py, PyDict>>,
+        grapheme_aware: bool,
+        burstiness: f64,
+        homerow_bias: f64,
+        syllable_swaps: bool,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let shift_map = shift_map.map(keyboard_typos::extract_shift_map).transpose()?;
+        let shift_slip = keyboard_typos::build_shift_slip_config(
+            shift_slip_rate,
+            shift_slip_exit_rate,
+            shift_slip_min_hold,
+            shift_slip_mode,
+            shift_map,
+        )?;
+        let motor_weighting = match motor_weights {
+            Some(weights) => keyboard_typos::extract_motor_weights(weights)?,
+            None => motor_weighting.and_then(MotorWeighting::parse).unwrap_or_default(),
+        };
+        let config = PyOperationConfig::Typo {
+            rate,
+            layout: extract_layout_vec(layout)?,
+            shift_slip,
+            motor_weighting,
+            grapheme_aware,
+            burstiness,
+            homerow_bias,
+            keyboard: keyboard_typos::parse_keyboard(keyboard)?,
+            syllable_swaps,
+        };
+        Ok(push(slf, "typo", name, seed, config))
+    }
+
+    #[pyo3(signature = (rate, extension_min=2, extension_max=5, word_length_threshold=6, base_p=0.45, name=None, seed=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn hokey<'py>(
+        slf: PyRefMut<'py, Self>,
+        rate: f64,
+        extension_min: i32,
+        extension_max: i32,
+        word_length_threshold: usize,
+        base_p: f64,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        let config = PyOperationConfig::Hokey {
+            rate,
+            extension_min,
+            extension_max,
+            word_length_threshold,
+            base_p,
+        };
+        push(slf, "hokey", name, seed, config)
+    }
+
+    #[pyo3(signature = (contractions=false, name=None, seed=None))]
+    fn quote_pairs<'py>(
+        slf: PyRefMut<'py, Self>,
+        contractions: bool,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "quote_pairs", name, seed, PyOperationConfig::QuotePairs { contractions })
+    }
+
+    #[pyo3(signature = (name=None, seed=None))]
+    fn identity<'py>(
+        slf: PyRefMut<'py, Self>,
+        name: Option<String>,
+        seed: Option<u64>,
+    ) -> PyRefMut<'py, Self> {
+        push(slf, "identity", name, seed, PyOperationConfig::Identity)
+    }
+}
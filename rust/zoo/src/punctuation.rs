@@ -0,0 +1,168 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::resources::split_affixes_ref;
+use crate::text_buffer::TextBuffer;
+
+/// Punctuation marks eligible for removal from word affixes.
+pub(crate) const DROPPABLE_PUNCTUATION: &[char] = &['.', ',', '!', '?', ';', ':'];
+
+#[inline]
+const fn ends_sentence(ch: char) -> bool {
+    matches!(ch, '.' | '!' | '?')
+}
+
+/// Lowercases a title-case word ("Then" → "then"). Single letters and words
+/// with further capitals ("I", "NASA", "McDonald") are left alone.
+fn lowercase_title(core: &str) -> Option<String> {
+    let mut chars = core.chars();
+    let first = chars.next()?;
+    let rest = chars.as_str();
+    if !first.is_uppercase() || rest.is_empty() || rest.chars().any(char::is_uppercase) {
+        return None;
+    }
+    let mut lowered: String = first.to_lowercase().collect();
+    lowered.push_str(rest);
+    Some(lowered)
+}
+
+/// Drops punctuation from word prefixes and suffixes to mimic informal,
+/// run-on chat text.
+///
+/// Each droppable mark in a word's affixes is removed with probability `rate`;
+/// punctuation inside a word's core ("3.14", "don't", "well-known") is never
+/// touched. With `lowercase_sentence_starts`, a title-case word following a
+/// dropped `.`, `!` or `?` is lowercased as well.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DropPunctuationOp {
+    pub rate: f64,
+    pub lowercase_sentence_starts: bool,
+}
+
+impl TextOperation for DropPunctuationOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        let mut dropped_terminal = false;
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx) else {
+                continue;
+            };
+            if !segment.is_mutable() {
+                dropped_terminal = false;
+                continue;
+            }
+
+            let (prefix, core, suffix) = split_affixes_ref(segment.text());
+            if core.is_empty() {
+                continue;
+            }
+
+            let rate = rate * segment.rate_scale();
+            let mut changed = false;
+            let mut kept_prefix = String::with_capacity(prefix.len());
+            for ch in prefix.chars() {
+                if DROPPABLE_PUNCTUATION.contains(&ch) && rng.random()? < rate {
+                    changed = true;
+                } else {
+                    kept_prefix.push(ch);
+                }
+            }
+
+            let mut new_core = None;
+            if self.lowercase_sentence_starts && dropped_terminal && kept_prefix.is_empty() {
+                new_core = lowercase_title(core);
+            }
+
+            dropped_terminal = false;
+            let mut kept_suffix = String::with_capacity(suffix.len());
+            for ch in suffix.chars() {
+                if DROPPABLE_PUNCTUATION.contains(&ch) && rng.random()? < rate {
+                    changed = true;
+                    dropped_terminal |= ends_sentence(ch);
+                } else {
+                    dropped_terminal &= !ends_sentence(ch);
+                    kept_suffix.push(ch);
+                }
+            }
+
+            if changed || new_core.is_some() {
+                let core = new_core.as_deref().unwrap_or(core);
+                replacements.push((idx, format!("{kept_prefix}{core}{kept_suffix}")));
+            }
+        }
+
+        let edits = replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_words_bulk(replacements)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, seed=None, lowercase_sentence_starts=false, return_count=false))]
+pub(crate) fn drop_punctuation(
+    text: &str,
+    rate: f64,
+    seed: Option<u64>,
+    lowercase_sentence_starts: bool,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = DropPunctuationOp {
+        rate,
+        lowercase_sentence_starts,
+    };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DropPunctuationOp;
+    use crate::operations::apply_seeded;
+
+    fn drop(text: &str, rate: f64, lowercase_sentence_starts: bool) -> String {
+        let op = DropPunctuationOp {
+            rate,
+            lowercase_sentence_starts,
+        };
+        apply_seeded(&op, text, 2051)
+    }
+
+    #[test]
+    fn drops_affix_punctuation_but_keeps_interior_marks() {
+        assert_eq!(
+            drop("Pi is 3.14, isn't it? Well-known: yes!", 1.0, false),
+            "Pi is 3.14 isn't it Well-known yes"
+        );
+        assert_eq!(drop("(Wait.) \"Really?\"", 1.0, false), "(Wait) \"Really\"");
+    }
+
+    #[test]
+    fn optionally_lowercases_sentence_starts() {
+        assert_eq!(
+            drop("Done. Then I left! NASA called. Ok", 1.0, true),
+            "Done then I left NASA called ok"
+        );
+        assert_eq!(drop("Done. Then", 1.0, false), "Done Then");
+    }
+
+    #[test]
+    fn partial_rate_is_deterministic() {
+        let text = "One, two; three: four. Five! Six?";
+        let result = drop(text, 0.5, true);
+        assert_eq!(result, drop(text, 0.5, true));
+        assert_ne!(result, text);
+        assert_eq!(drop(text, 0.0, true), text);
+    }
+}
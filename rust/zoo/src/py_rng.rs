@@ -0,0 +1,128 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::operations::{OperationError, OperationRng, TextOperation};
+use crate::rng::RngError;
+use crate::text_buffer::TextBuffer;
+use crate::GlitchOutput;
+
+/// Drives an operation from a caller-supplied Python RNG.
+///
+/// Two interfaces are accepted, detected once at construction:
+///
+/// - `random.Random`-like objects: `.random()` for floats in `[0, 1)` and
+///   `.randrange(n)` for indices;
+/// - numpy `Generator`s: `.random()` and `.integers(n)`.
+///
+/// Sharing one seeded generator across an augmentation stack then keeps the
+/// whole stack reproducible. Calls go through the GIL, so ops driven by this
+/// adapter cannot run detached.
+pub(crate) struct PythonRngAdapter<'py> {
+    rng: Bound<'py, PyAny>,
+    index_method: &'static str,
+    /// The first exception raised by the RNG, re-raised unchanged once the
+    /// operation unwinds.
+    error: Option<PyErr>,
+}
+
+impl<'py> PythonRngAdapter<'py> {
+    pub(crate) fn new(rng: Bound<'py, PyAny>) -> PyResult<Self> {
+        let index_method = if !rng.hasattr("random")? {
+            None
+        } else if rng.hasattr("randrange")? {
+            Some("randrange")
+        } else if rng.hasattr("integers")? {
+            Some("integers")
+        } else {
+            None
+        };
+
+        let Some(index_method) = index_method else {
+            let type_name = rng.get_type().name()?.to_string();
+            return Err(PyTypeError::new_err(format!(
+                "rng must provide random() and randrange(n) (random.Random) or random() and \
+                 integers(n) (numpy.random.Generator); got {type_name}"
+            )));
+        };
+
+        Ok(Self {
+            rng,
+            index_method,
+            error: None,
+        })
+    }
+
+    fn fail(&mut self, err: PyErr) -> OperationError {
+        let message = err.to_string();
+        self.error.get_or_insert(err);
+        OperationError::Callback(message)
+    }
+}
+
+impl OperationRng for PythonRngAdapter<'_> {
+    fn random(&mut self) -> Result<f64, OperationError> {
+        self.rng
+            .call_method0("random")
+            .and_then(|value| value.extract())
+            .map_err(|err| self.fail(err))
+    }
+
+    fn rand_index(&mut self, upper: usize) -> Result<usize, OperationError> {
+        if upper == 0 {
+            return Err(RngError::EmptyRange("rand_index").into());
+        }
+        let index: usize = self
+            .rng
+            .call_method1(self.index_method, (upper,))
+            .and_then(|value| value.extract())
+            .map_err(|err| self.fail(err))?;
+        if index >= upper {
+            let err = PyValueError::new_err(format!(
+                "rng.{}({upper}) returned out-of-range value {index}",
+                self.index_method
+            ));
+            return Err(self.fail(err));
+        }
+        Ok(index)
+    }
+
+    fn sample_indices(&mut self, population: usize, k: usize) -> Result<Vec<usize>, OperationError> {
+        if k > population {
+            return Err(RngError::SampleSizeTooLarge {
+                requested: k,
+                population,
+            }
+            .into());
+        }
+        // Partial Fisher-Yates so every draw goes through the caller's RNG.
+        let mut pool: Vec<usize> = (0..population).collect();
+        for slot in 0..k {
+            let pick = slot + self.rand_index(population - slot)?;
+            pool.swap(slot, pick);
+        }
+        pool.truncate(k);
+        Ok(pool)
+    }
+}
+
+/// Applies `op` with the Python `rng` when given, otherwise with the seeded
+/// deterministic RNG used by [`crate::apply_operation`].
+pub(crate) fn apply_with_optional_rng<O: TextOperation>(
+    text: &str,
+    op: O,
+    seed: Option<u64>,
+    rng: Option<Bound<'_, PyAny>>,
+    return_count: bool,
+) -> PyResult<GlitchOutput> {
+    let Some(rng) = rng else {
+        return crate::apply_operation_py(text, op, seed, return_count);
+    };
+
+    let mut adapter = PythonRngAdapter::new(rng)?;
+    let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+    let outcome = match op.apply(&mut buffer, &mut adapter) {
+        Ok(outcome) => outcome,
+        Err(err) => return Err(adapter.error.take().unwrap_or_else(|| err.into_pyerr())),
+    };
+    Ok(GlitchOutput::new(buffer.to_string(), outcome.edits, return_count))
+}
@@ -1,12 +1,17 @@
-use aho_corasick::AhoCorasick;
-use std::sync::LazyLock;
-use std::collections::HashMap;
+use aho_corasick::{AhoCorasick, MatchKind};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, LazyLock};
+use std::collections::{HashMap, HashSet};
+
+use crate::cache::ContentCache;
 
 const RAW_APOSTROFAE_PAIRS: &str = include_str!(concat!(env!("OUT_DIR"), "/apostrofae_pairs.json"));
 
 const RAW_OCR_CONFUSIONS: &str = include_str!(concat!(env!("OUT_DIR"), "/ocr_confusions.tsv"));
 const RAW_EKKOKIN_HOMOPHONES: &str =
     include_str!(concat!(env!("OUT_DIR"), "/ekkokin_homophones.json"));
+const RAW_STOPWORDS: &str = include_str!(concat!(env!("OUT_DIR"), "/stopwords.txt"));
 
 /// Replacement pairs used by the Apostrofae glitchling.
 pub static APOSTROFAE_PAIR_TABLE: LazyLock<HashMap<char, Vec<(String, String)>>> = LazyLock::new(|| {
@@ -87,12 +92,170 @@ pub fn ocr_automaton() -> &'static AhoCorasick {
     &OCR_AUTOMATON
 }
 
+/// High-confidence reverse OCR mappings as `(noisy, source)` pairs.
+///
+/// Inverts the confusion table, keeping a noisy form only when exactly one
+/// source produces it. Pairs the table lists in both directions ("rn" and "m",
+/// "0" and "O") are kept only where the noisy form is longer than its source,
+/// since an equal-length swap gives no hint which side was the original.
+static OCR_CORRECTIONS: LazyLock<Vec<(&'static str, &'static str)>> = LazyLock::new(|| {
+    let mut sources: HashMap<&'static str, Vec<&'static str>> = HashMap::new();
+    for (source, replacements) in confusion_table() {
+        for noisy in *replacements {
+            let entry = sources.entry(*noisy).or_default();
+            if !entry.contains(source) {
+                entry.push(source);
+            }
+        }
+    }
+
+    let mut corrections: Vec<(&'static str, &'static str)> = sources
+        .iter()
+        .filter_map(|(noisy, candidates)| match candidates.as_slice() {
+            [source] => {
+                let mutual = sources.get(source).is_some_and(|back| back.contains(noisy));
+                let longer = noisy.chars().count() > source.chars().count();
+                (!mutual || longer).then_some((*noisy, *source))
+            }
+            _ => None,
+        })
+        .collect();
+    corrections.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(b.0)));
+    corrections
+});
+
+static OCR_CORRECTION_AUTOMATON: LazyLock<AhoCorasick> = LazyLock::new(|| {
+    AhoCorasick::builder()
+        .match_kind(MatchKind::LeftmostLongest)
+        .build(OCR_CORRECTIONS.iter().map(|(noisy, _)| *noisy))
+        .expect("OCR correction patterns should build a valid automaton")
+});
+
+/// Returns the reverse OCR mappings `ocr_correct` applies, longest noisy form
+/// first.
+#[inline]
+pub fn ocr_corrections() -> &'static [(&'static str, &'static str)] {
+    OCR_CORRECTIONS.as_slice()
+}
+
+/// Best-effort inverse of the OCR operation: greedily replaces every noisy
+/// sequence in [`ocr_corrections`] with its source, leftmost-longest first.
+///
+/// This is not an OCR corrector. Genuine text that happens to contain a noisy
+/// form ("modern" holds "rn") is rewritten too; it exists to derive clean
+/// training targets from OCR-styled input.
+pub fn ocr_correct(text: &str) -> String {
+    let sources: Vec<&str> = ocr_corrections().iter().map(|(_, source)| *source).collect();
+    OCR_CORRECTION_AUTOMATON.replace_all(text, &sources)
+}
+
+/// The bundled OCR confusions extended with caller-supplied pairs, with its
+/// own automaton. Built per operation, so the shared table stays untouched.
+#[derive(Debug)]
+pub struct ConfusionTable {
+    extras: Vec<(String, Vec<String>)>,
+    entries: Vec<(String, Vec<String>)>,
+    automaton: AhoCorasick,
+}
+
+/// Merged tables keyed by their extras, so identical ops share one automaton.
+static CONFUSION_TABLE_CACHE: LazyLock<ContentCache<ConfusionTable>> =
+    LazyLock::new(ContentCache::new);
+
+impl ConfusionTable {
+    /// Like [`Self::with_extras`], but reuses the table built for an identical
+    /// `extras` list, so building many equal ops compiles the automaton once.
+    ///
+    /// The key respects order: extras merge in sequence, so a reordered list
+    /// can yield a different table.
+    #[must_use]
+    pub fn shared(extras: &[(String, Vec<String>)]) -> Arc<Self> {
+        let mut hasher = DefaultHasher::new();
+        extras.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        if let Some(cached) = CONFUSION_TABLE_CACHE.get(hash) {
+            if cached.extras == extras {
+                return cached;
+            }
+        }
+        CONFUSION_TABLE_CACHE.get_or_insert_matching(hash, Self::with_extras(extras), |a, b| {
+            a.extras == b.extras
+        })
+    }
+
+    /// Merges `extras` into the bundled table. Replacements for a source the
+    /// table already knows are appended to its choices; new sources are added
+    /// and the table is re-sorted longest source first, as the bundled one is.
+    #[must_use]
+    pub fn with_extras(extras: &[(String, Vec<String>)]) -> Self {
+        let mut entries: Vec<(String, Vec<String>)> = confusion_table()
+            .iter()
+            .map(|(source, choices)| {
+                (
+                    (*source).to_string(),
+                    choices.iter().map(|choice| (*choice).to_string()).collect(),
+                )
+            })
+            .collect();
+
+        for (source, replacements) in extras {
+            if source.is_empty() || replacements.is_empty() {
+                continue;
+            }
+            match entries.iter_mut().find(|(existing, _)| existing == source) {
+                Some((_, choices)) => {
+                    for replacement in replacements {
+                        if !choices.contains(replacement) {
+                            choices.push(replacement.clone());
+                        }
+                    }
+                }
+                None => entries.push((source.clone(), replacements.clone())),
+            }
+        }
+
+        entries.sort_by_key(|(source, _)| std::cmp::Reverse(source.len()));
+        let automaton = AhoCorasick::new(entries.iter().map(|(source, _)| source.as_str()))
+            .expect("OCR patterns should build a valid automaton");
+        Self {
+            extras: extras.to_vec(),
+            entries,
+            automaton,
+        }
+    }
+
+    #[must_use]
+    pub fn entries(&self) -> &[(String, Vec<String>)] {
+        &self.entries
+    }
+
+    #[must_use]
+    pub const fn automaton(&self) -> &AhoCorasick {
+        &self.automaton
+    }
+}
+
 /// Parsed homophone sets for the Wherewolf glitchling.
 pub static WHEREWOLF_HOMOPHONE_SETS: LazyLock<Vec<Vec<String>>> = LazyLock::new(|| {
     serde_json::from_str(RAW_EKKOKIN_HOMOPHONES)
         .expect("Wherewolf homophone table should be valid JSON")
 });
 
+/// Bundled English function words backing the "content" word weighting.
+static STOPWORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    RAW_STOPWORDS
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
+});
+
+/// Returns true when `word` is in the bundled stopword list, ignoring case.
+pub fn is_stopword(word: &str) -> bool {
+    STOPWORDS.contains(word.to_lowercase().as_str())
+}
+
 /// Returns the pre-sorted OCR confusion table.
 #[inline]
 pub fn confusion_table() -> &'static [(&'static str, &'static [&'static str])] {
@@ -203,7 +366,20 @@ pub fn split_affixes_ref(word: &str) -> (&str, &str, &str) {
 
 #[cfg(test)]
 mod tests {
-    use super::{apostrofae_pairs, confusion_table, split_affixes, split_affixes_ref, split_with_separators};
+    use super::{
+        apostrofae_pairs, confusion_table, is_stopword, ocr_correct, ocr_corrections,
+        split_affixes, split_affixes_ref, split_with_separators, ConfusionTable,
+    };
+    use std::sync::Arc;
+
+    #[test]
+    fn stopwords_match_case_insensitively() {
+        assert!(is_stopword("the"));
+        assert!(is_stopword("The"));
+        assert!(is_stopword("THEIR"));
+        assert!(!is_stopword("cathedral"));
+        assert!(!is_stopword(""));
+    }
 
     #[test]
     fn split_with_separators_matches_expected_boundaries() {
@@ -252,6 +428,44 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn confusion_table_extras_extend_bundled_entries() {
+        let extras = vec![
+            ("rn".to_string(), vec!["nn".to_string(), "m".to_string()]),
+            ("qu".to_string(), vec!["gu".to_string()]),
+        ];
+        let table = ConfusionTable::with_extras(&extras);
+        assert_eq!(table.entries().len(), confusion_table().len() + 1);
+        let rn = table.entries().iter().find(|(source, _)| source == "rn").unwrap();
+        assert_eq!(rn.1, ["m", "nn"]);
+        assert!(table.entries().windows(2).all(|pair| pair[0].0.len() >= pair[1].0.len()));
+        assert!(table.automaton().find("a quiet").is_some());
+    }
+
+    #[test]
+    fn shared_confusion_tables_are_built_once_per_extras() {
+        let extras = vec![("qu".to_string(), vec!["gu".to_string()])];
+        let first = ConfusionTable::shared(&extras);
+        let second = ConfusionTable::shared(&extras.clone());
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(first.entries(), ConfusionTable::with_extras(&extras).entries());
+
+        let other = ConfusionTable::shared(&[("qu".to_string(), vec!["qv".to_string()])]);
+        assert!(!Arc::ptr_eq(&first, &other));
+    }
+
+    #[test]
+    fn ocr_correct_reverses_unambiguous_confusions() {
+        assert_eq!(ocr_correct("the rnodel was vvet"), "the model was wet");
+        assert!(ocr_corrections().contains(&("rn", "m")));
+
+        // "l" comes from both "I" and "1", and "0"/"O" swap at equal length,
+        // so neither direction is trusted.
+        assert!(ocr_corrections().iter().all(|(noisy, _)| !["l", "I", "0", "O"].contains(noisy)));
+        assert_eq!(ocr_correct("l0g 0f It"), "l0g 0f It");
+        assert_eq!(ocr_correct(""), "");
+    }
+
     #[test]
     fn apostrofae_pairs_loaded_from_asset() {
         let table = apostrofae_pairs();
@@ -26,28 +26,161 @@ impl fmt::Display for RngError {
 
 impl std::error::Error for RngError {}
 
+/// Maps a uniform draw `u` in `[0, 1)` to a geometric variate by inversion:
+/// the number of failures before the first success of a Bernoulli(`p`)
+/// trial, so `P(k) = (1 - p)^k * p` and the mean is `(1 - p) / p`.
+///
+/// `p` is clamped to `(0, 1]`; NaN is treated as `1.0`, which always yields 0.
+pub(crate) fn geometric_from_uniform(u: f64, p: f64) -> usize {
+    let p = if p.is_nan() { 1.0 } else { p.clamp(f64::MIN_POSITIVE, 1.0) };
+    if p >= 1.0 {
+        return 0;
+    }
+    let failures = ((-u).ln_1p() / (-p).ln_1p()).floor();
+    // `as` saturates, which only matters for vanishingly small `p`.
+    failures as usize
+}
+
+/// Default PCG32 stream selector (the increment before it is forced odd).
+pub const PCG32_DEFAULT_STREAM: u64 = 0xDA3E_39CB_94B9_5BDB;
+
+const PCG32_MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+/// PCG-XSH-RR 64/32 generator, bit-for-bit identical to the reference
+/// `pcg32_srandom_r` / `pcg32_random_r` implementation.
+#[derive(Clone, Debug)]
+struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32 {
+    fn new(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        rng.next_u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next_u32();
+        rng
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.state = old
+            .wrapping_mul(PCG32_MULTIPLIER)
+            .wrapping_add(self.increment);
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rotation = (old >> 59) as u32;
+        xorshifted.rotate_right(rotation)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let high = u64::from(self.next_u32());
+        let low = u64::from(self.next_u32());
+        (high << 32) | low
+    }
+
+    /// Lemire's nearly-divisionless bounded draw in `[0, upper)`.
+    fn bounded_u32(&mut self, upper: u32) -> u32 {
+        let mut product = u64::from(self.next_u32()) * u64::from(upper);
+        let mut low = product as u32;
+        if low < upper {
+            let threshold = upper.wrapping_neg() % upper;
+            while low < threshold {
+                product = u64::from(self.next_u32()) * u64::from(upper);
+                low = product as u32;
+            }
+        }
+        (product >> 32) as u32
+    }
+
+    /// 64-bit variant of [`Pcg32::bounded_u32`] for bounds above `u32::MAX`.
+    fn bounded_u64(&mut self, upper: u64) -> u64 {
+        let mut product = u128::from(self.next_u64()) * u128::from(upper);
+        let mut low = product as u64;
+        if low < upper {
+            let threshold = upper.wrapping_neg() % upper;
+            while low < threshold {
+                product = u128::from(self.next_u64()) * u128::from(upper);
+                low = product as u64;
+            }
+        }
+        (product >> 64) as u64
+    }
+}
+
+#[derive(Clone)]
+enum Engine {
+    Small(SmallRng),
+    Pcg32(Pcg32),
+}
+
+/// Seeded RNG behind every corruption operation.
+///
+/// [`DeterministicRng::new`] wraps rand 0.8's `SmallRng` (Xoshiro256++ on
+/// 64-bit targets, seeded through `seed_from_u64`). That stream is stable
+/// for a given rand version but is not a published standard, so
+/// [`DeterministicRng::pcg32`] offers a portable alternative:
+///
+/// - bits: PCG-XSH-RR 64/32, seeded as in the reference `pcg32_srandom_r(seed, stream)`;
+/// - `random()`: two outputs `hi`, `lo` form `((hi << 32) | lo) >> 11`, scaled by 2^-53;
+/// - `rand_index(upper)`: Lemire's bounded method on one output when
+///   `upper <= u32::MAX`, otherwise on a 64-bit `(hi << 32) | lo` draw;
+/// - `sample_indices(n, k)`: partial Fisher-Yates where slot `i` swaps with
+///   `i + rand_index(n - i)`.
 #[derive(Clone)]
 pub struct DeterministicRng {
-    inner: SmallRng,
+    inner: Engine,
 }
 
 impl DeterministicRng {
     #[must_use] 
     pub fn new(seed: u64) -> Self {
         Self {
-            inner: SmallRng::seed_from_u64(seed),
+            inner: Engine::Small(SmallRng::seed_from_u64(seed)),
+        }
+    }
+
+    /// Portable PCG32 stream on [`PCG32_DEFAULT_STREAM`].
+    #[must_use]
+    pub fn pcg32(seed: u64) -> Self {
+        Self::pcg32_with_stream(seed, PCG32_DEFAULT_STREAM)
+    }
+
+    /// Portable PCG32 stream with an explicit stream selector.
+    #[must_use]
+    pub fn pcg32_with_stream(seed: u64, stream: u64) -> Self {
+        Self {
+            inner: Engine::Pcg32(Pcg32::new(seed, stream)),
         }
     }
 
     pub fn random(&mut self) -> f64 {
-        self.inner.gen::<f64>()
+        match &mut self.inner {
+            Engine::Small(rng) => rng.gen::<f64>(),
+            Engine::Pcg32(rng) => (rng.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
+        }
+    }
+
+    /// Geometric draw consuming a single [`Self::random`] value; see
+    /// [`geometric_from_uniform`] for the distribution.
+    pub fn geometric(&mut self, p: f64) -> usize {
+        geometric_from_uniform(self.random(), p)
     }
 
     pub fn rand_index(&mut self, upper: usize) -> Result<usize, RngError> {
         if upper == 0 {
             return Err(RngError::EmptyRange("rand_index"));
         }
-        Ok(self.inner.gen_range(0..upper))
+        match &mut self.inner {
+            Engine::Small(rng) => Ok(rng.gen_range(0..upper)),
+            Engine::Pcg32(rng) => Ok(match u32::try_from(upper) {
+                Ok(bound) => rng.bounded_u32(bound) as usize,
+                Err(_) => rng.bounded_u64(upper as u64) as usize,
+            }),
+        }
     }
 
     pub fn sample_indices(&mut self, population: usize, k: usize) -> Result<Vec<usize>, RngError> {
@@ -57,8 +190,18 @@ impl DeterministicRng {
                 population,
             });
         }
-        let sample = index::sample(&mut self.inner, population, k);
-        Ok(sample.into_iter().collect())
+        match &mut self.inner {
+            Engine::Small(rng) => Ok(index::sample(rng, population, k).into_iter().collect()),
+            Engine::Pcg32(_) => {
+                let mut pool: Vec<usize> = (0..population).collect();
+                for slot in 0..k {
+                    let pick = slot + self.rand_index(population - slot)?;
+                    pool.swap(slot, pick);
+                }
+                pool.truncate(k);
+                Ok(pool)
+            }
+        }
     }
 
     pub fn sample<T: Clone>(&mut self, population: &[T], k: usize) -> Result<Vec<T>, RngError> {
@@ -68,16 +211,20 @@ impl DeterministicRng {
                 population: population.len(),
             });
         }
-        Ok(population
-            .choose_multiple(&mut self.inner, k)
-            .cloned()
-            .collect())
+        match &mut self.inner {
+            Engine::Small(rng) => Ok(population.choose_multiple(rng, k).cloned().collect()),
+            Engine::Pcg32(_) => Ok(self
+                .sample_indices(population.len(), k)?
+                .into_iter()
+                .map(|index| population[index].clone())
+                .collect()),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::DeterministicRng;
+    use super::{geometric_from_uniform, DeterministicRng, Pcg32};
 
     #[test]
     #[allow(clippy::unreadable_literal)] // Reference values from Python - separators would harm readability
@@ -119,4 +266,56 @@ mod tests {
         assert_eq!(actual.len(), 5);
         assert!(actual.into_iter().all(|value| population.contains(&value)));
     }
+
+    #[test]
+    fn pcg32_matches_reference_stream() {
+        // First outputs of the reference pcg32-demo seeded with (42, 54).
+        let mut rng = Pcg32::new(42, 54);
+        let expected = [0xa15c_02b7, 0x7b47_f409, 0xba1d_3330, 0x83d2_f293, 0xbfa4_784b, 0xcbed_606e];
+        for value in expected {
+            assert_eq!(rng.next_u32(), value);
+        }
+    }
+
+    #[test]
+    fn pcg32_draws_stay_in_range_and_repeat() {
+        let mut first = DeterministicRng::pcg32(151);
+        let mut second = DeterministicRng::pcg32(151);
+        for upper in 1..200usize {
+            let value = first.rand_index(upper).unwrap();
+            assert!(value < upper);
+            assert_eq!(value, second.rand_index(upper).unwrap());
+            let float = first.random();
+            assert!((0.0..1.0).contains(&float));
+            assert_eq!(float.to_bits(), second.random().to_bits());
+        }
+        let sample = first.sample_indices(10, 10).unwrap();
+        let mut sorted = sample.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn geometric_mean_matches_failure_count_expectation() {
+        let mut rng = DeterministicRng::new(2043);
+        for p in [0.2, 0.5, 0.8] {
+            let draws = 20_000;
+            let total: usize = (0..draws).map(|_| rng.geometric(p)).sum();
+            let mean = total as f64 / f64::from(draws);
+            let expected = (1.0 - p) / p;
+            assert!(
+                (mean - expected).abs() < 0.05 * (expected + 1.0),
+                "p={p}: mean {mean}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn geometric_clamps_probability() {
+        assert_eq!(geometric_from_uniform(0.99, 1.0), 0);
+        assert_eq!(geometric_from_uniform(0.99, 7.5), 0);
+        assert_eq!(geometric_from_uniform(0.99, f64::NAN), 0);
+        assert!(geometric_from_uniform(0.5, 0.0) > 1_000_000);
+        assert_eq!(geometric_from_uniform(0.0, 0.3), 0);
+    }
 }
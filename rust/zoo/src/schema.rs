@@ -0,0 +1,380 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+/// Default value of an optional operation parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ParamDefault {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(&'static str),
+    EmptyList,
+    /// Unset: the operation falls back to its own behaviour.
+    None,
+}
+
+/// One parameter of an operation descriptor; `default` is `None` when the
+/// parameter is required.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ParamSpec {
+    pub name: &'static str,
+    pub default: Option<ParamDefault>,
+}
+
+/// Schema for one pipeline operation `type`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct OperationSpec {
+    pub op_type: &'static str,
+    pub aliases: &'static [&'static str],
+    pub description: &'static str,
+    pub params: &'static [ParamSpec],
+}
+
+const fn required(name: &'static str) -> ParamSpec {
+    ParamSpec {
+        name,
+        default: None,
+    }
+}
+
+const fn optional(name: &'static str, default: ParamDefault) -> ParamSpec {
+    ParamSpec {
+        name,
+        default: Some(default),
+    }
+}
+
+const RATE: ParamSpec = required("rate");
+
+/// Every descriptor `type` accepted by the pipeline parser, mirroring the
+/// match arms of `PyOperationConfig::extract_bound`. The Python test
+/// `tests/core/test_operation_schema.py` feeds each entry through that parser.
+pub(crate) const OPERATION_SPECS: &[OperationSpec] = &[
+    OperationSpec {
+        op_type: "reduplicate",
+        aliases: &[],
+        description: "Repeat words in place (\"yes yes\", \"bye-bye\").",
+        params: &[
+            RATE,
+            optional("unweighted", ParamDefault::Bool(false)),
+            optional("repeats", ParamDefault::Int(1)),
+            optional("stutter", ParamDefault::Bool(false)),
+            optional("separator", ParamDefault::Str("space")),
+        ],
+    },
+    OperationSpec {
+        op_type: "delete",
+        aliases: &[],
+        description: "Delete random words.",
+        params: &[
+            RATE,
+            optional("unweighted", ParamDefault::Bool(false)),
+            optional("min_words", ParamDefault::Int(0)),
+            optional("weighting", ParamDefault::Str("length")),
+            optional("preserve_spacing", ParamDefault::Bool(false)),
+        ],
+    },
+    OperationSpec {
+        op_type: "swap_adjacent",
+        aliases: &[],
+        description: "Swap the cores of adjacent word pairs.",
+        params: &[RATE],
+    },
+    OperationSpec {
+        op_type: "swap_words",
+        aliases: &[],
+        description: "Swap words with a partner up to max_distance words away.",
+        params: &[RATE, optional("max_distance", ParamDefault::Int(1))],
+    },
+    OperationSpec {
+        op_type: "delete_sentences",
+        aliases: &[],
+        description: "Delete whole sentences.",
+        params: &[RATE],
+    },
+    OperationSpec {
+        op_type: "shuffle_sentences",
+        aliases: &[],
+        description: "Shuffle sentence order.",
+        params: &[optional("rate", ParamDefault::Float(1.0))],
+    },
+    OperationSpec {
+        op_type: "phonetic",
+        aliases: &[],
+        description: "Respell words with sound-alike spellings.",
+        params: &[RATE],
+    },
+    OperationSpec {
+        op_type: "bidi",
+        aliases: &[],
+        description: "Wrap words in bidirectional override controls.",
+        params: &[RATE],
+    },
+    OperationSpec {
+        op_type: "fillers",
+        aliases: &[],
+        description: "Insert spoken-style filler words between words.",
+        params: &[
+            RATE,
+            optional("fillers", ParamDefault::None),
+            optional("commas", ParamDefault::Bool(false)),
+            optional("trailing", ParamDefault::Bool(false)),
+        ],
+    },
+    OperationSpec {
+        op_type: "whitespace_jitter",
+        aliases: &[],
+        description: "Replace separators with alternative whitespace.",
+        params: &[RATE, optional("palette", ParamDefault::None)],
+    },
+    OperationSpec {
+        op_type: "case",
+        aliases: &[],
+        description: "Randomise letter casing (random, alternating, random_words).",
+        params: &[RATE, required("mode")],
+    },
+    OperationSpec {
+        op_type: "contractions",
+        aliases: &[],
+        description: "Contract or expand English contractions (contract, expand).",
+        params: &[RATE, required("mode")],
+    },
+    OperationSpec {
+        op_type: "disemvowel",
+        aliases: &[],
+        description: "Drop interior vowels from words.",
+        params: &[RATE],
+    },
+    OperationSpec {
+        op_type: "double_words",
+        aliases: &[],
+        description: "Repeat function words, as in \"to to the store\".",
+        params: &[RATE],
+    },
+    OperationSpec {
+        op_type: "scramble",
+        aliases: &[],
+        description: "Shuffle the interior letters of words, keeping the first and last.",
+        params: &[RATE],
+    },
+    OperationSpec {
+        op_type: "drop_punctuation",
+        aliases: &[],
+        description: "Drop punctuation marks.",
+        params: &[RATE, optional("lowercase_sentence_starts", ParamDefault::Bool(false))],
+    },
+    OperationSpec {
+        op_type: "autocorrect",
+        aliases: &[],
+        description: "Replace words with plausible autocorrect mistakes.",
+        params: &[RATE],
+    },
+    OperationSpec {
+        op_type: "merge_words",
+        aliases: &[],
+        description: "Glue adjacent words together.",
+        params: &[RATE],
+    },
+    OperationSpec {
+        op_type: "split_words",
+        aliases: &[],
+        description: "Split longer words in two.",
+        params: &[RATE, optional("min_length", ParamDefault::Int(6))],
+    },
+    OperationSpec {
+        op_type: "diacritics",
+        aliases: &[],
+        description: "Strip or add accents (strip, add).",
+        params: &[RATE, required("mode")],
+    },
+    OperationSpec {
+        op_type: "zalgo",
+        aliases: &[],
+        description: "Stack combining marks on letters.",
+        params: &[required("intensity"), RATE],
+    },
+    OperationSpec {
+        op_type: "rushmore_combo",
+        aliases: &[],
+        description: "Run Rushmore's delete, duplicate and swap word attacks together.",
+        params: &[
+            required("modes"),
+            optional("delete", ParamDefault::None),
+            optional("duplicate", ParamDefault::None),
+            optional("swap", ParamDefault::None),
+            optional("shuffle_modes", ParamDefault::Bool(false)),
+        ],
+    },
+    OperationSpec {
+        op_type: "redact",
+        aliases: &[],
+        description: "Black out words with a replacement character.",
+        params: &[
+            required("replacement_char"),
+            RATE,
+            required("merge_adjacent"),
+            optional("merge_through_punctuation", ParamDefault::Bool(false)),
+            optional("fixed_width", ParamDefault::None),
+            optional("unweighted", ParamDefault::Bool(false)),
+            optional("weighting", ParamDefault::Str("length")),
+        ],
+    },
+    OperationSpec {
+        op_type: "ocr",
+        aliases: &[],
+        description: "Simulate OCR confusions and spacing errors.",
+        params: &[
+            RATE,
+            optional("burst_enter", ParamDefault::Float(0.0)),
+            optional("burst_exit", ParamDefault::Float(0.3)),
+            optional("burst_multiplier", ParamDefault::Float(3.0)),
+            optional("bias_k", ParamDefault::Int(0)),
+            optional("bias_beta", ParamDefault::Float(2.0)),
+            optional("space_drop_rate", ParamDefault::Float(0.0)),
+            optional("space_insert_rate", ParamDefault::Float(0.0)),
+            optional("mode", ParamDefault::Str("both")),
+            optional("sampling", ParamDefault::Str("count")),
+            optional("extra_confusions", ParamDefault::EmptyList),
+        ],
+    },
+    OperationSpec {
+        op_type: "typo",
+        aliases: &[],
+        description: "Introduce keyboard typos from a layout's neighbour map.",
+        params: &[
+            RATE,
+            required("layout"),
+            optional("shift_slip_rate", ParamDefault::Float(0.0)),
+            optional("shift_slip_exit_rate", ParamDefault::None),
+            optional("shift_slip_min_hold", ParamDefault::None),
+            optional("shift_slip_mode", ParamDefault::None),
+            optional("shift_map", ParamDefault::None),
+            optional("motor_weighting", ParamDefault::Str("uniform")),
+            optional("grapheme_aware", ParamDefault::Bool(false)),
+            optional("burstiness", ParamDefault::Float(0.0)),
+            optional("homerow_bias", ParamDefault::Float(0.0)),
+            optional("motor_weights", ParamDefault::None),
+            optional("keyboard", ParamDefault::Str("qwerty")),
+            optional("syllable_swaps", ParamDefault::Bool(false)),
+        ],
+    },
+    OperationSpec {
+        op_type: "mimic",
+        aliases: &[],
+        description: "Swap characters for confusable homoglyphs.",
+        params: &[
+            RATE,
+            optional("classes", ParamDefault::None),
+            optional("banned_characters", ParamDefault::None),
+            optional("mode", ParamDefault::Str("mixed_script")),
+            optional("max_consecutive", ParamDefault::Int(3)),
+            optional("only_characters", ParamDefault::None),
+        ],
+    },
+    OperationSpec {
+        op_type: "zwj",
+        aliases: &[],
+        description: "Inject zero-width characters.",
+        params: &[
+            RATE,
+            optional("characters", ParamDefault::EmptyList),
+            optional("visibility", ParamDefault::Str("glyphless")),
+            optional("placement", ParamDefault::Str("random")),
+            optional("max_consecutive", ParamDefault::Int(4)),
+        ],
+    },
+    OperationSpec {
+        op_type: "jargoyle",
+        aliases: &[],
+        description: "Substitute words from a lexeme dictionary.",
+        params: &[
+            optional("lexemes", ParamDefault::Str("synonyms")),
+            optional("mode", ParamDefault::Str("drift")),
+            RATE,
+        ],
+    },
+    OperationSpec {
+        op_type: "wherewolf",
+        aliases: &[],
+        description: "Swap words for homophones.",
+        params: &[
+            RATE,
+            optional("weighting", ParamDefault::Str("flat")),
+            optional("extra_sets", ParamDefault::EmptyList),
+        ],
+    },
+    OperationSpec {
+        op_type: "pedant",
+        aliases: &[],
+        description: "Apply a grammar rule; stone is required unless direction is given.",
+        params: &[
+            optional("direction", ParamDefault::None),
+            optional("stone", ParamDefault::None),
+        ],
+    },
+    OperationSpec {
+        op_type: "apostrofae",
+        aliases: &["quote_pairs"],
+        description: "Replace straight quote pairs with fancy counterparts.",
+        params: &[optional("contractions", ParamDefault::Bool(false))],
+    },
+    OperationSpec {
+        op_type: "hokey",
+        aliases: &[],
+        description: "Stretch expressive words (\"sooo\").",
+        params: &[
+            RATE,
+            required("extension_min"),
+            required("extension_max"),
+            required("word_length_threshold"),
+            optional("base_p", ParamDefault::Float(0.45)),
+        ],
+    },
+    OperationSpec {
+        op_type: "identity",
+        aliases: &[],
+        description: "Leave the text unchanged while holding a plan slot and seed.",
+        params: &[],
+    },
+];
+
+fn set_default(dict: &Bound<'_, PyDict>, name: &str, default: ParamDefault) -> PyResult<()> {
+    match default {
+        ParamDefault::Bool(value) => dict.set_item(name, value),
+        ParamDefault::Int(value) => dict.set_item(name, value),
+        ParamDefault::Float(value) => dict.set_item(name, value),
+        ParamDefault::Str(value) => dict.set_item(name, value),
+        ParamDefault::EmptyList => dict.set_item(name, PyList::empty(dict.py())),
+        ParamDefault::None => dict.set_item(name, dict.py().None()),
+    }
+}
+
+/// Describes every pipeline operation: its `type`, accepted aliases, required
+/// parameter names, optional parameters with their defaults and a summary.
+#[pyfunction]
+pub(crate) fn list_operations(py: Python<'_>) -> PyResult<Vec<Bound<'_, PyDict>>> {
+    OPERATION_SPECS
+        .iter()
+        .map(|spec| {
+            let entry = PyDict::new(py);
+            entry.set_item("type", spec.op_type)?;
+            entry.set_item("aliases", spec.aliases.to_vec())?;
+            entry.set_item("description", spec.description)?;
+            let required: Vec<&str> = spec
+                .params
+                .iter()
+                .filter(|param| param.default.is_none())
+                .map(|param| param.name)
+                .collect();
+            entry.set_item("required", required)?;
+            let optional = PyDict::new(py);
+            for param in spec.params {
+                if let Some(default) = param.default {
+                    set_default(&optional, param.name, default)?;
+                }
+            }
+            entry.set_item("optional", optional)?;
+            Ok(entry)
+        })
+        .collect()
+}
@@ -0,0 +1,121 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::resources::split_affixes_ref;
+use crate::text_buffer::TextBuffer;
+
+/// Cores this short have no interior worth shuffling.
+const MIN_SCRAMBLE_CHARS: usize = 4;
+
+/// Typoglycemia: "according" → "aroccdnig".
+///
+/// Each word whose core has at least four characters is selected with
+/// probability `rate`, and its interior characters are reordered by a
+/// Fisher-Yates shuffle. The first and last characters of the core and every
+/// affix stay in place, so "(research)," keeps its brackets, comma, `r` and
+/// `h`. A shuffle may land back on the original order; that is not an edit.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ScrambleOp {
+    pub rate: f64,
+}
+
+impl TextOperation for ScrambleOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx) else {
+                continue;
+            };
+            if !segment.is_mutable() {
+                continue;
+            }
+            let (prefix, core, suffix) = split_affixes_ref(segment.text());
+            let mut chars: Vec<char> = core.chars().collect();
+            if chars.len() < MIN_SCRAMBLE_CHARS {
+                continue;
+            }
+            if rng.random()? >= rate * segment.rate_scale() {
+                continue;
+            }
+
+            let last = chars.len() - 1;
+            let interior = &mut chars[1..last];
+            for slot in (1..interior.len()).rev() {
+                let pick = rng.rand_index(slot + 1)?;
+                interior.swap(slot, pick);
+            }
+
+            let mut output = String::with_capacity(segment.text().len());
+            output.push_str(prefix);
+            output.extend(chars);
+            output.push_str(suffix);
+            if output != segment.text() {
+                replacements.push((idx, output));
+            }
+        }
+
+        let edits = replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_words_bulk(replacements)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, seed=None, return_count=false))]
+pub(crate) fn scramble_interior(
+    text: &str,
+    rate: f64,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = ScrambleOp { rate };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScrambleOp;
+    use crate::operations::apply_seeded;
+
+    fn scramble(text: &str, rate: f64, seed: u64) -> String {
+        apply_seeded(&ScrambleOp { rate }, text, seed)
+    }
+
+    fn sorted(text: &str) -> Vec<char> {
+        let mut chars: Vec<char> = text.chars().collect();
+        chars.sort_unstable();
+        chars
+    }
+
+    #[test]
+    fn interior_letters_move_between_fixed_ends() {
+        let scrambled = scramble("according", 1.0, 3);
+        assert_ne!(scrambled, "according");
+        assert!(scrambled.starts_with('a') && scrambled.ends_with('g'));
+        assert_eq!(sorted(&scrambled), sorted("according"));
+        assert_eq!(scrambled, scramble("according", 1.0, 3));
+    }
+
+    #[test]
+    fn short_words_and_affixes_stay_put() {
+        assert_eq!(scramble("the cat ran", 1.0, 3), "the cat ran");
+        let scrambled = scramble("(research), today!", 1.0, 5);
+        assert!(scrambled.starts_with("(r") && scrambled.contains("h), t"));
+        assert!(scrambled.ends_with("y!"));
+        assert_eq!(sorted(&scrambled), sorted("(research), today!"));
+        assert_eq!(scramble("according to research", 0.0, 5), "according to research");
+    }
+}
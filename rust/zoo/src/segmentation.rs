@@ -0,0 +1,169 @@
+//! Sentence segmentation shared by [`crate::text_buffer::TextBuffer`],
+//! the sentence operations and the metrics.
+
+use std::ops::Range;
+
+/// Abbreviations whose trailing period should not end a sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "dr", "mr", "mrs", "ms", "mx", "prof", "sr", "jr", "mt", "vs", "e.g", "i.e", "a.m", "p.m",
+    "inc", "ltd", "fig", "approx", "dept", "gov", "sgt", "capt", "lt",
+];
+
+/// Abbreviations that are also common last words of a sentence; their period
+/// only continues the sentence when a lowercase word follows ("etc. and").
+const LOWERCASE_CONTINUED: &[&str] = &["etc", "co", "st", "gen"];
+
+/// Words that usually open a sentence, so a single capital before one is a
+/// letter ("Plan B. Then") rather than an initial ("J. Doe").
+const SENTENCE_STARTERS: &[&str] = &[
+    "a", "an", "and", "but", "he", "i", "if", "it", "she", "so", "that", "the", "then", "there",
+    "these", "they", "this", "we", "what", "when", "you",
+];
+
+#[inline]
+const fn is_terminator(ch: char) -> bool {
+    matches!(ch, '.' | '!' | '?')
+}
+
+#[inline]
+const fn is_closer(ch: char) -> bool {
+    matches!(ch, '"' | '\'' | ')' | ']' | '\u{201D}' | '\u{2019}')
+}
+
+/// Returns true when the period ending at `dot` belongs to an abbreviation or
+/// an initial ("J. Smith") rather than closing a sentence. `following` is the
+/// text after the period and any closers.
+fn is_abbreviation(text: &str, dot: usize, following: &str) -> bool {
+    let word_start = text[..dot]
+        .char_indices()
+        .rev()
+        .find(|(_, ch)| ch.is_whitespace())
+        .map_or(0, |(idx, ch)| idx + ch.len_utf8());
+    let word = text[word_start..dot].trim_start_matches(|ch: char| !ch.is_alphanumeric());
+    if word.is_empty() {
+        return false;
+    }
+
+    let next_word = following
+        .trim_start()
+        .split(char::is_whitespace)
+        .next()
+        .unwrap_or("")
+        .trim_start_matches(|ch: char| !ch.is_alphanumeric());
+    let next_char = next_word.chars().next();
+
+    let mut chars = word.chars();
+    if let (Some(first), None) = (chars.next(), chars.next()) {
+        let next_lowered = next_word
+            .trim_end_matches(|ch: char| !ch.is_alphanumeric())
+            .to_lowercase();
+        return first.is_uppercase() && !SENTENCE_STARTERS.contains(&next_lowered.as_str());
+    }
+
+    let lowered = word.to_lowercase();
+    match lowered.as_str() {
+        "no" => next_char.is_some_and(|ch| ch.is_ascii_digit()),
+        other if LOWERCASE_CONTINUED.contains(&other) => next_char.is_some_and(char::is_lowercase),
+        other => ABBREVIATIONS.contains(&other),
+    }
+}
+
+/// Returns the byte ranges of each sentence in `text`.
+///
+/// A sentence ends at a run of `.`, `!` or `?` (plus any closing quotes or
+/// brackets) followed by whitespace or the end of the text. Ranges exclude
+/// the surrounding whitespace.
+pub(crate) fn sentence_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((idx, ch)) = chars.next() {
+        if start.is_none() {
+            if ch.is_whitespace() {
+                continue;
+            }
+            start = Some(idx);
+        }
+
+        if !is_terminator(ch) {
+            continue;
+        }
+
+        let mut end = idx + ch.len_utf8();
+        let mut single_period = ch == '.';
+        while let Some(&(next_idx, next)) = chars.peek() {
+            if is_terminator(next) || is_closer(next) {
+                single_period &= !is_terminator(next);
+                end = next_idx + next.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        let at_boundary = chars.peek().is_none_or(|&(_, next)| next.is_whitespace());
+        if !at_boundary || (single_period && is_abbreviation(text, idx, &text[end..])) {
+            continue;
+        }
+
+        if let Some(sentence_start) = start.take() {
+            ranges.push(sentence_start..end);
+        }
+    }
+
+    if let Some(sentence_start) = start {
+        let end = sentence_start + text[sentence_start..].trim_end().len();
+        if end > sentence_start {
+            ranges.push(sentence_start..end);
+        }
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sentence_ranges;
+
+    fn sentences(text: &str) -> Vec<&str> {
+        sentence_ranges(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    #[test]
+    fn splits_on_terminal_punctuation() {
+        assert_eq!(
+            sentences("  One. Two?! \"Three.\" Four"),
+            vec!["One.", "Two?!", "\"Three.\"", "Four"]
+        );
+    }
+
+    #[test]
+    fn abbreviations_and_initials_do_not_split() {
+        assert_eq!(
+            sentences("Dr. Smith met J. Doe at 3.14 p.m. today. Then left."),
+            vec!["Dr. Smith met J. Doe at 3.14 p.m. today.", "Then left."]
+        );
+    }
+
+    #[test]
+    fn sentence_final_words_and_letters_still_split() {
+        assert_eq!(sentences("He said no. She left."), vec!["He said no.", "She left."]);
+        assert_eq!(sentences("Plan B. Then go."), vec!["Plan B.", "Then go."]);
+        assert_eq!(
+            sentences("Pens, paper, etc. The rest stays. Main St. We walked."),
+            vec!["Pens, paper, etc.", "The rest stays.", "Main St.", "We walked."]
+        );
+    }
+
+    #[test]
+    fn context_dependent_abbreviations_continue_the_sentence() {
+        assert_eq!(
+            sentences("See No. 5 and pens, paper, etc. and more. Done."),
+            vec!["See No. 5 and pens, paper, etc. and more.", "Done."]
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use pyo3::prelude::*;
+use std::ops::Range;
+use serde::{Deserialize, Serialize};
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::segmentation::sentence_ranges;
+use crate::text_buffer::{RebuildPiece, SegmentKind, TextBuffer};
+
+/// Removes whole sentences at the given rate.
+///
+/// The sentences to drop are sampled together, so every position is equally
+/// likely to go. At least one sentence always survives. Kept sentences retain the
+/// whitespace that originally preceded them, so paragraph breaks survive.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DeleteSentencesOp {
+    pub rate: f64,
+}
+
+impl TextOperation for DeleteSentencesOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= 0.0 {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let text = buffer.to_string();
+        let ranges = sentence_ranges(&text);
+        if ranges.len() < 2 {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        // Sentences overlapping an immutable segment are never deleted.
+        let protected: Vec<Range<usize>> = buffer
+            .spans()
+            .iter()
+            .filter(|span| span.kind == SegmentKind::Immutable)
+            .map(|span| span.byte_range.clone())
+            .collect();
+        let pinned: Vec<bool> = ranges
+            .iter()
+            .map(|range| {
+                protected
+                    .iter()
+                    .any(|span| span.start < range.end && range.start < span.end)
+            })
+            .collect();
+
+        // Pick the doomed sentences in one draw so every position is equally
+        // likely; at most all but one sentence go, and pinned ones never do.
+        let spans = buffer.spans();
+        let scale_at = |offset: usize| {
+            spans
+                .iter()
+                .position(|span| span.byte_range.contains(&offset))
+                .map_or(1.0, |segment| buffer.rate_scale(segment))
+        };
+        let candidates: Vec<(usize, f64)> = ranges
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| !pinned[index])
+            .map(|(index, range)| (index, scale_at(range.start)))
+            .collect();
+        let expected = rate * candidates.iter().map(|(_, scale)| scale).sum::<f64>();
+        let mut count = expected.floor() as usize;
+        if expected > count as f64 && rng.random()? < expected - count as f64 {
+            count += 1;
+        }
+        let count = count.min(ranges.len() - 1);
+        let mut keep = vec![true; ranges.len()];
+        let deleted = rng.weighted_sample(&candidates, count)?;
+        for &index in &deleted {
+            keep[index] = false;
+        }
+        let deletions = deleted.len();
+
+        if deletions == 0 {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let first_start = ranges[0].start;
+        let last_end = ranges[ranges.len() - 1].end;
+        let mut pieces = vec![RebuildPiece::Kept(0..first_start)];
+
+        let mut emitted_any = false;
+        for (index, range) in ranges.iter().enumerate() {
+            if !keep[index] {
+                continue;
+            }
+            if emitted_any {
+                pieces.push(RebuildPiece::Kept(ranges[index - 1].end..range.start));
+            }
+            pieces.push(RebuildPiece::Kept(range.clone()));
+            emitted_any = true;
+        }
+
+        pieces.push(RebuildPiece::Kept(last_end..text.len()));
+        *buffer = buffer.rebuild_from_pieces(pieces);
+        Ok(OpOutcome::edits(deletions))
+    }
+}
+
+/// Reorders whole sentences within each paragraph.
+///
+/// Paragraphs are split at separators holding a blank line. Each paragraph
+/// of two or more sentences is shuffled with probability `rate` using a
+/// Fisher-Yates pass. The whitespace between sentences stays in place, so
+/// only sentence order changes; each sentence's own text is kept verbatim.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ShuffleSentencesOp {
+    pub rate: f64,
+}
+
+impl TextOperation for ShuffleSentencesOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= 0.0 {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        buffer.reindex_if_needed();
+        let ranges = buffer.sentences();
+        if ranges.len() < 2 {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let segments = buffer.segments();
+        // Byte range covered by a range of segments.
+        let spans = buffer.spans();
+        let span_bytes = |range: Range<usize>| -> Range<usize> {
+            if range.is_empty() {
+                return 0..0;
+            }
+            spans[range.start].byte_range.start..spans[range.end - 1].byte_range.end
+        };
+        let span_text = |range: Range<usize>| -> String {
+            segments[range].iter().map(|segment| segment.text()).collect()
+        };
+
+        // Group sentence indices into paragraphs.
+        let mut paragraphs: Vec<Vec<usize>> = vec![vec![0]];
+        for index in 1..ranges.len() {
+            let gap = span_text(ranges[index - 1].end..ranges[index].start);
+            if gap.matches('\n').count() >= 2 {
+                paragraphs.push(Vec::new());
+            }
+            if let Some(paragraph) = paragraphs.last_mut() {
+                paragraph.push(index);
+            }
+        }
+
+        // Sentences holding an immutable segment keep their position; only the
+        // remaining sentences of a paragraph trade places.
+        let pinned = |sentence: usize| {
+            segments[ranges[sentence].clone()]
+                .iter()
+                .any(|segment| !segment.is_mutable())
+        };
+        let paragraphs: Vec<Vec<usize>> = paragraphs
+            .into_iter()
+            .map(|paragraph| paragraph.into_iter().filter(|&sentence| !pinned(sentence)).collect())
+            .collect();
+
+        let mut order: Vec<usize> = (0..ranges.len()).collect();
+        let mut shuffled = false;
+        for paragraph in paragraphs.iter().filter(|paragraph| paragraph.len() >= 2) {
+            // A paragraph takes the rate scale of its first movable sentence.
+            let rate = rate * buffer.rate_scale(ranges[paragraph[0]].start);
+            if rate < 1.0 && rng.random()? >= rate {
+                continue;
+            }
+            let mut slots = paragraph.clone();
+            for slot in (1..slots.len()).rev() {
+                let pick = rng.rand_index(slot + 1)?;
+                slots.swap(slot, pick);
+            }
+            for (position, sentence) in paragraph.iter().zip(slots) {
+                order[*position] = sentence;
+            }
+            shuffled = true;
+        }
+
+        let moved = order
+            .iter()
+            .enumerate()
+            .filter(|&(position, &sentence)| position != sentence)
+            .count();
+        if !shuffled || moved == 0 {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut pieces = vec![RebuildPiece::Kept(span_bytes(0..ranges[0].start))];
+        for (position, &sentence) in order.iter().enumerate() {
+            if position > 0 {
+                pieces.push(RebuildPiece::Kept(span_bytes(
+                    ranges[position - 1].end..ranges[position].start,
+                )));
+            }
+            pieces.push(RebuildPiece::Kept(span_bytes(ranges[sentence].clone())));
+        }
+        pieces.push(RebuildPiece::Kept(span_bytes(
+            ranges[ranges.len() - 1].end..segments.len(),
+        )));
+
+        *buffer = buffer.rebuild_from_pieces(pieces);
+        Ok(OpOutcome::edits(moved))
+    }
+}
+
+#[pyfunction(signature = (text, rate, seed=None, return_count=false))]
+pub(crate) fn delete_sentences(
+    text: &str,
+    rate: f64,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = DeleteSentencesOp { rate };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[pyfunction(signature = (text, seed=None, rate=1.0, return_count=false))]
+pub(crate) fn shuffle_sentences(
+    text: &str,
+    seed: Option<u64>,
+    rate: f64,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = ShuffleSentencesOp { rate };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DeleteSentencesOp, ShuffleSentencesOp};
+    use crate::segmentation::sentence_ranges;
+    use crate::operations::TextOperation;
+    use crate::rng::DeterministicRng;
+    use crate::text_buffer::TextBuffer;
+
+    fn sentences(text: &str) -> Vec<&str> {
+        sentence_ranges(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    fn delete(text: &str, rate: f64, seed: u64) -> String {
+        let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+        let mut rng = DeterministicRng::new(seed);
+        DeleteSentencesOp { rate }
+            .apply(&mut buffer, &mut rng)
+            .expect("deletion succeeds");
+        buffer.to_string()
+    }
+
+    #[test]
+    fn deletion_keeps_one_sentence_and_clean_spacing() {
+        let text = "First one. Second one!\n\nThird one? Fourth one.";
+        for seed in 0u64..8 {
+            let result = delete(text, 1.0, seed);
+            assert_eq!(sentences(&result), vec![result.as_str()]);
+            assert!(sentences(text).contains(&result.as_str()));
+        }
+    }
+
+    #[test]
+    fn deletions_spread_evenly_across_positions() {
+        let labels = ["A0.", "B1.", "C2.", "D3.", "E4.", "F5.", "G6.", "H7."];
+        let text = labels.join(" ");
+        let trials = 2000u64;
+        let mut deleted = [0u64; 8];
+        for seed in 0..trials {
+            let result = delete(&text, 0.25, seed);
+            for (count, label) in deleted.iter_mut().zip(labels) {
+                *count += u64::from(!result.contains(label));
+            }
+        }
+        // Each sentence should go a quarter of the time, wherever it sits.
+        for count in deleted {
+            assert!((400..600).contains(&count), "uneven deletions: {deleted:?}");
+        }
+    }
+
+    #[test]
+    fn partial_deletion_never_leaves_leading_space() {
+        let text = "Alpha beta. Gamma delta. Epsilon zeta. Eta theta.";
+        for seed in 0u64..16 {
+            let result = delete(text, 0.5, seed);
+            assert!(!result.starts_with(' '));
+            assert!(!result.contains("  "));
+            assert!(!result.is_empty());
+        }
+    }
+
+    fn shuffle(text: &str, rate: f64, seed: u64) -> String {
+        let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+        let mut rng = DeterministicRng::new(seed);
+        ShuffleSentencesOp { rate }
+            .apply(&mut buffer, &mut rng)
+            .expect("shuffle succeeds");
+        buffer.to_string()
+    }
+
+    #[test]
+    fn shuffle_moves_whole_sentences_within_paragraphs() {
+        let text = "  Alpha one. Beta two!  Gamma, 3.5 three?\n\nDelta four. Epsilon five. ";
+        let mut saw_change = false;
+        for seed in 0u64..16 {
+            let result = shuffle(text, 1.0, seed);
+            saw_change |= result != text;
+            assert!(result.starts_with("  "));
+            assert!(result.ends_with(' '));
+            let (first, second) = result.split_once("\n\n").expect("paragraph break kept");
+            let mut first_sentences = sentences(first);
+            first_sentences.sort_unstable();
+            assert_eq!(first_sentences, vec!["Alpha one.", "Beta two!", "Gamma, 3.5 three?"]);
+            let mut second_sentences = sentences(second);
+            second_sentences.sort_unstable();
+            assert_eq!(second_sentences, vec!["Delta four.", "Epsilon five."]);
+            assert_eq!(result.len(), text.len());
+        }
+        assert!(saw_change);
+        assert_eq!(shuffle(text, 1.0, 5), shuffle(text, 1.0, 5));
+    }
+
+    #[test]
+    fn zero_rate_or_single_sentence_is_unchanged() {
+        assert_eq!(shuffle("One. Two. Three.", 0.0, 3), "One. Two. Three.");
+        assert_eq!(shuffle("Only one sentence here.", 1.0, 3), "Only one sentence here.");
+    }
+}
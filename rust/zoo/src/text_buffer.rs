@@ -1,10 +1,13 @@
 use compact_str::CompactString;
 use regex::Regex;
+use smallvec::SmallVec;
 use std::collections::HashSet;
 use std::ops::Range;
 use std::sync::{Arc, LazyLock};
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::resources::split_with_separators;
+use crate::segmentation::sentence_ranges;
 
 // ---------------------------------------------------------------------------
 // Interned Separators
@@ -48,10 +51,13 @@ pub enum SegmentKind {
 ///
 /// Uses `CompactString` for storage which inlines short strings (up to ~24 bytes)
 /// avoiding heap allocations for typical words and separators.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TextSegment {
     kind: SegmentKind,
     text: CompactString,
+    /// Multiplier operations apply to their rate inside this segment; see
+    /// [`TextBuffer::set_rate_profile`].
+    rate_scale: f64,
     /// Cached count of Unicode characters (not bytes) in this segment.
     /// Stored to avoid expensive .chars().count() during reindex.
     char_len: usize,
@@ -68,11 +74,19 @@ impl TextSegment {
         Self {
             kind,
             text,
+            rate_scale: 1.0,
             char_len,
             byte_len,
         }
     }
 
+    /// Returns the segment with its rate scale replaced.
+    #[inline]
+    const fn scaled(mut self, rate_scale: f64) -> Self {
+        self.rate_scale = rate_scale;
+        self
+    }
+
     /// Creates a new segment from a &str (convenience method).
     #[inline]
     fn from_str(text: &str, kind: SegmentKind) -> Self {
@@ -114,6 +128,12 @@ impl TextSegment {
         !matches!(self.kind, SegmentKind::Immutable)
     }
 
+    /// Returns the multiplier operations apply to their rate inside this segment.
+    #[must_use]
+    pub const fn rate_scale(&self) -> f64 {
+        self.rate_scale
+    }
+
     /// Returns the cached character count (Unicode scalar values).
     const fn char_len(&self) -> usize {
         self.char_len
@@ -166,6 +186,22 @@ impl MaskingRules {
     }
 }
 
+/// A single reduplication request for [`TextBuffer::reduplicate_words_bulk`]:
+/// word index, replacement for the original word, the words inserted after
+/// it, and the separator placed before each inserted word.
+pub type Reduplication = (usize, String, SmallVec<[String; 1]>, Option<String>);
+
+/// A piece of the text assembled by [`TextBuffer::rebuild_from_pieces`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RebuildPiece {
+    /// A byte range of the buffer's current text, copied verbatim.
+    Kept(Range<usize>),
+    /// New text standing in for a (possibly empty) byte range of the current
+    /// text, tokenised afresh. It takes the rate scale in effect where that
+    /// range starts.
+    Fresh(Range<usize>, String),
+}
+
 /// Errors emitted by [`TextBuffer`] mutation helpers.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TextBufferError {
@@ -177,6 +213,11 @@ pub enum TextBufferError {
         end: usize,
         max: usize,
     },
+    InvalidGraphemeRange {
+        start: usize,
+        end: usize,
+        max: usize,
+    },
 }
 
 impl std::fmt::Display for TextBufferError {
@@ -191,6 +232,12 @@ impl std::fmt::Display for TextBufferError {
                     "invalid character range {start}..{end}; buffer length is {max} characters",
                 )
             }
+            Self::InvalidGraphemeRange { start, end, max } => {
+                write!(
+                    f,
+                    "invalid grapheme range {start}..{end}; buffer length is {max} graphemes",
+                )
+            }
         }
     }
 }
@@ -246,8 +293,45 @@ impl TextBuffer {
         Self::from_owned_with_rules(text, masking)
     }
 
+    /// Constructs a buffer whose given character ranges are immutable.
+    ///
+    /// `immutable_ranges` are Unicode scalar offsets, as in [`TextSpan::char_range`];
+    /// overlapping or adjacent ranges merge into one protected segment. Operations
+    /// that re-tokenise go through [`TextBuffer::rebuild_from_pieces`], which tracks
+    /// the spans by position, so other occurrences of the same text stay mutable.
+    pub fn from_owned_with_immutable(
+        text: String,
+        immutable_ranges: &[Range<usize>],
+    ) -> Result<Self, TextBufferError> {
+        let char_len = text.chars().count();
+        let byte_offset = |char_idx: usize| {
+            text.char_indices()
+                .nth(char_idx)
+                .map_or(text.len(), |(byte_idx, _)| byte_idx)
+        };
+
+        let mut spans = Vec::with_capacity(immutable_ranges.len());
+        for range in immutable_ranges {
+            if range.start > range.end || range.end > char_len {
+                return Err(TextBufferError::InvalidCharRange {
+                    start: range.start,
+                    end: range.end,
+                    max: char_len,
+                });
+            }
+            spans.push(byte_offset(range.start)..byte_offset(range.end));
+        }
+        let spans = merge_spans(spans);
+        let segments = segments_from_spans(&text, &spans);
+        Ok(Self::from_segments(segments, MaskingRules::default()))
+    }
+
     fn from_owned_with_rules(text: String, masking: MaskingRules) -> Self {
-        let segments = tokenise(&text, &masking);
+        let segments = tokenise(&text, &masking, Vec::new());
+        Self::from_segments(segments, masking)
+    }
+
+    fn from_segments(segments: Vec<TextSegment>, masking: MaskingRules) -> Self {
         let segment_count = segments.len();
 
         // Pre-allocate vectors to avoid reallocations during reindex
@@ -266,11 +350,149 @@ impl TextBuffer {
     }
 
     /// Rebuilds a buffer with the existing masking patterns preserved.
+    ///
+    /// Only pattern matches are re-protected; use
+    /// [`TextBuffer::rebuild_from_pieces`] to keep the current immutable spans.
     #[must_use]
     pub fn rebuild_with_patterns(&self, text: String) -> Self {
         Self::from_owned_with_rules(text, self.masking.clone())
     }
 
+    /// Rebuilds the buffer from byte ranges of its current text and new text.
+    ///
+    /// Immutable spans and rate scales are tracked by position: whatever part
+    /// of an immutable segment a [`RebuildPiece::Kept`] range carries stays
+    /// immutable at its new offset, while [`RebuildPiece::Fresh`] text is
+    /// tokenised afresh. Masking patterns are re-applied to the whole result.
+    /// A new segment takes the rate scale in effect at its first byte.
+    #[must_use]
+    pub fn rebuild_from_pieces<I>(&self, pieces: I) -> Self
+    where
+        I: IntoIterator<Item = RebuildPiece>,
+    {
+        let current = self.to_string();
+        let mut protected: Vec<Range<usize>> = Vec::new();
+        let mut scaled: Vec<(Range<usize>, f64)> = Vec::new();
+        let mut cursor = 0usize;
+        for segment in &self.segments {
+            let range = cursor..cursor + segment.byte_len();
+            if !segment.is_mutable() {
+                protected.push(range.clone());
+            }
+            if segment.rate_scale != 1.0 {
+                scaled.push((range.clone(), segment.rate_scale));
+            }
+            cursor = range.end;
+        }
+        let scale_at = |byte: usize| {
+            let mut end = 0usize;
+            for segment in &self.segments {
+                end += segment.byte_len();
+                if byte < end {
+                    return segment.rate_scale;
+                }
+            }
+            self.segments.last().map_or(1.0, TextSegment::rate_scale)
+        };
+
+        let mut text = String::with_capacity(current.len());
+        let mut carried: Vec<Range<usize>> = Vec::new();
+        let mut carried_scales: Vec<(Range<usize>, f64)> = Vec::new();
+        for piece in pieces {
+            let offset = text.len();
+            match piece {
+                RebuildPiece::Kept(range) => {
+                    let shift = |start: usize, end: usize| {
+                        start.max(range.start) - range.start + offset
+                            ..end.min(range.end) - range.start + offset
+                    };
+                    for span in &protected {
+                        if span.start < range.end && range.start < span.end {
+                            carried.push(shift(span.start, span.end));
+                        }
+                    }
+                    for (span, scale) in &scaled {
+                        if span.start < range.end && range.start < span.end {
+                            carried_scales.push((shift(span.start, span.end), *scale));
+                        }
+                    }
+                    text.push_str(&current[range]);
+                }
+                RebuildPiece::Fresh(range, fresh) => {
+                    let scale = scale_at(range.start);
+                    if scale != 1.0 && !fresh.is_empty() {
+                        carried_scales.push((offset..offset + fresh.len(), scale));
+                    }
+                    text.push_str(&fresh);
+                }
+            }
+        }
+
+        let masking = self.masking.clone();
+        let mut segments = tokenise(&text, &masking, carried);
+        if !carried_scales.is_empty() {
+            let mut start = 0usize;
+            for segment in &mut segments {
+                if let Some((_, scale)) =
+                    carried_scales.iter().find(|(span, _)| span.contains(&start))
+                {
+                    segment.rate_scale = *scale;
+                }
+                start += segment.byte_len();
+            }
+        }
+        Self::from_segments(segments, masking)
+    }
+
+    /// Re-tokenises the current segments, keeping immutable ones in place.
+    fn retokenised(&self) -> Self {
+        let len = self.segments.iter().map(TextSegment::byte_len).sum();
+        self.rebuild_from_pieces([RebuildPiece::Kept(0..len)])
+    }
+
+    /// Applies a whole-text transform to each maximal run of mutable segments
+    /// sharing a rate scale, copying immutable segments through verbatim, and
+    /// returns the rebuilt buffer.
+    ///
+    /// Operations that rewrite the rendered string (regex rules, dictionary
+    /// lookups) use this so protected spans survive the rebuild. The transform
+    /// receives each run with its rate scale. Without immutable segments or a
+    /// rate profile it sees the entire text in one call.
+    pub fn map_mutable_runs<F, E>(&self, mut transform: F) -> Result<Self, E>
+    where
+        F: FnMut(&str, f64) -> Result<String, E>,
+    {
+        let mut pieces: Vec<RebuildPiece> = Vec::new();
+        let mut run = String::new();
+        let mut run_start = 0usize;
+        let mut run_scale = 1.0;
+        let mut cursor = 0usize;
+        for segment in &self.segments {
+            let start = cursor;
+            cursor += segment.byte_len();
+            let continues_run = segment.is_mutable() && segment.rate_scale == run_scale;
+            if !run.is_empty() && !continues_run {
+                let output = transform(&run, run_scale)?;
+                pieces.push(RebuildPiece::Fresh(run_start..start, output));
+                run.clear();
+            }
+            if segment.is_mutable() {
+                if run.is_empty() {
+                    run_start = start;
+                    run_scale = segment.rate_scale;
+                }
+                run.push_str(segment.text());
+            } else {
+                pieces.push(RebuildPiece::Kept(start..cursor));
+            }
+        }
+        if !run.is_empty() {
+            let output = transform(&run, run_scale)?;
+            pieces.push(RebuildPiece::Fresh(run_start..cursor, output));
+        }
+        Ok(self.rebuild_from_pieces(pieces))
+    }
+
     /// Returns all tracked segments.
     #[must_use] 
     pub fn segments(&self) -> &[TextSegment] {
@@ -289,6 +511,20 @@ impl TextBuffer {
         self.total_chars
     }
 
+    /// Returns the number of extended grapheme clusters across the entire buffer.
+    ///
+    /// Clusters are counted over the joined text rather than per segment, since
+    /// a combining mark after whitespace belongs to the separator's cluster.
+    #[must_use]
+    pub fn grapheme_len(&self) -> usize {
+        self.segments
+            .iter()
+            .map(TextSegment::text)
+            .collect::<String>()
+            .graphemes(true)
+            .count()
+    }
+
     /// Returns the number of word segments tracked by the buffer.
     #[must_use] 
     pub const fn word_count(&self) -> usize {
@@ -304,6 +540,50 @@ impl TextBuffer {
             .and_then(|segment_index| self.segments.get(segment_index))
     }
 
+    /// Scales operation rates per segment over character regions.
+    ///
+    /// Each `(range, multiplier)` covers Unicode scalar offsets, as in
+    /// [`TextSpan::char_range`]. A segment takes the product of the multipliers
+    /// of every region holding its first character, so regions snap to segment
+    /// edges and a word cut by a region boundary is scaled as a whole; other
+    /// segments keep a scale of 1. Operations multiply their rate by
+    /// [`TextSegment::rate_scale`] wherever they choose what to corrupt, and
+    /// scales follow the text through later edits.
+    pub fn set_rate_profile(&mut self, regions: &[(Range<usize>, f64)]) {
+        self.reindex_if_needed();
+        for (segment, span) in self.segments.iter_mut().zip(&self.spans) {
+            let start = span.char_range.start;
+            segment.rate_scale = regions
+                .iter()
+                .filter(|(range, _)| range.contains(&start))
+                .map(|(_, multiplier)| *multiplier)
+                .product();
+        }
+    }
+
+    /// Returns the rate scale of the segment at `segment_index`, or 1 when
+    /// there is no such segment.
+    #[must_use]
+    pub fn rate_scale(&self, segment_index: usize) -> f64 {
+        self.segments
+            .get(segment_index)
+            .map_or(1.0, TextSegment::rate_scale)
+    }
+
+    /// Returns the rate scale of the word at `word_index`, or 1 when there is
+    /// no such word.
+    #[must_use]
+    pub fn word_rate_scale(&self, word_index: usize) -> f64 {
+        self.word_segment(word_index)
+            .map_or(1.0, TextSegment::rate_scale)
+    }
+
+    /// Returns true when any segment's rate scale differs from 1.
+    #[must_use]
+    pub fn has_rate_profile(&self) -> bool {
+        self.segments.iter().any(|segment| segment.rate_scale != 1.0)
+    }
+
     /// Returns an iterator over all segments with their word index (if they are word segments).
     ///
     /// Each item is (segment_index, segment, word_index_option).
@@ -319,6 +599,48 @@ impl TextBuffer {
         })
     }
 
+    /// Returns the segment-index range covered by each sentence.
+    ///
+    /// Boundaries come from [`sentence_ranges`]: a sentence ends at a segment
+    /// closing in `.`, `!` or `?` (decimals and abbreviations excepted), and
+    /// trailing text without terminal punctuation forms the final sentence.
+    /// Ranges start and end on non-separator segments, so the whitespace
+    /// between sentences belongs to none of them. Sentences sharing a single
+    /// segment (possible inside an immutable span) are merged.
+    #[must_use]
+    pub fn sentences(&self) -> Vec<Range<usize>> {
+        let text = self.to_string();
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        let mut segment_idx = 0usize;
+        let mut segment_start = 0usize;
+
+        for sentence in sentence_ranges(&text) {
+            while segment_idx < self.segments.len()
+                && segment_start + self.segments[segment_idx].byte_len() <= sentence.start
+            {
+                segment_start += self.segments[segment_idx].byte_len();
+                segment_idx += 1;
+            }
+            // A sentence starting inside the previous sentence's last segment
+            // shares that segment.
+            let first = if sentence.start < segment_start {
+                segment_idx - 1
+            } else {
+                segment_idx
+            };
+            while segment_idx < self.segments.len() && segment_start < sentence.end {
+                segment_start += self.segments[segment_idx].byte_len();
+                segment_idx += 1;
+            }
+
+            match ranges.last_mut() {
+                Some(previous) if first < previous.end => previous.end = segment_idx,
+                _ => ranges.push(first..segment_idx),
+            }
+        }
+        ranges
+    }
+
     /// Replace the text for the given word index.
     pub fn replace_word(
         &mut self,
@@ -396,19 +718,52 @@ impl TextBuffer {
             .get(word_index)
             .copied()
             .ok_or(TextBufferError::InvalidWordIndex { index: word_index })?;
+        let scale = self.rate_scale(segment_index);
         let mut insert_at = segment_index + 1;
         if let Some(sep) = separator {
             if !sep.is_empty() {
                 self.segments.insert(
                     insert_at,
-                    TextSegment::new_separator(sep),
+                    TextSegment::new_separator(sep).scaled(scale),
                 );
                 insert_at += 1;
             }
         }
         self.segments.insert(
             insert_at,
-            TextSegment::from_str(word, SegmentKind::Word),
+            TextSegment::from_str(word, SegmentKind::Word).scaled(scale),
+        );
+        self.mark_dirty();
+        Ok(())
+    }
+
+    /// Inserts a word directly before the provided word index.
+    ///
+    /// When `separator` is provided it will be inserted between the new word
+    /// and the existing word as a separator segment, mirroring
+    /// [`TextBuffer::insert_word_after`]. Inserting before word `0` prepends
+    /// the word ahead of any leading segments of that word.
+    pub fn insert_word_before(
+        &mut self,
+        word_index: usize,
+        word: &str,
+        separator: Option<&str>,
+    ) -> Result<(), TextBufferError> {
+        let segment_index = self
+            .word_segment_indices
+            .get(word_index)
+            .copied()
+            .ok_or(TextBufferError::InvalidWordIndex { index: word_index })?;
+        let scale = self.rate_scale(segment_index);
+        if let Some(sep) = separator {
+            if !sep.is_empty() {
+                self.segments
+                    .insert(segment_index, TextSegment::new_separator(sep).scaled(scale));
+            }
+        }
+        self.segments.insert(
+            segment_index,
+            TextSegment::from_str(word, SegmentKind::Word).scaled(scale),
         );
         self.mark_dirty();
         Ok(())
@@ -419,13 +774,13 @@ impl TextBuffer {
     /// Each reduplication consists of:
     /// - word_index: the index of the word to reduplicate
     /// - first_replacement: the text to replace the original word with
-    /// - second_word: the duplicated word to insert after
-    /// - separator: optional separator between the two words
+    /// - followers: the duplicated words to insert after, in order
+    /// - separator: optional separator placed before each follower
     ///
     /// Rebuilds the segment vector in a single pass to avoid O(N^2) behavior from repeated insertions.
     pub fn reduplicate_words_bulk<I>(&mut self, reduplications: I) -> Result<(), TextBufferError>
     where
-        I: IntoIterator<Item = (usize, String, String, Option<String>)>,
+        I: IntoIterator<Item = Reduplication>,
     {
         // Ensure indices are fresh before we start
         self.reindex_if_needed();
@@ -435,7 +790,7 @@ impl TextBuffer {
         if ops.is_empty() {
             return Ok(());
         }
-        ops.sort_by(|a, b| a.0.cmp(&b.0)); // Ascending order
+        ops.sort_by_key(|op| op.0); // Ascending order
 
         // Validate all indices first
         if let Some((max_idx, _, _, _)) = ops.last() {
@@ -463,21 +818,28 @@ impl TextBuffer {
                 if let Some(&(target_word_idx, _, _, _)) = ops_iter.peek() {
                     if target_word_idx == word_idx {
                         // Apply the operation
-                        let (_, first_replacement, second_word, separator) =
+                        let (_, first_replacement, followers, separator) =
                             ops_iter.next().unwrap();
+                        let scale = segment.rate_scale();
 
                         // 1. First word (replacement)
-                        new_segments.push(TextSegment::from_str(&first_replacement, SegmentKind::Word));
-
-                        // 2. Separator (if any)
-                        if let Some(sep) = separator {
-                            if !sep.is_empty() {
-                                new_segments.push(TextSegment::new_separator(&sep));
+                        new_segments.push(
+                            TextSegment::from_str(&first_replacement, SegmentKind::Word).scaled(scale),
+                        );
+
+                        for follower in followers {
+                            // 2. Separator (if any)
+                            if let Some(sep) = separator.as_deref() {
+                                if !sep.is_empty() {
+                                    new_segments.push(TextSegment::new_separator(sep).scaled(scale));
+                                }
                             }
-                        }
 
-                        // 3. Second word
-                        new_segments.push(TextSegment::from_str(&second_word, SegmentKind::Word));
+                            // 3. Duplicated word
+                            new_segments.push(
+                                TextSegment::from_str(&follower, SegmentKind::Word).scaled(scale),
+                            );
+                        }
 
                         continue; // Skip adding the original segment
                     }
@@ -493,6 +855,72 @@ impl TextBuffer {
         Ok(())
     }
 
+    /// Glues word pairs together in a single pass.
+    ///
+    /// Takes an iterator of (word_index, merged_text) pairs. Each entry replaces
+    /// word `word_index`, the separators following it and word `word_index + 1`
+    /// with a single word segment holding `merged_text`. Pairs must not overlap
+    /// and only separators may lie between the two words.
+    pub fn merge_words_bulk<I>(&mut self, merges: I) -> Result<(), TextBufferError>
+    where
+        I: IntoIterator<Item = (usize, String)>,
+    {
+        self.reindex_if_needed();
+
+        let mut ops: Vec<_> = merges.into_iter().collect();
+        if ops.is_empty() {
+            return Ok(());
+        }
+        ops.sort_by_key(|op| op.0);
+
+        // Resolve each pair to the segment range it collapses
+        let mut ranges: Vec<(Range<usize>, String)> = Vec::with_capacity(ops.len());
+        let mut previous_end = 0;
+        for (word_index, merged_text) in ops {
+            let invalid = TextBufferError::InvalidWordIndex { index: word_index };
+            let start = self.word_segment_indices.get(word_index).copied().ok_or(invalid.clone())?;
+            let end = self
+                .word_segment_indices
+                .get(word_index + 1)
+                .copied()
+                .ok_or(invalid.clone())?;
+            let only_separators = self.segments[start + 1..end]
+                .iter()
+                .all(|segment| matches!(segment.kind(), SegmentKind::Separator));
+            if !only_separators || (!ranges.is_empty() && start <= previous_end) {
+                return Err(invalid);
+            }
+            previous_end = end;
+            ranges.push((start..end + 1, merged_text));
+        }
+
+        let old_segments = std::mem::take(&mut self.segments);
+        let mut new_segments = Vec::with_capacity(old_segments.len());
+        let mut ranges_iter = ranges.into_iter().peekable();
+        for (segment_index, segment) in old_segments.into_iter().enumerate() {
+            match ranges_iter.peek() {
+                // The first word of a pair carries the merged text...
+                Some((range, merged_text)) if range.start == segment_index => {
+                    new_segments.push(
+                        TextSegment::from_str(merged_text, SegmentKind::Word)
+                            .scaled(segment.rate_scale()),
+                    );
+                }
+                // ...while its separators and the second word are dropped
+                Some((range, _)) if range.contains(&segment_index) => {
+                    if segment_index + 1 == range.end {
+                        ranges_iter.next();
+                    }
+                }
+                _ => new_segments.push(segment),
+            }
+        }
+
+        self.segments = new_segments;
+        self.mark_dirty();
+        Ok(())
+    }
+
     /// Deletes multiple words in a single pass.
     ///
     /// Takes an iterator of (word_index, optional_replacement) pairs.
@@ -502,6 +930,26 @@ impl TextBuffer {
     /// Processes in descending index order to avoid index shifting.
     /// Only reindexes once at the end.
     pub fn delete_words_bulk<I>(&mut self, deletions: I) -> Result<(), TextBufferError>
+    where
+        I: IntoIterator<Item = (usize, Option<String>)>,
+    {
+        self.remove_words(deletions, false)
+    }
+
+    /// Like [`Self::delete_words_bulk`], but each removed word takes exactly one
+    /// adjacent separator with it and every other separator is left untouched,
+    /// so column alignment and indentation survive without a normalize pass.
+    ///
+    /// The separator after the word goes unless there is none, it is already
+    /// taken, or it holds a line break the separator before does not.
+    pub fn splice_words_bulk<I>(&mut self, deletions: I) -> Result<(), TextBufferError>
+    where
+        I: IntoIterator<Item = (usize, Option<String>)>,
+    {
+        self.remove_words(deletions, true)
+    }
+
+    fn remove_words<I>(&mut self, deletions: I, take_separator: bool) -> Result<(), TextBufferError>
     where
         I: IntoIterator<Item = (usize, Option<String>)>,
     {
@@ -542,6 +990,28 @@ impl TextBuffer {
             }
         }
 
+        if take_separator {
+            let mut removed_words: Vec<usize> = removal_indices.iter().copied().collect();
+            removed_words.sort_unstable_by(|a, b| b.cmp(a));
+            for segment_index in removed_words {
+                let free_separator = |index: usize| {
+                    self.segments
+                        .get(index)
+                        .filter(|segment| matches!(segment.kind(), SegmentKind::Separator))
+                        .map(|segment| segment.text().contains('\n'))
+                        .filter(|_| !removal_indices.contains(&index))
+                };
+                let after = free_separator(segment_index + 1);
+                let before = segment_index.checked_sub(1).and_then(free_separator);
+                let taken = match (after, before) {
+                    (Some(true), Some(false)) | (None, Some(_)) => segment_index - 1,
+                    (Some(_), _) => segment_index + 1,
+                    (None, None) => continue,
+                };
+                removal_indices.insert(taken);
+            }
+        }
+
         // Rebuild segments vector in single O(n) pass, filtering out removed indices
         if !removal_indices.is_empty() {
             let new_segments: Vec<_> = self
@@ -557,7 +1027,7 @@ impl TextBuffer {
         // If we had replacements (e.g., punctuation-only affixes), rebuild to re-tokenize
         // properly. This ensures punctuation doesn't become standalone Word segments.
         if had_replacements {
-            *self = self.rebuild_with_patterns(self.to_string());
+            *self = self.retokenised();
         } else {
             self.mark_dirty();
         }
@@ -571,6 +1041,7 @@ impl TextBuffer {
         char_range: Range<usize>,
         replacement: &str,
     ) -> Result<(), TextBufferError> {
+        self.reindex_if_needed();
         if char_range.start > char_range.end || char_range.end > self.total_chars {
             return Err(TextBufferError::InvalidCharRange {
                 start: char_range.start,
@@ -583,7 +1054,6 @@ impl TextBuffer {
             return Ok(());
         }
 
-        let mut text = self.to_string();
         let start_byte =
             self.char_to_byte_index(char_range.start)
                 .ok_or(TextBufferError::InvalidCharRange {
@@ -598,15 +1068,60 @@ impl TextBuffer {
                     end: char_range.end,
                     max: self.total_chars,
                 })?;
-        text.replace_range(start_byte..end_byte, replacement);
-        *self = self.rebuild_with_patterns(text);
+        *self = self.rebuild_from_pieces([
+            RebuildPiece::Kept(0..start_byte),
+            RebuildPiece::Fresh(start_byte..end_byte, replacement.to_string()),
+            RebuildPiece::Kept(end_byte..self.total_bytes),
+        ]);
+        Ok(())
+    }
+
+    /// Replaces the provided grapheme-cluster range with new text.
+    ///
+    /// The grapheme counterpart of [`Self::replace_char_range`]: indices count
+    /// extended grapheme clusters, so a range never splits an emoji sequence or
+    /// separates a letter from its combining marks.
+    pub fn replace_grapheme_range(
+        &mut self,
+        grapheme_range: Range<usize>,
+        replacement: &str,
+    ) -> Result<(), TextBufferError> {
+        let text = self.to_string();
+        // Byte offset of every cluster start, plus the end of the text
+        let boundaries: Vec<usize> = text
+            .grapheme_indices(true)
+            .map(|(byte_index, _)| byte_index)
+            .chain(std::iter::once(text.len()))
+            .collect();
+        let max = boundaries.len() - 1;
+        if grapheme_range.start > grapheme_range.end || grapheme_range.end > max {
+            return Err(TextBufferError::InvalidGraphemeRange {
+                start: grapheme_range.start,
+                end: grapheme_range.end,
+                max,
+            });
+        }
+
+        if grapheme_range.start == grapheme_range.end && replacement.is_empty() {
+            return Ok(());
+        }
+
+        let start = boundaries[grapheme_range.start];
+        let end = boundaries[grapheme_range.end];
+        *self = self.rebuild_from_pieces([
+            RebuildPiece::Kept(0..start),
+            RebuildPiece::Fresh(start..end, replacement.to_string()),
+            RebuildPiece::Kept(end..text.len()),
+        ]);
         Ok(())
     }
 
     /// Normalizes whitespace and punctuation spacing without reparsing.
     ///
     /// This method:
-    /// - Merges consecutive separator segments into single spaces
+    /// - Merges consecutive separator segments into single spaces, except that
+    ///   a run containing a line break keeps its newline-bearing separator
+    ///   verbatim so paragraph breaks survive deletions
     /// - Removes spaces before punctuation (.,:;)
     /// - Trims leading/trailing whitespace
     ///
@@ -615,12 +1130,18 @@ impl TextBuffer {
         // First pass: identify segments to merge/modify
         let mut normalized: Vec<TextSegment> = Vec::new();
         let mut pending_separator = false;
+        // The separator with the most line breaks in the pending run, if any
+        let mut pending_break: Option<(usize, &TextSegment)> = None;
 
         for segment in &self.segments {
             match segment.kind() {
                 SegmentKind::Separator => {
                     // Mark that we have a separator pending
                     pending_separator = true;
+                    let breaks = segment.text().matches('\n').count();
+                    if breaks > pending_break.map_or(0, |(most, _)| most) {
+                        pending_break = Some((breaks, segment));
+                    }
                 }
                 SegmentKind::Word => {
                     let text = segment.text();
@@ -632,20 +1153,27 @@ impl TextBuffer {
                         .map(|c| matches!(c, '.' | ',' | ':' | ';'))
                         .unwrap_or(false);
 
-                    // Add separator if needed (but not before sentence punctuation)
-                    if pending_separator && !starts_with_punct && !normalized.is_empty() {
-                        normalized.push(TextSegment::new_separator(" "));
+                    // Add separator if needed (but not before sentence punctuation).
+                    // Line breaks are kept even there rather than joining lines.
+                    if let (Some((_, line_break)), false) = (pending_break, normalized.is_empty()) {
+                        normalized.push(line_break.clone());
+                    } else if pending_separator && !starts_with_punct && !normalized.is_empty() {
+                        normalized.push(TextSegment::new_separator(" ").scaled(segment.rate_scale()));
                     }
                     pending_separator = false;
+                    pending_break = None;
 
                     // Add the word
                     normalized.push(segment.clone());
                 }
                 SegmentKind::Immutable => {
-                    if pending_separator && !normalized.is_empty() {
-                        normalized.push(TextSegment::new_separator(" "));
+                    if let (Some((_, line_break)), false) = (pending_break, normalized.is_empty()) {
+                        normalized.push(line_break.clone());
+                    } else if pending_separator && !normalized.is_empty() {
+                        normalized.push(TextSegment::new_separator(" ").scaled(segment.rate_scale()));
                     }
                     pending_separator = false;
+                    pending_break = None;
                     normalized.push(segment.clone());
                 }
             }
@@ -681,8 +1209,9 @@ impl TextBuffer {
             return;
         }
 
-        let kind = self.segments[segment_index].kind();
-        self.segments[segment_index] = TextSegment::from_str(new_text, kind);
+        let segment = &self.segments[segment_index];
+        self.segments[segment_index] =
+            TextSegment::from_str(new_text, segment.kind()).scaled(segment.rate_scale());
         self.mark_dirty();
     }
 
@@ -697,8 +1226,9 @@ impl TextBuffer {
         let mut replaced = false;
         for (segment_index, new_text) in replacements {
             if segment_index < self.segments.len() {
-                let kind = self.segments[segment_index].kind();
-                self.segments[segment_index] = TextSegment::from_str(&new_text, kind);
+                let segment = &self.segments[segment_index];
+                self.segments[segment_index] =
+                    TextSegment::from_str(&new_text, segment.kind()).scaled(segment.rate_scale());
                 replaced = true;
             }
         }
@@ -711,7 +1241,10 @@ impl TextBuffer {
     /// removing separators between them.
     ///
     /// This is used by RedactWordsOp to merge adjacent redacted words like "███ ███" into "██████".
-    pub fn merge_repeated_char_words(&mut self, repeated_char: &str) {
+    /// With `through_punctuation`, words whose run is wrapped in punctuation-only affixes merge
+    /// too: punctuation between two runs becomes part of the block ("███, ███" gives "███████")
+    /// while the outer prefix and suffix of the merged run are kept.
+    pub fn merge_repeated_char_words(&mut self, repeated_char: &str, through_punctuation: bool) {
         if self.segments.is_empty() || repeated_char.is_empty() {
             return;
         }
@@ -731,6 +1264,26 @@ impl TextBuffer {
                 .all(|chunk| chunk == repeated_char.as_bytes())
         };
 
+        // Splits a redacted word into (prefix, token count, suffix). Affixes are only
+        // allowed when merging through punctuation, and must be punctuation-only.
+        let split_redacted = |text: &str| -> Option<(usize, usize, usize)> {
+            if is_repeated_token(text) {
+                return Some((0, text.len() / repeated_char.len(), text.len()));
+            }
+            if !through_punctuation {
+                return None;
+            }
+            let start = text.find(repeated_char)?;
+            let end = text.rfind(repeated_char)? + repeated_char.len();
+            let is_punctuation = |affix: &str| {
+                affix.chars().all(|ch| !ch.is_alphanumeric() && !ch.is_whitespace())
+            };
+            (is_repeated_token(&text[start..end])
+                && is_punctuation(&text[..start])
+                && is_punctuation(&text[end..]))
+                .then(|| (start, (end - start) / repeated_char.len(), end))
+        };
+
         let mut merged: Vec<TextSegment> = Vec::new();
         let mut i = 0;
 
@@ -741,36 +1294,36 @@ impl TextBuffer {
                 let text = segment.text();
 
                 // Check if this word is composed of repeated tokens
-                if is_repeated_token(text) {
-                    // Count how many copies of the token we have
-                    let mut token_count = text.len() / repeated_char.len();
+                if let Some((core_start, mut token_count, core_end)) = split_redacted(text) {
+                    let prefix = &text[..core_start];
+                    let mut suffix = &text[core_end..];
 
                     // Look ahead for more repeated token words separated by separators
                     let mut j = i + 1;
-                    while j < self.segments.len() {
-                        if matches!(self.segments[j].kind(), SegmentKind::Separator) {
-                            // Skip separator, check next word
-                            if j + 1 < self.segments.len() {
-                                let next_word = &self.segments[j + 1];
-                                if matches!(next_word.kind(), SegmentKind::Word) {
-                                    let next_text = next_word.text();
-                                    if is_repeated_token(next_text) {
-                                        // This is also a repeated token word - merge it
-                                        token_count += next_text.len() / repeated_char.len();
-                                        j += 2; // Skip separator and word
-                                        continue;
-                                    }
-                                }
-                            }
+                    while j + 1 < self.segments.len()
+                        && matches!(self.segments[j].kind(), SegmentKind::Separator)
+                        && matches!(self.segments[j + 1].kind(), SegmentKind::Word)
+                    {
+                        let next_text = self.segments[j + 1].text();
+                        let Some((next_start, next_count, next_end)) = split_redacted(next_text)
+                        else {
                             break;
-                        } else {
-                            break;
-                        }
+                        };
+                        // Punctuation between the two runs joins the block
+                        token_count += suffix.chars().count()
+                            + next_text[..next_start].chars().count()
+                            + next_count;
+                        suffix = &next_text[next_end..];
+                        j += 2; // Skip separator and word
                     }
 
                     // Create merged word with total count
-                    let merged_text = repeated_char.repeat(token_count);
-                    merged.push(TextSegment::from_str(&merged_text, SegmentKind::Word));
+                    let merged_text =
+                        format!("{prefix}{}{suffix}", repeated_char.repeat(token_count));
+                    merged.push(
+                        TextSegment::from_str(&merged_text, SegmentKind::Word)
+                            .scaled(segment.rate_scale()),
+                    );
 
                     // Skip to position j (we've consumed segments i..j)
                     i = j;
@@ -863,7 +1416,7 @@ fn byte_index_for_char_offset(text: &str, offset: usize) -> usize {
     text.len()
 }
 
-fn merge_spans(mut spans: Vec<Range<usize>>) -> Vec<Range<usize>> {
+pub(crate) fn merge_spans(mut spans: Vec<Range<usize>>) -> Vec<Range<usize>> {
     if spans.is_empty() {
         return spans;
     }
@@ -935,7 +1488,8 @@ fn push_mutable_segments(text: &str, segments: &mut Vec<TextSegment>) {
     }
 }
 
-fn tokenise(text: &str, masking: &MaskingRules) -> Vec<TextSegment> {
+/// Tokenises `text`, protecting pattern matches and the byte spans in `carried`.
+fn tokenise(text: &str, masking: &MaskingRules, carried: Vec<Range<usize>>) -> Vec<TextSegment> {
     if text.is_empty() {
         return Vec::new();
     }
@@ -944,13 +1498,24 @@ fn tokenise(text: &str, masking: &MaskingRules) -> Vec<TextSegment> {
     let mut immutable_spans = collect_match_spans(masking.exclude(), text);
     if !include_spans.is_empty() {
         immutable_spans.extend(invert_spans(&include_spans, text.len()));
-        immutable_spans = merge_spans(immutable_spans);
+    }
+    immutable_spans.extend(carried);
+    let immutable_spans = merge_spans(immutable_spans);
+
+    segments_from_spans(text, &immutable_spans)
+}
+
+/// Splits `text` into segments, marking the sorted, disjoint byte `immutable_spans`
+/// as [`SegmentKind::Immutable`] and tokenising the text between them.
+fn segments_from_spans(text: &str, immutable_spans: &[Range<usize>]) -> Vec<TextSegment> {
+    if text.is_empty() {
+        return Vec::new();
     }
 
     let mut segments: Vec<TextSegment> = Vec::new();
     let mut cursor = 0usize;
 
-    for span in immutable_spans {
+    for span in immutable_spans.iter().cloned() {
         if cursor < span.start {
             push_mutable_segments(&text[cursor..span.start], &mut segments);
         }
@@ -976,7 +1541,45 @@ fn tokenise(text: &str, masking: &MaskingRules) -> Vec<TextSegment> {
 
 #[cfg(test)]
 mod tests {
-    use super::{SegmentKind, TextBuffer, TextBufferError};
+    use super::{SegmentKind, TextBuffer, TextBufferError, TextSegment};
+
+    fn sentence_texts(buffer: &TextBuffer) -> Vec<String> {
+        buffer
+            .sentences()
+            .into_iter()
+            .map(|range| {
+                buffer.segments()[range]
+                    .iter()
+                    .map(|segment| segment.text())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sentences_cover_segment_ranges() {
+        let buffer = TextBuffer::from_owned(
+            "  Pi is 3.14 today. Really?!\n\nNo trailing stop ".to_string(),
+            &[],
+            &[],
+        );
+        assert_eq!(
+            sentence_texts(&buffer),
+            vec!["Pi is 3.14 today.", "Really?!", "No trailing stop"]
+        );
+        let ranges = buffer.sentences();
+        assert_eq!(ranges[0].start, 1);
+        assert!(ranges.windows(2).all(|pair| pair[0].end < pair[1].start));
+    }
+
+    #[test]
+    fn sentences_merge_within_an_immutable_segment() {
+        let exclude = vec![regex::Regex::new(r"\[[^\]]*\]").unwrap()];
+        let buffer =
+            TextBuffer::from_owned("One [two. three] four. Five.".to_string(), &[], &exclude);
+        assert_eq!(sentence_texts(&buffer), vec!["One [two. three] four.", "Five."]);
+        assert!(TextBuffer::from_owned(String::new(), &[], &[]).sentences().is_empty());
+    }
 
     #[test]
     fn tokenisation_tracks_words_and_separators() {
@@ -1020,6 +1623,29 @@ mod tests {
             .all(|span| matches!(span.kind, SegmentKind::Separator)));
     }
 
+    #[test]
+    fn normalize_keeps_line_breaks_verbatim() {
+        let text = "one two\nthree four\n\nfive six";
+        let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+        buffer
+            .delete_words_bulk([(1, None), (3, None), (4, None)])
+            .unwrap();
+        buffer.normalize();
+        assert_eq!(buffer.to_string(), "one\nthree\n\nsix");
+    }
+
+    #[test]
+    fn splicing_words_keeps_the_remaining_separators() {
+        let text = "id    name    qty\n    alpha beta   gamma";
+        let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+        buffer.splice_words_bulk([(1, None), (3, None)]).unwrap();
+        assert_eq!(buffer.to_string(), "id    qty\n    beta   gamma");
+
+        let mut buffer = TextBuffer::from_owned("one two three".to_string(), &[], &[]);
+        buffer.splice_words_bulk([(1, None), (2, None)]).unwrap();
+        assert_eq!(buffer.to_string(), "one");
+    }
+
     #[test]
     fn inserting_words_preserves_separator_control() {
         let mut buffer = TextBuffer::from_owned("Hello world".to_string(), &[], &[]);
@@ -1030,6 +1656,21 @@ mod tests {
         assert_eq!(buffer.spans().len(), 5);
     }
 
+    #[test]
+    fn inserting_words_before_handles_the_first_word() {
+        let mut buffer = TextBuffer::from_owned("Hello world".to_string(), &[], &[]);
+        buffer.insert_word_before(0, "well,", Some(" ")).unwrap();
+        buffer.reindex_if_needed();
+        assert_eq!(buffer.to_string(), "well, Hello world");
+        assert_eq!(buffer.word_count(), 3);
+        assert_eq!(buffer.word_segment(0).map(|segment| segment.text()), Some("well,"));
+
+        buffer.insert_word_before(2, "big", Some(" ")).unwrap();
+        buffer.reindex_if_needed();
+        assert_eq!(buffer.to_string(), "well, Hello big world");
+        assert!(buffer.insert_word_before(9, "nope", None).is_err());
+    }
+
     #[test]
     fn bulk_replace_words_updates_multiple_entries() {
         let mut buffer = TextBuffer::from_owned("alpha beta gamma delta".to_string(), &[], &[]);
@@ -1054,6 +1695,124 @@ mod tests {
         assert_eq!(buffer.spans().len(), 3);
     }
 
+    #[test]
+    fn merging_words_collapses_pairs_and_separators() {
+        let mut buffer = TextBuffer::from_owned("the cat  sat on mats".to_string(), &[], &[]);
+        buffer
+            .merge_words_bulk(vec![(3, "onmats".to_string()), (0, "thecat".to_string())])
+            .expect("merge succeeds");
+        buffer.reindex_if_needed();
+        assert_eq!(buffer.to_string(), "thecat  sat onmats");
+        assert_eq!(buffer.word_count(), 3);
+
+        let err = buffer.merge_words_bulk(vec![(2, "x".to_string())]).unwrap_err();
+        assert!(matches!(err, TextBufferError::InvalidWordIndex { index: 2 }));
+        let err = buffer
+            .merge_words_bulk(vec![(0, "a".to_string()), (1, "b".to_string())])
+            .unwrap_err();
+        assert!(matches!(err, TextBufferError::InvalidWordIndex { index: 1 }));
+    }
+
+    #[test]
+    fn grapheme_ranges_keep_clusters_whole() {
+        let text = "flag \u{1F1EB}\u{1F1F7} cafe\u{0301}!";
+        let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+        assert_eq!(buffer.grapheme_len(), 12);
+        assert_eq!(buffer.char_len(), 14);
+
+        buffer.replace_grapheme_range(5..6, "FR").unwrap();
+        assert_eq!(buffer.to_string(), "flag FR cafe\u{0301}!");
+        buffer.replace_grapheme_range(11..12, "e").unwrap();
+        assert_eq!(buffer.to_string(), "flag FR cafe!");
+        assert_eq!(buffer.word_count(), 3);
+
+        let err = buffer.replace_grapheme_range(3..20, "x").unwrap_err();
+        assert_eq!(
+            err,
+            TextBufferError::InvalidGraphemeRange {
+                start: 3,
+                end: 20,
+                max: 13
+            }
+        );
+    }
+
+    #[test]
+    fn mutable_runs_skip_protected_spans() {
+        let exclude = vec![regex::Regex::new(r"<[^>]*>").unwrap()];
+        let buffer = TextBuffer::from_owned("one <two three> four five".to_string(), &[], &exclude);
+        let mut runs = Vec::new();
+        let output = buffer
+            .map_mutable_runs(|run, _| {
+                runs.push(run.to_string());
+                Ok::<_, ()>(run.to_uppercase())
+            })
+            .unwrap();
+        assert_eq!(output.to_string(), "ONE <two three> FOUR FIVE");
+        assert_eq!(runs, vec!["one ", " four five"]);
+    }
+
+    #[test]
+    fn explicit_immutable_ranges_survive_rebuilds() {
+        let text = "naïve <café> and more words";
+        let mut buffer =
+            TextBuffer::from_owned_with_immutable(text.to_string(), &[6..12, 10..12]).unwrap();
+        let kinds: Vec<SegmentKind> =
+            buffer.segments().iter().map(|segment| segment.kind()).collect();
+        assert_eq!(
+            kinds[..4],
+            [
+                SegmentKind::Word,
+                SegmentKind::Separator,
+                SegmentKind::Immutable,
+                SegmentKind::Separator
+            ]
+        );
+        assert_eq!(buffer.segments()[2].text(), "<café>");
+        assert_eq!(buffer.word_count(), 4);
+        assert_eq!(buffer.spans()[2].char_range, 6..12);
+
+        buffer.delete_word(1).unwrap();
+        buffer.normalize();
+        assert_eq!(buffer.to_string(), "naïve <café> more words");
+        assert!(!buffer.segments()[2].is_mutable());
+
+        buffer.replace_char_range(0..5, "plain").unwrap();
+        assert_eq!(buffer.to_string(), "plain <café> more words");
+        assert_eq!(buffer.segments()[2].kind(), SegmentKind::Immutable);
+
+        let err = TextBuffer::from_owned_with_immutable(text.to_string(), &[3..4, 20..40])
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TextBufferError::InvalidCharRange {
+                start: 20,
+                end: 40,
+                max: 27
+            }
+        );
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn explicit_immutable_ranges_protect_only_the_marked_copy() {
+        let text = "cafe then cafe and cafeteria";
+        let buffer = TextBuffer::from_owned_with_immutable(text.to_string(), &[10..14]).unwrap();
+        let rebuilt = buffer
+            .map_mutable_runs(|run, _| Ok::<_, ()>(run.to_uppercase()))
+            .unwrap();
+        assert_eq!(rebuilt.to_string(), "CAFE THEN cafe AND CAFETERIA");
+
+        let protected: Vec<&str> = rebuilt
+            .segments()
+            .iter()
+            .filter(|segment| !segment.is_mutable())
+            .map(TextSegment::text)
+            .collect();
+        assert_eq!(protected, vec!["cafe"]);
+        assert_eq!(rebuilt.spans()[4].char_range, 10..14);
+    }
+
     #[test]
     fn invalid_operations_return_errors() {
         let mut buffer = TextBuffer::from_owned("Hello".to_string(), &[], &[]);
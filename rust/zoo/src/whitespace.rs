@@ -0,0 +1,136 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::text_buffer::{SegmentKind, TextBuffer};
+
+/// Default jitter palette: double space, tab, NO-BREAK SPACE and NARROW
+/// NO-BREAK SPACE.
+pub(crate) const DEFAULT_WHITESPACE_PALETTE: &[&str] = &["  ", "\t", "\u{00A0}", "\u{202F}"];
+
+/// Replaces separator segments with alternative whitespace, as seen in
+/// scraped or copy-pasted text.
+///
+/// Separators containing a newline are left alone unless some palette entry
+/// itself contains one, so paragraph structure survives by default.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WhitespaceJitterOp {
+    pub rate: f64,
+    palette: Vec<String>,
+}
+
+impl WhitespaceJitterOp {
+    /// Builds the op, falling back to [`DEFAULT_WHITESPACE_PALETTE`]. Every
+    /// palette entry must be non-empty and consist only of whitespace.
+    pub fn new(rate: f64, palette: Option<Vec<String>>) -> Result<Self, String> {
+        let palette = palette.unwrap_or_else(|| {
+            DEFAULT_WHITESPACE_PALETTE
+                .iter()
+                .map(|entry| (*entry).to_string())
+                .collect()
+        });
+        if palette.is_empty() {
+            return Err("whitespace palette must not be empty".to_string());
+        }
+        if let Some(entry) = palette
+            .iter()
+            .find(|entry| entry.is_empty() || !entry.chars().all(char::is_whitespace))
+        {
+            return Err(format!(
+                "whitespace palette entries must be non-empty whitespace, got {entry:?}"
+            ));
+        }
+        Ok(Self { rate, palette })
+    }
+
+    #[must_use]
+    pub fn palette(&self) -> &[String] {
+        &self.palette
+    }
+}
+
+impl TextOperation for WhitespaceJitterOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let allow_newlines = self.palette.iter().any(|entry| entry.contains('\n'));
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        for (index, segment) in buffer.segments().iter().enumerate() {
+            if segment.kind() != SegmentKind::Separator {
+                continue;
+            }
+            if !allow_newlines && segment.text().contains('\n') {
+                continue;
+            }
+            if rng.random()? >= rate * segment.rate_scale() {
+                continue;
+            }
+            let choice = &self.palette[rng.rand_index(self.palette.len())?];
+            if choice != segment.text() {
+                replacements.push((index, choice.clone()));
+            }
+        }
+
+        let edits = replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_segments_bulk(replacements);
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, palette=None, seed=None, return_count=false))]
+pub(crate) fn jitter_whitespace(
+    text: &str,
+    rate: f64,
+    palette: Option<Vec<String>>,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = WhitespaceJitterOp::new(rate, palette).map_err(PyValueError::new_err)?;
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WhitespaceJitterOp;
+    use crate::operations::apply_seeded;
+
+    #[test]
+    fn jitter_rewrites_separators_but_keeps_words_and_newlines() {
+        let op = WhitespaceJitterOp::new(1.0, None).expect("default palette is valid");
+        let text = "one two three\n\nfour five six";
+        let result = apply_seeded(&op, text, 2048);
+        assert_ne!(result, text);
+        assert_eq!(result, apply_seeded(&op, text, 2048));
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>(),
+            text.split_whitespace().collect::<Vec<_>>()
+        );
+        assert!(result.contains("three\n\nfour"));
+    }
+
+    #[test]
+    fn newlines_are_jittered_only_when_in_palette() {
+        let op = WhitespaceJitterOp::new(1.0, Some(vec!["\t".to_string()])).unwrap();
+        assert_eq!(apply_seeded(&op, "a b\nc", 1), "a\tb\nc");
+        let op = WhitespaceJitterOp::new(1.0, Some(vec!["\r\n".to_string()])).unwrap();
+        assert_eq!(apply_seeded(&op, "a b\nc", 1), "a\r\nb\r\nc");
+    }
+
+    #[test]
+    fn palette_rejects_non_whitespace_entries() {
+        assert!(WhitespaceJitterOp::new(0.5, Some(vec!["_".to_string()])).is_err());
+        assert!(WhitespaceJitterOp::new(0.5, Some(vec![String::new()])).is_err());
+        assert!(WhitespaceJitterOp::new(0.5, Some(Vec::new())).is_err());
+    }
+}
@@ -0,0 +1,276 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use smallvec::smallvec;
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::resources::split_affixes_ref;
+use crate::text_buffer::{Reduplication, SegmentKind, TextBuffer};
+use crate::word_stretching::is_vowel;
+
+/// Shortest piece either side of a split, in characters.
+const MIN_SPLIT_PIECE: usize = 2;
+
+/// Minimum core length used when a descriptor omits `min_length`.
+pub(crate) const DEFAULT_SPLIT_MIN_LENGTH: usize = 6;
+
+/// Glues adjacent words together ("the cat" → "thecat") to mimic OCR and
+/// dictation output that drops the space between words.
+///
+/// Each eligible pair is merged with probability `rate`. A pair is eligible
+/// when only whitespace without a line break separates the two words and the
+/// boundary carries no punctuation: the first word's prefix and the second
+/// word's suffix are kept, so "(the cat)" becomes "(thecat)", while "end. Next"
+/// is left alone. Merged pairs never overlap, so three words collapse into at
+/// most a pair and a single word.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MergeWordsOp {
+    pub rate: f64,
+}
+
+/// Returns the word-index pairs that may be glued, in order.
+pub(crate) fn mergeable_pairs(buffer: &TextBuffer) -> Vec<usize> {
+    let mut pairs = Vec::new();
+    // The previous word and whether only line-free whitespace has followed it
+    let mut previous_word: Option<usize> = None;
+    let mut clean_gap = false;
+    for (_, segment, word_index) in buffer.segments_with_word_indices() {
+        match (segment.kind(), word_index) {
+            (SegmentKind::Word, Some(word_index)) => {
+                if let Some(previous) = previous_word.filter(|_| clean_gap) {
+                    pairs.push(previous);
+                }
+                previous_word = Some(word_index);
+                clean_gap = true;
+            }
+            (SegmentKind::Separator, _) => {
+                clean_gap &= !segment.text().contains(['\n', '\r']);
+            }
+            _ => {
+                previous_word = None;
+                clean_gap = false;
+            }
+        }
+    }
+    pairs
+}
+
+impl TextOperation for MergeWordsOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() < 2 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut merges: Vec<(usize, String)> = Vec::new();
+        let mut next_free = 0;
+        for idx in mergeable_pairs(buffer) {
+            if idx < next_free {
+                continue;
+            }
+            let (Some(first), Some(second)) = (buffer.word_segment(idx), buffer.word_segment(idx + 1))
+            else {
+                continue;
+            };
+            if !first.is_mutable() || !second.is_mutable() {
+                continue;
+            }
+
+            let (prefix, first_core, inner_suffix) = split_affixes_ref(first.text());
+            let (inner_prefix, second_core, suffix) = split_affixes_ref(second.text());
+            if first_core.is_empty()
+                || second_core.is_empty()
+                || !inner_suffix.is_empty()
+                || !inner_prefix.is_empty()
+            {
+                continue;
+            }
+
+            if rng.random()? < rate * first.rate_scale() {
+                merges.push((idx, format!("{prefix}{first_core}{second_core}{suffix}")));
+                next_free = idx + 2;
+            }
+        }
+
+        let edits = merges.len();
+        if !merges.is_empty() {
+            buffer.merge_words_bulk(merges)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, seed=None, return_count=false))]
+pub(crate) fn merge_words(
+    text: &str,
+    rate: f64,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = MergeWordsOp { rate };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+/// Splits longer words in two ("keyboard" → "key board") to mimic
+/// over-segmented OCR and tokeniser output.
+///
+/// Words whose core is purely alphabetic and at least `min_length`
+/// characters long are split with probability `rate`. The split lands before
+/// a consonant that opens a consonant-vowel syllable ("pa|per", "ba|nana")
+/// and only falls back to a uniform position when no such onset exists.
+/// Both pieces keep at least two characters, and the word's prefix and
+/// suffix stay on the outer pieces.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SplitWordsOp {
+    pub rate: f64,
+    pub min_length: usize,
+}
+
+/// Returns the char offsets inside `core` where a consonant-vowel onset starts.
+fn onset_split_points(core: &[char]) -> Vec<usize> {
+    let last = core.len().saturating_sub(MIN_SPLIT_PIECE);
+    (MIN_SPLIT_PIECE..=last)
+        .filter(|&offset| {
+            let here = core[offset].to_ascii_lowercase();
+            let next = core[offset + 1].to_ascii_lowercase();
+            !is_vowel(here) && is_vowel(next)
+        })
+        .collect()
+}
+
+impl TextOperation for SplitWordsOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+        let min_length = self.min_length.max(MIN_SPLIT_PIECE * 2);
+
+        let mut splits: Vec<Reduplication> = Vec::new();
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx) else {
+                continue;
+            };
+            if !segment.is_mutable() {
+                continue;
+            }
+
+            let (prefix, core, suffix) = split_affixes_ref(segment.text());
+            let chars: Vec<char> = core.chars().collect();
+            if chars.len() < min_length || !chars.iter().all(|ch| ch.is_alphabetic()) {
+                continue;
+            }
+            if rng.random()? >= rate * segment.rate_scale() {
+                continue;
+            }
+
+            let onsets = onset_split_points(&chars);
+            let offset = if onsets.is_empty() {
+                MIN_SPLIT_PIECE + rng.rand_index(chars.len() - MIN_SPLIT_PIECE * 2 + 1)?
+            } else {
+                onsets[rng.rand_index(onsets.len())?]
+            };
+
+            let left: String = chars[..offset].iter().collect();
+            let right: String = chars[offset..].iter().collect();
+            splits.push((
+                idx,
+                format!("{prefix}{left}"),
+                smallvec![format!("{right}{suffix}")],
+                Some(" ".to_string()),
+            ));
+        }
+
+        let edits = splits.len();
+        if !splits.is_empty() {
+            buffer.reduplicate_words_bulk(splits)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, rate, min_length, seed=None, return_count=false))]
+pub(crate) fn split_words(
+    text: &str,
+    rate: f64,
+    min_length: usize,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = SplitWordsOp { rate, min_length };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{onset_split_points, MergeWordsOp, SplitWordsOp};
+    use crate::operations::{apply_seeded, TextOperation};
+    use crate::rng::DeterministicRng;
+    use crate::text_buffer::TextBuffer;
+
+    fn merge(text: &str, rate: f64, exclude: &[regex::Regex]) -> String {
+        let mut buffer = TextBuffer::from_owned(text.to_string(), &[], exclude);
+        let mut rng = DeterministicRng::new(2066);
+        MergeWordsOp { rate }
+            .apply(&mut buffer, &mut rng)
+            .expect("merging succeeds");
+        buffer.to_string()
+    }
+
+    #[test]
+    fn glues_pairs_and_keeps_outer_affixes() {
+        assert_eq!(merge("(the cat) sat down", 1.0, &[]), "(thecat) satdown");
+        assert_eq!(merge("one two three", 1.0, &[]), "onetwo three");
+    }
+
+    #[test]
+    fn leaves_punctuated_and_line_boundaries_alone() {
+        assert_eq!(merge("end. Next", 1.0, &[]), "end. Next");
+        assert_eq!(merge("top\nbottom", 1.0, &[]), "top\nbottom");
+        assert_eq!(merge("a \"quoted\" word", 1.0, &[]), "a \"quoted\" word");
+    }
+
+    #[test]
+    fn immutable_spans_are_not_merged() {
+        let exclude = vec![regex::Regex::new(r"\[[^\]]*\]").unwrap()];
+        assert_eq!(merge("left [keep this] right", 1.0, &exclude), "left [keep this] right");
+        let text = "alpha beta gamma delta epsilon zeta";
+        let result = merge(text, 0.5, &[]);
+        assert_eq!(result, merge(text, 0.5, &[]));
+        assert_eq!(merge(text, 0.0, &[]), text);
+    }
+
+    fn split(text: &str, rate: f64, min_length: usize) -> String {
+        apply_seeded(&SplitWordsOp { rate, min_length }, text, 2067)
+    }
+
+    #[test]
+    fn splits_prefer_consonant_vowel_onsets() {
+        let chars: Vec<char> = "paper".chars().collect();
+        assert_eq!(onset_split_points(&chars), vec![2]);
+        assert_eq!(split("(Paper)!", 1.0, 4), "(Pa per)!");
+        assert_eq!(split("strength", 1.0, 6).split(' ').count(), 2);
+    }
+
+    #[test]
+    fn short_and_non_alphabetic_words_are_kept() {
+        assert_eq!(split("a cat sat", 1.0, 6), "a cat sat");
+        assert_eq!(split("3.14159 well-known", 1.0, 4), "3.14159 well-known");
+        let text = "extraordinary circumstances require remarkable patience";
+        let result = split(text, 1.0, 6);
+        assert_eq!(result.split(' ').count(), 10);
+        assert_eq!(result.replace(' ', ""), text.replace(' ', ""));
+        assert_eq!(split(text, 0.0, 6), text);
+    }
+}
@@ -1,14 +1,14 @@
 use pyo3::prelude::*;
 use rayon::prelude::*;
 use regex::Regex;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::sync::OnceLock;
 
-use crate::operations::{TextOperation, OperationError, OperationRng};
-use crate::text_buffer::TextBuffer;
+use crate::operations::{TextOperation, OpOutcome, OperationError, OperationRng};
+use crate::text_buffer::{SegmentKind, TextBuffer};
 
 static TOKEN_REGEX: OnceLock<Regex> = OnceLock::new();
 
@@ -138,7 +138,7 @@ struct StretchSite {
     end: usize,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WordStretchOp {
     pub rate: f64,
     pub extension_min: i32,
@@ -568,6 +568,7 @@ impl WordStretchOp {
         candidates: &[StretchCandidate],
         tokens: &[TokenInfo<'_>],
         rate: f64,
+        scales: &[f64],
         rng: &mut dyn OperationRng,
     ) -> Result<Vec<usize>, OperationError> {
         if candidates.is_empty() || rate <= 0.0 {
@@ -615,7 +616,8 @@ impl WordStretchOp {
 
             for &cand_idx in &clause_candidate_indices {
                 let candidate = &candidates[cand_idx];
-                let probability = (rate * (0.35 + 0.65 * candidate.score)).clamp(0.0, 1.0);
+                let probability =
+                    (rate * scales[cand_idx] * (0.35 + 0.65 * candidate.score)).clamp(0.0, 1.0);
                 if rng.random()? < probability {
                     provisional.push(cand_idx);
                 }
@@ -757,37 +759,81 @@ impl WordStretchOp {
     }
 }
 
-/// A stretch replacement to apply, sorted by byte position for efficient single-pass assembly.
+/// A stretch replacement to apply, sorted by byte position within its word segment.
 struct StretchReplacement {
-    /// Byte offset in the original text where this token starts
+    /// Index of the buffer word segment containing the token
+    word_index: usize,
+    /// Byte offset within the word segment where this token starts
     byte_start: usize,
-    /// Byte offset in the original text where this token ends
+    /// Byte offset within the word segment where this token ends
     byte_end: usize,
     /// The stretched replacement text
     stretched: String,
 }
 
+/// Maps each token to the mutable word segment containing it, as
+/// `(word_index, segment_byte_start)`. Tokens inside immutable segments or
+/// separators map to `None` and are never stretched.
+fn token_locations(buffer: &TextBuffer, tokens: &[TokenInfo<'_>]) -> Vec<Option<(usize, usize)>> {
+    let spans = buffer.spans();
+    let mut span_idx = 0usize;
+    let mut word_index = 0usize;
+    let mut locations = Vec::with_capacity(tokens.len());
+
+    for token in tokens {
+        let token_end = token.start + token.text.len();
+        while span_idx < spans.len() && spans[span_idx].byte_range.end <= token.start {
+            if spans[span_idx].kind == SegmentKind::Word {
+                word_index += 1;
+            }
+            span_idx += 1;
+        }
+        let location = spans.get(span_idx).and_then(|span| {
+            (span.kind == SegmentKind::Word && span.byte_range.end >= token_end)
+                .then_some((word_index, span.byte_range.start))
+        });
+        locations.push(location);
+    }
+    locations
+}
+
 impl TextOperation for WordStretchOp {
-    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<(), OperationError> {
-        let text = buffer.to_string();
-        if text.is_empty() {
-            return Ok(());
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 {
+            return Ok(OpOutcome::UNCHANGED);
         }
+        buffer.reindex_if_needed();
 
+        // Score against the full text so clause and sentiment context still
+        // see protected neighbours, but only stretch tokens in mutable words.
+        let text = buffer.to_string();
         let tokens = self.tokenise(&text);
-        let candidates = self.analyse(&tokens);
-        let selected_indices = self.select_candidates(&candidates, &tokens, self.rate, rng)?;
+        let locations = token_locations(buffer, &tokens);
+        let mut candidates = self.analyse(&tokens);
+        candidates.retain(|candidate| locations[candidate.token_index].is_some());
+        let scales: Vec<f64> = candidates
+            .iter()
+            .map(|candidate| {
+                locations[candidate.token_index]
+                    .map_or(1.0, |(word_index, _)| buffer.word_rate_scale(word_index))
+            })
+            .collect();
+        let selected_indices =
+            self.select_candidates(&candidates, &tokens, self.rate, &scales, rng)?;
         if selected_indices.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
-        // Collect stretch replacements with byte positions (already sorted by token position)
+        // Collect stretch replacements (already sorted by token position)
         let mut replacements: Vec<StretchReplacement> = Vec::with_capacity(selected_indices.len());
 
         for &cand_idx in &selected_indices {
             let candidate = &candidates[cand_idx];
             let token_idx = candidate.token_index;
             let token = &tokens[token_idx];
+            let Some((word_index, segment_start)) = locations[token_idx] else {
+                continue;
+            };
 
             // Use pre-computed stretch site from candidate
             let Some(site) = candidate.stretch_site else {
@@ -820,49 +866,47 @@ impl TextOperation for WordStretchOp {
             }
 
             let stretched = self.apply_stretch(&token.text, &site, repeats as usize);
-            let byte_end = token.start + token.text.len();
+            let byte_start = token.start - segment_start;
             replacements.push(StretchReplacement {
-                byte_start: token.start,
-                byte_end,
+                word_index,
+                byte_start,
+                byte_end: byte_start + token.text.len(),
                 stretched,
             });
         }
 
         if replacements.is_empty() {
-            return Ok(());
+            return Ok(OpOutcome::UNCHANGED);
         }
 
-        // Ensure replacements are sorted by byte position for correct reconstruction.
-        // While selected_indices is sorted by token position, some candidates may be
-        // skipped during iteration, and we need ascending byte order for the cursor logic.
-        replacements.sort_by_key(|r| r.byte_start);
+        // Some candidates may be skipped above, so re-sort to guarantee the
+        // ascending (word, byte) order the splice below relies on.
+        replacements.sort_by_key(|r| (r.word_index, r.byte_start));
 
-        // Build result string in a single pass using byte positions
-        // Estimate capacity: original length + extra chars from stretching
-        let extra_chars: usize = replacements
-            .iter()
-            .map(|r| r.stretched.len().saturating_sub(r.byte_end - r.byte_start))
-            .sum();
-        let mut result = String::with_capacity(text.len() + extra_chars);
-
-        let mut cursor = 0usize;
-        for replacement in &replacements {
-            // Copy unchanged text before this replacement
-            if cursor < replacement.byte_start {
-                result.push_str(&text[cursor..replacement.byte_start]);
+        // Splice every stretch belonging to a word segment into one new word.
+        let mut word_replacements: Vec<(usize, String)> = Vec::new();
+        let mut pending = replacements.iter().peekable();
+        while let Some(first) = pending.next() {
+            let Some(segment) = buffer.word_segment(first.word_index) else {
+                continue;
+            };
+            let original = segment.text();
+            let mut word = String::with_capacity(original.len() + first.stretched.len());
+            word.push_str(&original[..first.byte_start]);
+            word.push_str(&first.stretched);
+            let mut cursor = first.byte_end;
+            while let Some(next) = pending.next_if(|r| r.word_index == first.word_index) {
+                word.push_str(&original[cursor..next.byte_start]);
+                word.push_str(&next.stretched);
+                cursor = next.byte_end;
             }
-            // Insert stretched text
-            result.push_str(&replacement.stretched);
-            cursor = replacement.byte_end;
-        }
-        // Copy remaining text after last replacement
-        if cursor < text.len() {
-            result.push_str(&text[cursor..]);
+            word.push_str(&original[cursor..]);
+            word_replacements.push((first.word_index, word));
         }
 
-        *buffer = buffer.rebuild_with_patterns(result);
+        buffer.replace_words_bulk(word_replacements)?;
         buffer.reindex_if_needed();
-        Ok(())
+        Ok(OpOutcome::edits(replacements.len()))
     }
 }
 
@@ -973,7 +1017,7 @@ fn vowel_site(clusters: &[(usize, usize)]) -> Option<StretchSite> {
         .map(|&(start, end)| StretchSite { start, end })
 }
 
-const fn is_vowel(ch: char) -> bool {
+pub(crate) const fn is_vowel(ch: char) -> bool {
     matches!(ch, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
 }
 
@@ -982,7 +1026,8 @@ fn contains_vowel(chars: &[char]) -> bool {
 }
 
 /// Python wrapper for the word stretching operation.
-#[pyfunction(name = "stretch_word", signature = (text, rate, extension_min, extension_max, word_length_threshold, base_p, seed=None))]
+#[allow(clippy::too_many_arguments)]
+#[pyfunction(name = "stretch_word", signature = (text, rate, extension_min, extension_max, word_length_threshold, base_p, seed=None, rng=None, return_count=false))]
 pub fn stretch_word(
     text: &str,
     rate: f64,
@@ -991,7 +1036,9 @@ pub fn stretch_word(
     word_length_threshold: usize,
     base_p: f64,
     seed: Option<u64>,
-) -> PyResult<String> {
+    rng: Option<Bound<'_, PyAny>>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
     let op = WordStretchOp {
         rate,
         extension_min,
@@ -999,7 +1046,7 @@ pub fn stretch_word(
         word_length_threshold,
         base_p,
     };
-    crate::apply_operation(text, op, seed).map_err(crate::operations::OperationError::into_pyerr)
+    crate::py_rng::apply_with_optional_rng(text, op, seed, rng, return_count)
 }
 
 #[cfg(test)]
@@ -1422,4 +1469,42 @@ mod tests {
         assert!(result.len() >= "café cool".len());
         assert!(result.is_char_boundary(0));
     }
+
+    #[test]
+    fn hokey_leaves_immutable_segments_untouched() {
+        let op = WordStretchOp {
+            rate: 1.0,
+            extension_min: 2,
+            extension_max: 5,
+            word_length_threshold: 10,
+            base_p: 0.45,
+        };
+        let exclude = vec![regex::Regex::new(r"\[[^\]]*\]").unwrap()];
+        let text = "wow so cool [wow so cool] wow so cool";
+        let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &exclude);
+        let mut rng = DeterministicRng::new(42);
+        op.apply(&mut buffer, &mut rng).expect("hokey succeeds");
+        let result = buffer.to_string();
+        assert_ne!(result, text);
+        assert!(result.contains(" [wow so cool] "));
+    }
+
+    #[test]
+    fn hokey_stretches_multiple_tokens_within_one_segment() {
+        let op = WordStretchOp {
+            rate: 1.0,
+            extension_min: 2,
+            extension_max: 2,
+            word_length_threshold: 10,
+            base_p: 0.45,
+        };
+        let mut buffer = TextBuffer::from_owned("wow,cool!".to_string(), &[], &[]);
+        let mut rng = DeterministicRng::new(7);
+        op.apply(&mut buffer, &mut rng).expect("hokey succeeds");
+        let result = buffer.to_string();
+        assert_eq!(buffer.word_count(), 1);
+        assert!(result.contains(','));
+        assert!(result.ends_with('!'));
+        assert!(result.len() > "wow,cool!".len());
+    }
 }
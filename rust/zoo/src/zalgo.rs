@@ -0,0 +1,156 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::operations::{OpOutcome, OperationError, OperationRng, TextOperation};
+use crate::text_buffer::TextBuffer;
+
+/// First code point of the Combining Diacritical Marks block.
+const ZALGO_FIRST_MARK: u32 = 0x0300;
+
+/// Last code point of the Combining Diacritical Marks block.
+const ZALGO_LAST_MARK: u32 = 0x036F;
+
+/// Marks inside the block that are skipped: U+034F COMBINING GRAPHEME JOINER
+/// is invisible and only affects collation, so it adds no visual noise.
+const ZALGO_EXCLUDED_MARKS: &[u32] = &[0x034F];
+
+/// Number of marks [`ZalgoOp`] draws from.
+const ZALGO_PALETTE_LEN: usize =
+    (ZALGO_LAST_MARK - ZALGO_FIRST_MARK + 1) as usize - ZALGO_EXCLUDED_MARKS.len();
+
+/// Maps a palette index onto its combining mark, stepping over exclusions.
+fn palette_mark(index: usize) -> char {
+    let mut code = ZALGO_FIRST_MARK + index as u32;
+    for &excluded in ZALGO_EXCLUDED_MARKS {
+        if code >= excluded {
+            code += 1;
+        }
+    }
+    char::from_u32(code).unwrap_or('\u{0300}')
+}
+
+/// Stacks combining diacritics onto letters and digits ("Zalgo" text) to
+/// stress-test renderers and tokenisers.
+///
+/// Each alphanumeric grapheme cluster inside a mutable word is decorated with
+/// probability `rate`, receiving between one and `intensity` marks drawn from
+/// U+0300–U+036F. Marks are appended after the whole cluster, so existing
+/// accents and multi-codepoint graphemes stay intact; punctuation, symbols
+/// and emoji are never decorated.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ZalgoOp {
+    pub intensity: usize,
+    pub rate: f64,
+}
+
+impl TextOperation for ZalgoOp {
+    fn apply(&self, buffer: &mut TextBuffer, rng: &mut dyn OperationRng) -> Result<OpOutcome, OperationError> {
+        if buffer.word_count() == 0 || self.intensity == 0 || self.rate.is_nan() {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let rate = self.rate.clamp(0.0, 1.0);
+        if rate <= f64::EPSILON {
+            return Ok(OpOutcome::UNCHANGED);
+        }
+
+        let mut replacements: Vec<(usize, String)> = Vec::new();
+        for idx in 0..buffer.word_count() {
+            let Some(segment) = buffer.word_segment(idx) else {
+                continue;
+            };
+            if !segment.is_mutable() {
+                continue;
+            }
+            let word = segment.text();
+
+            let mut output = String::with_capacity(word.len() * 2);
+            let mut changed = false;
+            for grapheme in word.graphemes(true) {
+                output.push_str(grapheme);
+                let decoratable = grapheme.chars().next().is_some_and(char::is_alphanumeric);
+                if !decoratable || rng.random()? >= rate * segment.rate_scale() {
+                    continue;
+                }
+                let marks = rng.rand_index(self.intensity)? + 1;
+                for _ in 0..marks {
+                    output.push(palette_mark(rng.rand_index(ZALGO_PALETTE_LEN)?));
+                }
+                changed = true;
+            }
+
+            if changed {
+                replacements.push((idx, output));
+            }
+        }
+
+        let edits = replacements.len();
+        if !replacements.is_empty() {
+            buffer.replace_words_bulk(replacements)?;
+        }
+
+        buffer.reindex_if_needed();
+        Ok(OpOutcome::edits(edits))
+    }
+}
+
+#[pyfunction(signature = (text, intensity, rate, seed=None, return_count=false))]
+pub(crate) fn zalgo(
+    text: &str,
+    intensity: usize,
+    rate: f64,
+    seed: Option<u64>,
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
+    let op = ZalgoOp { intensity, rate };
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{palette_mark, ZalgoOp, ZALGO_PALETTE_LEN};
+    use crate::operations::apply_seeded;
+    use unicode_segmentation::UnicodeSegmentation;
+
+    fn zalgo(text: &str, intensity: usize, rate: f64, seed: u64) -> String {
+        apply_seeded(&ZalgoOp { intensity, rate }, text, seed)
+    }
+
+    #[test]
+    fn palette_covers_the_block_without_the_grapheme_joiner() {
+        let marks: Vec<char> = (0..ZALGO_PALETTE_LEN).map(palette_mark).collect();
+        assert_eq!(marks.first(), Some(&'\u{0300}'));
+        assert_eq!(marks.last(), Some(&'\u{036F}'));
+        assert!(!marks.contains(&'\u{034F}'));
+        assert_eq!(marks.len(), 111);
+    }
+
+    #[test]
+    fn marks_stay_attached_to_their_graphemes() {
+        let text = "Hello, cafe\u{0301} 👍🏽!";
+        let result = zalgo(text, 3, 1.0, 2069);
+        assert_eq!(result, zalgo(text, 3, 1.0, 2069));
+        assert_eq!(
+            result.graphemes(true).count(),
+            text.graphemes(true).count(),
+            "marks must not form clusters of their own"
+        );
+        for (before, after) in text.graphemes(true).zip(result.graphemes(true)) {
+            assert!(after.starts_with(before));
+            let added = after.chars().count() - before.chars().count();
+            if before.chars().next().is_some_and(char::is_alphanumeric) {
+                assert!((1..=3).contains(&added), "{before:?} got {added} marks");
+            } else {
+                assert_eq!(added, 0, "{before:?} must not be decorated");
+            }
+        }
+    }
+
+    #[test]
+    fn zero_rate_or_intensity_is_identity() {
+        assert_eq!(zalgo("plain text", 0, 1.0, 1), "plain text");
+        assert_eq!(zalgo("plain text", 4, 0.0, 1), "plain text");
+        assert_ne!(zalgo("plain text", 4, 0.5, 1), zalgo("plain text", 4, 0.5, 2));
+    }
+}
@@ -4,7 +4,43 @@ use pyo3::Bound;
 
 use crate::operations::{PlacementMode, VisibilityMode, ZeroWidthOp};
 
-#[pyfunction(signature = (text, rate, characters, seed=None, visibility=None, placement=None, max_consecutive=None))]
+/// Invisible format and bidi-control code points removed by [`strip_zero_widths`].
+pub(crate) const ZERO_WIDTH_CONTROLS: &[char] = &[
+    '\u{034F}', // COMBINING GRAPHEME JOINER
+    '\u{061C}', // ARABIC LETTER MARK
+    '\u{180E}', // MONGOLIAN VOWEL SEPARATOR
+    '\u{200B}', // ZERO WIDTH SPACE
+    '\u{200C}', // ZERO WIDTH NON-JOINER
+    '\u{200D}', // ZERO WIDTH JOINER
+    '\u{200E}', // LEFT-TO-RIGHT MARK
+    '\u{200F}', // RIGHT-TO-LEFT MARK
+    '\u{202A}', // LEFT-TO-RIGHT EMBEDDING
+    '\u{202B}', // RIGHT-TO-LEFT EMBEDDING
+    '\u{202C}', // POP DIRECTIONAL FORMATTING
+    '\u{202D}', // LEFT-TO-RIGHT OVERRIDE
+    '\u{202E}', // RIGHT-TO-LEFT OVERRIDE
+    '\u{2060}', // WORD JOINER
+    '\u{2061}', // FUNCTION APPLICATION
+    '\u{2062}', // INVISIBLE TIMES
+    '\u{2063}', // INVISIBLE SEPARATOR
+    '\u{2064}', // INVISIBLE PLUS
+    '\u{2066}', // LEFT-TO-RIGHT ISOLATE
+    '\u{2067}', // RIGHT-TO-LEFT ISOLATE
+    '\u{2068}', // FIRST STRONG ISOLATE
+    '\u{2069}', // POP DIRECTIONAL ISOLATE
+    '\u{FEFF}', // BYTE ORDER MARK
+];
+
+/// Removes every zero-width and bidi-control character in a single pass.
+#[pyfunction]
+pub(crate) fn strip_zero_widths(text: &str) -> String {
+    text.chars()
+        .filter(|ch| !ZERO_WIDTH_CONTROLS.contains(ch))
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+#[pyfunction(signature = (text, rate, characters, seed=None, visibility=None, placement=None, max_consecutive=None, return_count=false))]
 pub(crate) fn inject_zero_widths(
     text: &str,
     rate: f64,
@@ -13,9 +49,10 @@ pub(crate) fn inject_zero_widths(
     visibility: Option<&str>,
     placement: Option<&str>,
     max_consecutive: Option<usize>,
-) -> PyResult<String> {
+    return_count: bool,
+) -> PyResult<crate::GlitchOutput> {
     if text.is_empty() {
-        return Ok(String::new());
+        return Ok(crate::GlitchOutput::new(String::new(), 0, return_count));
     }
 
     let list = characters.downcast::<PyList>()?;
@@ -49,5 +86,34 @@ pub(crate) fn inject_zero_widths(
     let max_consec = max_consecutive.unwrap_or(4);
 
     let op = ZeroWidthOp::with_options(rate, palette, visibility_mode, placement_mode, max_consec);
-    crate::apply_operation(text, op, seed).map_err(crate::operations::OperationError::into_pyerr)
+    crate::apply_operation_py(text, op, seed, return_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_zero_widths;
+    use crate::operations::{TextOperation, VisibilityMode, ZeroWidthOp};
+    use crate::rng::DeterministicRng;
+    use crate::text_buffer::TextBuffer;
+
+    #[test]
+    fn stripping_recovers_injected_text() {
+        let text = "The quick brown fox jumps over the lazy dog.";
+        let palette = VisibilityMode::Glyphless.default_palette();
+        for seed in 0u64..8 {
+            let mut buffer = TextBuffer::from_owned(text.to_string(), &[], &[]);
+            let mut rng = DeterministicRng::new(seed);
+            ZeroWidthOp::new(0.8, palette.clone())
+                .apply(&mut buffer, &mut rng)
+                .expect("injection succeeds");
+            let injected = buffer.to_string();
+            assert_ne!(injected, text);
+            assert_eq!(strip_zero_widths(&injected), text);
+        }
+    }
+
+    #[test]
+    fn stripping_removes_bidi_controls() {
+        assert_eq!(strip_zero_widths("\u{202E}abc\u{202C}\u{200F}"), "abc");
+    }
 }
@@ -6,8 +6,8 @@
 /// 3. The buffer can be re-parsed from its string representation without loss
 use _corruption_engine::{
     DeleteRandomWordsOp, DeterministicRng, MotorWeighting, TextOperation, Operation, OcrArtifactsOp,
-    QuotePairsOp, RedactWordsOp, ReduplicateWordsOp, SegmentKind, SwapAdjacentWordsOp, TextBuffer,
-    TypoOp, ZeroWidthOp,
+    QuotePairsOp, RedactWordsOp, ReduplicateWordsOp, ReduplicationSeparator, SegmentKind,
+    SwapAdjacentWordsOp, TextBuffer, TypoOp, WordWeighting, ZeroWidthOp,
 };
 
 /// Test corpus covering various text patterns
@@ -138,7 +138,13 @@ fn test_reduplicate_words_roundtrip() {
     for text in TEST_CORPUS {
         for rate in [0.0, 0.5, 1.0] {
             for unweighted in [false, true] {
-                let op = ReduplicateWordsOp { rate, unweighted };
+                let op = ReduplicateWordsOp {
+                    rate,
+                    unweighted,
+                    repeats: 1,
+                    stutter: false,
+                    separator: ReduplicationSeparator::Space,
+                };
                 test_op_roundtrip(op, text, 42, "ReduplicateWordsOp");
             }
         }
@@ -150,7 +156,13 @@ fn test_delete_random_words_roundtrip() {
     for text in TEST_CORPUS {
         for rate in [0.0, 0.3, 0.5, 0.8] {
             for unweighted in [false, true] {
-                let op = DeleteRandomWordsOp { rate, unweighted };
+                let op = DeleteRandomWordsOp {
+                    rate,
+                    unweighted,
+                    min_words: 0,
+                    weighting: WordWeighting::Length,
+                    preserve_spacing: false,
+                };
                 test_op_roundtrip(op, text, 123, "DeleteRandomWordsOp");
             }
         }
@@ -177,7 +189,10 @@ fn test_redact_words_roundtrip() {
                         replacement_char: "█".to_string(),
                         rate,
                         merge_adjacent,
+                        merge_through_punctuation: false,
+                        fixed_width: None,
                         unweighted,
+                        weighting: WordWeighting::Length,
                     };
                     // This may error on empty/whitespace-only inputs - that's ok
                     test_op_roundtrip(op, text, 789, "RedactWordsOp");
@@ -212,6 +227,11 @@ fn test_typo_roundtrip() {
                 layout: layout.clone(),
                 shift_slip: None,
                 motor_weighting: MotorWeighting::default(),
+                grapheme_aware: false,
+                burstiness: 0.0,
+                homerow_bias: 0.0,
+                keyboard: Default::default(),
+                syllable_swaps: false,
             };
             test_op_roundtrip(op, text, 202, "TypoOp");
         }
@@ -234,7 +254,7 @@ fn test_zero_width_roundtrip() {
 #[test]
 fn test_quote_pairs_roundtrip() {
     for text in TEST_CORPUS {
-        let op = QuotePairsOp;
+        let op = QuotePairsOp::default();
         test_op_roundtrip(op, text, 404, "QuotePairsOp");
     }
 }
@@ -251,6 +271,9 @@ fn test_deterministic_operations() {
             Operation::Reduplicate(ReduplicateWordsOp {
                 rate: 0.5,
                 unweighted: false,
+                repeats: 1,
+                stutter: false,
+                separator: ReduplicationSeparator::Space,
             }),
         ),
         (
@@ -258,6 +281,9 @@ fn test_deterministic_operations() {
             Operation::Delete(DeleteRandomWordsOp {
                 rate: 0.3,
                 unweighted: false,
+                min_words: 0,
+                weighting: WordWeighting::Length,
+                preserve_spacing: false,
             }),
         ),
         (
@@ -265,7 +291,7 @@ fn test_deterministic_operations() {
             Operation::SwapAdjacent(SwapAdjacentWordsOp { rate: 0.5 }),
         ),
         ("Ocr", Operation::Ocr(OcrArtifactsOp::new(0.5))),
-        ("QuotePairs", Operation::QuotePairs(QuotePairsOp)),
+        ("QuotePairs", Operation::QuotePairs(QuotePairsOp::default())),
     ];
 
     for (name, op) in ops {
@@ -296,12 +322,18 @@ fn test_long_text_roundtrip() {
             Box::new(ReduplicateWordsOp {
                 rate: 0.1,
                 unweighted: false,
+                repeats: 1,
+                stutter: false,
+                separator: ReduplicationSeparator::Space,
             })
         }),
         Box::new(|| {
             Box::new(DeleteRandomWordsOp {
                 rate: 0.1,
                 unweighted: false,
+                min_words: 0,
+                weighting: WordWeighting::Length,
+                preserve_spacing: false,
             })
         }),
         Box::new(|| Box::new(SwapAdjacentWordsOp { rate: 0.1 })),
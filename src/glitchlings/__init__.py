@@ -21,6 +21,7 @@ from .zoo import (
     Gaggle,
     Glitchling,
     Hokey,
+    Identity,
     Jargoyle,
     Mim1c,
     Pedant,
@@ -32,6 +33,7 @@ from .zoo import (
     Wherewolf,
     Zeedub,
     hokey,
+    identity,
     jargoyle,
     mim1c,
     pedant,
@@ -88,6 +90,8 @@ __all__ = [
     "scannequin",
     "Zeedub",
     "zeedub",
+    "Identity",
+    "identity",
     "summon",
     "Glitchling",
     "Gaggle",
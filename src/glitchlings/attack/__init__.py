@@ -70,11 +70,27 @@ from .core_planning import (
 from .encode import describe_tokenizer, encode_batch, encode_single
 from .metrics import (
     MetricName,
+    batch_text_metric,
+    char_entropy_delta,
+    character_error_rate,
+    confusable_edit_distance,
     entropy_delta,
+    entropy_delta_str,
+    injection_rate,
     jensen_shannon_divergence,
+    jensen_shannon_divergence_str,
     merge_split_index,
+    merge_split_index_str,
+    merge_split_signed,
     normalized_edit_distance,
+    normalized_edit_distance_str,
+    positional_jsd,
+    positional_jsd_str,
+    readability_delta,
     subsequence_retention,
+    subsequence_retention_str,
+    word_error_rate,
+    word_error_rate_str,
 )
 from .metrics_dispatch import TokenBatch, TokenSequence, is_batch, validate_batch_consistency
 from .tokenization import (
@@ -116,6 +132,22 @@ __all__ = [
     "subsequence_retention",
     "entropy_delta",
     "merge_split_index",
+    "merge_split_signed",
+    "readability_delta",
+    "positional_jsd",
+    "confusable_edit_distance",
+    "word_error_rate",
+    "character_error_rate",
+    "injection_rate",
+    "jensen_shannon_divergence_str",
+    "normalized_edit_distance_str",
+    "subsequence_retention_str",
+    "positional_jsd_str",
+    "entropy_delta_str",
+    "merge_split_index_str",
+    "word_error_rate_str",
+    "char_entropy_delta",
+    "batch_text_metric",
     # Analysis tools (impure orchestrators)
     "SeedSweep",
     "SeedSweepResult",
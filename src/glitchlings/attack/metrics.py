@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from enum import Enum
-from typing import TYPE_CHECKING, Protocol, cast
+from typing import TYPE_CHECKING, Literal, Protocol, Sequence, cast
 
 from ..internal.rust import get_rust_operation
 from .metrics_dispatch import TokenBatch, TokenSequence, is_batch, validate_batch_consistency
@@ -22,17 +22,52 @@ class BatchMetric(Protocol):
     def __call__(self, inputs: TokenBatch, outputs: TokenBatch) -> list[float]: ...
 
 
+class TextMetric(Protocol):
+    def __call__(self, original: str, corrupted: str) -> float: ...
+
+
+class BatchTextMetric(Protocol):
+    def __call__(self, inputs: Sequence[str], outputs: Sequence[str]) -> list[float]: ...
+
+
+MetricTokenizer = Literal["whitespace", "words"]
+
+
 # Rust function references (loaded on first use via get_rust_operation)
 _single_jsd = cast(Metric, get_rust_operation("jensen_shannon_divergence"))
 _single_ned = cast(Metric, get_rust_operation("normalized_edit_distance"))
 _single_sr = cast(Metric, get_rust_operation("subsequence_retention"))
 _single_ed = cast(Metric, get_rust_operation("entropy_delta"))
 _single_msi = cast(Metric, get_rust_operation("merge_split_index"))
+_single_mss = cast(Metric, get_rust_operation("merge_split_signed"))
 _batch_jsd = cast(BatchMetric, get_rust_operation("batch_jensen_shannon_divergence"))
 _batch_ned = cast(BatchMetric, get_rust_operation("batch_normalized_edit_distance"))
 _batch_sr = cast(BatchMetric, get_rust_operation("batch_subsequence_retention"))
 _batch_ed = cast(BatchMetric, get_rust_operation("batch_entropy_delta"))
 _batch_msi = cast(BatchMetric, get_rust_operation("batch_merge_split_index"))
+_batch_mss = cast(BatchMetric, get_rust_operation("batch_merge_split_signed"))
+_single_rd = cast(TextMetric, get_rust_operation("readability_delta"))
+_batch_rd = cast(BatchTextMetric, get_rust_operation("batch_readability_delta"))
+_single_pjsd = get_rust_operation("positional_jsd")
+_batch_pjsd = get_rust_operation("batch_positional_jsd")
+_single_cfed = cast(TextMetric, get_rust_operation("confusable_edit_distance"))
+_batch_cfed = cast(BatchTextMetric, get_rust_operation("batch_confusable_edit_distance"))
+_single_wer = cast(Metric, get_rust_operation("word_error_rate"))
+_batch_wer = cast(BatchMetric, get_rust_operation("batch_word_error_rate"))
+_single_cer = cast(TextMetric, get_rust_operation("character_error_rate"))
+_batch_cer = cast(BatchTextMetric, get_rust_operation("batch_character_error_rate"))
+_single_ir = cast(TextMetric, get_rust_operation("injection_rate"))
+_batch_ir = cast(BatchTextMetric, get_rust_operation("batch_injection_rate"))
+_jensen_shannon_divergence_str = get_rust_operation("jensen_shannon_divergence_str")
+_normalized_edit_distance_str = get_rust_operation("normalized_edit_distance_str")
+_subsequence_retention_str = get_rust_operation("subsequence_retention_str")
+_positional_jsd_str = get_rust_operation("positional_jsd_str")
+_entropy_delta_str = get_rust_operation("entropy_delta_str")
+_merge_split_index_str = get_rust_operation("merge_split_index_str")
+_word_error_rate_str = get_rust_operation("word_error_rate_str")
+_single_ced = cast(TextMetric, get_rust_operation("char_entropy_delta"))
+_batch_ced = cast(BatchTextMetric, get_rust_operation("batch_char_entropy_delta"))
+_batch_text_metric = get_rust_operation("batch_text_metric")
 
 
 def _dispatch_metric(
@@ -55,6 +90,27 @@ def _dispatch_metric(
     return single(original, corrupted)
 
 
+def _dispatch_text_metric(
+    original: str | Sequence[str],
+    corrupted: str | Sequence[str],
+    *,
+    single: TextMetric,
+    batch: BatchTextMetric,
+    name: str,
+) -> float | list[float]:
+    """Dispatch a raw-text metric to its single or batch implementation.
+
+    A ``str`` is one document; any other sequence is a batch of documents.
+    """
+    if isinstance(original, str) != isinstance(corrupted, str):
+        raise TypeError(f"{name} expects either both batch inputs or both single strings")
+
+    if isinstance(original, str):
+        return single(original, cast(str, corrupted))
+
+    return batch(list(original), list(corrupted))
+
+
 def jensen_shannon_divergence(
     original_tokens: TokenSequence | TokenBatch,
     corrupted_tokens: TokenSequence | TokenBatch,
@@ -150,6 +206,303 @@ def merge_split_index(
     )
 
 
+def merge_split_signed(
+    original_tokens: TokenSequence | TokenBatch,
+    corrupted_tokens: TokenSequence | TokenBatch,
+) -> float | list[float]:
+    """Compute the merge-split index with its direction kept.
+
+    Same magnitude as :func:`merge_split_index`, but positive when splits
+    outnumber merges and negative when merges do.
+
+    MSS = (splits - merges) / max(m, n) ∈ [-1, 1]
+
+    Args:
+        original_tokens: Original token sequence(s).
+        corrupted_tokens: Corrupted token sequence(s).
+
+    Returns:
+        Signed merge-split index in [-1, 1], or list for batches.
+    """
+    return _dispatch_metric(
+        original_tokens,
+        corrupted_tokens,
+        single=_single_mss,
+        batch=_batch_mss,
+        name="merge_split_signed",
+    )
+
+
+def readability_delta(
+    original: str | Sequence[str],
+    corrupted: str | Sequence[str],
+) -> float | list[float]:
+    """Compute the change in Flesch Reading Ease from original to corrupted.
+
+    Negative values mean the corrupted text reads harder. An original
+    without words scores 0.0.
+
+    Args:
+        original: Original text(s).
+        corrupted: Corrupted text(s).
+
+    Returns:
+        Reading-ease delta, or list for batches.
+    """
+    return _dispatch_text_metric(
+        original,
+        corrupted,
+        single=_single_rd,
+        batch=_batch_rd,
+        name="readability_delta",
+    )
+
+
+def positional_jsd(
+    original_tokens: TokenSequence | TokenBatch,
+    corrupted_tokens: TokenSequence | TokenBatch,
+    bins: int,
+) -> float | list[float]:
+    """Compute Jensen-Shannon divergence per positional bin.
+
+    Buckets each sequence by relative position into ``bins`` slices and
+    averages the divergence of matching slices, skipping slices empty on both
+    sides, so where the corruption lands matters.
+
+    Args:
+        original_tokens: Original token sequence(s).
+        corrupted_tokens: Corrupted token sequence(s).
+        bins: Number of positional bins; must be positive.
+
+    Returns:
+        Mean per-bin divergence in [0, 1], or list for batches.
+    """
+    return _dispatch_metric(
+        original_tokens,
+        corrupted_tokens,
+        single=cast(Metric, lambda original, corrupted: _single_pjsd(original, corrupted, bins)),
+        batch=cast(BatchMetric, lambda inputs, outputs: _batch_pjsd(inputs, outputs, bins)),
+        name="positional_jsd",
+    )
+
+
+def confusable_edit_distance(
+    original: str | Sequence[str],
+    corrupted: str | Sequence[str],
+) -> float | list[float]:
+    """Compute character edit distance that ignores homoglyph swaps.
+
+    Characters from the same confusable class count as equal, so this
+    measures *visible* change: pure homoglyph substitutions score 0.0.
+
+    Args:
+        original: Original text(s).
+        corrupted: Corrupted text(s).
+
+    Returns:
+        Normalized edit distance in [0, 1], or list for batches.
+    """
+    return _dispatch_text_metric(
+        original,
+        corrupted,
+        single=_single_cfed,
+        batch=_batch_cfed,
+        name="confusable_edit_distance",
+    )
+
+
+def word_error_rate(
+    reference_tokens: TokenSequence | TokenBatch,
+    hypothesis_tokens: TokenSequence | TokenBatch,
+) -> float | list[float]:
+    """Compute the word error rate of a hypothesis against a reference.
+
+    WER = (substitutions + deletions + insertions) / len(reference)
+
+    Insertions can push the rate above 1.0.
+
+    Args:
+        reference_tokens: Reference token sequence(s).
+        hypothesis_tokens: Hypothesis token sequence(s).
+
+    Returns:
+        Word error rate, or list for batches.
+
+    Raises:
+        ValueError: If a reference is empty but its hypothesis is not.
+    """
+    return _dispatch_metric(
+        reference_tokens,
+        hypothesis_tokens,
+        single=_single_wer,
+        batch=_batch_wer,
+        name="word_error_rate",
+    )
+
+
+def character_error_rate(
+    reference: str | Sequence[str],
+    hypothesis: str | Sequence[str],
+) -> float | list[float]:
+    """Compute the character error rate of a hypothesis against a reference.
+
+    The character-level analogue of :func:`word_error_rate`, with the same
+    empty-reference handling.
+
+    Args:
+        reference: Reference text(s).
+        hypothesis: Hypothesis text(s).
+
+    Returns:
+        Character error rate, or list for batches.
+
+    Raises:
+        ValueError: If a reference is empty but its hypothesis is not.
+    """
+    return _dispatch_text_metric(
+        reference,
+        hypothesis,
+        single=_single_cer,
+        batch=_batch_cer,
+        name="character_error_rate",
+    )
+
+
+def injection_rate(
+    original: str | Sequence[str],
+    corrupted: str | Sequence[str],
+) -> float | list[float]:
+    """Compute the fraction of injected non-ASCII characters.
+
+    Counts corrupted characters that are non-ASCII and absent from the
+    original: homoglyphs, zero-width characters and bidi controls. An
+    empty corrupted text scores 0.0.
+
+    Args:
+        original: Original text(s).
+        corrupted: Corrupted text(s).
+
+    Returns:
+        Injection rate in [0, 1], or list for batches.
+    """
+    return _dispatch_text_metric(
+        original,
+        corrupted,
+        single=_single_ir,
+        batch=_batch_ir,
+        name="injection_rate",
+    )
+
+
+def char_entropy_delta(
+    original: str | Sequence[str],
+    corrupted: str | Sequence[str],
+) -> float | list[float]:
+    """Compute :func:`entropy_delta` over character distributions.
+
+    Sub-word corruption (typos, homoglyphs) registers even when the token
+    distribution barely moves.
+
+    Args:
+        original: Original text(s).
+        corrupted: Corrupted text(s).
+
+    Returns:
+        Normalized character entropy delta in [-1, 1], or list for batches.
+    """
+    return _dispatch_text_metric(
+        original,
+        corrupted,
+        single=_single_ced,
+        batch=_batch_ced,
+        name="char_entropy_delta",
+    )
+
+
+# ---------------------------------------------------------------------------
+# Raw-String Variants
+# ---------------------------------------------------------------------------
+
+
+def jensen_shannon_divergence_str(
+    original: str, corrupted: str, *, tokenizer: MetricTokenizer = "whitespace"
+) -> float:
+    """:func:`jensen_shannon_divergence` over raw strings tokenised in Rust."""
+    return cast(float, _jensen_shannon_divergence_str(original, corrupted, tokenizer))
+
+
+def normalized_edit_distance_str(
+    original: str, corrupted: str, *, tokenizer: MetricTokenizer = "whitespace"
+) -> float:
+    """:func:`normalized_edit_distance` over raw strings tokenised in Rust."""
+    return cast(float, _normalized_edit_distance_str(original, corrupted, tokenizer))
+
+
+def subsequence_retention_str(
+    original: str, corrupted: str, *, tokenizer: MetricTokenizer = "whitespace"
+) -> float:
+    """:func:`subsequence_retention` over raw strings tokenised in Rust."""
+    return cast(float, _subsequence_retention_str(original, corrupted, tokenizer))
+
+
+def positional_jsd_str(
+    original: str, corrupted: str, bins: int, *, tokenizer: MetricTokenizer = "whitespace"
+) -> float:
+    """:func:`positional_jsd` over raw strings tokenised in Rust."""
+    return cast(float, _positional_jsd_str(original, corrupted, bins, tokenizer))
+
+
+def entropy_delta_str(
+    original: str, corrupted: str, *, tokenizer: MetricTokenizer = "whitespace"
+) -> float:
+    """:func:`entropy_delta` over raw strings tokenised in Rust."""
+    return cast(float, _entropy_delta_str(original, corrupted, tokenizer))
+
+
+def merge_split_index_str(
+    original: str, corrupted: str, *, tokenizer: MetricTokenizer = "whitespace"
+) -> float:
+    """:func:`merge_split_index` over raw strings tokenised in Rust."""
+    return cast(float, _merge_split_index_str(original, corrupted, tokenizer))
+
+
+def word_error_rate_str(
+    reference: str, hypothesis: str, *, tokenizer: MetricTokenizer = "whitespace"
+) -> float:
+    """:func:`word_error_rate` over raw strings tokenised in Rust."""
+    return cast(float, _word_error_rate_str(reference, hypothesis, tokenizer))
+
+
+def batch_text_metric(
+    metric: str,
+    originals: Sequence[str],
+    corrupted: Sequence[str],
+    *,
+    per_sentence: bool = False,
+) -> list[list[float]]:
+    """Score raw documents with a metric chosen by name.
+
+    Token metrics split on whitespace. With ``per_sentence``, each inner list
+    holds one score per sentence of the original; output sentences pair with
+    original sentences by position. Without it, each inner list holds the
+    single whole-document score.
+
+    Args:
+        metric: Metric name, e.g. ``"jsd"``, ``"ned"`` or
+            ``"readability_delta"``.
+        originals: Original documents.
+        corrupted: Corrupted documents, one per original.
+        per_sentence: Score sentence by sentence instead of whole documents.
+
+    Returns:
+        Scores per document; documents without sentences give an empty list.
+    """
+    return cast(
+        list[list[float]],
+        _batch_text_metric(metric, list(originals), list(corrupted), per_sentence),
+    )
+
+
 # ---------------------------------------------------------------------------
 # MetricName Enum
 # ---------------------------------------------------------------------------
@@ -208,4 +561,20 @@ __all__ = [
     "subsequence_retention",
     "entropy_delta",
     "merge_split_index",
+    "merge_split_signed",
+    "readability_delta",
+    "positional_jsd",
+    "confusable_edit_distance",
+    "word_error_rate",
+    "character_error_rate",
+    "injection_rate",
+    "jensen_shannon_divergence_str",
+    "normalized_edit_distance_str",
+    "subsequence_retention_str",
+    "positional_jsd_str",
+    "entropy_delta_str",
+    "merge_split_index_str",
+    "word_error_rate_str",
+    "char_entropy_delta",
+    "batch_text_metric",
 ]
@@ -27,7 +27,7 @@ See AGENTS.md "Functional Purity Architecture" for full details.
 
 from __future__ import annotations
 
-from typing import Any, Literal, Mapping, Sequence, cast
+from typing import Any, Iterable, Iterator, Literal, Mapping, Sequence, cast, overload
 
 from .rust import get_rust_operation, load_rust_module, resolve_seed
 
@@ -36,8 +36,17 @@ __all__ = [
     "resolve_seed",
     # Orchestration operations
     "plan_operations_rust",
+    "apply_glitch_rust",
+    "calibrate_rate_rust",
+    "detect_markup_ranges_rust",
+    "plan_operations_detailed_rust",
+    "derive_seed_rust",
     "compose_operations_rust",
+    "compose_operations_batch_rust",
+    "generate_pair_rust",
+    "apply_operation_batch_rust",
     "build_pipeline_rust",
+    "pipeline_builder_rust",
     "RustPipeline",
     # Character-level operations
     "keyboard_typo_rust",
@@ -46,6 +55,10 @@ __all__ = [
     "ocr_artifacts_rust",
     "inject_zero_widths_rust",
     "stretch_word_rust",
+    "inject_bidi_rust",
+    "corrupt_case_rust",
+    "diacritics_rust",
+    "zalgo_rust",
     # Word-level operations
     "delete_random_words_rust",
     "reduplicate_words_rust",
@@ -56,8 +69,29 @@ __all__ = [
     "list_bundled_lexeme_dictionaries_rust",
     "is_bundled_lexeme_rust",
     "substitute_homophones_rust",
+    "phonetic_rust",
+    "jitter_whitespace_rust",
+    "drop_punctuation_rust",
+    "autocorrect_rust",
+    "merge_words_rust",
+    "split_words_rust",
+    "inject_fillers_rust",
+    "contractions_rust",
+    "disemvowel_rust",
+    "scramble_interior_rust",
+    "double_words_rust",
+    # Sentence-level operations
+    "delete_sentences_rust",
+    "shuffle_sentences_rust",
     # Grammar operations
     "apply_grammar_rule_rust",
+    # Resource tables
+    "ocr_confusions_rust",
+    "ocr_correct_rust",
+    "homoglyph_table_rust",
+    "homophone_sets_rust",
+    "list_operations_rust",
+    "motor_weighting_modes_rust",
 ]
 
 
@@ -85,6 +119,7 @@ class RustPipeline:
         *,
         include_only_patterns: Sequence[str] | None = None,
         exclude_patterns: Sequence[str] | None = None,
+        max_total_edits: int | None = None,
     ) -> None:
         module = load_rust_module()
         pipeline_cls = getattr(module, "Pipeline")
@@ -93,12 +128,155 @@ class RustPipeline:
         )
         exclude_patterns_list = list(exclude_patterns) if exclude_patterns is not None else None
         self._pipeline = pipeline_cls(
-            list(descriptors), int(master_seed), include_patterns_list, exclude_patterns_list
+            list(descriptors),
+            int(master_seed),
+            include_patterns_list,
+            exclude_patterns_list,
+            max_total_edits,
         )
 
     def run(self, text: str) -> str:
         return cast(str, self._pipeline.run(text))
 
+    def to_json(self) -> str:
+        """Serialise the pipeline so :meth:`from_json` can rebuild it exactly."""
+        return cast(str, self._pipeline.to_json())
+
+    @classmethod
+    def from_json(cls, payload: str) -> RustPipeline:
+        """Rebuild a pipeline from :meth:`to_json` output.
+
+        The restored pipeline produces identical output for identical input.
+        """
+        module = load_rust_module()
+        pipeline = cls.__new__(cls)
+        pipeline._pipeline = getattr(module, "Pipeline").from_json(payload)
+        return pipeline
+
+    @classmethod
+    def from_builder(
+        cls,
+        builder: Any,
+        master_seed: int,
+        *,
+        include_only_patterns: Sequence[str] | None = None,
+        exclude_patterns: Sequence[str] | None = None,
+        max_total_edits: int | None = None,
+    ) -> RustPipeline:
+        """Compile a :func:`pipeline_builder_rust` chain into a pipeline.
+
+        The result matches a pipeline built from the equivalent descriptor
+        dicts with the same names and seeds.
+        """
+        pipeline = cls.__new__(cls)
+        pipeline._pipeline = builder.build(
+            int(master_seed),
+            list(include_only_patterns) if include_only_patterns is not None else None,
+            list(exclude_patterns) if exclude_patterns is not None else None,
+            max_total_edits,
+        )
+        return pipeline
+
+    def run_instrumented(self, text: str) -> tuple[str, list[dict[str, Any]]]:
+        """Run the pipeline and report each operation's effect.
+
+        Returns:
+            The corrupted text and one dict per operation with ``name``,
+            ``edits``, ``char_delta``, ``word_delta`` and ``elapsed_seconds``.
+        """
+        output, reports = self._pipeline.run_instrumented(text)
+        return cast(str, output), [dict(report) for report in reports]
+
+    def run_with_metric_trace(
+        self, text: str, metric: str = "normalized_edit_distance"
+    ) -> list[tuple[str, float]]:
+        """Score the text after each operation against the original.
+
+        Args:
+            text: Input text.
+            metric: Metric name, e.g. ``"jensen_shannon_divergence"``,
+                ``"normalized_edit_distance"`` or ``"readability_delta"``.
+                Token metrics split on whitespace.
+
+        Returns:
+            One ``(operation name, metric value)`` pair per operation, in order.
+        """
+        return [
+            (cast(str, name), float(value))
+            for name, value in self._pipeline.run_with_metric_trace(text, metric)
+        ]
+
+    def plan_edits(self, text: str) -> list[dict[str, Any]]:
+        """Preview the edits each operation would make, without applying them.
+
+        Returns:
+            One dict per edit with ``op_index``, ``name``, ``start``, ``end``,
+            ``original`` and ``replacement``. ``start`` and ``end`` index the
+            text operation ``op_index`` receives: only the first operation's
+            ranges refer to ``text``, later ones are relative to the
+            intermediate text left by the operations before them.
+        """
+        return [dict(edit) for edit in self._pipeline.plan_edits(text)]
+
+    def run_range(self, text: str, start: int, end: int) -> str:
+        """Corrupt only characters ``start:end`` of ``text``.
+
+        The passage is corrupted exactly as if it were passed to :meth:`run`
+        on its own; everything outside the range is returned unchanged.
+        """
+        return cast(str, self._pipeline.run_range(text, start, end))
+
+    def run_with_rate_regions(
+        self,
+        text: str,
+        regions: Sequence[tuple[int, int, float]],
+    ) -> str:
+        """Run with every operation's rate scaled inside character regions.
+
+        Args:
+            text: Input text.
+            regions: ``(start, end, multiplier)`` character ranges; overlapping
+                regions multiply. Each word or separator takes the scale of the
+                regions holding its first character, and the pipeline runs once
+                over the whole text.
+        """
+        return cast(str, self._pipeline.run_with_rate_regions(text, list(regions)))
+
+    def run_with_rate_markup(self, text: str, *, open: str = "[[", close: str = "]]") -> str:
+        """Strip ``[[multiplier|content]]`` markup and run with those rate regions."""
+        return cast(str, self._pipeline.run_with_rate_markup(text, open, close))
+
+    def iter_chunks(
+        self,
+        lines: Iterable[str],
+        *,
+        boundary: Literal["line", "paragraph"] = "line",
+    ) -> Iterator[str]:
+        """Yield corrupted chunks of a line-oriented stream, e.g. an open file.
+
+        Lines must keep their terminators. The Rust splitter behind
+        ``run_chunked`` cuts chunks at every line or at paragraph breaks (after
+        any run of blank lines) and each is corrupted with a seed derived from
+        the master seed and the chunk index, so the output matches
+        ``run_chunked`` on the concatenated input. Every
+        operation is chunk-local: quote pairing and sentence operations never
+        see across chunk boundaries.
+        """
+        splitter = getattr(load_rust_module(), "ChunkSplitter")(boundary)
+        index = 0
+        for line in lines:
+            chunk = splitter.push(line)
+            if chunk is not None:
+                yield cast(str, self._pipeline.run_chunk(chunk, index))
+                index += 1
+        tail = splitter.finish()
+        if tail is not None:
+            yield cast(str, self._pipeline.run_chunk(tail, index))
+
+    def run_chunked(self, text: str, *, boundary: Literal["line", "paragraph"] = "line") -> str:
+        """Corrupt ``text`` chunk by chunk; see :meth:`iter_chunks`."""
+        return cast(str, self._pipeline.run_chunked(text, boundary))
+
     def run_batch(self, texts: Sequence[str]) -> list[str]:
         """Process multiple texts in parallel.
 
@@ -137,6 +315,119 @@ def plan_operations_rust(
     return [(int(index), int(seed)) for index, seed in plan]
 
 
+def plan_operations_detailed_rust(
+    specs: Sequence[Mapping[str, Any]],
+    master_seed: int,
+) -> list[Any]:
+    """Invoke the Rust planner, returning ``GagglePlanStep`` objects.
+
+    Each step exposes ``index``, ``name``, ``scope``, ``order`` and ``seed``;
+    the seeds match :func:`plan_operations_rust`.
+    """
+    plan_fn = get_rust_operation("plan_operations_detailed")
+    return list(plan_fn(specs, int(master_seed)))
+
+
+def derive_seed_rust(master_seed: int, glitchling_name: str, index: int) -> int:
+    """Derive a glitchling seed exactly as the Rust planner does."""
+    derive_fn = get_rust_operation("derive_seed")
+    return int(derive_fn(int(master_seed), glitchling_name, int(index)))
+
+
+@overload
+def apply_glitch_rust(
+    text: str,
+    descriptor: Mapping[str, Any],
+    *,
+    seed: int | None = None,
+    return_count: Literal[False] = False,
+) -> str: ...
+
+
+@overload
+def apply_glitch_rust(
+    text: str,
+    descriptor: Mapping[str, Any],
+    *,
+    seed: int | None = None,
+    return_count: Literal[True],
+) -> tuple[str, int]: ...
+
+
+def apply_glitch_rust(
+    text: str,
+    descriptor: Mapping[str, Any],
+    *,
+    seed: int | None = None,
+    return_count: bool = False,
+) -> str | tuple[str, int]:
+    """Run one operation without building a pipeline.
+
+    Args:
+        text: Input text to transform.
+        descriptor: Operation config, i.e. the ``operation`` entry of a
+            pipeline descriptor (``{"type": "delete", "rate": 0.1, ...}``).
+        seed: Seed for the operation's RNG; random when omitted.
+        return_count: Also return how many edits the operation applied.
+
+    Returns:
+        Transformed text, or ``(text, edits)`` when ``return_count`` is set.
+        An edit count of zero means the text came back untouched.
+    """
+    apply_fn = get_rust_operation("apply_glitch")
+    result = apply_fn(text, dict(descriptor), seed, return_count)
+    if return_count:
+        output, edits = result
+        return cast(str, output), int(edits)
+    return cast(str, result)
+
+
+def calibrate_rate_rust(
+    text: str,
+    descriptor: Mapping[str, Any],
+    metric: str,
+    target: float,
+    *,
+    tolerance: float = 0.01,
+    seed: int | None = None,
+) -> float:
+    """Find the operation rate whose output scores ``target`` on ``metric``.
+
+    Bisects the descriptor's ``rate`` over ``[0, 1]`` with a fixed seed.
+    Metrics that are not monotonic in the rate may not converge; the closest
+    rate tried is returned instead.
+
+    Args:
+        text: Input text to corrupt.
+        descriptor: Operation config with a top-level ``rate`` field.
+        metric: Metric name, e.g. ``"normalized_edit_distance"``.
+        target: Desired metric value.
+        tolerance: Accepted distance from ``target``.
+        seed: Seed shared by every trial; random when omitted.
+
+    Returns:
+        The calibrated rate.
+    """
+    calibrate_fn = get_rust_operation("calibrate_rate")
+    return cast(float, calibrate_fn(text, dict(descriptor), metric, target, tolerance, seed))
+
+
+def detect_markup_ranges_rust(text: str, syntax: str) -> list[tuple[int, int]]:
+    """Locate structural markup that corruption should leave intact.
+
+    Args:
+        text: Markdown or HTML source.
+        syntax: ``"markdown"`` (emphasis, links, images, code) or ``"html"``
+            (tags, comments, entities).
+
+    Returns:
+        Sorted, disjoint ``(start, end)`` offsets into ``text``. Prose such as
+        link text is never included.
+    """
+    detect_fn = get_rust_operation("detect_markup_ranges")
+    return cast(list[tuple[int, int]], detect_fn(text, syntax))
+
+
 def compose_operations_rust(
     text: str,
     descriptors: Sequence[PipelineDescriptor],
@@ -144,6 +435,7 @@ def compose_operations_rust(
     *,
     include_only_patterns: Sequence[str] | None = None,
     exclude_patterns: Sequence[str] | None = None,
+    max_total_edits: int | None = None,
 ) -> str:
     """Execute a sequence of operations through the Rust pipeline.
 
@@ -153,6 +445,8 @@ def compose_operations_rust(
         master_seed: Master seed for determinism.
         include_only_patterns: Regex patterns limiting mutations to matching spans.
         exclude_patterns: Regex patterns that should not be modified.
+        max_total_edits: Edit budget; once the operations run so far have
+            reported this many edits, the remaining operations are skipped.
 
     Returns:
         Transformed text.
@@ -162,10 +456,151 @@ def compose_operations_rust(
         int(master_seed),
         include_only_patterns=include_only_patterns,
         exclude_patterns=exclude_patterns,
+        max_total_edits=max_total_edits,
     )
     return pipeline.run(text)
 
 
+def compose_operations_batch_rust(
+    texts: Sequence[str],
+    descriptors: Sequence[PipelineDescriptor],
+    master_seed: int,
+    *,
+    include_only_patterns: Sequence[str] | None = None,
+    exclude_patterns: Sequence[str] | None = None,
+    max_total_edits: int | None = None,
+) -> list[str]:
+    """Execute one compiled pipeline over many documents.
+
+    Each document is seeded from ``master_seed`` and its index, so any single
+    document can be reproduced on its own.
+
+    Args:
+        texts: Input documents to transform.
+        descriptors: Pipeline descriptors for each operation.
+        master_seed: Master seed for determinism.
+        include_only_patterns: Regex patterns limiting mutations to matching spans.
+        exclude_patterns: Regex patterns that should not be modified.
+        max_total_edits: Per-document edit budget, as in
+            :func:`compose_operations_rust`.
+
+    Returns:
+        Transformed documents in input order.
+    """
+    batch_fn = get_rust_operation("compose_operations_batch")
+    return cast(
+        list[str],
+        batch_fn(
+            list(texts),
+            list(descriptors),
+            int(master_seed),
+            list(include_only_patterns) if include_only_patterns is not None else None,
+            list(exclude_patterns) if exclude_patterns is not None else None,
+            max_total_edits,
+        ),
+    )
+
+
+@overload
+def generate_pair_rust(
+    text: str,
+    descriptors: Sequence[PipelineDescriptor],
+    master_seed: int,
+    *,
+    aligned: Literal[False] = False,
+    include_only_patterns: Sequence[str] | None = None,
+    exclude_patterns: Sequence[str] | None = None,
+    max_total_edits: int | None = None,
+) -> tuple[str, str]: ...
+
+
+@overload
+def generate_pair_rust(
+    text: str,
+    descriptors: Sequence[PipelineDescriptor],
+    master_seed: int,
+    *,
+    aligned: Literal[True],
+    include_only_patterns: Sequence[str] | None = None,
+    exclude_patterns: Sequence[str] | None = None,
+    max_total_edits: int | None = None,
+) -> tuple[str, str, list[dict[str, Any]]]: ...
+
+
+def generate_pair_rust(
+    text: str,
+    descriptors: Sequence[PipelineDescriptor],
+    master_seed: int,
+    *,
+    aligned: bool = False,
+    include_only_patterns: Sequence[str] | None = None,
+    exclude_patterns: Sequence[str] | None = None,
+    max_total_edits: int | None = None,
+) -> tuple[str, str] | tuple[str, str, list[dict[str, Any]]]:
+    """Build a ``(clean, corrupted)`` pair for training denoisers.
+
+    The clean side is ``text`` with normalised whitespace: runs of spaces
+    collapse to one (runs holding a line break keep it as written), spaces
+    before ``.``, ``,``, ``:`` and ``;`` are dropped, and the ends are
+    trimmed. The corrupted side is the pipeline run over the clean side, so
+    ``compose_operations_rust(clean, descriptors, master_seed)`` reproduces it.
+
+    Args:
+        text: Input text to pair.
+        descriptors: Pipeline descriptors for each operation.
+        master_seed: Master seed for determinism.
+        aligned: Also return the token alignment between the two sides.
+        include_only_patterns: Regex patterns limiting mutations to matching spans.
+        exclude_patterns: Regex patterns that should not be modified.
+        max_total_edits: Edit budget, as in :func:`compose_operations_rust`.
+
+    Returns:
+        ``(clean, corrupted)``, or ``(clean, corrupted, alignment)`` when
+        ``aligned`` is set. The alignment has one dict per changed token hunk
+        with ``start``, ``end`` (character offsets into ``clean``),
+        ``original`` and ``replacement``. Ops that move words rather than
+        rewrite them show up as wider replacements.
+    """
+    pair_fn = get_rust_operation("generate_pair")
+    result = pair_fn(
+        text,
+        list(descriptors),
+        int(master_seed),
+        aligned,
+        list(include_only_patterns) if include_only_patterns is not None else None,
+        list(exclude_patterns) if exclude_patterns is not None else None,
+        max_total_edits,
+    )
+    if aligned:
+        clean, corrupted, alignment = result
+        return cast(str, clean), cast(str, corrupted), [dict(hunk) for hunk in alignment]
+    clean, corrupted = result
+    return cast(str, clean), cast(str, corrupted)
+
+
+def apply_operation_batch_rust(
+    texts: Sequence[str],
+    operation: Mapping[str, Any],
+    master_seed: int,
+) -> list[str]:
+    """Apply a single operation to many strings in one call.
+
+    Batch counterpart of the per-string helpers such as :func:`ocr_artifacts_rust`.
+    Each string is seeded from ``master_seed`` and its index, so any single
+    item can be reproduced on its own, and the GIL is released throughout.
+
+    Args:
+        texts: Input strings to transform.
+        operation: Operation config, e.g. ``{"type": "ocr", "rate": 0.1}``.
+        master_seed: Master seed for determinism.
+
+    Returns:
+        Transformed strings in input order.
+    """
+    batch_fn = get_rust_operation("apply_operation_batch")
+    return cast(list[str], batch_fn(list(texts), dict(operation), int(master_seed)))
+
+
 def build_pipeline_rust(
     descriptors: Sequence[PipelineDescriptor],
     master_seed: int,
@@ -182,6 +617,20 @@ def build_pipeline_rust(
     )
 
 
+def pipeline_builder_rust() -> Any:
+    """Create an empty Rust ``PipelineBuilder``.
+
+    Chain one method per operation, e.g.
+    ``pipeline_builder_rust().swap_adjacent(rate=0.1).redact(rate=0.2)``, then
+    compile it with :meth:`RustPipeline.from_builder`. Each method accepts
+    optional ``name`` and ``seed`` keywords; unpinned seeds are derived from
+    the master seed, the name and the operation's position. Operation types
+    without a dedicated method can be added with ``.operation(descriptor)``.
+    """
+    module = load_rust_module()
+    return getattr(module, "PipelineBuilder")()
+
+
 # ---------------------------------------------------------------------------
 # Character-Level Operations
 # ---------------------------------------------------------------------------
@@ -197,6 +646,14 @@ def keyboard_typo_rust(
     shift_slip_exit_rate: float | None = None,
     shift_map: Mapping[str, str] | None = None,
     motor_weighting: str | None = None,
+    shift_slip_min_hold: int | None = None,
+    shift_slip_mode: str | None = None,
+    grapheme_aware: bool = False,
+    burstiness: float = 0.0,
+    homerow_bias: float = 0.0,
+    motor_weights: Mapping[str, float] | None = None,
+    keyboard: str | None = None,
+    syllable_swaps: bool = False,
 ) -> str:
     """Introduce keyboard typos via Rust.
 
@@ -210,6 +667,22 @@ def keyboard_typo_rust(
         shift_map: Mapping of unshifted -> shifted keys for the active layout.
         motor_weighting: Weighting mode for error sampling ('uniform', 'wet_ink',
             'hastily_edited').
+        shift_slip_min_hold: Characters kept shifted before the first exit roll.
+        shift_slip_mode: 'momentary' (held shift) or 'caps_lock' (letters only,
+            released only at word boundaries).
+        grapheme_aware: Edit whole grapheme clusters so combining marks and emoji
+            sequences are never split.
+        burstiness: Probability that a character typo is followed by one or two
+            correlated slips beside it, within the same word.
+        homerow_bias: Extra weight for home-row neighbours when inserting or
+            replacing keys; their weight is multiplied by ``1 + homerow_bias``.
+        motor_weights: Custom multipliers keyed by ``same_finger``, ``same_hand``,
+            ``cross_hand`` and ``space``; overrides ``motor_weighting`` when given.
+        keyboard: Physical layout name (e.g. ``"QWERTZ"``) used to assign fingers
+            for motor weighting and the home row. Defaults to US QWERTY when omitted;
+            an unrecognised name raises ``ValueError``.
+        syllable_swaps: Enable the syllable-swap action, which transposes common
+            letter pairs ("freind") or two adjacent 2-letter chunks.
 
     Returns:
         Text with simulated typing errors.
@@ -226,6 +699,14 @@ def keyboard_typo_rust(
             shift_slip_exit_rate,
             shift_map,
             motor_weighting,
+            shift_slip_min_hold,
+            shift_slip_mode,
+            grapheme_aware,
+            burstiness,
+            homerow_bias,
+            motor_weights,
+            keyboard,
+            syllable_swaps,
         ),
     )
 
@@ -261,6 +742,8 @@ def swap_homoglyphs_rust(
     seed: int,
     mode: str | None = None,
     max_consecutive: int | None = None,
+    rng: Any | None = None,
+    only_characters: list[str] | None = None,
 ) -> str:
     """Replace characters with homoglyphs via Rust.
 
@@ -272,12 +755,18 @@ def swap_homoglyphs_rust(
         seed: Deterministic seed.
         mode: Substitution mode - "single_script", "mixed_script", "compatibility", or "aggressive".
         max_consecutive: Maximum consecutive substitutions (locality control).
+        rng: Optional ``random.Random`` or ``numpy.random.Generator`` that
+            replaces the seeded RNG.
+        only_characters: If set, only these source characters are replaced.
 
     Returns:
         Text with homoglyph substitutions.
     """
     fn = get_rust_operation("swap_homoglyphs")
-    return cast(str, fn(text, rate, classes, banned, seed, mode, max_consecutive))
+    return cast(
+        str,
+        fn(text, rate, classes, banned, seed, mode, max_consecutive, rng, only_characters),
+    )
 
 
 def ocr_artifacts_rust(
@@ -292,6 +781,9 @@ def ocr_artifacts_rust(
     bias_beta: float | None = None,
     space_drop_rate: float | None = None,
     space_insert_rate: float | None = None,
+    extra_confusions: Mapping[str, Sequence[str]] | None = None,
+    mode: Literal["both", "expand", "contract"] = "both",
+    sampling: Literal["count", "bernoulli"] = "count",
 ) -> str:
     """Introduce OCR-like artifacts via Rust with research-backed enhancements.
 
@@ -319,6 +811,12 @@ def ocr_artifacts_rust(
         bias_beta: Amplification factor for biased patterns (default 2.0).
         space_drop_rate: Probability of dropping a space (default 0.0).
         space_insert_rate: Probability of inserting a spurious space (default 0.0).
+        extra_confusions: Additional ``source -> replacements`` confusions merged
+            over the bundled table, e.g. ``{"5": ["S"]}``.
+        mode: ``"expand"`` applies only length-increasing confusions ("m" -> "rn"),
+            ``"contract"`` only length-decreasing ones; ``"both"`` applies all.
+        sampling: ``"count"`` edits ``floor(candidates * rate)`` shuffled candidates;
+            ``"bernoulli"`` rolls ``rate`` independently for each candidate.
 
     Returns:
         Text with simulated OCR errors.
@@ -343,10 +841,23 @@ def ocr_artifacts_rust(
             bias_beta,
             space_drop_rate,
             space_insert_rate,
+            _confusion_pairs(extra_confusions),
+            mode,
+            sampling,
         ),
     )
 
 
+def _confusion_pairs(
+    confusions: Mapping[str, Sequence[str]] | None,
+) -> list[tuple[str, list[str]]] | None:
+    if confusions is None:
+        return None
+    return [
+        (str(source), [str(choice) for choice in choices]) for source, choices in confusions.items()
+    ]
+
+
 def inject_zero_widths_rust(
     text: str,
     rate: float,
@@ -383,6 +894,7 @@ def stretch_word_rust(
     word_length_threshold: int,
     base_p: float,
     seed: int | None,
+    rng: Any | None = None,
 ) -> str:
     """Extend expressive segments via Rust.
 
@@ -394,6 +906,8 @@ def stretch_word_rust(
         word_length_threshold: Preferred max word length.
         base_p: Base probability for sampler.
         seed: Deterministic seed.
+        rng: Optional ``random.Random`` or ``numpy.random.Generator`` that
+            replaces the seeded RNG.
 
     Returns:
         Text with extended expressive segments.
@@ -401,10 +915,94 @@ def stretch_word_rust(
     fn = get_rust_operation("stretch_word")
     return cast(
         str,
-        fn(text, rate, extension_min, extension_max, word_length_threshold, base_p, seed),
+        fn(text, rate, extension_min, extension_max, word_length_threshold, base_p, seed, rng),
     )
 
 
+def inject_bidi_rust(
+    text: str,
+    rate: float,
+    seed: int | None = None,
+) -> str:
+    """Wrap word cores in directional override spans via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of wrapping each word.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text whose stored characters are unchanged but render reordered.
+    """
+    fn = get_rust_operation("inject_bidi")
+    return cast(str, fn(text, rate, seed))
+
+
+def corrupt_case_rust(
+    text: str,
+    rate: float,
+    mode: str,
+    seed: int | None = None,
+) -> str:
+    """Randomise letter casing via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of recasing each letter or word.
+        mode: "random", "alternating" (alias "sarcasm") or "random_words"
+            (alias "words").
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with casing corrupted.
+    """
+    fn = get_rust_operation("corrupt_case")
+    return cast(str, fn(text, rate, mode, seed))
+
+
+def diacritics_rust(
+    text: str,
+    rate: float,
+    mode: str,
+    seed: int | None = None,
+) -> str:
+    """Strip or add diacritics via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of touching each eligible letter.
+        mode: "strip" (alias "remove") drops accents; "add" attaches random
+            combining accents to vowels.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with accents stripped or added.
+    """
+    fn = get_rust_operation("diacritics")
+    return cast(str, fn(text, rate, mode, seed))
+
+
+def zalgo_rust(
+    text: str,
+    intensity: int,
+    rate: float,
+    seed: int | None = None,
+) -> str:
+    """Stack combining marks onto letters and digits via Rust.
+
+    Args:
+        text: Input text.
+        intensity: Maximum number of marks added to a decorated character.
+        rate: Probability of decorating each alphanumeric grapheme.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with combining marks stacked on.
+    """
+    fn = get_rust_operation("zalgo")
+    return cast(str, fn(text, intensity, rate, seed))
+
+
 # ---------------------------------------------------------------------------
 # Word-Level Operations
 # ---------------------------------------------------------------------------
@@ -415,20 +1013,27 @@ def delete_random_words_rust(
     rate: float,
     unweighted: bool,
     seed: int,
+    min_words: int = 0,
+    weighting: str = "length",
+    preserve_spacing: bool = False,
 ) -> str:
     """Delete random words via Rust.
 
     Args:
         text: Input text.
         rate: Probability of deleting each word.
-        unweighted: If True, use uniform selection; else weight by length.
+        unweighted: If True, use uniform selection; else weight by ``weighting``.
         seed: Deterministic seed.
+        min_words: Minimum number of words that must survive deletion.
+        weighting: "length" favours short words; "content" spares stopwords.
+        preserve_spacing: Keep the surrounding whitespace as-is instead of
+            collapsing it to single spaces.
 
     Returns:
         Text with words deleted.
     """
     fn = get_rust_operation("delete_random_words")
-    return cast(str, fn(text, rate, unweighted, seed))
+    return cast(str, fn(text, rate, unweighted, seed, min_words, weighting, preserve_spacing))
 
 
 def reduplicate_words_rust(
@@ -436,6 +1041,9 @@ def reduplicate_words_rust(
     rate: float,
     unweighted: bool,
     seed: int,
+    repeats: int = 1,
+    stutter: bool = False,
+    separator: str = "space",
 ) -> str:
     """Reduplicate random words via Rust.
 
@@ -444,12 +1052,17 @@ def reduplicate_words_rust(
         rate: Probability of duplicating each word.
         unweighted: If True, use uniform selection; else weight by length.
         seed: Deterministic seed.
+        repeats: Number of extra copies emitted for each selected word.
+        stutter: If True, prepend the leading consonant cluster ("b-b-ball")
+            instead of repeating the whole word.
+        separator: How copies are joined: 'space' ("yes yes"), 'comma'
+            ("no, no") or 'hyphen' ("bye-bye").
 
     Returns:
         Text with words duplicated.
     """
     fn = get_rust_operation("reduplicate_words")
-    return cast(str, fn(text, rate, unweighted, seed))
+    return cast(str, fn(text, rate, unweighted, seed, repeats, stutter, separator))
 
 
 def swap_adjacent_words_rust(
@@ -471,6 +1084,7 @@ def swap_adjacent_words_rust(
     return cast(str, fn(text, rate, seed))
 
 
+@overload
 def redact_words_rust(
     text: str,
     replacement: str,
@@ -478,7 +1092,43 @@ def redact_words_rust(
     merge: bool,
     unweighted: bool,
     seed: int,
-) -> str:
+    *,
+    merge_through_punctuation: bool = False,
+    fixed_width: int | None = None,
+    weighting: str = "length",
+    return_count: Literal[False] = False,
+) -> str: ...
+
+
+@overload
+def redact_words_rust(
+    text: str,
+    replacement: str,
+    rate: float,
+    merge: bool,
+    unweighted: bool,
+    seed: int,
+    *,
+    merge_through_punctuation: bool = False,
+    fixed_width: int | None = None,
+    weighting: str = "length",
+    return_count: Literal[True],
+) -> tuple[str, int]: ...
+
+
+def redact_words_rust(
+    text: str,
+    replacement: str,
+    rate: float,
+    merge: bool,
+    unweighted: bool,
+    seed: int,
+    *,
+    merge_through_punctuation: bool = False,
+    fixed_width: int | None = None,
+    weighting: str = "length",
+    return_count: bool = False,
+) -> str | tuple[str, int]:
     """Redact random words via Rust.
 
     Args:
@@ -486,14 +1136,36 @@ def redact_words_rust(
         replacement: Character to replace word characters with.
         rate: Probability of redacting each word.
         merge: If True, merge adjacent redactions.
-        unweighted: If True, use uniform selection; else weight by length.
+        unweighted: If True, use uniform selection; else weight by ``weighting``.
         seed: Deterministic seed.
+        merge_through_punctuation: When merging, also absorb punctuation
+            between adjacent redactions into the block.
+        fixed_width: If set, replace every redacted word with exactly this
+            many replacement characters regardless of its length.
+        weighting: "length" favours long words; "content" spares stopwords.
+        return_count: Also return how many edits the operation applied.
 
     Returns:
-        Text with words redacted.
+        Text with words redacted, or ``(text, edits)`` when ``return_count``
+        is set.
     """
     fn = get_rust_operation("redact_words")
-    return cast(str, fn(text, replacement, rate, merge, unweighted, seed))
+    result = fn(
+        text,
+        replacement,
+        rate,
+        merge,
+        unweighted,
+        seed,
+        merge_through_punctuation,
+        fixed_width,
+        weighting,
+        return_count,
+    )
+    if return_count:
+        output, edits = result
+        return cast(str, output), int(edits)
+    return cast(str, result)
 
 
 def substitute_lexeme_rust(
@@ -574,6 +1246,279 @@ def substitute_homophones_rust(
     return cast(str, fn(text, rate, weighting, seed))
 
 
+def phonetic_rust(
+    text: str,
+    rate: float,
+    seed: int | None = None,
+) -> str:
+    """Respell words phonetically via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of respelling each eligible word.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with words respelled.
+    """
+    fn = get_rust_operation("phonetic")
+    return cast(str, fn(text, rate, seed))
+
+
+def jitter_whitespace_rust(
+    text: str,
+    rate: float,
+    palette: Sequence[str] | None = None,
+    seed: int | None = None,
+) -> str:
+    """Replace separators with alternative whitespace via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of replacing each separator.
+        palette: Whitespace strings to substitute; defaults to double space, tab,
+            NO-BREAK SPACE and NARROW NO-BREAK SPACE.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with whitespace jittered.
+    """
+    fn = get_rust_operation("jitter_whitespace")
+    palette_list = list(palette) if palette is not None else None
+    return cast(str, fn(text, rate, palette_list, seed))
+
+
+def drop_punctuation_rust(
+    text: str,
+    rate: float,
+    seed: int | None = None,
+    *,
+    lowercase_sentence_starts: bool = False,
+) -> str:
+    """Drop punctuation from word affixes via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of dropping each droppable mark.
+        seed: Deterministic seed; random when omitted.
+        lowercase_sentence_starts: Also lowercase a title-case word that follows
+            a dropped sentence terminator.
+
+    Returns:
+        Text with punctuation dropped.
+    """
+    fn = get_rust_operation("drop_punctuation")
+    return cast(str, fn(text, rate, seed, lowercase_sentence_starts))
+
+
+def autocorrect_rust(
+    text: str,
+    rate: float,
+    seed: int | None = None,
+) -> str:
+    """Replace words with a common word one edit away via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of replacing each eligible word.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with words over-corrected.
+    """
+    fn = get_rust_operation("autocorrect")
+    return cast(str, fn(text, rate, seed))
+
+
+def merge_words_rust(
+    text: str,
+    rate: float,
+    seed: int | None = None,
+) -> str:
+    """Glue adjacent words together via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of merging each eligible word pair.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with some word boundaries removed.
+    """
+    fn = get_rust_operation("merge_words")
+    return cast(str, fn(text, rate, seed))
+
+
+def split_words_rust(
+    text: str,
+    rate: float,
+    min_length: int,
+    seed: int | None = None,
+) -> str:
+    """Split longer words in two via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of splitting each eligible word.
+        min_length: Minimum core length of a word eligible for splitting.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with some words split.
+    """
+    fn = get_rust_operation("split_words")
+    return cast(str, fn(text, rate, min_length, seed))
+
+
+def inject_fillers_rust(
+    text: str,
+    rate: float,
+    fillers: Sequence[str] | None = None,
+    seed: int | None = None,
+    *,
+    commas: bool = False,
+    trailing: bool = False,
+) -> str:
+    """Insert spoken-style filler words via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of inserting a filler at each word boundary.
+        fillers: Filler words to draw from; defaults to "um", "like", "you know"
+            and similar.
+        seed: Deterministic seed; random when omitted.
+        commas: Append a comma to each inserted filler.
+        trailing: Also consider the boundary after the final word.
+
+    Returns:
+        Text with fillers inserted.
+    """
+    fn = get_rust_operation("inject_fillers")
+    filler_list = list(fillers) if fillers is not None else None
+    return cast(str, fn(text, rate, filler_list, seed, commas, trailing))
+
+
+def contractions_rust(
+    text: str,
+    rate: float,
+    mode: str,
+    seed: int | None = None,
+) -> str:
+    """Contract or expand contractions via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of rewriting each eligible phrase.
+        mode: "contract" ("do not" → "don't") or "expand" ("don't" → "do not").
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with contractions rewritten.
+    """
+    fn = get_rust_operation("contractions")
+    return cast(str, fn(text, rate, mode, seed))
+
+
+def disemvowel_rust(
+    text: str,
+    rate: float,
+    seed: int | None = None,
+) -> str:
+    """Drop interior vowels from words via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of disemvowelling each eligible word.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with vowels dropped.
+    """
+    fn = get_rust_operation("disemvowel")
+    return cast(str, fn(text, rate, seed))
+
+
+def scramble_interior_rust(
+    text: str,
+    rate: float,
+    seed: int | None = None,
+) -> str:
+    """Shuffle the interior letters of words via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of scrambling each word of four or more characters.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with word interiors scrambled.
+    """
+    fn = get_rust_operation("scramble_interior")
+    return cast(str, fn(text, rate, seed))
+
+
+def double_words_rust(
+    text: str,
+    rate: float,
+    seed: int | None = None,
+) -> str:
+    """Double function words via Rust.
+
+    Args:
+        text: Input text.
+        rate: Probability of doubling each stopword.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with some function words doubled.
+    """
+    fn = get_rust_operation("double_words")
+    return cast(str, fn(text, rate, seed))
+
+
+# ---------------------------------------------------------------------------
+# Sentence-Level Operations
+# ---------------------------------------------------------------------------
+
+
+def delete_sentences_rust(
+    text: str,
+    rate: float,
+    seed: int | None = None,
+) -> str:
+    """Delete whole sentences via Rust.
+
+    Args:
+        text: Input text.
+        rate: Fraction of sentences to delete; at least one always survives.
+        seed: Deterministic seed; random when omitted.
+
+    Returns:
+        Text with sentences removed.
+    """
+    fn = get_rust_operation("delete_sentences")
+    return cast(str, fn(text, rate, seed))
+
+
+def shuffle_sentences_rust(
+    text: str,
+    seed: int | None = None,
+    rate: float = 1.0,
+) -> str:
+    """Reorder sentences within each paragraph via Rust.
+
+    Args:
+        text: Input text.
+        seed: Deterministic seed; random when omitted.
+        rate: Probability of shuffling each paragraph of two or more sentences.
+
+    Returns:
+        Text with sentences reordered.
+    """
+    fn = get_rust_operation("shuffle_sentences")
+    return cast(str, fn(text, seed, rate))
+
+
 # ---------------------------------------------------------------------------
 # Grammar Operations
 # ---------------------------------------------------------------------------
@@ -597,3 +1542,79 @@ def apply_grammar_rule_rust(
     """
     fn = get_rust_operation("apply_grammar_rule")
     return cast(str, fn(text, stone=stone, seed=seed))
+
+
+# ---------------------------------------------------------------------------
+# Resource Tables
+# ---------------------------------------------------------------------------
+
+
+def ocr_confusions_rust() -> dict[str, list[str]]:
+    """Return the bundled OCR confusion table.
+
+    Returns:
+        Mapping of source sequence to replacement choices, ordered longest
+        source first as the OCR operation matches them.
+    """
+    fn = get_rust_operation("ocr_confusions")
+    return cast(dict[str, list[str]], fn())
+
+
+def ocr_correct_rust(text: str) -> str:
+    """Undo unambiguous OCR confusions, e.g. ``"rn"`` back to ``"m"``.
+
+    A best-effort structural inverse of the OCR operation for deriving clean
+    targets. Noisy forms with more than one plausible source are left alone.
+
+    Args:
+        text: Input text.
+
+    Returns:
+        Text with each high-confidence noisy sequence replaced by its source.
+    """
+    fn = get_rust_operation("ocr_correct")
+    return cast(str, fn(text))
+
+
+def homoglyph_table_rust() -> dict[str, list[tuple[str, str]]]:
+    """Return the homoglyph table used by Mim1c.
+
+    Returns:
+        Mapping of character to ``(glyph, script alias)`` candidates, in the
+        order the operation samples from them.
+    """
+    fn = get_rust_operation("homoglyph_table")
+    return cast(dict[str, list[tuple[str, str]]], fn())
+
+
+def homophone_sets_rust() -> list[list[str]]:
+    """Return the bundled homophone sets used by Wherewolf.
+
+    Returns:
+        List of homophone groups.
+    """
+    fn = get_rust_operation("homophone_sets")
+    return cast(list[list[str]], fn())
+
+
+def list_operations_rust() -> list[dict[str, Any]]:
+    """Describe every operation type accepted in pipeline descriptors.
+
+    Returns:
+        One entry per operation with its ``type``, ``aliases``, a
+        ``description``, the ``required`` parameter names, and an ``optional``
+        mapping of parameter name to default (``None`` when unset).
+    """
+    fn = get_rust_operation("list_operations")
+    return cast(list[dict[str, Any]], fn())
+
+
+def motor_weighting_modes_rust() -> dict[str, dict[str, float]]:
+    """Get the transition multipliers behind each named motor-weighting mode.
+
+    Returns:
+        Mapping of mode name to its ``same_finger``, ``same_hand``,
+        ``cross_hand`` and ``space`` weights.
+    """
+    fn = get_rust_operation("motor_weighting_modes")
+    return cast(dict[str, dict[str, float]], fn())
@@ -5,6 +5,7 @@ from typing import Any
 
 from .core import Gaggle, Glitchling, plan_operations
 from .hokey import Hokey, hokey
+from .identity import Identity, identity
 from .jargoyle import Jargoyle, jargoyle
 from .mim1c import Mim1c, mim1c
 from .pedant import Pedant, pedant
@@ -37,6 +38,8 @@ __all__ = [
     "zeedub",
     "Pedant",
     "pedant",
+    "Identity",
+    "identity",
     "Glitchling",
     "Gaggle",
     "plan_operations",
@@ -0,0 +1,59 @@
+"""Identity glitchling that holds a pipeline slot without corrupting text."""
+
+from __future__ import annotations
+
+import random
+from typing import Any, cast
+
+from .core import AttackOrder, AttackWave, PipelineOperationPayload
+from .core import Glitchling as GlitchlingBase
+
+
+def pass_through(
+    text: str,
+    seed: int | None = None,
+    rng: random.Random | None = None,
+    **_: Any,
+) -> str:
+    """Return ``text`` unchanged."""
+
+    return text
+
+
+class Identity(GlitchlingBase):
+    """Glitchling that leaves text untouched but still occupies a plan slot.
+
+    An identity takes part in gaggle planning and seed derivation exactly like
+    any other glitchling, so padding a control gaggle with one keeps the seeds
+    of the glitchlings after it aligned with a treatment gaggle that runs a real
+    corruption in the same position. ``scope`` and ``order`` choose the slot.
+    """
+
+    flavor = "Nothing to see here."
+
+    def __init__(
+        self,
+        *,
+        name: str = "Identity",
+        scope: AttackWave = AttackWave.DOCUMENT,
+        order: AttackOrder = AttackOrder.NORMAL,
+        seed: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        super().__init__(
+            name=name,
+            corruption_function=pass_through,
+            scope=scope,
+            order=order,
+            seed=seed,
+            **kwargs,
+        )
+
+    def pipeline_operation(self) -> PipelineOperationPayload:
+        return cast(PipelineOperationPayload, {"type": "identity"})
+
+
+identity = Identity()
+
+
+__all__ = ["Identity", "identity", "pass_through"]
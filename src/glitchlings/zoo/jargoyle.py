@@ -12,9 +12,10 @@ You can also drop additional dictionaries into ``assets/lexemes`` to make
 them available without modifying the code. The backend discovers any
 ``*.json`` file in that directory at runtime.
 
-Two modes are available:
+Three modes are available:
 - "literal": First entry in each word's alternatives (deterministic mapping)
 - "drift": Random selection from alternatives (probabilistic)
+- "antonym": Swap words for their opposites using the bundled antonym lexicon
 """
 
 from __future__ import annotations
@@ -80,8 +81,8 @@ def _configure_lexeme_directory() -> Path | None:
 DEFAULT_LEXEMES = "synonyms"
 
 # Valid modes
-JargoyleMode = Literal["literal", "drift"]
-VALID_MODES = ("literal", "drift")
+JargoyleMode = Literal["literal", "drift", "antonym"]
+VALID_MODES = ("literal", "drift", "antonym")
 DEFAULT_MODE: JargoyleMode = "drift"
 
 
@@ -168,9 +169,10 @@ def jargoyle_drift(
         text: Input text to transform.
         lexemes: Name of the dictionary to use.
         mode: "literal" for deterministic first-entry swaps,
-              "drift" for random selection from alternatives.
+              "drift" for random selection from alternatives,
+              "antonym" for opposites from the antonym lexicon.
         rate: Probability of transforming each matching word (0.0 to 1.0).
-        seed: Seed for deterministic randomness (only used in "drift" mode).
+        seed: Seed for deterministic randomness (unused in "literal" mode).
         rng: Random number generator (alternative to seed).
 
     Returns:
@@ -183,7 +185,7 @@ def jargoyle_drift(
     normalized_mode = _validate_mode(mode)
 
     effective_rate = DEFAULT_JARGOYLE_RATE if rate is None else float(rate)
-    resolved_seed = resolve_seed(seed, rng) if normalized_mode == "drift" else None
+    resolved_seed = resolve_seed(seed, rng) if normalized_mode != "literal" else None
 
     return substitute_lexeme_rust(
         text,
@@ -207,10 +209,11 @@ class Jargoyle(Glitchling):
     - **lovecraftian**: Cosmic horror terminology.
     - **custom**: Any ``*.json`` dictionary placed in ``assets/lexemes``.
 
-    Two modes are supported:
+    Three modes are supported:
 
     - **literal**: Use the first (canonical) entry for each word.
     - **drift**: Randomly select from available alternatives.
+    - **antonym**: Swap words for their opposites, ignoring ``lexemes``.
 
     Example:
         >>> from glitchlings import Jargoyle
@@ -240,7 +243,7 @@ class Jargoyle(Glitchling):
             lexemes: Name of the dictionary to use. See ``list_lexeme_dictionaries()``
                 for the full, dynamic list (including any custom ``*.json`` files).
             mode: Transformation mode. "literal" for deterministic swaps,
-                "drift" for random selection.
+                "drift" for random selection, "antonym" for opposites.
             rate: Probability of transforming each matching word (0.0 to 1.0).
                 Defaults to 0.01.
             seed: Seed for deterministic randomness.
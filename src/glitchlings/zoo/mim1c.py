@@ -73,6 +73,16 @@ def _normalise_banned(value: object) -> tuple[str, ...] | None:
     raise TypeError("banned_characters must be an iterable of strings")
 
 
+def _normalise_only_characters(value: object) -> tuple[str, ...] | None:
+    if value is None:
+        return None
+    if isinstance(value, str):
+        return tuple(dict.fromkeys(value))
+    if isinstance(value, Iterable):
+        return tuple(dict.fromkeys(str(item) for item in value))
+    raise TypeError("only_characters must be an iterable of strings")
+
+
 def _serialise_classes(
     value: tuple[str, ...] | Literal["all"] | None,
 ) -> list[str] | Literal["all"] | None:
@@ -101,6 +111,7 @@ def swap_homoglyphs(
     rng: random.Random | None = None,
     mode: HomoglyphMode | None = None,
     max_consecutive: int | None = None,
+    only_characters: Collection[str] | None = None,
 ) -> str:
     """Replace characters with visually confusable homoglyphs via the Rust engine.
 
@@ -119,6 +130,8 @@ def swap_homoglyphs(
             - "aggressive": All confusable types.
         max_consecutive: Maximum consecutive characters to substitute. Default 3.
             Set to 0 for unlimited.
+        only_characters: If set, only these source characters are replaced,
+            e.g. ``"amazon"`` to spoof just the letters of a brand name.
 
     Returns:
         Text with some characters replaced by visually similar confusables.
@@ -137,6 +150,7 @@ def swap_homoglyphs(
     else:
         payload_classes = _serialise_classes(normalised_classes)
     payload_banned = _serialise_banned(normalised_banned)
+    normalised_only = _normalise_only_characters(only_characters)
 
     return swap_homoglyphs_rust(
         text,
@@ -146,6 +160,7 @@ def swap_homoglyphs(
         resolve_seed(seed, rng),
         effective_mode,
         effective_max_consecutive,
+        only_characters=None if normalised_only is None else list(normalised_only),
     )
 
 
@@ -179,6 +194,7 @@ class Mim1c(Glitchling):
         mode: Substitution mode. One of "single_script", "mixed_script",
             "compatibility", "aggressive".
         max_consecutive: Maximum consecutive characters to substitute. Default 3.
+        only_characters: If set, only these source characters are replaced.
         seed: Random seed for deterministic behavior.
     """
 
@@ -195,6 +211,7 @@ class Mim1c(Glitchling):
         banned_characters: Collection[str] | None = None,
         mode: HomoglyphMode | None = None,
         max_consecutive: int | None = None,
+        only_characters: Collection[str] | None = None,
         seed: int | None = None,
         **kwargs: Any,
     ) -> None:
@@ -216,6 +233,7 @@ class Mim1c(Glitchling):
             banned_characters=normalised_banned,
             mode=effective_mode,
             max_consecutive=effective_max_consecutive,
+            only_characters=_normalise_only_characters(only_characters),
             **kwargs,
         )
 
@@ -244,6 +262,10 @@ class Mim1c(Glitchling):
         if max_consecutive is not None:
             descriptor["max_consecutive"] = int(max_consecutive)
 
+        only_characters = self.kwargs.get("only_characters")
+        if only_characters is not None:
+            descriptor["only_characters"] = list(only_characters)
+
         return cast(PipelineOperationPayload, descriptor)
 
     def set_param(self, key: str, value: object) -> None:
@@ -253,6 +275,9 @@ class Mim1c(Glitchling):
         if key == "banned_characters":
             super().set_param(key, _normalise_banned(value))
             return
+        if key == "only_characters":
+            super().set_param(key, _normalise_only_characters(value))
+            return
         if key == "mode":
             super().set_param(key, normalize_mim1c_mode(str(value) if value else None))
             return
@@ -79,8 +79,10 @@ EVOLUTIONS: Dict[PedantStone, Type[PedantEvolution]] = {}
 try:  # pragma: no cover - import resolution occurs at runtime
     from .forms import (
         Aetheria,
+        Americanus,
         Andi,
         Apostrofae,
+        Britannicus,
         Commama,
         Infinitoad,
     )
@@ -93,6 +95,8 @@ else:
         PedantStone.COEURITE: Aetheria,
         PedantStone.CURLITE: Apostrofae,
         PedantStone.OXFORDIUM: Commama,
+        PedantStone.COLOURITE: Britannicus,
+        PedantStone.COLORITE: Americanus,
     }
 
 
@@ -41,10 +41,26 @@ class Commama(PedantEvolution):
     flavor = "Oxonian hero of the list."
 
 
+class Britannicus(PedantEvolution):
+    stone = PedantStone.COLOURITE
+    name = "Britannicus"
+    type = "Normal"
+    flavor = "Adds the u that colour was always missing."
+
+
+class Americanus(PedantEvolution):
+    stone = PedantStone.COLORITE
+    name = "Americanus"
+    type = "Normal"
+    flavor = "Drops every u that never pulled its weight."
+
+
 __all__ = [
     "Andi",
     "Infinitoad",
     "Aetheria",
     "Apostrofae",
     "Commama",
+    "Britannicus",
+    "Americanus",
 ]
@@ -39,6 +39,16 @@ class PedantStone(Enum):
         "Coaches punctuation to embrace typographic curls.",
     )
     OXFORDIUM = Stone("Oxfordium", "Steel", "Polishes serial comma usage.")
+    COLOURITE = Stone(
+        "Colourite",
+        "Normal",
+        "Converts American spellings to British ones.",
+    )
+    COLORITE = Stone(
+        "Colorite",
+        "Normal",
+        "Converts British spellings to American ones.",
+    )
 
     @property
     def descriptor(self) -> Stone:
@@ -75,6 +85,14 @@ class PedantStone(Enum):
 
 
 _STONE_BY_NAME: dict[str, PedantStone] = {stone.value.name: stone for stone in PedantStone}
+_STONE_BY_NAME.update(
+    {
+        "uk": PedantStone.COLOURITE,
+        "british": PedantStone.COLOURITE,
+        "us": PedantStone.COLORITE,
+        "american": PedantStone.COLORITE,
+    }
+)
 
 
 STONES: dict[str, Stone] = {stone.label: stone.descriptor for stone in PedantStone}
@@ -16,8 +16,19 @@ def redact_words(
     rng: random.Random | None = None,
     *,
     unweighted: bool = False,
+    merge_through_punctuation: bool = False,
+    fixed_width: int | None = None,
+    weighting: str = "length",
 ) -> str:
-    """Redact random words by replacing their characters."""
+    """Redact random words by replacing their characters.
+
+    With ``merge_adjacent`` and ``merge_through_punctuation`` both set,
+    punctuation between adjacent redactions joins the block, so "foo, bar"
+    becomes one run of replacement characters. Setting ``fixed_width`` makes
+    every redacted word exactly that many characters long, hiding word lengths.
+    Unless ``unweighted``, longer words are likelier picks; ``weighting="content"``
+    instead favours content words over the bundled stopwords.
+    """
     effective_rate = DEFAULT_REDACTYL_RATE if rate is None else rate
 
     replacement = DEFAULT_REDACTYL_CHAR if replacement_char is None else str(replacement_char)
@@ -33,6 +44,9 @@ def redact_words(
         merge,
         unweighted_flag,
         resolve_seed(seed, rng),
+        merge_through_punctuation=bool(merge_through_punctuation),
+        fixed_width=None if fixed_width is None else int(fixed_width),
+        weighting=str(weighting),
     )
 
 
@@ -49,6 +63,9 @@ class Redactyl(Glitchling):
         merge_adjacent: bool = False,
         seed: int = 151,
         unweighted: bool = False,
+        merge_through_punctuation: bool = False,
+        fixed_width: int | None = None,
+        weighting: str = "length",
         **kwargs: Any,
     ) -> None:
         effective_rate = DEFAULT_REDACTYL_RATE if rate is None else rate
@@ -61,6 +78,9 @@ class Redactyl(Glitchling):
             rate=effective_rate,
             merge_adjacent=merge_adjacent,
             unweighted=unweighted,
+            merge_through_punctuation=merge_through_punctuation,
+            fixed_width=fixed_width,
+            weighting=weighting,
             **kwargs,
         )
 
@@ -75,17 +95,24 @@ class Redactyl(Glitchling):
         rate = float(DEFAULT_REDACTYL_RATE if rate_value is None else rate_value)
         merge_adjacent = bool(merge_value)
         unweighted = bool(self.kwargs.get("unweighted", False))
-
-        return cast(
-            PipelineOperationPayload,
-            {
-                "type": "redact",
-                "replacement_char": replacement_char,
-                "rate": rate,
-                "merge_adjacent": merge_adjacent,
-                "unweighted": unweighted,
-            },
-        )
+        merge_through_punctuation = bool(self.kwargs.get("merge_through_punctuation", False))
+        fixed_width = self.kwargs.get("fixed_width")
+        weighting = str(self.kwargs.get("weighting", "length"))
+
+        payload: dict[str, Any] = {
+            "type": "redact",
+            "replacement_char": replacement_char,
+            "rate": rate,
+            "merge_adjacent": merge_adjacent,
+            "merge_through_punctuation": merge_through_punctuation,
+            "unweighted": unweighted,
+        }
+        if fixed_width is not None:
+            payload["fixed_width"] = int(fixed_width)
+        if weighting != "length":
+            payload["weighting"] = weighting
+
+        return cast(PipelineOperationPayload, payload)
 
 
 redactyl = Redactyl()
@@ -10,6 +10,7 @@ from typing import Any
 from glitchlings.constants import RUSHMORE_DEFAULT_RATES
 from glitchlings.internal.rust_ffi import (
     delete_random_words_rust,
+    derive_seed_rust,
     reduplicate_words_rust,
     resolve_seed,
     swap_adjacent_words_rust,
@@ -53,6 +54,17 @@ class RushmoreRuntimeConfig:
     rates: dict[RushmoreMode, float]
     delete_unweighted: bool
     duplicate_unweighted: bool
+    shuffle_modes: bool = False
+    delete_weighting: str = "length"
+
+    def _delete_descriptor(self) -> dict[str, Any]:
+        descriptor: dict[str, Any] = {
+            "rate": self.rates[RushmoreMode.DELETE],
+            "unweighted": self.delete_unweighted,
+        }
+        if self.delete_weighting != "length":
+            descriptor["weighting"] = self.delete_weighting
+        return descriptor
 
     def has_mode(self, mode: RushmoreMode) -> bool:
         return mode in self.rates
@@ -68,11 +80,7 @@ class RushmoreRuntimeConfig:
                 message = f"Rushmore mode {mode!r} is missing a configured rate"
                 raise RuntimeError(message)
             if mode is RushmoreMode.DELETE:
-                return {
-                    "type": "delete",
-                    "rate": rate,
-                    "unweighted": self.delete_unweighted,
-                }
+                return {"type": "delete", **self._delete_descriptor()}
             if mode is RushmoreMode.DUPLICATE:
                 return {
                     "type": "reduplicate",
@@ -92,10 +100,7 @@ class RushmoreRuntimeConfig:
             "modes": [mode.value for mode in self.modes],
         }
         if self.has_mode(RushmoreMode.DELETE):
-            descriptor["delete"] = {
-                "rate": self.rates[RushmoreMode.DELETE],
-                "unweighted": self.delete_unweighted,
-            }
+            descriptor["delete"] = self._delete_descriptor()
         if self.has_mode(RushmoreMode.DUPLICATE):
             descriptor["duplicate"] = {
                 "rate": self.rates[RushmoreMode.DUPLICATE],
@@ -103,6 +108,8 @@ class RushmoreRuntimeConfig:
             }
         if self.has_mode(RushmoreMode.SWAP):
             descriptor["swap"] = {"rate": self.rates[RushmoreMode.SWAP]}
+        if self.shuffle_modes:
+            descriptor["shuffle_modes"] = True
         return descriptor
 
 
@@ -193,6 +200,8 @@ def _resolve_rushmore_config(
     delete_unweighted: bool | None,
     duplicate_unweighted: bool | None,
     allow_defaults: bool,
+    shuffle_modes: bool = False,
+    weighting: str = "length",
 ) -> RushmoreRuntimeConfig | None:
     normalized_modes = _normalize_modes(modes)
     global_rate = float(rate) if rate is not None else None
@@ -223,6 +232,8 @@ def _resolve_rushmore_config(
         rates=rates,
         delete_unweighted=delete_flag,
         duplicate_unweighted=duplicate_flag,
+        shuffle_modes=bool(shuffle_modes),
+        delete_weighting=str(weighting),
     )
 
 
@@ -232,15 +243,22 @@ def delete_random_words(
     seed: int | None = None,
     rng: random.Random | None = None,
     unweighted: bool = False,
+    weighting: str = "length",
 ) -> str:
-    """Delete random words from the input text."""
+    """Delete random words from the input text.
+
+    Unless ``unweighted``, shorter words are likelier picks; ``weighting="content"``
+    instead favours content words over the bundled stopwords.
+    """
     effective_rate = RUSHMORE_DEFAULT_RATES["delete"] if rate is None else rate
 
     clamped_rate = max(0.0, effective_rate)
     unweighted_flag = bool(unweighted)
 
     seed_value = resolve_seed(seed, rng)
-    return delete_random_words_rust(text, clamped_rate, unweighted_flag, seed_value)
+    return delete_random_words_rust(
+        text, clamped_rate, unweighted_flag, seed_value, weighting=str(weighting)
+    )
 
 
 def reduplicate_words(
@@ -286,10 +304,16 @@ def rushmore_attack(
     unweighted: bool = False,
     delete_unweighted: bool | None = None,
     duplicate_unweighted: bool | None = None,
+    shuffle_modes: bool = False,
+    weighting: str = "length",
     seed: int | None = None,
     rng: random.Random | None = None,
 ) -> str:
-    """Apply the configured Rushmore attack modes to ``text``."""
+    """Apply the configured Rushmore attack modes to ``text``.
+
+    With ``shuffle_modes`` the modes run in a seeded random order rather than
+    the declared one. ``weighting`` chooses how deletion weights its candidates.
+    """
     config = _resolve_rushmore_config(
         modes=modes,
         rate=rate,
@@ -300,16 +324,22 @@ def rushmore_attack(
         delete_unweighted=delete_unweighted,
         duplicate_unweighted=duplicate_unweighted,
         allow_defaults=True,
+        shuffle_modes=shuffle_modes,
+        weighting=weighting,
     )
     if config is None:
         return text
 
-    mode_rng = rng
-    if mode_rng is None and seed is not None:
-        mode_rng = random.Random(resolve_seed(seed, None))
+    # Each mode runs on its own seed, derived from one combo-level seed and
+    # the mode's declared index, so retuning one mode leaves the others alone.
+    combo_seed = resolve_seed(seed, None) if rng is None else resolve_seed(None, rng)
+
+    ordered_modes = list(enumerate(config.modes))
+    if config.shuffle_modes:
+        random.Random(combo_seed).shuffle(ordered_modes)
 
     result = text
-    for mode in config.modes:
+    for index, mode in ordered_modes:
         if not config.has_mode(mode):
             continue
 
@@ -317,25 +347,27 @@ def rushmore_attack(
         if rate_value <= 0.0:
             continue
 
+        mode_seed = derive_seed_rust(combo_seed, mode.value, index)
         if mode is RushmoreMode.DELETE:
             result = delete_random_words(
                 result,
                 rate=rate_value,
-                rng=mode_rng,
+                seed=mode_seed,
                 unweighted=config.delete_unweighted,
+                weighting=config.delete_weighting,
             )
         elif mode is RushmoreMode.DUPLICATE:
             result = reduplicate_words(
                 result,
                 rate=rate_value,
-                rng=mode_rng,
+                seed=mode_seed,
                 unweighted=config.duplicate_unweighted,
             )
         else:
             result = swap_adjacent_words(
                 result,
                 rate=rate_value,
-                rng=mode_rng,
+                seed=mode_seed,
             )
 
     return result
@@ -352,6 +384,8 @@ def _rushmore_pipeline_descriptor(glitchling: Glitchling) -> dict[str, Any] | No
         delete_unweighted=glitchling.kwargs.get("delete_unweighted"),
         duplicate_unweighted=glitchling.kwargs.get("duplicate_unweighted"),
         allow_defaults=True,
+        shuffle_modes=bool(glitchling.kwargs.get("shuffle_modes", False)),
+        weighting=str(glitchling.kwargs.get("weighting", "length")),
     )
     if config is None:
         return None
@@ -381,6 +415,8 @@ class Rushmore(Glitchling):
         unweighted: bool = False,
         delete_unweighted: bool | None = None,
         duplicate_unweighted: bool | None = None,
+        shuffle_modes: bool = False,
+        weighting: str = "length",
         **kwargs: Any,
     ) -> None:
         normalized_modes = _normalize_modes(modes)
@@ -398,6 +434,8 @@ class Rushmore(Glitchling):
             unweighted=unweighted,
             delete_unweighted=delete_unweighted,
             duplicate_unweighted=duplicate_unweighted,
+            shuffle_modes=shuffle_modes,
+            weighting=weighting,
             **kwargs,
         )
 
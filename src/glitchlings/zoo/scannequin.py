@@ -19,7 +19,7 @@ References
 """
 
 import random
-from typing import Any, Literal, cast
+from typing import Any, Literal, Mapping, Sequence, cast
 
 from glitchlings.constants import (
     DEFAULT_SCANNEQUIN_BIAS_BETA,
@@ -38,6 +38,8 @@ from .core import AttackOrder, AttackWave, Glitchling, PipelineOperationPayload
 
 # Type alias for preset names
 PresetName = Literal["clean_300dpi", "newspaper", "fax", "photocopy_3rd_gen"]
+OcrMode = Literal["both", "expand", "contract"]
+OcrSampling = Literal["count", "bernoulli"]
 
 
 def ocr_artifacts(
@@ -53,6 +55,9 @@ def ocr_artifacts(
     bias_beta: float | None = None,
     space_drop_rate: float | None = None,
     space_insert_rate: float | None = None,
+    extra_confusions: Mapping[str, Sequence[str]] | None = None,
+    mode: OcrMode = "both",
+    sampling: OcrSampling = "count",
 ) -> str:
     """Introduce OCR-like artifacts into text with research-backed enhancements.
 
@@ -98,6 +103,16 @@ def ocr_artifacts(
         Probability of deleting a space, merging words (default 0.0).
     space_insert_rate : float, optional
         Probability of inserting a spurious space (default 0.0).
+    extra_confusions : mapping of str to sequence of str, optional
+        Additional ``source -> replacements`` confusions merged over the
+        bundled table, e.g. ``{"5": ["S"], "8": ["B"]}`` for a specific scanner.
+    mode : {"both", "expand", "contract"}, optional
+        Restrict confusions to length-increasing (``"m"`` -> ``"rn"``) or
+        length-decreasing (``"rn"`` -> ``"m"``) ones. Default ``"both"``.
+    sampling : {"count", "bernoulli"}, optional
+        ``"count"`` edits exactly ``floor(candidates * rate)`` candidates, so
+        short texts may get none; ``"bernoulli"`` rolls ``rate`` for each
+        candidate independently. Default ``"count"``.
 
     Returns
     -------
@@ -128,6 +143,9 @@ def ocr_artifacts(
         bias_beta=bias_beta,
         space_drop_rate=space_drop_rate,
         space_insert_rate=space_insert_rate,
+        extra_confusions=extra_confusions,
+        mode=mode,
+        sampling=sampling,
     )
 
 
@@ -182,6 +200,13 @@ class Scannequin(Glitchling):
         P(delete space, merge words) (default 0.0 = disabled).
     space_insert_rate : float, optional
         P(insert spurious space) (default 0.0 = disabled).
+    extra_confusions : mapping of str to sequence of str, optional
+        Scanner-specific ``source -> replacements`` confusions merged over the
+        bundled table.
+    mode : {"both", "expand", "contract"}, optional
+        Restrict confusions by length change (default ``"both"``).
+    sampling : {"count", "bernoulli"}, optional
+        Fixed edit count or independent per-candidate rolls (default ``"count"``).
     **kwargs
         Additional parameters passed to base Glitchling.
 
@@ -229,6 +254,9 @@ class Scannequin(Glitchling):
         bias_beta: float | None = None,
         space_drop_rate: float | None = None,
         space_insert_rate: float | None = None,
+        extra_confusions: Mapping[str, Sequence[str]] | None = None,
+        mode: OcrMode = "both",
+        sampling: OcrSampling = "count",
         **kwargs: Any,
     ) -> None:
         # If preset is specified, load parameters from it
@@ -300,6 +328,9 @@ class Scannequin(Glitchling):
             bias_beta=effective_bias_beta,
             space_drop_rate=effective_space_drop_rate,
             space_insert_rate=effective_space_insert_rate,
+            extra_confusions=extra_confusions,
+            mode=mode,
+            sampling=sampling,
             **kwargs,
         )
 
@@ -339,28 +370,32 @@ class Scannequin(Glitchling):
         rate_value = self.kwargs.get("rate", DEFAULT_SCANNEQUIN_RATE)
         rate = DEFAULT_SCANNEQUIN_RATE if rate_value is None else float(rate_value)
 
-        return cast(
-            PipelineOperationPayload,
-            {
-                "type": "ocr",
-                "rate": rate,
-                "burst_enter": float(
-                    self.kwargs.get("burst_enter", DEFAULT_SCANNEQUIN_BURST_ENTER)
-                ),
-                "burst_exit": float(self.kwargs.get("burst_exit", DEFAULT_SCANNEQUIN_BURST_EXIT)),
-                "burst_multiplier": float(
-                    self.kwargs.get("burst_multiplier", DEFAULT_SCANNEQUIN_BURST_MULTIPLIER)
-                ),
-                "bias_k": int(self.kwargs.get("bias_k", DEFAULT_SCANNEQUIN_BIAS_K)),
-                "bias_beta": float(self.kwargs.get("bias_beta", DEFAULT_SCANNEQUIN_BIAS_BETA)),
-                "space_drop_rate": float(
-                    self.kwargs.get("space_drop_rate", DEFAULT_SCANNEQUIN_SPACE_DROP_RATE)
-                ),
-                "space_insert_rate": float(
-                    self.kwargs.get("space_insert_rate", DEFAULT_SCANNEQUIN_SPACE_INSERT_RATE)
-                ),
-            },
-        )
+        payload: dict[str, Any] = {
+            "type": "ocr",
+            "rate": rate,
+            "burst_enter": float(self.kwargs.get("burst_enter", DEFAULT_SCANNEQUIN_BURST_ENTER)),
+            "burst_exit": float(self.kwargs.get("burst_exit", DEFAULT_SCANNEQUIN_BURST_EXIT)),
+            "burst_multiplier": float(
+                self.kwargs.get("burst_multiplier", DEFAULT_SCANNEQUIN_BURST_MULTIPLIER)
+            ),
+            "bias_k": int(self.kwargs.get("bias_k", DEFAULT_SCANNEQUIN_BIAS_K)),
+            "bias_beta": float(self.kwargs.get("bias_beta", DEFAULT_SCANNEQUIN_BIAS_BETA)),
+            "space_drop_rate": float(
+                self.kwargs.get("space_drop_rate", DEFAULT_SCANNEQUIN_SPACE_DROP_RATE)
+            ),
+            "space_insert_rate": float(
+                self.kwargs.get("space_insert_rate", DEFAULT_SCANNEQUIN_SPACE_INSERT_RATE)
+            ),
+            "mode": str(self.kwargs.get("mode", "both")),
+            "sampling": str(self.kwargs.get("sampling", "count")),
+        }
+        extra_confusions = self.kwargs.get("extra_confusions")
+        if extra_confusions is not None:
+            payload["extra_confusions"] = [
+                (str(source), [str(choice) for choice in choices])
+                for source, choices in extra_confusions.items()
+            ]
+        return cast(PipelineOperationPayload, payload)
 
 
 # Default instance for convenience
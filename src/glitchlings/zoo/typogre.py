@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import math
 import random
 from collections.abc import Mapping, Sequence
 from typing import Any, cast
@@ -45,6 +46,30 @@ def _resolve_motor_weighting(motor_weighting: str | None) -> str:
     return normalized
 
 
+_MOTOR_WEIGHT_KEYS = ("same_finger", "same_hand", "cross_hand", "space")
+
+
+def _resolve_motor_weights(
+    motor_weights: Mapping[str, float] | None,
+) -> dict[str, float] | None:
+    """Validate custom motor weights, returning a plain dict for the Rust layer."""
+    if motor_weights is None:
+        return None
+
+    resolved: dict[str, float] = {}
+    for key, value in motor_weights.items():
+        if key not in _MOTOR_WEIGHT_KEYS:
+            valid_keys = ", ".join(_MOTOR_WEIGHT_KEYS)
+            message = f"Unknown motor weight '{key}'. Valid keys: {valid_keys}"
+            raise ValueError(message)
+        weight = float(value)
+        if not math.isfinite(weight) or weight < 0.0:
+            message = f"Motor weight '{key}' must be a finite number >= 0, got {value!r}"
+            raise ValueError(message)
+        resolved[key] = weight
+    return resolved
+
+
 def fatfinger(
     text: str,
     rate: float | None = None,
@@ -57,14 +82,22 @@ def fatfinger(
     shift_slip_exit_rate: float | None = None,
     shift_map: Mapping[str, str] | None = None,
     motor_weighting: str | None = None,
+    shift_slip_min_hold: int | None = None,
+    shift_slip_mode: str | None = None,
+    grapheme_aware: bool = False,
+    burstiness: float = 0.0,
+    homerow_bias: float = 0.0,
+    motor_weights: Mapping[str, float] | None = None,
+    syllable_swaps: bool = False,
 ) -> str:
     """Introduce character-level "fat finger" edits with a Rust fast path.
 
     Args:
         text: Input text to corrupt.
         rate: Probability of corrupting each character (default 0.02).
-        keyboard: Keyboard layout name for adjacency mapping.
-        layout: Custom keyboard neighbor mapping (overrides keyboard).
+        keyboard: Keyboard layout name for adjacency mapping and for the physical
+            finger map used by motor weighting and home-row bias.
+        layout: Custom keyboard neighbor mapping (overrides keyboard's adjacency).
         seed: Deterministic seed for reproducible results.
         rng: Random generator (alternative to seed).
         shift_slip_rate: Probability of entering a shifted burst.
@@ -72,6 +105,24 @@ def fatfinger(
         shift_map: Custom unshifted->shifted character mapping.
         motor_weighting: Weighting mode for error sampling based on finger/hand
             coordination. One of 'uniform' (default), 'wet_ink', or 'hastily_edited'.
+        shift_slip_min_hold: Characters kept shifted before the first exit roll
+            (default 1).
+        shift_slip_mode: 'momentary' (default) for a held shift key, or
+            'caps_lock' to uppercase letters only and release at word boundaries.
+        grapheme_aware: Edit whole grapheme clusters instead of code points, so
+            accents and emoji sequences are never split (default False).
+        burstiness: Probability that a character typo snowballs into one or two
+            correlated slips beside it ("hellllo", "thier"), staying within the
+            word (default 0.0).
+        homerow_bias: Extra weight for home-row (``asdfghjkl;``) neighbours when
+            a typo inserts or replaces a key; their weight is multiplied by
+            ``1 + homerow_bias`` on top of motor weighting (default 0.0).
+        motor_weights: Custom multipliers for 'same_finger', 'same_hand',
+            'cross_hand' and 'space' transitions; missing keys default to 1.0.
+            Overrides ``motor_weighting`` when given.
+        syllable_swaps: Add a typo action that transposes commonly reversed
+            letter pairs ("freind", "recieve") or two adjacent 2-letter chunks.
+            Off by default so existing seeds reproduce (default False).
 
     Returns:
         Text with simulated typing errors.
@@ -86,6 +137,7 @@ def fatfinger(
     slip_exit_rate = _resolve_slip_exit_rate(slip_rate, shift_slip_exit_rate)
     slip_map = shift_map if shift_map is not None else getattr(SHIFT_MAPS, keyboard, None)
     resolved_motor_weighting = _resolve_motor_weighting(motor_weighting)
+    resolved_motor_weights = _resolve_motor_weights(motor_weights)
 
     clamped_rate = max(0.0, effective_rate)
     if slip_rate == 0.0 and clamped_rate == 0.0:
@@ -100,6 +152,14 @@ def fatfinger(
         shift_slip_exit_rate=slip_exit_rate,
         shift_map=slip_map,
         motor_weighting=resolved_motor_weighting,
+        shift_slip_min_hold=shift_slip_min_hold,
+        shift_slip_mode=shift_slip_mode,
+        grapheme_aware=grapheme_aware,
+        burstiness=burstiness,
+        homerow_bias=homerow_bias,
+        motor_weights=resolved_motor_weights,
+        keyboard=keyboard,
+        syllable_swaps=syllable_swaps,
     )
 
 
@@ -111,6 +171,14 @@ class Typogre(Glitchling):
         keyboard: Keyboard layout name for adjacency mapping.
         shift_slip_rate: Probability of entering a shifted burst.
         shift_slip_exit_rate: Probability of releasing shift during a burst.
+        shift_slip_min_hold: Characters kept shifted before the first exit roll.
+        shift_slip_mode: 'momentary' (default) or 'caps_lock'.
+        grapheme_aware: Edit whole grapheme clusters so accents and emoji
+            sequences are never split (default False).
+        burstiness: Probability that a character typo comes with one or two
+            correlated slips beside it, like a sloppy burst (default 0.0).
+        homerow_bias: Extra weight for home-row neighbours in insertions and
+            replacements, layered on motor weighting (default 0.0).
         motor_weighting: Weighting mode for error sampling based on finger/hand
             coordination. One of:
             - 'uniform': All neighbors equally likely (default, original behavior).
@@ -118,6 +186,10 @@ class Typogre(Glitchling):
               caught and corrected, cross-hand errors slip through.
             - 'hastily_edited': Simulates raw typing before correction - same-finger
               errors occur most often.
+        motor_weights: Custom multipliers for 'same_finger', 'same_hand',
+            'cross_hand' and 'space' transitions, overriding ``motor_weighting``.
+        syllable_swaps: Also transpose common letter pairs or adjacent 2-letter
+            chunks within words (default False).
         seed: Deterministic seed for reproducible results.
     """
 
@@ -130,12 +202,20 @@ class Typogre(Glitchling):
         keyboard: str = DEFAULT_TYPOGRE_KEYBOARD,
         shift_slip_rate: float = 0.0,
         shift_slip_exit_rate: float | None = None,
+        shift_slip_min_hold: int | None = None,
+        shift_slip_mode: str | None = None,
         motor_weighting: str | None = None,
+        grapheme_aware: bool = False,
+        burstiness: float = 0.0,
+        homerow_bias: float = 0.0,
+        motor_weights: Mapping[str, float] | None = None,
+        syllable_swaps: bool = False,
         seed: int | None = None,
         **kwargs: Any,
     ) -> None:
         effective_rate = DEFAULT_TYPOGRE_RATE if rate is None else rate
         resolved_motor_weighting = _resolve_motor_weighting(motor_weighting)
+        resolved_motor_weights = _resolve_motor_weights(motor_weights)
         super().__init__(
             name="Typogre",
             corruption_function=fatfinger,
@@ -146,7 +226,14 @@ class Typogre(Glitchling):
             keyboard=keyboard,
             shift_slip_rate=max(0.0, shift_slip_rate),
             shift_slip_exit_rate=shift_slip_exit_rate,
+            shift_slip_min_hold=shift_slip_min_hold,
+            shift_slip_mode=shift_slip_mode,
             motor_weighting=resolved_motor_weighting,
+            grapheme_aware=grapheme_aware,
+            burstiness=burstiness,
+            homerow_bias=homerow_bias,
+            motor_weights=resolved_motor_weights,
+            syllable_swaps=syllable_swaps,
             **kwargs,
         )
 
@@ -172,20 +259,38 @@ class Typogre(Glitchling):
             raise RuntimeError(message)
 
         motor_weighting = self.kwargs.get("motor_weighting", DEFAULT_TYPOGRE_MOTOR_WEIGHTING)
-
-        return cast(
-            PipelineOperationPayload,
-            {
-                "type": "typo",
-                "rate": float(rate),
-                "keyboard": keyboard,
-                "layout": serialized_layout,
-                "shift_slip_rate": shift_slip_rate,
-                "shift_slip_exit_rate": float(resolved_exit_rate),
-                "shift_map": serialized_shift_map,
-                "motor_weighting": str(motor_weighting),
-            },
-        )
+        shift_slip_min_hold = self.kwargs.get("shift_slip_min_hold")
+        shift_slip_mode = self.kwargs.get("shift_slip_mode")
+
+        payload: dict[str, Any] = {
+            "type": "typo",
+            "rate": float(rate),
+            "keyboard": keyboard,
+            "layout": serialized_layout,
+            "shift_slip_rate": shift_slip_rate,
+            "shift_slip_exit_rate": float(resolved_exit_rate),
+            "shift_map": serialized_shift_map,
+            "motor_weighting": str(motor_weighting),
+        }
+        if shift_slip_min_hold is not None:
+            payload["shift_slip_min_hold"] = int(shift_slip_min_hold)
+        if shift_slip_mode is not None:
+            payload["shift_slip_mode"] = str(shift_slip_mode)
+        if self.kwargs.get("grapheme_aware"):
+            payload["grapheme_aware"] = True
+        burstiness = float(self.kwargs.get("burstiness", 0.0) or 0.0)
+        if burstiness > 0.0:
+            payload["burstiness"] = burstiness
+        homerow_bias = float(self.kwargs.get("homerow_bias", 0.0) or 0.0)
+        if homerow_bias > 0.0:
+            payload["homerow_bias"] = homerow_bias
+        motor_weights = self.kwargs.get("motor_weights")
+        if motor_weights is not None:
+            payload["motor_weights"] = dict(motor_weights)
+        if self.kwargs.get("syllable_swaps"):
+            payload["syllable_swaps"] = True
+
+        return cast(PipelineOperationPayload, payload)
 
 
 typogre = Typogre()
@@ -0,0 +1,70 @@
+"""Tests that the operation schema matches what the pipeline parser accepts."""
+
+import pytest
+
+from glitchlings.internal.rust_ffi import RustPipeline, list_operations_rust
+from glitchlings.util import get_serialized_layout
+
+TEXT = "The quick brown fox jumps over the lazy dog. Don't you think so?"
+MASTER_SEED = 151
+
+# Sample values for required parameters; ``mode`` depends on the operation.
+REQUIRED_SAMPLES = {
+    "rate": 0.1,
+    "replacement_char": "█",
+    "merge_adjacent": False,
+    "intensity": 2,
+    "modes": ["swap"],
+    "extension_min": 2,
+    "extension_max": 5,
+    "word_length_threshold": 6,
+}
+MODE_SAMPLES = {
+    "case": "random",
+    "contractions": "contract",
+    "diacritics": "strip",
+}
+# Parameters an operation needs only when others are unset.
+CONDITIONAL_SAMPLES = {
+    "pedant": {"stone": "Oxfordium"},
+}
+
+SPECS = list_operations_rust()
+
+
+def _minimal_operation(spec: dict, op_type: str) -> dict:
+    operation = {"type": op_type, **CONDITIONAL_SAMPLES.get(spec["type"], {})}
+    for name in spec["required"]:
+        if name == "mode":
+            operation[name] = MODE_SAMPLES[spec["type"]]
+        elif name == "layout":
+            operation[name] = get_serialized_layout("QWERTY")
+        else:
+            operation[name] = REQUIRED_SAMPLES[name]
+    return operation
+
+
+def _run(operation: dict) -> str:
+    descriptor = {"name": operation["type"], "seed": 7, "operation": operation}
+    return RustPipeline([descriptor], MASTER_SEED).run(TEXT)
+
+
+@pytest.mark.parametrize(
+    ("spec", "op_type"),
+    [
+        pytest.param(spec, op_type, id=op_type)
+        for spec in SPECS
+        for op_type in [spec["type"], *spec["aliases"]]
+    ],
+)
+def test_minimal_descriptor_parses(spec: dict, op_type: str) -> None:
+    assert isinstance(_run(_minimal_operation(spec, op_type)), str)
+
+
+@pytest.mark.parametrize("spec", [pytest.param(spec, id=spec["type"]) for spec in SPECS])
+def test_documented_defaults_parse(spec: dict) -> None:
+    operation = _minimal_operation(spec, spec["type"])
+    operation.update(
+        {name: default for name, default in spec["optional"].items() if default is not None}
+    )
+    assert _run(operation) == _run(_minimal_operation(spec, spec["type"]))
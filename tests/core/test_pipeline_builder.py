@@ -0,0 +1,123 @@
+"""Tests for the chainable Rust pipeline builder."""
+
+import pytest
+
+from glitchlings.internal.rust_ffi import (
+    RustPipeline,
+    apply_glitch_rust,
+    compose_operations_batch_rust,
+    derive_seed_rust,
+    keyboard_typo_rust,
+    pipeline_builder_rust,
+)
+from glitchlings.util import get_serialized_layout
+
+TEXT = "The quick brown fox jumps over the lazy dog, again and again."
+MASTER_SEED = 151
+
+
+def _descriptor(name: str, index: int, operation: dict) -> dict:
+    return {
+        "name": name,
+        "seed": derive_seed_rust(MASTER_SEED, name, index),
+        "operation": operation,
+    }
+
+
+def test_builder_matches_dict_descriptors() -> None:
+    layout = get_serialized_layout("QWERTY")
+    builder = (
+        pipeline_builder_rust()
+        .typo(rate=0.1, layout=layout, keyboard="QWERTY", burstiness=0.5)
+        .swap_adjacent(rate=0.3, name="Rushmore")
+        .redact(rate=0.2)
+    )
+    descriptors = [
+        _descriptor(
+            "typo",
+            0,
+            {
+                "type": "typo",
+                "rate": 0.1,
+                "layout": layout,
+                "keyboard": "QWERTY",
+                "burstiness": 0.5,
+            },
+        ),
+        _descriptor("Rushmore", 1, {"type": "swap_adjacent", "rate": 0.3}),
+        _descriptor(
+            "redact",
+            2,
+            {
+                "type": "redact",
+                "replacement_char": "█",
+                "rate": 0.2,
+                "merge_adjacent": False,
+            },
+        ),
+    ]
+
+    built = RustPipeline.from_builder(builder, MASTER_SEED)
+    expected = RustPipeline(descriptors, MASTER_SEED)
+
+    assert len(builder) == 3
+    assert built.run(TEXT) == expected.run(TEXT)
+
+
+def test_builder_accepts_descriptor_dicts_and_pinned_seeds() -> None:
+    builder = (
+        pipeline_builder_rust()
+        .operation({"type": "zwj", "rate": 0.2}, seed=7)
+        .hokey(rate=0.5, seed=11)
+    )
+    descriptors = [
+        {"name": "zwj", "seed": 7, "operation": {"type": "zwj", "rate": 0.2}},
+        {
+            "name": "hokey",
+            "seed": 11,
+            "operation": {
+                "type": "hokey",
+                "rate": 0.5,
+                "extension_min": 2,
+                "extension_max": 5,
+                "word_length_threshold": 6,
+            },
+        },
+    ]
+
+    built = RustPipeline.from_builder(builder, MASTER_SEED)
+    assert built.run(TEXT) == RustPipeline(descriptors, MASTER_SEED).run(TEXT)
+
+
+def test_builder_rejects_invalid_parameters() -> None:
+    with pytest.raises(ValueError):
+        pipeline_builder_rust().case(rate=0.5, mode="sideways")
+    with pytest.raises(ValueError, match="rate"):
+        RustPipeline.from_builder(pipeline_builder_rust().swap_adjacent(rate=2.0), MASTER_SEED)
+
+
+def test_unknown_keyboard_names_are_rejected() -> None:
+    layout = get_serialized_layout("QWERTY")
+    operation = {"type": "typo", "rate": 0.1, "layout": layout, "keyboard": "QWERTYY"}
+
+    with pytest.raises(ValueError, match="unknown keyboard 'QWERTYY'"):
+        pipeline_builder_rust().typo(rate=0.1, layout=layout, keyboard="QWERTYY")
+    with pytest.raises(ValueError, match="unknown keyboard 'QWERTYY'"):
+        apply_glitch_rust(TEXT, operation, seed=MASTER_SEED)
+    with pytest.raises(ValueError, match="unknown keyboard 'QWERTYY'"):
+        keyboard_typo_rust(TEXT, 0.1, layout, MASTER_SEED, keyboard="QWERTYY")
+    assert keyboard_typo_rust(TEXT, 0.1, layout, MASTER_SEED) == keyboard_typo_rust(
+        TEXT, 0.1, layout, MASTER_SEED, keyboard="QWERTY"
+    )
+
+
+def test_pipeline_paths_reject_what_apply_glitch_rejects() -> None:
+    operation = {"type": "swap_adjacent", "rate": 2.5}
+    descriptors = [_descriptor("Rushmore", 0, operation)]
+
+    with pytest.raises(ValueError, match=r"rate must be in \[0, 1\]"):
+        apply_glitch_rust(TEXT, operation, seed=MASTER_SEED)
+    with pytest.raises(ValueError, match=r"Rushmore: rate must be in \[0, 1\]"):
+        RustPipeline(descriptors, MASTER_SEED)
+    with pytest.raises(ValueError, match=r"Rushmore: rate must be in \[0, 1\]"):
+        compose_operations_batch_rust([TEXT], descriptors, MASTER_SEED)
@@ -10,6 +10,9 @@ from glitchlings import (
     wherewolf,
     zeedub,
 )
+from glitchlings.constants import DEFAULT_REDACTYL_CHAR
+from glitchlings.zoo.identity import Identity
+from glitchlings.zoo.mim1c import Mim1c
 from glitchlings.zoo.redactyl import Redactyl
 from glitchlings.zoo.rushmore import Rushmore
 from glitchlings.zoo.scannequin import Scannequin
@@ -57,9 +60,34 @@ def test_all_glitchlings_emit_pipeline_descriptors(glitchling, required_field):
                 "replacement_char": "#",
                 "rate": 0.5,
                 "merge_adjacent": True,
+                "merge_through_punctuation": False,
                 "unweighted": True,
             },
         ),
+        (
+            lambda: Redactyl(rate=0.5, weighting="content"),
+            {
+                "type": "redact",
+                "replacement_char": DEFAULT_REDACTYL_CHAR,
+                "rate": 0.5,
+                "merge_adjacent": False,
+                "merge_through_punctuation": False,
+                "unweighted": False,
+                "weighting": "content",
+            },
+        ),
+        (
+            lambda: Redactyl(rate=0.5, fixed_width=4),
+            {
+                "type": "redact",
+                "replacement_char": DEFAULT_REDACTYL_CHAR,
+                "rate": 0.5,
+                "merge_adjacent": False,
+                "merge_through_punctuation": False,
+                "unweighted": False,
+                "fixed_width": 4,
+            },
+        ),
         (
             lambda: Rushmore(rate=0.33, unweighted=True),
             {
@@ -68,6 +96,15 @@ def test_all_glitchlings_emit_pipeline_descriptors(glitchling, required_field):
                 "unweighted": True,
             },
         ),
+        (
+            lambda: Rushmore(rate=0.33, weighting="content"),
+            {
+                "type": "delete",
+                "rate": 0.33,
+                "unweighted": False,
+                "weighting": "content",
+            },
+        ),
         (
             lambda: Rushmore(modes="duplicate", duplicate_rate=0.25),
             {
@@ -90,6 +127,31 @@ def test_all_glitchlings_emit_pipeline_descriptors(glitchling, required_field):
                 "swap": {"rate": 0.6},
             },
         ),
+        (
+            lambda: Rushmore(
+                modes=("delete", "swap"),
+                delete_rate=0.2,
+                swap_rate=0.6,
+                shuffle_modes=True,
+            ),
+            {
+                "type": "rushmore_combo",
+                "modes": ["delete", "swap"],
+                "delete": {"rate": 0.2, "unweighted": False},
+                "swap": {"rate": 0.6},
+                "shuffle_modes": True,
+            },
+        ),
+        (
+            lambda: Mim1c(rate=0.1, only_characters="amazon"),
+            {
+                "type": "mimic",
+                "rate": 0.1,
+                "mode": "mixed_script",
+                "max_consecutive": 3,
+                "only_characters": ["a", "m", "z", "o", "n"],
+            },
+        ),
         (
             lambda: Scannequin(rate=0.12),
             {
@@ -102,8 +164,14 @@ def test_all_glitchlings_emit_pipeline_descriptors(glitchling, required_field):
                 "bias_beta": 2.0,
                 "space_drop_rate": 0.0,
                 "space_insert_rate": 0.0,
+                "mode": "both",
+                "sampling": "count",
             },
         ),
+        (
+            lambda: Identity(),
+            {"type": "identity"},
+        ),
     ],
 )
 def test_pipeline_operations_emit_expected_descriptors(factory, expected):
@@ -0,0 +1,16 @@
+"""Tests for the edit counts returned by the per-operation entry points."""
+
+import re
+
+from glitchlings.internal.rust_ffi import redact_words_rust
+
+TEXT = "The quick brown fox jumps over the lazy dog."
+
+
+def test_redact_words_return_count_matches_plain_call() -> None:
+    plain = redact_words_rust(TEXT, "█", 0.5, False, True, 151)
+    output, edits = redact_words_rust(TEXT, "█", 0.5, False, True, 151, return_count=True)
+
+    assert output == plain
+    assert edits == len(re.findall("█+", output))
+    assert edits > 0